@@ -0,0 +1,46 @@
+//! Contains [`AddMut`], the attribute option that determine if the `#[get]` attribute
+//! also creates the mutable getter.
+
+use super::attribute_option::ParseOptionUtils;
+
+/// Option to determine if the `#[get]` attribute also creates the mutable getter.
+/// By default only the immutable getter is created.
+///
+/// The mutable getter shares the visibility with the immutable getter and its name is
+/// `{name}_mut` where `{name}` is the name of the immutable getter.
+///
+/// Accept value : like `#[get(both)]` or `#[get(add_mut)]`.
+/// - `both`
+/// - `add_mut`
+/// - `add_mut = "true"/"false"`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum AddMut {
+    /// Only the immutable getter.
+    #[default]
+    ImmutableOnly,
+    /// Both the immutable and the mutable getter.
+    Both,
+}
+
+impl ParseOptionUtils for AddMut {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == "both" || path == "add_mut").then_some(Self::Both)
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        if path == "true" {
+            Some(Self::Both)
+        } else if path == "false" {
+            Some(Self::ImmutableOnly)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == "add_mut"
+    }
+}
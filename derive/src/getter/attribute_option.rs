@@ -48,8 +48,8 @@
 // TODO more explanation about the code.
 
 use macro_utils::field::FieldInformation;
-use proc_macro2::{Ident, TokenStream as TokenStream2};
-use syn::{Expr, ExprLit, Lit, Meta, MetaList, MetaNameValue, Path};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use syn::{spanned::Spanned, Expr, ExprLit, Lit, Meta, MetaList, MetaNameValue, Path};
 
 use super::error::{AcceptableParseError, ParseAttributeOptionError, UnacceptableParseError};
 
@@ -81,6 +81,17 @@ pub trait ParseOptionUtils: Sized {
     #[must_use]
     fn parse_option_from_str_assignment(path: &str) -> Option<Self>;
 
+    /// Try parse the option from a string in the case of an assignment, knowing the [`Span`]
+    /// of the right hand value in the attribute.
+    ///
+    /// By default the span is dropped and it calls [`Self::parse_option_from_str_assignment`].
+    /// Options that create new tokens (like the name of the getter) should override it so
+    /// the generated code points back to the attribute.
+    #[must_use]
+    fn parse_option_from_str_assignment_spanned(path: &str, _span: Span) -> Option<Self> {
+        Self::parse_option_from_str_assignment(path)
+    }
+
     /// return accepted value for the left hand element of the assignment.
     #[must_use]
     fn left_hand_path_accepted(path: &str) -> bool;
@@ -101,7 +112,7 @@ pub trait ParseOptionUtils: Sized {
     /// Try parse a Self from a [`Ident`] as an assignment
     #[must_use]
     fn parse_from_ident_assignment(ident: &Ident) -> Option<Self> {
-        Self::parse_option_from_str_assignment(&ident.to_string())
+        Self::parse_option_from_str_assignment_spanned(&ident.to_string(), ident.span())
     }
 
     /// Try to parse the option element from a [`Meta`] return [`Some`] if the element is valid
@@ -131,7 +142,7 @@ pub trait ParseOptionUtils: Sized {
         ) {
             let string = get_string_literal(&name_value.value)
                 .ok_or(UnacceptableParseError::RightHandNameValueExprNotLitString)?;
-            Self::parse_option_from_str_assignment(&string)
+            Self::parse_option_from_str_assignment_spanned(&string, name_value.value.span())
                 .ok_or_else(|| UnacceptableParseError::RightHandValueInvalid.into())
         } else {
             Err(AcceptableParseError::LeftHandSideValueNotRecognized.into())
@@ -187,4 +198,12 @@ pub trait ToCode {
     /// get the code with the [`FieldInformation`] information
     #[must_use]
     fn to_code(&self, field: &FieldInformation) -> TokenStream2;
+
+    /// get the declaration of the getter as a trait method, i.e. the signature without body
+    #[must_use]
+    fn to_trait_declaration_code(&self, field: &FieldInformation) -> TokenStream2;
+
+    /// get the implementation of the getter as a trait method, i.e. without visibility
+    #[must_use]
+    fn to_trait_impl_code(&self, field: &FieldInformation) -> TokenStream2;
 }
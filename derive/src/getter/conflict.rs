@@ -0,0 +1,370 @@
+//! Contains [`CONFLICTS`], the validation matrix of the options of the `#[get]` attribute:
+//! the pairs of options that cannot be combined. In tests every other pair is listed as
+//! compatible, so that a new option has to be checked against all the others.
+
+use super::{
+    error::OptionValidationError,
+    option_enum::{
+        ImmutableOptionList::{
+            self, AddMut, ConstTy, GetterTy, IntoInner, MissingValue, Nested, NestedTy, Newtype,
+            SelfTy, TryInto, With,
+        },
+        MutableOptionList,
+    },
+};
+
+/// The option `name`
+const NAME: ImmutableOptionList =
+    ImmutableOptionList::MutableOption(MutableOptionList::IdentOption);
+
+/// The option `prefix`
+const PREFIX: ImmutableOptionList = ImmutableOptionList::MutableOption(MutableOptionList::Prefix);
+
+/// The option `hidden`
+#[cfg(test)]
+const HIDDEN: ImmutableOptionList = ImmutableOptionList::MutableOption(MutableOptionList::Hidden);
+
+/// Condition under which a pair of options conflicts, see
+/// [`super::ImmutableGetterOption::validate`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ConflictRule {
+    /// both options are set
+    BothSet,
+    /// the getter is constant and by clone
+    ConstClone,
+    /// the getter takes `self` by value and returns a reference
+    SelfValueReturnRef,
+    /// the number of nested fields and of nested types differ
+    NestedCountMismatch,
+    /// both options give the same function name explicitly
+    SameName,
+    /// the getter converts the field and returns a reference
+    TryIntoReference,
+}
+
+/// A pair of options of the `#[get]` attribute that cannot be combined.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Conflict {
+    /// the two options
+    pub options: [ImmutableOptionList; 2],
+    /// when the options conflict
+    pub rule: ConflictRule,
+    /// the error given by the validation
+    pub error: OptionValidationError,
+    /// short explanation for the documentation of the derive
+    pub description: &'static str,
+}
+
+impl Conflict {
+    /// Create a conflict between `first` and `second`.
+    const fn new(
+        first: ImmutableOptionList,
+        second: ImmutableOptionList,
+        rule: ConflictRule,
+        error: OptionValidationError,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            options: [first, second],
+            rule,
+            error,
+            description,
+        }
+    }
+
+    /// Create the conflict of `nested` with an option of the getter of the field itself.
+    const fn nested(option: ImmutableOptionList) -> Self {
+        Self::new(
+            Nested,
+            option,
+            ConflictRule::BothSet,
+            OptionValidationError::NestedWithFieldGetterOption(option),
+            "no getter is created on the field itself",
+        )
+    }
+
+    /// Create the conflict of two options giving the same function name.
+    const fn same_name(first: ImmutableOptionList, second: ImmutableOptionList) -> Self {
+        Self::new(
+            first,
+            second,
+            ConflictRule::SameName,
+            OptionValidationError::FunctionNameClash(first, second),
+            "the names given to the two functions must differ",
+        )
+    }
+}
+
+/// The pairs of options that cannot be combined, in the order they are validated.
+pub const CONFLICTS: [Conflict; 20] = [
+    Conflict::new(
+        SelfTy,
+        GetterTy,
+        ConflictRule::SelfValueReturnRef,
+        OptionValidationError::SelfMoveOnReturnRef,
+        "a getter taking self by value cannot return a reference",
+    ),
+    Conflict::new(
+        ConstTy,
+        GetterTy,
+        ConflictRule::ConstClone,
+        OptionValidationError::ConstClone,
+        "a constant getter cannot clone the field",
+    ),
+    Conflict::new(
+        TryInto,
+        GetterTy,
+        ConflictRule::TryIntoReference,
+        OptionValidationError::TryIntoByReference,
+        "the field is converted by value, use a getter by copy or by clone",
+    ),
+    Conflict::new(
+        ConstTy,
+        TryInto,
+        ConflictRule::BothSet,
+        OptionValidationError::ConstTryInto,
+        "a constant getter cannot call `TryInto::try_into`",
+    ),
+    Conflict::new(
+        MissingValue,
+        TryInto,
+        ConflictRule::BothSet,
+        OptionValidationError::TryIntoWithMissingValue,
+        "both change the return type of the getter",
+    ),
+    Conflict::new(
+        Newtype,
+        AddMut,
+        ConflictRule::BothSet,
+        OptionValidationError::NewtypeWithMut,
+        "use a separate `#[get_mut]` attribute for the mutable getter",
+    ),
+    Conflict::new(
+        IntoInner,
+        AddMut,
+        ConflictRule::BothSet,
+        OptionValidationError::IntoInnerWithMut,
+        "use a separate `#[get_mut]` attribute for the mutable getter",
+    ),
+    Conflict::new(
+        Nested,
+        NestedTy,
+        ConflictRule::NestedCountMismatch,
+        OptionValidationError::NestedTyMismatch,
+        "one type has to be given per nested field",
+    ),
+    Conflict::nested(NAME),
+    Conflict::nested(PREFIX),
+    Conflict::nested(MissingValue),
+    Conflict::nested(IntoInner),
+    Conflict::nested(With),
+    Conflict::nested(Newtype),
+    Conflict::nested(AddMut),
+    Conflict::nested(TryInto),
+    Conflict::same_name(NAME, IntoInner),
+    Conflict::same_name(NAME, With),
+    Conflict::same_name(IntoInner, With),
+    Conflict::same_name(AddMut, With),
+];
+
+/// The pairs of options that can be combined, each option with the compatible options after
+/// it in [`ImmutableOptionList::ALL`].
+#[cfg(test)]
+const COMPATIBLE: [(ImmutableOptionList, &[ImmutableOptionList]); 14] = [
+    (
+        ImmutableOptionList::MutableOption(MutableOptionList::Visibility),
+        &[
+            NAME,
+            HIDDEN,
+            PREFIX,
+            ConstTy,
+            GetterTy,
+            SelfTy,
+            AddMut,
+            IntoInner,
+            MissingValue,
+            Nested,
+            NestedTy,
+            Newtype,
+            With,
+            TryInto,
+        ],
+    ),
+    (
+        NAME,
+        &[
+            HIDDEN,
+            PREFIX,
+            ConstTy,
+            GetterTy,
+            SelfTy,
+            AddMut,
+            MissingValue,
+            NestedTy,
+            Newtype,
+            TryInto,
+        ],
+    ),
+    (
+        HIDDEN,
+        &[
+            PREFIX,
+            ConstTy,
+            GetterTy,
+            SelfTy,
+            AddMut,
+            IntoInner,
+            MissingValue,
+            Nested,
+            NestedTy,
+            Newtype,
+            With,
+            TryInto,
+        ],
+    ),
+    (
+        PREFIX,
+        &[
+            ConstTy,
+            GetterTy,
+            SelfTy,
+            AddMut,
+            IntoInner,
+            MissingValue,
+            NestedTy,
+            Newtype,
+            With,
+            TryInto,
+        ],
+    ),
+    (
+        ConstTy,
+        &[
+            SelfTy,
+            AddMut,
+            IntoInner,
+            MissingValue,
+            Nested,
+            NestedTy,
+            Newtype,
+            With,
+        ],
+    ),
+    (
+        GetterTy,
+        &[
+            AddMut,
+            IntoInner,
+            MissingValue,
+            Nested,
+            NestedTy,
+            Newtype,
+            With,
+        ],
+    ),
+    (
+        SelfTy,
+        &[
+            AddMut,
+            IntoInner,
+            MissingValue,
+            Nested,
+            NestedTy,
+            Newtype,
+            With,
+            TryInto,
+        ],
+    ),
+    (AddMut, &[MissingValue, NestedTy, TryInto]),
+    (IntoInner, &[MissingValue, NestedTy, Newtype, TryInto]),
+    (MissingValue, &[NestedTy, Newtype, With]),
+    (Nested, &[]),
+    (NestedTy, &[Newtype, With, TryInto]),
+    (Newtype, &[With, TryInto]),
+    (With, &[TryInto]),
+];
+
+/// Get the documentation of the conflicts, one line `/// - {option} and {option}: {description}`
+/// per conflict as in the documentation of the derive.
+#[cfg(test)]
+fn documentation() -> String {
+    CONFLICTS
+        .iter()
+        .map(|conflict| {
+            let [first, second] = conflict.options;
+            format!("/// - {first} and {second}: {}", conflict.description)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{documentation, ImmutableOptionList, MutableOptionList, COMPATIBLE, CONFLICTS};
+
+    /// Get the index of the option in [`ImmutableOptionList::ALL`]. The match is exhaustive so
+    /// that a new option has to be added to the list.
+    const fn index(option: ImmutableOptionList) -> usize {
+        match option {
+            ImmutableOptionList::MutableOption(MutableOptionList::Visibility) => 0,
+            ImmutableOptionList::MutableOption(MutableOptionList::IdentOption) => 1,
+            ImmutableOptionList::MutableOption(MutableOptionList::Hidden) => 2,
+            ImmutableOptionList::MutableOption(MutableOptionList::Prefix) => 3,
+            ImmutableOptionList::ConstTy => 4,
+            ImmutableOptionList::GetterTy => 5,
+            ImmutableOptionList::SelfTy => 6,
+            ImmutableOptionList::AddMut => 7,
+            ImmutableOptionList::IntoInner => 8,
+            ImmutableOptionList::MissingValue => 9,
+            ImmutableOptionList::Nested => 10,
+            ImmutableOptionList::NestedTy => 11,
+            ImmutableOptionList::Newtype => 12,
+            ImmutableOptionList::With => 13,
+            ImmutableOptionList::TryInto => 14,
+        }
+    }
+
+    #[test]
+    fn matrix_covers_every_pair() {
+        for (i, option) in ImmutableOptionList::ALL.into_iter().enumerate() {
+            assert_eq!(index(option), i, "{option} is not at its index");
+        }
+
+        let count = |first: ImmutableOptionList, second: ImmutableOptionList| {
+            let pair = |[a, b]: [ImmutableOptionList; 2]| {
+                (a == first && b == second) || (a == second && b == first)
+            };
+            let conflicts = CONFLICTS
+                .iter()
+                .filter(|conflict| pair(conflict.options))
+                .count();
+            let compatible = COMPATIBLE
+                .iter()
+                .flat_map(|(option, others)| others.iter().map(|other| [*option, *other]))
+                .filter(|options| pair(*options))
+                .count();
+            conflicts + compatible
+        };
+        for (i, first) in ImmutableOptionList::ALL.into_iter().enumerate() {
+            assert_eq!(count(first, first), 0, "{first} conflicts with itself");
+            for second in ImmutableOptionList::ALL.into_iter().skip(i + 1) {
+                assert_eq!(
+                    count(first, second),
+                    1,
+                    "the pair {first} and {second} should be in the matrix exactly once"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_documentation() {
+        let lib = include_str!("../lib.rs");
+        assert!(
+            lib.contains(&documentation()),
+            "the documentation of the derive is not up to date with the conflicts, it should \
+            contain:\n{}",
+            documentation()
+        );
+    }
+}
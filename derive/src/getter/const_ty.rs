@@ -4,7 +4,7 @@ use std::fmt::{self, Display};
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens};
-use syn::{Expr, ExprLit, Lit, MetaNameValue};
+use syn::{spanned::Spanned, Expr, ExprLit, Lit, MetaNameValue};
 
 use super::{
     attribute_option::{get_string_literal, ParseOptionUtils},
@@ -86,7 +86,7 @@ impl ParseOptionUtils for ConstTy {
                 // this is the default behavior, see [`ParseOptionUtils::parse_name_value`]
                 let string = get_string_literal(&name_value.value)
                     .ok_or(UnacceptableParseError::RightHandNameValueExprNotLitString)?;
-                Self::parse_option_from_str_assignment(&string)
+                Self::parse_option_from_str_assignment_spanned(&string, name_value.value.span())
                     .ok_or_else(|| UnacceptableParseError::RightHandValueInvalid.into())
             }
         } else {
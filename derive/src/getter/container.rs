@@ -0,0 +1,887 @@
+//! Contains [`ContainerOption`], the option given by the `#[getter]` attribute on the
+//! struct itself.
+
+use std::fmt::{self, Debug};
+
+use macro_utils::case::Case;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::ParseStream,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{self, Paren},
+    Attribute, LitStr, MacroDelimiter, Member, Meta, MetaList, MetaNameValue, Path, Token, Type,
+};
+
+use super::{
+    attribute_option::ParseOptionUtils,
+    error::{AddConfigError, ContainerParseError, ParseAttributeOptionError, SpannedError},
+    newtype::NewtypeFlag,
+    option::ParseGetterOption,
+    option_enum::ContainerOptionList,
+    ParseOption,
+};
+
+/// Name of the module in which the getter trait is generated,
+/// like `#[getter(module = "accessors")]` or `#[getter(module(accessors))]`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct ModuleName(Ident);
+
+impl ParseOptionUtils for ModuleName {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        syn::parse_str::<Ident>(path)
+            .ok()
+            .map(|ident| Self(Ident::new(&ident.to_string(), span)))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == "module"
+    }
+}
+
+/// Name of the object safe trait defined with the immutable getters and implemented for the
+/// struct, like `#[getter(trait_def = "HasPosition")]` or `#[getter(trait_def(HasPosition))]`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct TraitDef(Ident);
+
+impl ParseOptionUtils for TraitDef {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        syn::parse_str::<Ident>(path)
+            .ok()
+            .map(|ident| Self(Ident::new(&ident.to_string(), span)))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == "trait_def"
+    }
+}
+
+/// Flag to seal the getter trait, like `#[getter(sealed)]`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct SealedFlag;
+
+impl ParseOptionUtils for SealedFlag {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == "sealed").then_some(Self)
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(_path: &str) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(_path: &str) -> bool {
+        false
+    }
+}
+
+/// Flag to not emit the `#[allow(...)]` shim on the generated code, like
+/// `#[getter(no_lint_allow)]`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct NoLintAllowFlag;
+
+impl ParseOptionUtils for NoLintAllowFlag {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == "no_lint_allow").then_some(Self)
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(_path: &str) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(_path: &str) -> bool {
+        false
+    }
+}
+
+/// Flag to generate nothing instead of an error when no field has a getter attribute, like
+/// `#[getter(allow_empty)]`. It is meant for attributes added with `#[cfg_attr(...)]`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct AllowEmptyFlag;
+
+impl ParseOptionUtils for AllowEmptyFlag {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == "allow_empty").then_some(Self)
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(_path: &str) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(_path: &str) -> bool {
+        false
+    }
+}
+
+/// Flag to emit a deprecation warning on the field attributes that do not change the
+/// generated getters, like `#[getter(warn_redundant)]`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct WarnRedundantFlag;
+
+impl ParseOptionUtils for WarnRedundantFlag {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == "warn_redundant").then_some(Self)
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(_path: &str) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(_path: &str) -> bool {
+        false
+    }
+}
+
+/// Type on which the getters are generated instead of the struct,
+/// like `#[getter(for = "Handle")]` or `#[getter(for(Handle))]`.
+#[derive(Clone)]
+struct TargetType(Type);
+
+impl ParseOptionUtils for TargetType {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        LitStr::new(path, span).parse().ok().map(Self)
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == "for"
+    }
+}
+
+/// Path from the target type to the struct, like `#[getter(via = "0")]` or
+/// `#[getter(via = "inner.0")]`.
+#[derive(Clone)]
+struct Via(Vec<Member>);
+
+impl ParseOptionUtils for Via {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        LitStr::new(path, span)
+            .parse_with(Punctuated::<Member, Token![.]>::parse_separated_nonempty)
+            .ok()
+            .map(|members| Self(members.into_iter().collect()))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == "via"
+    }
+}
+
+/// Case of the default names of the getters, like `#[getter(rename_all = "camelCase")]`,
+/// see [`Case::from_name`] for the accepted values.
+#[derive(Clone, Copy)]
+struct RenameAll(Case);
+
+impl ParseOptionUtils for RenameAll {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Case::from_name(path).map(Self)
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == "rename_all"
+    }
+}
+
+/// Prefix of the default names of the getters of the fields of a tuple struct, like
+/// `#[getter(tuple_names = "item_")]` giving `item_0`, or `#[getter(tuple_names)]` for the
+/// default prefix [`Self::DEFAULT`]. The name of the first field must be a valid ident that is
+/// not a keyword.
+#[derive(Clone)]
+struct TupleNames(String);
+
+impl TupleNames {
+    /// Default prefix of the option `tuple_names`, giving `field_0`
+    const DEFAULT: &'static str = "field_";
+
+    /// Path string of the option
+    const PATH: &'static str = "tuple_names";
+}
+
+impl ParseOptionUtils for TupleNames {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == Self::PATH).then(|| Self(Self::DEFAULT.to_owned()))
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        syn::parse_str::<Ident>(&format!("{path}0"))
+            .is_ok()
+            .then(|| Self(path.to_owned()))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::PATH
+    }
+}
+
+/// Parse an option of the container attribute as a [`Meta`]. Unlike [`Meta`] itself it
+/// accepts the keyword `for` as the path of the option.
+fn parse_meta(input: ParseStream<'_>) -> syn::Result<Meta> {
+    if !input.peek(Token![for]) {
+        return input.parse();
+    }
+    let keyword: Token![for] = input.parse()?;
+    let path = Path::from(Ident::new("for", keyword.span));
+    if input.peek(Token![=]) {
+        Ok(Meta::NameValue(MetaNameValue {
+            path,
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        }))
+    } else if input.peek(Paren) {
+        let content;
+        let paren: token::Paren = parenthesized!(content in input);
+        Ok(Meta::List(MetaList {
+            path,
+            delimiter: MacroDelimiter::Paren(paren),
+            tokens: content.parse()?,
+        }))
+    } else {
+        Ok(Meta::Path(path))
+    }
+}
+
+/// Option of the `#[getter]` attribute on the struct.
+///
+/// - `module = "{name}"` or `module({name})`: generate the getters as an extension trait
+///   `{Struct}Getters` in a child module `{name}` instead of an inherent implementation.
+/// - `sealed`: seal the extension trait, it requires `module`.
+/// - `no_lint_allow`: do not emit the `#[allow(...)]` shim on the generated implementation.
+/// - `for = "{type}"`: generate the getters on the type `{type}`, a wrapper of the struct.
+/// - `via = "{path}"`: the path from the wrapper to the struct, like `0` or `inner`, it
+///   requires `for`.
+/// - `trait_def = "{name}"` or `trait_def({name})`: also define the object safe trait `{name}`
+///   with one method per immutable getter and implement it for the struct.
+/// - `allow_empty`: generate nothing instead of an error if no field has a getter attribute.
+/// - `rename_all = "{case}"`: convert the default names of the getters to `snake_case`,
+///   `camelCase`, `PascalCase` or `SCREAMING_SNAKE_CASE`.
+/// - `tuple_names = "{prefix}"` or `tuple_names`: name the getters of the fields of a tuple
+///   struct `{prefix}0`, `{prefix}1`, ... by default, the prefix is `field_` if not given.
+/// - `warn_redundant`: emit a deprecation warning on the field attributes that do not change
+///   the generated getters.
+/// - `newtype`: give the single field of the struct the getters of `#[get(newtype)]`, see
+///   [`NewtypeFlag`].
+#[allow(clippy::struct_excessive_bools)] // reason = "one flag per container option"
+#[derive(Clone, Default)]
+pub struct ContainerOption {
+    /// name of the module for the getter trait
+    module: Option<Ident>,
+    /// if the getter trait is sealed
+    sealed: bool,
+    /// if the lint allow shim is not emitted
+    no_lint_allow: bool,
+    /// the type on which the getters are generated, the struct itself if [`None`]
+    target: Option<Type>,
+    /// the path from the target type to the struct
+    via: Vec<Member>,
+    /// name of the object safe trait defined with the immutable getters
+    trait_def: Option<Ident>,
+    /// if no code is generated when no field has a getter attribute
+    allow_empty: bool,
+    /// case of the default names of the getters, the field names are kept if [`None`]
+    rename_all: Option<Case>,
+    /// if a warning is emitted on the redundant field attributes
+    warn_redundant: bool,
+    /// prefix of the default names of the fields of a tuple struct, they have no default
+    /// name if [`None`]
+    tuple_names: Option<String>,
+    /// if the single field has the conventional getters of a newtype
+    newtype: bool,
+}
+
+impl Debug for ContainerOption {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContainerOption")
+            .field("module", &self.module)
+            .field("sealed", &self.sealed)
+            .field("no_lint_allow", &self.no_lint_allow)
+            .field(
+                "target",
+                &self
+                    .target
+                    .as_ref()
+                    .map(|target| target.to_token_stream().to_string()),
+            )
+            .field(
+                "via",
+                &self
+                    .via
+                    .iter()
+                    .map(|member| member.to_token_stream().to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .field("trait_def", &self.trait_def)
+            .field("allow_empty", &self.allow_empty)
+            .field("rename_all", &self.rename_all)
+            .field("warn_redundant", &self.warn_redundant)
+            .field("tuple_names", &self.tuple_names)
+            .field("newtype", &self.newtype)
+            .finish()
+    }
+}
+
+impl ContainerOption {
+    /// Path string for the container attribute
+    const PATH: &'static str = "getter";
+
+    /// Parse the option from the attributes of the struct. Attributes that are not
+    /// `#[getter(...)]` are ignored.
+    ///
+    /// The error is given with the span of the attribute (or attribute option) that caused it.
+    pub fn from_attributes(
+        attributes: &[Attribute],
+    ) -> Result<Self, SpannedError<ContainerParseError>> {
+        let mut out = Self::default();
+        for attribute in attributes {
+            if !attribute.path().is_ident(Self::PATH) {
+                continue;
+            }
+            let Meta::List(meta_list) = &attribute.meta else {
+                return Err(SpannedError::new(
+                    ContainerParseError::NotList,
+                    attribute.span(),
+                ));
+            };
+            let list = meta_list
+                .parse_args_with(|input: ParseStream<'_>| {
+                    Punctuated::<Meta, Token![,]>::parse_terminated_with(input, parse_meta)
+                })
+                .map_err(|err| {
+                    let span = err.span();
+                    SpannedError::new(err.into(), span)
+                })?;
+            let option = Self::parse(list).map_err(SpannedError::err_into)?;
+            if option.module.is_some() {
+                out.module = option.module;
+            }
+            out.sealed |= option.sealed;
+            out.no_lint_allow |= option.no_lint_allow;
+            out.allow_empty |= option.allow_empty;
+            out.warn_redundant |= option.warn_redundant;
+            out.newtype |= option.newtype;
+            if option.target.is_some() {
+                out.target = option.target;
+            }
+            if !option.via.is_empty() {
+                out.via = option.via;
+            }
+            if option.trait_def.is_some() {
+                out.trait_def = option.trait_def;
+            }
+            if option.rename_all.is_some() {
+                out.rename_all = option.rename_all;
+            }
+            if option.tuple_names.is_some() {
+                out.tuple_names = option.tuple_names;
+            }
+        }
+
+        let error = if out.sealed && out.module.is_none() {
+            Some(ContainerParseError::SealedWithoutModule)
+        } else if !out.via.is_empty() && out.target.is_none() {
+            Some(ContainerParseError::ViaWithoutTarget)
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            let span = attributes
+                .iter()
+                .find(|attribute| attribute.path().is_ident(Self::PATH))
+                .map_or_else(Span::call_site, Spanned::span);
+            return Err(SpannedError::new(error, span));
+        }
+
+        Ok(out)
+    }
+
+    /// Getter on the module name. If it is [`Some`] the getters are generated as a trait
+    /// in this module.
+    #[must_use]
+    pub const fn module(&self) -> Option<&Ident> {
+        self.module.as_ref()
+    }
+
+    /// Getter on the sealed flag.
+    #[must_use]
+    pub const fn sealed(&self) -> bool {
+        self.sealed
+    }
+
+    /// Get the type on which the getters are generated, the struct itself if [`None`].
+    #[must_use]
+    pub const fn target(&self) -> Option<&Type> {
+        self.target.as_ref()
+    }
+
+    /// Get the path from the target type to the struct, empty if the getters are
+    /// generated on the struct itself or if the target dereferences to the struct.
+    #[must_use]
+    pub fn via(&self) -> &[Member] {
+        &self.via
+    }
+
+    /// Get the name of the object safe trait defined with the immutable getters, if the
+    /// option `trait_def` is set.
+    #[must_use]
+    pub const fn trait_def(&self) -> Option<&Ident> {
+        self.trait_def.as_ref()
+    }
+
+    /// Get the case of the default names of the getters given by the option `rename_all`,
+    /// the field names are kept if [`None`].
+    #[must_use]
+    pub const fn rename_all(&self) -> Option<Case> {
+        self.rename_all
+    }
+
+    /// Get the prefix of the default names of the fields of a tuple struct given by the
+    /// option `tuple_names`, the fields of a tuple struct have no default name if [`None`].
+    #[must_use]
+    pub fn tuple_names(&self) -> Option<&str> {
+        self.tuple_names.as_deref()
+    }
+
+    /// Getter on the allow empty flag. If it is set no code is generated, instead of an
+    /// error, when no field has a getter attribute.
+    #[must_use]
+    pub const fn allow_empty(&self) -> bool {
+        self.allow_empty
+    }
+
+    /// Getter on the flag `warn_redundant`, if a warning is emitted on the field attributes
+    /// that do not change the generated getters.
+    #[must_use]
+    pub const fn warn_redundant(&self) -> bool {
+        self.warn_redundant
+    }
+
+    /// Getter on the flag `newtype`, if the single field of the struct has the conventional
+    /// getters of a newtype.
+    #[must_use]
+    pub const fn newtype(&self) -> bool {
+        self.newtype
+    }
+
+    /// Get the `#[allow(non_snake_case)]` attribute put on the generated inherent
+    /// implementation and trait definitions if the option `rename_all` gives names that
+    /// are not in snake case, empty otherwise. Unlike [`Self::lint_allow`] it is not
+    /// affected by `no_lint_allow` as the case is explicitly asked for.
+    #[must_use]
+    pub fn case_allow(&self) -> TokenStream2 {
+        match self.rename_all {
+            None | Some(Case::Snake) => TokenStream2::new(),
+            Some(_) => quote! {
+                #[allow(non_snake_case, reason = "option rename_all")]
+            },
+        }
+    }
+
+    /// Get the `#[allow(...)]` attribute put on the generated implementations, empty if the
+    /// option `no_lint_allow` is set.
+    ///
+    /// The pedantic and nursery lints of the downstream crate are silenced on the generated
+    /// code, for instance `missing_const_for_fn` on a getter that could be `Const`: the fix
+    /// belongs to the attribute of the field and the getter is still correct without it.
+    #[must_use]
+    pub fn lint_allow(&self) -> TokenStream2 {
+        if self.no_lint_allow {
+            TokenStream2::new()
+        } else {
+            quote! {
+                #[allow(
+                    clippy::missing_const_for_fn,
+                    clippy::must_use_candidate,
+                    clippy::trivially_copy_pass_by_ref,
+                    reason = "generated code"
+                )]
+            }
+        }
+    }
+}
+
+impl ParseGetterOption for ContainerOption {
+    type Option = ContainerOptionList;
+
+    #[allow(clippy::too_many_lines)] // reason = "one block per option"
+    fn add_config(&mut self, option: &Meta) -> Result<Self::Option, AddConfigError<Self::Option>> {
+        match ModuleName::parse_option(option) {
+            Ok(ModuleName(module)) => {
+                self.module = Some(module);
+                return Ok(ContainerOptionList::Module);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::Module,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match SealedFlag::parse_option(option) {
+            Ok(SealedFlag) => {
+                self.sealed = true;
+                return Ok(ContainerOptionList::Sealed);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::Sealed,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match NoLintAllowFlag::parse_option(option) {
+            Ok(NoLintAllowFlag) => {
+                self.no_lint_allow = true;
+                return Ok(ContainerOptionList::NoLintAllow);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::NoLintAllow,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match AllowEmptyFlag::parse_option(option) {
+            Ok(AllowEmptyFlag) => {
+                self.allow_empty = true;
+                return Ok(ContainerOptionList::AllowEmpty);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::AllowEmpty,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match WarnRedundantFlag::parse_option(option) {
+            Ok(WarnRedundantFlag) => {
+                self.warn_redundant = true;
+                return Ok(ContainerOptionList::WarnRedundant);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::WarnRedundant,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match NewtypeFlag::parse_option(option) {
+            Ok(NewtypeFlag) => {
+                self.newtype = true;
+                return Ok(ContainerOptionList::Newtype);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::Newtype,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match TargetType::parse_option(option) {
+            Ok(TargetType(target)) => {
+                self.target = Some(target);
+                return Ok(ContainerOptionList::Target);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::Target,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match Via::parse_option(option) {
+            Ok(Via(via)) => {
+                self.via = via;
+                return Ok(ContainerOptionList::Via);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(err, ContainerOptionList::Via));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match TraitDef::parse_option(option) {
+            Ok(TraitDef(name)) => {
+                self.trait_def = Some(name);
+                return Ok(ContainerOptionList::TraitDef);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::TraitDef,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match TupleNames::parse_option(option) {
+            Ok(TupleNames(prefix)) => {
+                self.tuple_names = Some(prefix);
+                return Ok(ContainerOptionList::TupleNames);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ContainerOptionList::TupleNames,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match RenameAll::parse_option(option) {
+            Ok(RenameAll(case)) => {
+                self.rename_all = Some(case);
+                Ok(ContainerOptionList::RenameAll)
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => Err(AddConfigError::Unacceptable(
+                err,
+                ContainerOptionList::RenameAll,
+            )),
+            Err(ParseAttributeOptionError::Acceptable(err)) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use macro_utils::case::Case;
+    use quote::ToTokens;
+    use syn::{parse_quote, Attribute};
+
+    use super::ContainerOption;
+    use crate::getter::{
+        error::{ContainerParseErrorKind, GetterParseErrorKind, UnacceptableParseErrorKind},
+        option_enum::ContainerOptionList,
+    };
+
+    /// Get the kind of the error of the parsing of the attributes.
+    fn container_error(attributes: &[Attribute]) -> ContainerParseErrorKind {
+        ContainerOption::from_attributes(attributes)
+            .expect_err("expected an error")
+            .error()
+            .kind()
+    }
+
+    #[test]
+    fn container_parse_error_kind() {
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter = "accessors"])]),
+            ContainerParseErrorKind::NotList
+        );
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter(sealed)])]),
+            ContainerParseErrorKind::SealedWithoutModule
+        );
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter(module = "1m")])]),
+            ContainerParseErrorKind::GetterParseError(GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ContainerOptionList::Module
+            ))
+        );
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter(sealed, sealed, module = "m")])]),
+            ContainerParseErrorKind::GetterParseError(
+                GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                    ContainerOptionList::Sealed
+                )
+            )
+        );
+        assert!(matches!(
+            container_error(&[parse_quote!(#[getter(1)])]),
+            ContainerParseErrorKind::SynError(_)
+        ));
+        // the other attributes are ignored
+        let option = ContainerOption::from_attributes(&[parse_quote!(#[derive(Debug)])])
+            .expect("no getter attribute");
+        assert!(option.module().is_none());
+
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter(via = "0")])]),
+            ContainerParseErrorKind::ViaWithoutTarget
+        );
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter(for = "Handle", via = "0 1")])]),
+            ContainerParseErrorKind::GetterParseError(GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ContainerOptionList::Via
+            ))
+        );
+    }
+
+    #[test]
+    fn target() {
+        let option = ContainerOption::from_attributes(&[parse_quote!(
+            #[getter(for = "Handle<u8>", via = "inner.0")]
+        )])
+        .expect("valid option");
+        assert_eq!(
+            option.target().map(|ty| ty.to_token_stream().to_string()),
+            Some("Handle < u8 >".to_owned())
+        );
+        assert_eq!(
+            option
+                .via()
+                .iter()
+                .map(|member| member.to_token_stream().to_string())
+                .collect::<Vec<_>>(),
+            vec!["inner".to_owned(), "0".to_owned()]
+        );
+
+        let option = ContainerOption::from_attributes(&[parse_quote!(#[getter(for(Handle))])])
+            .expect("valid option");
+        assert!(option.target().is_some());
+        assert!(option.via().is_empty());
+
+        let option = ContainerOption::from_attributes(&[]).expect("no attribute");
+        assert!(option.target().is_none());
+    }
+
+    #[test]
+    fn trait_def() {
+        let option =
+            ContainerOption::from_attributes(&[parse_quote!(#[getter(trait_def = "HasPosition")])])
+                .expect("valid option");
+        assert_eq!(
+            option.trait_def().map(ToString::to_string),
+            Some("HasPosition".to_owned())
+        );
+
+        let option =
+            ContainerOption::from_attributes(&[parse_quote!(#[getter(trait_def(HasPosition))])])
+                .expect("valid option");
+        assert!(option.trait_def().is_some());
+
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter(trait_def = "Has Position")])]),
+            ContainerParseErrorKind::GetterParseError(GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ContainerOptionList::TraitDef
+            ))
+        );
+    }
+
+    #[test]
+    fn rename_all() {
+        for case in Case::ALL {
+            let name = case.name();
+            let option =
+                ContainerOption::from_attributes(&[parse_quote!(#[getter(rename_all = #name)])])
+                    .expect("valid option");
+            assert_eq!(option.rename_all(), Some(case));
+        }
+        let option = ContainerOption::from_attributes(&[]).expect("no attribute");
+        assert_eq!(option.rename_all(), None);
+
+        assert_eq!(
+            container_error(&[parse_quote!(#[getter(rename_all = "kebab-case")])]),
+            ContainerParseErrorKind::GetterParseError(GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ContainerOptionList::RenameAll
+            ))
+        );
+    }
+
+    #[test]
+    fn warn_redundant() {
+        let option = ContainerOption::from_attributes(&[
+            parse_quote!(#[getter(Pub)]),
+            parse_quote!(#[getter(warn_redundant)]),
+        ])
+        .expect("valid option");
+        assert!(option.warn_redundant());
+
+        let option = ContainerOption::from_attributes(&[]).expect("no attribute");
+        assert!(!option.warn_redundant());
+    }
+
+    #[test]
+    fn tuple_names() {
+        let option = ContainerOption::from_attributes(&[parse_quote!(#[getter(tuple_names)])])
+            .expect("valid option");
+        assert_eq!(option.tuple_names(), Some("field_"));
+
+        let option =
+            ContainerOption::from_attributes(&[parse_quote!(#[getter(tuple_names = "_")])])
+                .expect("valid option");
+        assert_eq!(option.tuple_names(), Some("_"));
+
+        let option = ContainerOption::from_attributes(&[]).expect("no attribute");
+        assert_eq!(option.tuple_names(), None);
+
+        for prefix in ["", "1", "item-", "r#"] {
+            assert_eq!(
+                container_error(&[parse_quote!(#[getter(tuple_names = #prefix)])]),
+                ContainerParseErrorKind::GetterParseError(GetterParseErrorKind::AddConfigError(
+                    UnacceptableParseErrorKind::RightHandValueInvalid,
+                    ContainerOptionList::TupleNames
+                )),
+                "{prefix}"
+            );
+        }
+    }
+}
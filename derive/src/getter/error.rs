@@ -5,7 +5,9 @@ use std::{
     fmt::{self, Debug, Display},
 };
 
-use super::option_enum::{ImmutableOptionList, MutableOptionList, OptionList};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use super::option_enum::{ContainerOptionList, ImmutableOptionList, MutableOptionList, OptionList};
 
 // TODO names
 /// Error return by [`super::option::GetterOption::parse`].
@@ -13,7 +15,8 @@ use super::option_enum::{ImmutableOptionList, MutableOptionList, OptionList};
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum OptionParseError {
-    /// the attribute is a name value which is not supported yet
+    /// the attribute is a name value whose value is not a literal string, only the shorthand
+    /// `#[get = "name"]` is supported
     NameValue,
     /// no attribute found and at least one is necessary
     NotFound,
@@ -26,6 +29,38 @@ pub enum OptionParseError {
     OptionValidationError(OptionValidationError),
 }
 
+impl OptionParseError {
+    /// Get the comparable kind of the error, see [`OptionParseErrorKind`].
+    #[must_use]
+    pub fn kind(&self) -> OptionParseErrorKind {
+        match self {
+            Self::NameValue => OptionParseErrorKind::NameValue,
+            Self::NotFound => OptionParseErrorKind::NotFound,
+            Self::ExprParseError(err) => OptionParseErrorKind::SynError(err.to_string()),
+            Self::GetterParseError(err) => OptionParseErrorKind::GetterParseError(err.kind()),
+            Self::OptionValidationError(err) => OptionParseErrorKind::OptionValidationError(*err),
+        }
+    }
+}
+
+/// Comparable kind of [`OptionParseError`] given by [`OptionParseError::kind`], the
+/// [`syn::Error`] are converted to their message.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum OptionParseErrorKind {
+    /// see [`OptionParseError::NameValue`]
+    NameValue,
+    /// see [`OptionParseError::NotFound`]
+    NotFound,
+    /// message of [`OptionParseError::ExprParseError`]
+    SynError(String),
+    /// see [`OptionParseError::GetterParseError`]
+    GetterParseError(GetterParseErrorKind<ImmutableOptionList>),
+    /// see [`OptionParseError::OptionValidationError`]
+    OptionValidationError(OptionValidationError),
+}
+
 impl From<OptionValidationError> for OptionParseError {
     #[inline]
     fn from(value: OptionValidationError) -> Self {
@@ -54,7 +89,7 @@ impl Display for OptionParseError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self{
-            Self::NameValue => write!(f, "field attribute is not supported in name value mode, please refer to the documentation"),
+            Self::NameValue => write!(f, "field attribute in name value mode only accepts a literal string as the name of the getter, like #[get = \"name\"], please refer to the documentation"),
             Self::NotFound => write!(f, "attribute #[get] or #[get_mut] not found and at least one is necessary"),
             Self::ExprParseError(ref err) => write!(f, "{err}"),
             Self::GetterParseError(ref err) => write!(f, "{err}"),
@@ -134,6 +169,39 @@ pub enum UnacceptableParseError {
     IdentParseError(syn::Error),
 }
 
+impl UnacceptableParseError {
+    /// Get the comparable kind of the error, see [`UnacceptableParseErrorKind`].
+    #[must_use]
+    pub fn kind(&self) -> UnacceptableParseErrorKind {
+        match self {
+            Self::LeftHandSideValueNotIdent => {
+                UnacceptableParseErrorKind::LeftHandSideValueNotIdent
+            }
+            Self::RightHandValueInvalid => UnacceptableParseErrorKind::RightHandValueInvalid,
+            Self::RightHandNameValueExprNotLitString => {
+                UnacceptableParseErrorKind::RightHandNameValueExprNotLitString
+            }
+            Self::IdentParseError(err) => UnacceptableParseErrorKind::SynError(err.to_string()),
+        }
+    }
+}
+
+/// Comparable kind of [`UnacceptableParseError`] given by [`UnacceptableParseError::kind`],
+/// the [`syn::Error`] is converted to its message.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum UnacceptableParseErrorKind {
+    /// see [`UnacceptableParseError::LeftHandSideValueNotIdent`]
+    LeftHandSideValueNotIdent,
+    /// see [`UnacceptableParseError::RightHandValueInvalid`]
+    RightHandValueInvalid,
+    /// see [`UnacceptableParseError::RightHandNameValueExprNotLitString`]
+    RightHandNameValueExprNotLitString,
+    /// message of [`UnacceptableParseError::IdentParseError`]
+    SynError(String),
+}
+
 impl From<syn::Error> for UnacceptableParseError {
     #[inline]
     fn from(value: syn::Error) -> Self {
@@ -181,6 +249,31 @@ pub enum ParseAttributeOptionError {
     Unacceptable(UnacceptableParseError),
 }
 
+#[cfg_attr(not(test), allow(dead_code))] // reason = "only compared in the tests"
+impl ParseAttributeOptionError {
+    /// Get the comparable kind of the error, see [`ParseAttributeOptionErrorKind`].
+    #[must_use]
+    pub fn kind(&self) -> ParseAttributeOptionErrorKind {
+        match self {
+            Self::Acceptable(err) => ParseAttributeOptionErrorKind::Acceptable(*err),
+            Self::Unacceptable(err) => ParseAttributeOptionErrorKind::Unacceptable(err.kind()),
+        }
+    }
+}
+
+/// Comparable kind of [`ParseAttributeOptionError`] given by
+/// [`ParseAttributeOptionError::kind`].
+#[cfg_attr(not(test), allow(dead_code))] // reason = "only compared in the tests"
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParseAttributeOptionErrorKind {
+    /// see [`ParseAttributeOptionError::Acceptable`]
+    Acceptable(AcceptableParseError),
+    /// see [`ParseAttributeOptionError::Unacceptable`]
+    Unacceptable(UnacceptableParseErrorKind),
+}
+
 impl From<AcceptableParseError> for ParseAttributeOptionError {
     #[inline]
     fn from(value: AcceptableParseError) -> Self {
@@ -235,6 +328,38 @@ pub enum AddConfigError<T: OptionList> {
     Acceptable(AcceptableParseError),
     /// Error while trying to add given configuration.
     Unacceptable(UnacceptableParseError, T),
+    /// The option is recognized but only valid on the immutable getter, it is given on a
+    /// `#[get_mut]` attribute.
+    ImmutableOnly(ImmutableOptionList),
+}
+
+#[cfg_attr(not(test), allow(dead_code))] // reason = "only compared in the tests"
+impl<T: OptionList + Copy> AddConfigError<T> {
+    /// Get the comparable kind of the error, see [`AddConfigErrorKind`].
+    #[must_use]
+    pub fn kind(&self) -> AddConfigErrorKind<T> {
+        match self {
+            Self::Acceptable(err) => AddConfigErrorKind::Acceptable(*err),
+            Self::Unacceptable(err, option) => {
+                AddConfigErrorKind::Unacceptable(err.kind(), *option)
+            }
+            Self::ImmutableOnly(option) => AddConfigErrorKind::ImmutableOnly(*option),
+        }
+    }
+}
+
+/// Comparable kind of [`AddConfigError`] given by [`AddConfigError::kind`].
+#[cfg_attr(not(test), allow(dead_code))] // reason = "only compared in the tests"
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum AddConfigErrorKind<T: OptionList> {
+    /// see [`AddConfigError::Acceptable`]
+    Acceptable(AcceptableParseError),
+    /// see [`AddConfigError::Unacceptable`]
+    Unacceptable(UnacceptableParseErrorKind, T),
+    /// see [`AddConfigError::ImmutableOnly`]
+    ImmutableOnly(ImmutableOptionList),
 }
 
 impl From<AddConfigError<MutableOptionList>> for AddConfigError<ImmutableOptionList> {
@@ -243,6 +368,7 @@ impl From<AddConfigError<MutableOptionList>> for AddConfigError<ImmutableOptionL
         match value {
             AddConfigError::Acceptable(err) => Self::Acceptable(err),
             AddConfigError::Unacceptable(err, option) => Self::Unacceptable(err, option.into()),
+            AddConfigError::ImmutableOnly(option) => Self::ImmutableOnly(option),
         }
     }
 }
@@ -262,6 +388,7 @@ impl<T: OptionList + Display> Display for AddConfigError<T> {
             Self::Unacceptable(ref err, ref option) => {
                 write!(f, "got error {err} while parsing option {option}")
             }
+            Self::ImmutableOnly(ref option) => write_immutable_only(f, *option),
         }
     }
 }
@@ -272,6 +399,7 @@ impl<T: OptionList + Debug + Display> Error for AddConfigError<T> {
         match self {
             Self::Acceptable(ref err) => Some(err),
             Self::Unacceptable(ref err, _) => Some(err),
+            Self::ImmutableOnly(_) => None,
         }
     }
 }
@@ -293,6 +421,38 @@ pub enum GetterParseError<T: OptionList> {
     AddConfigError(UnacceptableParseError, T),
     /// This attribute option is set multiple time we only accept it once.
     FieldAttributeOptionSetMultipleTimes(T),
+    /// The option is only valid on `#[get]` but it is given on `#[get_mut]`, see
+    /// [`AddConfigError::ImmutableOnly`].
+    ImmutableOnlyOption(ImmutableOptionList),
+}
+
+impl<T: OptionList + Copy> GetterParseError<T> {
+    /// Get the comparable kind of the error, see [`GetterParseErrorKind`].
+    #[must_use]
+    pub fn kind(&self) -> GetterParseErrorKind<T> {
+        match self {
+            Self::AddConfigError(err, option) => {
+                GetterParseErrorKind::AddConfigError(err.kind(), *option)
+            }
+            Self::FieldAttributeOptionSetMultipleTimes(option) => {
+                GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(*option)
+            }
+            Self::ImmutableOnlyOption(option) => GetterParseErrorKind::ImmutableOnlyOption(*option),
+        }
+    }
+}
+
+/// Comparable kind of [`GetterParseError`] given by [`GetterParseError::kind`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum GetterParseErrorKind<T: OptionList> {
+    /// see [`GetterParseError::AddConfigError`]
+    AddConfigError(UnacceptableParseErrorKind, T),
+    /// see [`GetterParseError::FieldAttributeOptionSetMultipleTimes`]
+    FieldAttributeOptionSetMultipleTimes(T),
+    /// see [`GetterParseError::ImmutableOnlyOption`]
+    ImmutableOnlyOption(ImmutableOptionList),
 }
 
 impl<T: OptionList + Display> Display for GetterParseError<T> {
@@ -305,6 +465,7 @@ impl<T: OptionList + Display> Display for GetterParseError<T> {
             Self::AddConfigError(ref err, ref option) => {
                 write!(f, "got error {err} while parsing option {option}")
             }
+            Self::ImmutableOnlyOption(ref option) => write_immutable_only(f, *option),
         }
     }
 }
@@ -313,7 +474,7 @@ impl<T: OptionList + Display + Debug> Error for GetterParseError<T> {
     #[inline]
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::FieldAttributeOptionSetMultipleTimes(_) => None,
+            Self::FieldAttributeOptionSetMultipleTimes(_) | Self::ImmutableOnlyOption(_) => None,
             Self::AddConfigError(ref err, _) => Some(err),
         }
     }
@@ -329,6 +490,189 @@ impl From<GetterParseError<MutableOptionList>> for GetterParseError<ImmutableOpt
             GetterParseError::AddConfigError(err, option) => {
                 Self::AddConfigError(err, option.into())
             }
+            GetterParseError::ImmutableOnlyOption(option) => Self::ImmutableOnlyOption(option),
+        }
+    }
+}
+
+/// Write the message of an option only valid on `#[get]` given on a `#[get_mut]` attribute,
+/// see [`AddConfigError::ImmutableOnly`].
+fn write_immutable_only(f: &mut fmt::Formatter<'_>, option: ImmutableOptionList) -> fmt::Result {
+    write!(
+        f,
+        "option `{option}` is only valid on #[get], not #[get_mut], remove it or move it to a \
+        #[get] attribute"
+    )
+}
+
+/// Error on the struct itself that prevents deriving the getters. It is reported at the
+/// name of the struct.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum StructError {
+    /// the struct is a unit struct like `struct S;`, it contains the name of the struct
+    UnitStruct(String),
+    /// the struct has an empty field list like `struct S {}` or `struct S();`,
+    /// it contains the name of the struct
+    EmptyFields(String),
+    /// the struct has fields but none of them has a getter attribute
+    NoAttribute {
+        /// name of the struct
+        name: String,
+        /// name of the fields that could be annotated
+        fields: Vec<String>,
+    },
+    /// the container option `newtype` is set on a struct with more than one field, it
+    /// contains the name of the struct
+    NewtypeNotSingleField(String),
+}
+
+impl Display for StructError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnitStruct(name) => write!(
+                f,
+                "the trait Getter cannot be derived on the unit struct `{name}` as it has no field, \
+                add fields annotated with #[get] or #[get_mut]"
+            ),
+            Self::EmptyFields(name) => write!(
+                f,
+                "the trait Getter cannot be derived on `{name}` as its field list is empty, \
+                add fields annotated with #[get] or #[get_mut]"
+            ),
+            Self::NoAttribute { name, fields } => {
+                write!(
+                    f,
+                    "no field of `{name}` has the attribute #[get] or #[get_mut], \
+                    annotate at least one of the fields:"
+                )?;
+                for (index, field) in fields.iter().enumerate() {
+                    let separator = if index == 0 { " " } else { ", " };
+                    write!(f, "{separator}`{field}`")?;
+                }
+                write!(
+                    f,
+                    ", or add #[getter(allow_empty)] if the attributes are conditional"
+                )
+            }
+            Self::NewtypeNotSingleField(name) => write!(
+                f,
+                "#[getter(newtype)] requires `{name}` to have a single field, \
+                annotate the field with #[get] instead"
+            ),
+        }
+    }
+}
+
+impl Error for StructError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::UnitStruct(_)
+            | Self::EmptyFields(_)
+            | Self::NoAttribute { .. }
+            | Self::NewtypeNotSingleField(_) => None,
+        }
+    }
+}
+
+/// Error return by [`super::container::ContainerOption::from_attributes`] while parsing
+/// the `#[getter]` attribute on the struct.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ContainerParseError {
+    /// the attribute is not a list like `#[getter(module = "name")]`
+    NotList,
+    /// parse error form syn
+    ExprParseError(syn::Error),
+    /// error while parsing option in the container attribute
+    GetterParseError(GetterParseError<ContainerOptionList>),
+    /// the option `sealed` is set without `module`
+    SealedWithoutModule,
+    /// the option `via` is set without `for`
+    ViaWithoutTarget,
+}
+
+#[cfg_attr(not(test), allow(dead_code))] // reason = "only compared in the tests"
+impl ContainerParseError {
+    /// Get the comparable kind of the error, see [`ContainerParseErrorKind`].
+    #[must_use]
+    pub fn kind(&self) -> ContainerParseErrorKind {
+        match self {
+            Self::NotList => ContainerParseErrorKind::NotList,
+            Self::ExprParseError(err) => ContainerParseErrorKind::SynError(err.to_string()),
+            Self::GetterParseError(err) => ContainerParseErrorKind::GetterParseError(err.kind()),
+            Self::SealedWithoutModule => ContainerParseErrorKind::SealedWithoutModule,
+            Self::ViaWithoutTarget => ContainerParseErrorKind::ViaWithoutTarget,
+        }
+    }
+}
+
+/// Comparable kind of [`ContainerParseError`] given by [`ContainerParseError::kind`], the
+/// [`syn::Error`] are converted to their message.
+#[cfg_attr(not(test), allow(dead_code))] // reason = "only compared in the tests"
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ContainerParseErrorKind {
+    /// see [`ContainerParseError::NotList`]
+    NotList,
+    /// message of [`ContainerParseError::ExprParseError`]
+    SynError(String),
+    /// see [`ContainerParseError::GetterParseError`]
+    GetterParseError(GetterParseErrorKind<ContainerOptionList>),
+    /// see [`ContainerParseError::SealedWithoutModule`]
+    SealedWithoutModule,
+    /// see [`ContainerParseError::ViaWithoutTarget`]
+    ViaWithoutTarget,
+}
+
+impl From<syn::Error> for ContainerParseError {
+    #[inline]
+    fn from(value: syn::Error) -> Self {
+        Self::ExprParseError(value)
+    }
+}
+
+impl From<GetterParseError<ContainerOptionList>> for ContainerParseError {
+    #[inline]
+    fn from(value: GetterParseError<ContainerOptionList>) -> Self {
+        Self::GetterParseError(value)
+    }
+}
+
+impl Display for ContainerParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotList => write!(
+                f,
+                "the attribute #[getter] expects a list of options like #[getter(module = \"name\")]"
+            ),
+            Self::ExprParseError(ref err) => write!(f, "{err}"),
+            Self::GetterParseError(ref err) => write!(f, "{err}"),
+            Self::SealedWithoutModule => write!(
+                f,
+                "the option sealed can only be used with the option module"
+            ),
+            Self::ViaWithoutTarget => write!(
+                f,
+                "the option via can only be used with the option for giving the wrapper type"
+            ),
+        }
+    }
+}
+
+impl Error for ContainerParseError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotList | Self::SealedWithoutModule | Self::ViaWithoutTarget => None,
+            Self::ExprParseError(ref err) => Some(err),
+            Self::GetterParseError(ref err) => Some(err),
         }
     }
 }
@@ -343,6 +687,45 @@ pub enum OptionValidationError {
     /// self_ty is value but getter_ty is reference which is not valid,
     /// it create a dandling reference which the borrow checker reject
     SelfMoveOnReturnRef,
+    /// the getter is constant and by clone, [`Clone::clone`] cannot be called in a const fn
+    ConstClone,
+    /// `into_inner` is combined with the mutable getter option (`both` or `add_mut`) in the same
+    /// attribute
+    IntoInnerWithMut,
+    /// `expect` or `unwrap` is used on a field whose type is not `Option<...>`
+    MissingValueNotOption,
+    /// `nested` is not given with `nested_ty` giving one type per nested field, or `nested_ty`
+    /// is given without `nested`
+    NestedTyMismatch,
+    /// `nested` is combined with the given option of the getter of the field itself: `name`,
+    /// `expect`, `unwrap`, `into_inner`, `with`, `newtype`, `both`, `add_mut` or `try_into`
+    NestedWithFieldGetterOption(ImmutableOptionList),
+    /// `newtype` is combined with the mutable getter option (`both` or `add_mut`) in the same
+    /// attribute
+    NewtypeWithMut,
+    /// `newtype` is used on a field of a struct with more than one field
+    NewtypeNotSingleField,
+    /// the getter takes `self` by value, with `self_ty = "value"`, `into_inner` or `with`, while
+    /// the container option `trait_def` is set, the method could not be called on a trait
+    /// object
+    ByValueSelfInTraitDef,
+    /// the getter is by reference or by clone on a field of a `#[repr(packed)]` struct, the
+    /// field can be unaligned so no reference to it can be taken
+    ReferenceInPackedStruct,
+    /// a mutable getter is asked on a field of a `#[repr(packed)]` struct, the field can be
+    /// unaligned so no mutable reference to it can be taken
+    MutableGetterInPackedStruct,
+    /// the two options give the same name explicitly to two generated functions, like
+    /// `#[get(name = "value", into_inner = "value")]`
+    FunctionNameClash(ImmutableOptionList, ImmutableOptionList),
+    /// `try_into` is used with a getter by reference, the field has to be converted by value
+    TryIntoByReference,
+    /// `try_into` is used with a constant getter, [`TryInto::try_into`] cannot be called in a
+    /// const fn
+    ConstTryInto,
+    /// `try_into` is combined with `expect` or `unwrap`, both change the return type of the
+    /// getter
+    TryIntoWithMissingValue,
 }
 
 impl Display for OptionValidationError {
@@ -357,6 +740,72 @@ impl Display for OptionValidationError {
                 "self_ty is value but getter_ty is reference which is not valid, \
                 it create a dandling reference which the borrow checker reject"
             ),
+            Self::ConstClone => write!(
+                f,
+                "a getter by clone cannot be const as Clone::clone cannot be called in a const fn, \
+                use getter_ty = \"copy\" for a Copy type or remove Const"
+            ),
+            Self::IntoInnerWithMut => write!(
+                f,
+                "into_inner cannot be combined with both or add_mut in the same attribute, \
+                use a separate #[get_mut] attribute instead"
+            ),
+            Self::MissingValueNotOption => write!(
+                f,
+                "expect and unwrap require the type of the field to be Option<...>"
+            ),
+            Self::NestedTyMismatch => write!(
+                f,
+                "nested requires nested_ty giving the type of each nested field, in the same order"
+            ),
+            Self::NestedWithFieldGetterOption(option) => write!(
+                f,
+                "nested and {option} cannot be combined as no getter is created on the field \
+                itself"
+            ),
+            Self::NewtypeWithMut => write!(
+                f,
+                "newtype cannot be combined with both or add_mut in the same attribute as it \
+                creates the consuming getter into_inner, use a separate #[get_mut] attribute instead"
+            ),
+            Self::NewtypeNotSingleField => write!(
+                f,
+                "newtype requires the struct to have a single field, like `struct Meters(f64);`"
+            ),
+            Self::ByValueSelfInTraitDef => write!(
+                f,
+                "trait_def requires the getters to take self by reference so that they can be \
+                called on a trait object, remove self_ty = \"value\", into_inner and with"
+            ),
+            Self::ReferenceInPackedStruct => write!(
+                f,
+                "the fields of a #[repr(packed)] struct can be unaligned so no reference to them \
+                can be taken, use getter_ty = \"copy\""
+            ),
+            Self::MutableGetterInPackedStruct => write!(
+                f,
+                "the fields of a #[repr(packed)] struct can be unaligned so no mutable reference \
+                to them can be taken, remove the mutable getter"
+            ),
+            Self::FunctionNameClash(first, second) => write!(
+                f,
+                "{first} and {second} give the same name to two functions, rename one of them"
+            ),
+            Self::TryIntoByReference => write!(
+                f,
+                "try_into converts the field by value, use getter_ty = \"copy\" or \
+                getter_ty = \"clone\""
+            ),
+            Self::ConstTryInto => write!(
+                f,
+                "a getter with try_into cannot be const as TryInto::try_into cannot be called in \
+                a const fn, remove Const"
+            ),
+            Self::TryIntoWithMissingValue => write!(
+                f,
+                "try_into cannot be combined with expect or unwrap as both change the return type \
+                of the getter"
+            ),
         }
     }
 }
@@ -365,7 +814,80 @@ impl Error for OptionValidationError {
     #[inline]
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::FunctionNameMissing | Self::SelfMoveOnReturnRef => None,
+            Self::FunctionNameMissing
+            | Self::SelfMoveOnReturnRef
+            | Self::ConstClone
+            | Self::IntoInnerWithMut
+            | Self::MissingValueNotOption
+            | Self::NestedTyMismatch
+            | Self::NestedWithFieldGetterOption(_)
+            | Self::NewtypeWithMut
+            | Self::NewtypeNotSingleField
+            | Self::ByValueSelfInTraitDef
+            | Self::ReferenceInPackedStruct
+            | Self::MutableGetterInPackedStruct
+            | Self::FunctionNameClash(_, _)
+            | Self::TryIntoByReference
+            | Self::ConstTryInto
+            | Self::TryIntoWithMissingValue => None,
         }
     }
 }
+
+/// An error with the [`Span`] of the tokens that caused it, so that the compile error
+/// points to the attribute (or the attribute option) instead of the derive.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct SpannedError<E> {
+    /// the wrapped error
+    error: E,
+    /// where the error should be reported
+    span: Span,
+}
+
+impl<E> SpannedError<E> {
+    /// Wrap an error with the span it should be reported at.
+    #[inline]
+    #[must_use]
+    pub const fn new(error: E, span: Span) -> Self {
+        Self { error, span }
+    }
+
+    /// Getter on the wrapped error.
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Convert the wrapped error keeping the span.
+    #[inline]
+    #[must_use]
+    pub fn err_into<F: From<E>>(self) -> SpannedError<F> {
+        SpannedError::new(self.error.into(), self.span)
+    }
+}
+
+impl<E: Display> SpannedError<E> {
+    /// Create a `compile_error!` at the span of the error with the given prefix before
+    /// the error message.
+    #[inline]
+    #[must_use]
+    pub fn to_compile_error(&self, prefix: &str) -> TokenStream2 {
+        syn::Error::new(self.span, format!("{prefix}{}", self.error)).to_compile_error()
+    }
+}
+
+impl<E: Display> Display for SpannedError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl<E: Error + 'static> Error for SpannedError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
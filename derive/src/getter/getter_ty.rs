@@ -2,8 +2,8 @@
 
 use std::fmt::{self, Display};
 
+use macro_utils::getter::GetterTy as SpecGetterTy;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
 
 use super::attribute_option::ParseOptionUtils;
 
@@ -66,24 +66,12 @@ pub enum GetterTy {
 }
 
 impl GetterTy {
-    /// Get the quote for start of the function implementation
+    /// Get the quote of the function implementation from the field access `self.field`,
+    /// see [`SpecGetterTy::body`].
     #[must_use]
     #[inline]
-    pub fn prefix_quote(self) -> TokenStream2 {
-        match self {
-            Self::Ref => quote! {&},
-            Self::Clone | Self::Copy => quote! {},
-        }
-    }
-
-    /// Get the quote for end of the function implementation
-    #[must_use]
-    #[inline]
-    pub fn suffix_quote(self) -> TokenStream2 {
-        match self {
-            Self::Clone => quote! {.clone()},
-            Self::Copy | Self::Ref => quote! {},
-        }
+    pub fn body_quote(self, field_access: &TokenStream2) -> TokenStream2 {
+        SpecGetterTy::from(self).body(field_access)
     }
 
     /// Parse the option from a string
@@ -123,6 +111,17 @@ impl ParseOptionUtils for GetterTy {
     }
 }
 
+impl From<GetterTy> for SpecGetterTy {
+    #[inline]
+    fn from(value: GetterTy) -> Self {
+        match value {
+            GetterTy::Copy => Self::Copy,
+            GetterTy::Clone => Self::Clone,
+            GetterTy::Ref => Self::Ref,
+        }
+    }
+}
+
 impl Display for GetterTy {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
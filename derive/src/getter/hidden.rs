@@ -0,0 +1,51 @@
+//! Contains [`Hidden`], the attribute option that hides the getters from the documentation.
+
+use super::attribute_option::ParseOptionUtils;
+
+/// Option to add `#[doc(hidden)]` on the functions created by the attribute, for getters
+/// that are only meant for the code generated by other macros. By default the getters are
+/// documented.
+///
+/// Accept value : like `#[get(hidden)]` or `#[get_mut(hidden)]`.
+/// - `hidden`
+/// - `hidden = "true"/"false"`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum Hidden {
+    /// The getters are documented.
+    #[default]
+    Documented,
+    /// The getters have `#[doc(hidden)]`.
+    Hidden,
+}
+
+impl Hidden {
+    /// Returns `true` if the getters have `#[doc(hidden)]`.
+    #[inline]
+    #[must_use]
+    pub fn is_hidden(self) -> bool {
+        self == Self::Hidden
+    }
+}
+
+impl ParseOptionUtils for Hidden {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == "hidden").then_some(Self::Hidden)
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        if path == "true" {
+            Some(Self::Hidden)
+        } else if path == "false" {
+            Some(Self::Documented)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == "hidden"
+    }
+}
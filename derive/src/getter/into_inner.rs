@@ -0,0 +1,87 @@
+//! Contains [`IntoInner`], the attribute option that determine if the `#[get]` attribute
+//! also creates a consuming getter.
+
+use macro_utils::field::FieldName;
+use proc_macro2::{Ident, Span};
+
+use super::{attribute_option::ParseOptionUtils, name::FunctionName};
+
+/// Option to determine if the `#[get]` attribute also creates a consuming getter
+/// `fn into_{field}(self) -> Ty`. By default no consuming getter is created.
+///
+/// The consuming getter shares the visibility with the immutable getter.
+///
+/// Accept value : like `#[get(into_inner)]` or `#[get(into_inner = "name")]`.
+/// - `into_inner` for the default name `into_{field}`
+/// - `into_inner = "{name}"` to set the name of the consuming getter
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum IntoInner {
+    /// No consuming getter.
+    #[default]
+    None,
+    /// A consuming getter with the default name `into_{field}`.
+    DefaultName,
+    /// A consuming getter with the given name.
+    Named(Ident),
+}
+
+impl IntoInner {
+    /// Path string for the option
+    const PATH: &'static str = "into_inner";
+
+    /// Returns `true` if the consuming getter is created.
+    #[inline]
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Set the name of the consuming getter to `name` unless it is given in the attribute. The
+    /// consuming getter is created even if the option is unset.
+    #[must_use]
+    pub fn with_name_if_unnamed(self, name: &str) -> Self {
+        match self {
+            Self::Named(ident) => Self::Named(ident),
+            Self::None | Self::DefaultName => Self::Named(Ident::new(name, Span::call_site())),
+        }
+    }
+
+    /// Get the consuming getter function name as an [`Ident`].
+    ///
+    /// Return [`None`] if the option is unset or if the field is identless and the
+    /// name is not given. The default name `into_{field}` keeps the span of the field ident
+    /// and follows the case of the name of the getter `getter_name`, see
+    /// [`FunctionName::default_name`].
+    #[must_use]
+    pub fn name(&self, field: &FieldName, getter_name: &FunctionName) -> Option<Ident> {
+        match self {
+            Self::None => None,
+            Self::DefaultName => getter_name.default_name(field, "into_", ""),
+            Self::Named(ident) => Some(ident.clone()),
+        }
+    }
+}
+
+impl ParseOptionUtils for IntoInner {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == Self::PATH).then_some(Self::DefaultName)
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    /// The ident of the getter is created with the span of the value given in the attribute,
+    /// like for [`super::name::FunctionName`].
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        syn::parse_str::<Ident>(path)
+            .ok()
+            .map(|ident| Self::Named(Ident::new(&ident.to_string(), span)))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::PATH
+    }
+}
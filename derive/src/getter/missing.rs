@@ -0,0 +1,201 @@
+//! Contains [`MissingValue`], the attribute option that determine how the `#[get]` attribute
+//! handles an unset `Option` field.
+
+use std::fmt::{self, Display};
+
+use macro_utils::field::FieldName;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{Expr, GenericArgument, LitStr, Path, PathArguments, Type};
+
+use super::{attribute_option::ParseOptionUtils, getter_ty::GetterTy, option::tokens_string};
+
+/// Option to determine how the getter of an `Option<T>` field handles the [`None`] case,
+/// for builder-like structs where [`None`] means "not yet set". By default the getter
+/// returns the `Option<T>` like for any other field.
+///
+/// Accept value : like `#[get(expect = "MyError::FieldMissing")]` or `#[get(unwrap)]`.
+/// - `expect = "{expr}"`: the getter returns `Result<&T, E>` with the expression as the error
+///   value if the field is [`None`]. The error type `E` is the path of the expression
+///   without its last segment if it has more than one segment (`MyError::FieldMissing`,
+///   `MyError::new(...)` or `MyError::Missing { .. }` gives `MyError`), the whole path otherwise
+///   (`FieldMissing` or `FieldMissing(...)` gives `FieldMissing`).
+/// - `unwrap`: the getter returns `&T` and panics with a message naming the field if the
+///   field is [`None`].
+///
+/// Both require the type of the field to be `Option<...>`.
+#[derive(Clone, Default)]
+pub enum MissingValue {
+    /// The getter returns the `Option` itself.
+    #[default]
+    None,
+    /// The getter returns a `Result` with the given error.
+    Expect {
+        /// The expression of the error value
+        value: Box<Expr>,
+        /// The type of the error
+        ty: Path,
+    },
+    /// The getter panics if the field is [`None`].
+    Unwrap,
+}
+
+impl MissingValue {
+    /// Path string for the `expect` option
+    const EXPECT: &'static str = "expect";
+    /// Path string for the `unwrap` option
+    const UNWRAP: &'static str = "unwrap";
+
+    /// Returns `true` if the getter unwraps the `Option`.
+    #[inline]
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Get the error type of the `expect` expression, see [`MissingValue`].
+    /// Returns [`None`] if the expression is not a path, a call of a path or a struct literal.
+    fn error_ty(expr: &Expr) -> Option<Path> {
+        let mut path = match expr {
+            Expr::Path(expr_path) if expr_path.qself.is_none() => expr_path.path.clone(),
+            Expr::Call(expr_call) => match &*expr_call.func {
+                Expr::Path(expr_path) if expr_path.qself.is_none() => expr_path.path.clone(),
+                _ => return None,
+            },
+            Expr::Struct(expr_struct) if expr_struct.qself.is_none() => expr_struct.path.clone(),
+            _ => return None,
+        };
+        if path.segments.len() > 1 {
+            path.segments.pop();
+            path.segments.pop_punct();
+        }
+        Some(path)
+    }
+
+    /// Get the return type of the getter from the return type of a getter on the inner
+    /// value of the `Option`, i.e. `&T`. It is unchanged if the option is unset or `unwrap`.
+    #[must_use]
+    pub fn return_ty(&self, inner: &TokenStream2) -> TokenStream2 {
+        match self {
+            Self::None | Self::Unwrap => inner.clone(),
+            Self::Expect { ty, .. } => quote! {::core::result::Result<#inner, #ty>},
+        }
+    }
+
+    /// Get the body of the getter from the field access `self.field`. It uses a `match` so
+    /// that the getter can still be `const` if the error value allows it.
+    #[must_use]
+    pub fn body(
+        &self,
+        getter_ty: GetterTy,
+        field_access: &TokenStream2,
+        field_name: &FieldName,
+    ) -> TokenStream2 {
+        let (scrutinee, value) = match getter_ty {
+            GetterTy::Ref => (quote! {&#field_access}, quote! {value}),
+            GetterTy::Copy => (quote! {#field_access}, quote! {value}),
+            GetterTy::Clone => (
+                quote! {&#field_access},
+                quote! {::core::clone::Clone::clone(value)},
+            ),
+        };
+        match self {
+            Self::None => getter_ty.body_quote(field_access),
+            Self::Expect { value: error, .. } => quote! {
+                match #scrutinee {
+                    ::core::option::Option::Some(value) => ::core::result::Result::Ok(#value),
+                    ::core::option::Option::None => ::core::result::Result::Err(#error),
+                }
+            },
+            Self::Unwrap => {
+                let message = format!("the field `{field_name}` is not set");
+                quote! {
+                    match #scrutinee {
+                        ::core::option::Option::Some(value) => #value,
+                        ::core::option::Option::None => ::core::panic!(#message),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get the documentation comment added after the one of the getter, explaining the
+    /// [`None`] case.
+    #[must_use]
+    pub const fn comment(&self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Expect { .. } => {
+                "\n\n# Errors\nReturns the error if the field is [`None`], i.e. not set."
+            }
+            Self::Unwrap => "\n\n# Panics\nPanics if the field is [`None`], i.e. not set.",
+        }
+    }
+}
+
+/// Display how the [`None`] case is handled, the expression of the error is not displayed.
+impl Display for MissingValue {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "returning the option"),
+            Self::Expect { ty, .. } => write!(
+                f,
+                "returning the error `{}` if the field is not set",
+                tokens_string(ty.to_token_stream())
+            ),
+            Self::Unwrap => write!(f, "panicking if the field is not set"),
+        }
+    }
+}
+
+impl ParseOptionUtils for MissingValue {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == Self::UNWRAP).then_some(Self::Unwrap)
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    /// The expression is created with the span of the value given in the attribute so the
+    /// errors in the expression point back to the attribute.
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        let value = LitStr::new(path, span).parse::<Expr>().ok()?;
+        let ty = Self::error_ty(&value)?;
+        Some(Self::Expect {
+            value: Box::new(value),
+            ty,
+        })
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::EXPECT
+    }
+}
+
+/// Get the type `T` of a type `Option<T>`, the path of the `Option` can be qualified like
+/// `core::option::Option<T>`. Returns [`None`] if the type is not an `Option`.
+#[must_use]
+pub fn option_inner_ty(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    let mut arguments = arguments.args.iter();
+    match (arguments.next(), arguments.next()) {
+        (Some(GenericArgument::Type(inner)), None) => Some(inner),
+        _ => None,
+    }
+}
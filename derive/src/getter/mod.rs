@@ -1,28 +1,44 @@
 //! Contain proc macro for `Getter` derive
 
+mod add_mut;
 mod attribute_option;
+mod conflict;
 mod const_ty;
+mod container;
 mod error;
 mod getter_ty;
+mod hidden;
+mod into_inner;
+mod missing;
 mod name;
+mod nested;
+mod newtype;
 mod option;
 mod option_enum;
 mod self_ty;
 mod syntax;
+mod try_into;
 mod visibility;
 mod which_getter;
+mod with;
 
-use macro_utils::field::Field;
+use macro_utils::field::{Field, FieldName};
 use macro_utils::quote_compile_error;
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens};
-use syn::{parse_macro_input, Data, DeriveInput, Fields};
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Attribute, Data, DeriveInput,
+    Fields, Generics, Meta, Token,
+};
 
 pub use self::attribute_option::ParseOption;
+use self::container::ContainerOption;
 pub use self::error::OptionParseError;
+use self::error::{OptionParseErrorKind, SpannedError, StructError};
 use self::option::{GetterOption, ImmutableGetterOption, MutableGetterOption};
 use self::visibility::Visibility;
+use crate::sealed::private_module;
 
 // TODO share option for both
 // TODO multiple error reporting on #[get] #[get_mut]
@@ -33,35 +49,76 @@ use self::visibility::Visibility;
 #[must_use]
 pub fn derive(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
+    derive_input(input).into()
+}
+
+/// Generate the getters of the parsed struct, see [`derive`]. It works with
+/// [`TokenStream2`] so that the expansion can be unit tested.
+///
+/// The output is deterministic: the getters are emitted in the declaration order of the
+/// fields, the immutable getter of a field before its mutable one, and the attributes of a
+/// field are processed in their order.
+#[allow(clippy::too_many_lines)] // reason = "one step per container option"
+fn derive_input(input: DeriveInput) -> TokenStream2 {
+    let container = match ContainerOption::from_attributes(&input.attrs) {
+        Ok(container) => container,
+        Err(err) => return err.to_compile_error("error parsing option: "),
+    };
+
+    let packed = is_packed(&input.attrs);
+    let struct_ty = {
+        let name = &input.ident;
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        quote! {#name #ty_generics}
+    };
+
+    let struct_error = |error: StructError| -> TokenStream2 {
+        SpannedError::new(error, input.ident.span()).to_compile_error("")
+    };
 
-    let vec: Vec<TokenStream2> = match input.data {
+    let (vec, field_names): (Vec<Result<GetterOption, TokenStream2>>, Vec<String>) = match input
+        .data
+    {
         Data::Struct(data) => {
-            let iter = match data.fields {
-                Fields::Named(fields) => fields.named.into_iter(),
-                Fields::Unnamed(fields) => fields.unnamed.into_iter(),
+            let fields = match data.fields {
+                Fields::Named(fields) => fields.named,
+                Fields::Unnamed(fields) => fields.unnamed,
                 Fields::Unit => {
-                    // cspell: ignore fieldless
-                    return quote_compile_error!(
-                        "The trait getter cannot be derive on fieldless struct."
-                    );
+                    return struct_error(StructError::UnitStruct(input.ident.to_string()));
                 }
             };
+            if fields.is_empty() {
+                return struct_error(StructError::EmptyFields(input.ident.to_string()));
+            }
+            let single_field = fields.len() == 1;
+            if container.newtype() && !single_field {
+                return struct_error(StructError::NewtypeNotSingleField(input.ident.to_string()));
+            }
+
+            let fields = fields
+                .into_iter()
+                .enumerate()
+                .map(|(field_index, field)| Field::new(field, field_index))
+                .collect::<Vec<_>>();
+            let field_names = fields
+                .iter()
+                .map(|field| FieldName::from_field_ref(field).to_string())
+                .collect();
 
-            iter.enumerate()
-                .filter_map(|(field_index, field)| {
-                    let field = Field::new(field, field_index);
-                    let option = GetterOption::parse(field);
-
-                    match option {
-                        Ok(option) => Some(option.into_token_stream()),
-                        Err(OptionParseError::NotFound) => None,
-                        Err(err) => {
-                            let message = format!("error parsing option: {err}");
-                            Some(quote_compile_error!(#message))
-                        }
-                    }
+            let vec = fields
+                .into_iter()
+                .filter_map(|field| {
+                    field_option(
+                        field,
+                        &container,
+                        packed,
+                        single_field,
+                        &struct_ty,
+                        &input.generics,
+                    )
                 })
-                .collect::<Vec<TokenStream2>>()
+                .collect();
+            (vec, field_names)
         }
         Data::Enum(_) => {
             return quote_compile_error!("It is not possible to derive getter for enums yet.");
@@ -71,23 +128,579 @@ pub fn derive(item: TokenStream) -> TokenStream {
         }
     };
 
-    let out = if vec.is_empty() {
-        let message = OptionParseError::NotFound.to_string();
-        //"No field has attribute #[get] or #[get_mut] has been found."
-        quote_compile_error!(#message)
+    if vec.is_empty() {
+        if container.allow_empty() {
+            return TokenStream2::new();
+        }
+        return struct_error(StructError::NoAttribute {
+            name: input.ident.to_string(),
+            fields: field_names,
+        });
+    }
+
+    let trait_def = container
+        .trait_def()
+        .map_or_else(TokenStream2::new, |trait_name| {
+            trait_def_code(
+                &input.ident,
+                &input.vis,
+                &input.generics,
+                trait_name,
+                &container,
+                &vec,
+            )
+        });
+
+    let warnings = redundant_attribute_warnings(&container, &vec);
+
+    let newtype = {
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let name = &input.ident;
+        let target = container
+            .target()
+            .map_or_else(|| quote! {#name #ty_generics}, ToTokens::to_token_stream);
+        vec.iter()
+            .filter_map(|option| option.as_ref().ok())
+            .map(|option| option.newtype_implementation(&input.generics, &target))
+            .collect::<TokenStream2>()
+    };
+
+    let out = if let Some(module) = container.module() {
+        module_code(
+            &input.ident,
+            &input.vis,
+            &input.generics,
+            module,
+            &container,
+            &vec,
+        )
     } else {
         let name = input.ident;
         let generics = input.generics;
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let target = container
+            .target()
+            .map_or_else(|| quote! {#name #ty_generics}, ToTokens::to_token_stream);
+        let vec = vec.into_iter().map(|option| match option {
+            Ok(option) => option.into_token_stream(),
+            Err(err) => err,
+        });
+        let lint_allow = container.lint_allow();
+        let case_allow = container.case_allow();
 
         quote! {
             /// Automatically generated implementation for getters
             #[automatically_derived]
-            impl #impl_generics #name #ty_generics #where_clause {
+            #lint_allow
+            #case_allow
+            impl #impl_generics #target #where_clause {
                 #(#vec)*
             }
         }
     };
 
-    out.into()
+    quote! {
+        #out
+
+        #newtype
+
+        #trait_def
+
+        #warnings
+    }
+}
+
+/// Generate the deprecation warnings of the redundant field attributes if the container
+/// option `warn_redundant` is set, see [`GetterOption::redundant_attribute_warnings`].
+fn redundant_attribute_warnings(
+    container: &ContainerOption,
+    options: &[Result<GetterOption, TokenStream2>],
+) -> TokenStream2 {
+    if container.warn_redundant() {
+        options
+            .iter()
+            .filter_map(|option| option.as_ref().ok())
+            .map(GetterOption::redundant_attribute_warnings)
+            .collect()
+    } else {
+        TokenStream2::new()
+    }
+}
+
+/// Generate the trait `trait_name` of the container option `trait_def` with one method per
+/// immutable getter and its implementation for the struct. The getters take `self` by
+/// reference, see [`GetterOption::validate_trait_def`], so the trait is object safe. Like
+/// the trait of [`module_code`] it has the generics of the struct.
+///
+/// The errors of the options are reported by the inherent implementation or the module.
+fn trait_def_code(
+    name: &Ident,
+    visibility: &syn::Visibility,
+    generics: &Generics,
+    trait_name: &Ident,
+    container: &ContainerOption,
+    options: &[Result<GetterOption, TokenStream2>],
+) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let target = container
+        .target()
+        .map_or_else(|| quote! {#name #ty_generics}, ToTokens::to_token_stream);
+
+    let declarations = options.iter().filter_map(|option| {
+        option
+            .as_ref()
+            .ok()
+            .map(GetterOption::trait_def_declaration)
+    });
+    let implementations = options.iter().filter_map(|option| {
+        option
+            .as_ref()
+            .ok()
+            .map(GetterOption::trait_def_implementation)
+    });
+
+    let trait_doc = format!("Automatically generated trait for the getters of [`{name}`].");
+    let lint_allow = container.lint_allow();
+    let case_allow = container.case_allow();
+
+    quote! {
+        #[doc=#trait_doc]
+        #case_allow
+        #visibility trait #trait_name #impl_generics #where_clause {
+            #(#declarations)*
+        }
+
+        /// Automatically generated implementation for getters
+        #[automatically_derived]
+        #lint_allow
+        impl #impl_generics #trait_name #ty_generics for #target #where_clause {
+            #(#implementations)*
+        }
+    }
+}
+
+/// Whether the struct is annotated with `#[repr(packed)]` or `#[repr(packed(N))]`, its fields
+/// can then be unaligned, see [`GetterOption::validate_packed`].
+fn is_packed(attributes: &[Attribute]) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("repr"))
+        .any(|attribute| {
+            attribute
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .is_ok_and(|list| list.iter().any(|meta| meta.path().is_ident("packed")))
+        })
+}
+
+/// Parse the getter option of a field, [`None`] if the field has no getter attribute.
+/// The parse errors are given as compile errors, like the validation errors of the options
+/// depending on the container: `trait_def`, `#[repr(packed)]` and the number of fields for
+/// `newtype`.
+///
+/// `Self` in the type of the field is replaced by `struct_ty`, the type of the struct, see
+/// [`GetterOption::with_struct_type`], and the type parameters in `generics` are not linked in
+/// the documentation, see [`GetterOption::with_generics`].
+fn field_option(
+    field: Field,
+    container: &ContainerOption,
+    packed: bool,
+    single_field: bool,
+    struct_ty: &TokenStream2,
+    generics: &Generics,
+) -> Option<Result<GetterOption, TokenStream2>> {
+    let span = field.field().span();
+    let option = match GetterOption::parse(field, container.tuple_names(), container.newtype()) {
+        Ok(option) => option,
+        Err(err) if err.error().kind() == OptionParseErrorKind::NotFound => return None,
+        Err(err) => return Some(Err(err.to_compile_error("error parsing option: "))),
+    };
+    let option = container
+        .trait_def()
+        .map_or(Ok(()), |_| option.validate_trait_def())
+        .and_then(|()| {
+            if packed {
+                option.validate_packed()
+            } else {
+                Ok(())
+            }
+        })
+        .and_then(|()| option.validate_newtype(single_field))
+        .map(|()| {
+            option
+                .with_via(container.via())
+                .with_rename_all(container.rename_all())
+                .with_struct_type(struct_ty)
+                .with_generics(generics)
+        })
+        .map_err(|err| {
+            SpannedError::new(OptionParseError::from(err), span)
+                .to_compile_error("error parsing option: ")
+        });
+    Some(option)
+}
+
+/// Generate the module `module` containing the extension trait `{name}Getters` with the
+/// getters as trait method and its implementation for the struct. If the option `sealed`
+/// is set the trait is sealed with a private module, like [`crate::trait_sealed`] does.
+fn module_code(
+    name: &Ident,
+    visibility: &syn::Visibility,
+    generics: &Generics,
+    module: &Ident,
+    container: &ContainerOption,
+    options: &[Result<GetterOption, TokenStream2>],
+) -> TokenStream2 {
+    let trait_name = format_ident!("{name}Getters");
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let target = container
+        .target()
+        .map_or_else(|| quote! {#name #ty_generics}, ToTokens::to_token_stream);
+
+    let declarations = options
+        .iter()
+        .filter_map(|option| option.as_ref().ok().map(GetterOption::trait_declaration));
+    let implementations = options
+        .iter()
+        .filter_map(|option| option.as_ref().ok().map(GetterOption::trait_implementation));
+    let errors = options.iter().filter_map(|option| option.as_ref().err());
+
+    let module_doc = format!(
+        "Automatically generated module containing [`{trait_name}`], the getters of [`{name}`]."
+    );
+    let trait_doc = format!("Automatically generated trait for the getters of [`{name}`].");
+    let lint_allow = container.lint_allow();
+    let case_allow = container.case_allow();
+
+    let (sealed_code, super_trait) = if container.sealed() {
+        let private_module = private_module();
+        (
+            quote! {
+                #private_module
+
+                #[automatically_derived]
+                impl #impl_generics private::Sealed for #target #where_clause {}
+            },
+            quote! {: private::Sealed},
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    quote! {
+        #[doc=#module_doc]
+        #visibility mod #module {
+            use super::*;
+
+            #(#errors)*
+
+            #sealed_code
+
+            #[doc=#trait_doc]
+            #case_allow
+            pub trait #trait_name #impl_generics #super_trait #where_clause {
+                #(#declarations)*
+            }
+
+            /// Automatically generated implementation for getters
+            #[automatically_derived]
+            #lint_allow
+            impl #impl_generics #trait_name #ty_generics for #target #where_clause {
+                #(#implementations)*
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+    use syn::{parse_quote, DeriveInput};
+
+    use super::derive_input;
+
+    /// Get the names of the functions in the order they appear in the token stream.
+    fn fn_names(tokens: TokenStream2) -> Vec<String> {
+        let mut names = Vec::new();
+        let mut previous_is_fn = false;
+        for token in tokens {
+            match &token {
+                TokenTree::Group(group) => names.extend(fn_names(group.stream())),
+                TokenTree::Ident(ident) if previous_is_fn => names.push(ident.to_string()),
+                _ => {}
+            }
+            previous_is_fn = matches!(&token, TokenTree::Ident(ident) if ident == "fn");
+        }
+        names
+    }
+
+    #[test]
+    fn deterministic_output_order() {
+        let input: DeriveInput = parse_quote! {
+            struct Big {
+                #[get] f00: u8, #[get_mut] #[get] f01: u8, #[get] f02: u8,
+                f03: u8, #[get] f04: u8, #[get] #[get_mut] f05: u8,
+                #[get] f06: u8, #[get] f07: u8, #[get_mut] f08: u8,
+                #[get] f09: u8, #[get] f10: u8, #[get] f11: u8,
+                #[get] f12: u8, f13: u8, #[get] f14: u8,
+                #[get] f15: u8, #[get] f16: u8, #[get] f17: u8,
+                #[get] f18: u8, #[get] f19: u8, #[get(name = "renamed")] f20: u8,
+                #[get] f21: u8, #[get] f22: u8, #[get] f23: u8,
+                #[get] f24: u8, #[get] f25: u8, #[get] f26: u8,
+                #[get] f27: u8, #[get] f28: u8, #[get] f29: u8,
+            }
+        };
+
+        let mut expected = Vec::new();
+        for index in 0_usize..30_usize {
+            let name = format!("f{index:02}");
+            match index {
+                3 | 13 => {}
+                1 | 5 => {
+                    expected.push(name.clone());
+                    expected.push(format!("{name}_mut"));
+                }
+                8 => expected.push(format!("{name}_mut")),
+                20 => expected.push("renamed".to_owned()),
+                _ => expected.push(name),
+            }
+        }
+
+        let output = derive_input(input.clone());
+        assert_eq!(fn_names(output.clone()), expected);
+        // the expansion does not change from one call to the other
+        for _ in 0_usize..10_usize {
+            assert_eq!(derive_input(input.clone()).to_string(), output.to_string());
+        }
+    }
+
+    #[test]
+    fn lint_allow() {
+        let allow = "clippy :: missing_const_for_fn";
+        let output = derive_input(parse_quote! {
+            struct S {
+                #[get] f: u8,
+            }
+        });
+        assert!(output.to_string().contains(allow));
+
+        let output = derive_input(parse_quote! {
+            #[getter(module = "accessors")]
+            struct S {
+                #[get] f: u8,
+            }
+        });
+        assert!(output.to_string().contains(allow));
+
+        let output = derive_input(parse_quote! {
+            #[getter(no_lint_allow)]
+            struct S {
+                #[get] f: u8,
+            }
+        });
+        assert!(!output.to_string().contains(allow));
+        assert_eq!(fn_names(output), vec!["f".to_owned()]);
+    }
+
+    #[test]
+    fn rename_all() {
+        let output = derive_input(parse_quote! {
+            #[getter(rename_all = "camelCase")]
+            struct S {
+                #[get(into_inner)] #[get_mut] my_field: u8,
+                #[get(name = "explicit_name")] other_field: u8,
+                #[get_mut] r#type: u8,
+            }
+        });
+        assert!(output.to_string().contains("non_snake_case"));
+        assert_eq!(
+            fn_names(output),
+            vec![
+                "myField".to_owned(),
+                "intoMyField".to_owned(),
+                "myFieldMut".to_owned(),
+                "explicit_name".to_owned(),
+                "typeMut".to_owned(),
+            ]
+        );
+
+        let output = derive_input(parse_quote! {
+            #[getter(rename_all = "snake_case")]
+            struct S {
+                #[get] my_field: u8,
+            }
+        });
+        assert!(!output.to_string().contains("non_snake_case"));
+        assert_eq!(fn_names(output), vec!["my_field".to_owned()]);
+    }
+
+    #[test]
+    fn warn_redundant() {
+        let warning = "redundant #[get] attribute";
+        let input: DeriveInput = parse_quote! {
+            struct S {
+                #[get] #[get(name = "f")] f: u8,
+                #[get] #[get(Pub)] g: u8,
+            }
+        };
+        let output = derive_input(input.clone()).to_string();
+        assert!(!output.contains("redundant"));
+
+        let mut input = input;
+        input.attrs.push(parse_quote!(#[getter(warn_redundant)]));
+        let output = derive_input(input).to_string();
+        assert_eq!(output.matches(warning).count(), 1);
+        assert!(output.contains("field `f`"));
+
+        let output = derive_input(parse_quote! {
+            #[getter(warn_redundant)]
+            struct S {
+                #[get] #[get_mut] f: u8,
+                #[get_mut] #[get_mut(Pub)] g: u8,
+            }
+        })
+        .to_string();
+        assert!(!output.contains("redundant"));
+    }
+
+    #[test]
+    fn tuple_names() {
+        let input: DeriveInput = parse_quote! {
+            struct S(
+                #[get] #[get_mut] u8,
+                #[get(name = "explicit")] u8,
+                #[get(into_inner)] u8,
+            );
+        };
+        let output = derive_input(input.clone());
+        assert!(output.to_string().contains("compile_error"));
+
+        let mut with_default = input.clone();
+        with_default
+            .attrs
+            .push(parse_quote!(#[getter(tuple_names)]));
+        assert_eq!(
+            fn_names(derive_input(with_default)),
+            vec![
+                "field_0".to_owned(),
+                "field_0_mut".to_owned(),
+                "explicit".to_owned(),
+                "field_2".to_owned(),
+                "into_field_2".to_owned(),
+            ]
+        );
+
+        let mut with_prefix = input;
+        with_prefix
+            .attrs
+            .push(parse_quote!(#[getter(tuple_names = "item_", rename_all = "PascalCase")]));
+        assert_eq!(
+            fn_names(derive_input(with_prefix)),
+            vec![
+                "Item0".to_owned(),
+                "Item0Mut".to_owned(),
+                "explicit".to_owned(),
+                "Item2".to_owned(),
+                "IntoItem2".to_owned(),
+            ]
+        );
+
+        // the named fields keep their names
+        let output = derive_input(parse_quote! {
+            #[getter(tuple_names)]
+            struct S {
+                #[get] f: u8,
+            }
+        });
+        assert_eq!(fn_names(output), vec!["f".to_owned()]);
+    }
+
+    #[test]
+    fn self_type() {
+        let input: DeriveInput = parse_quote! {
+            struct Node<T> {
+                #[get(Const)] #[get_mut] next: Option<Box<Self>>,
+                #[get] value: T,
+            }
+        };
+        let output = derive_input(input.clone()).to_string();
+        assert!(!output.contains("Self >"));
+        assert!(output.contains(
+            "\"Getter on a reference of the field `next` with type `Option<Box<Node<T>>>`.\""
+        ));
+        assert!(output.contains(
+            "\"Getter on a mutable reference of the field next with type \
+            `Option<Box<Node<T>>>`.\""
+        ));
+        assert!(output.contains("const fn next (& self) -> & Option < Box < Node < T > > >"));
+        // the type parameter is not a link, rustdoc could not resolve it
+        assert!(output.contains("with type `T`."));
+
+        // in the trait `Self` is the implementor
+        let mut input = input;
+        input
+            .attrs
+            .push(parse_quote!(#[getter(module = "accessors", trait_def = "HasNext")]));
+        let output = derive_input(input).to_string();
+        assert!(!output.contains("Self >"));
+        assert!(output.contains("fn next (& self) -> & Option < Box < Node < T > > > ;"));
+    }
+
+    #[test]
+    fn allow_empty() {
+        let input: DeriveInput = parse_quote! {
+            struct S {
+                f: u8,
+            }
+        };
+        let output = derive_input(input.clone());
+        assert!(output.to_string().contains("compile_error"));
+
+        let mut input = input;
+        input.attrs.push(parse_quote!(#[getter(allow_empty)]));
+        assert!(derive_input(input).is_empty());
+
+        let output = derive_input(parse_quote! {
+            #[getter(allow_empty)]
+            struct S {
+                #[get] f: u8,
+            }
+        });
+        assert_eq!(fn_names(output), vec!["f".to_owned()]);
+    }
+
+    #[test]
+    fn packed() {
+        for repr in [
+            quote::quote!(#[repr(packed)]),
+            quote::quote!(#[repr(C, packed(2))]),
+        ] {
+            let output = derive_input(parse_quote! {
+                #repr
+                struct S {
+                    #[get]
+                    f: u8,
+                }
+            });
+            assert!(output.to_string().contains("compile_error"), "{repr}");
+        }
+
+        let output = derive_input(parse_quote! {
+            #[repr(C, align(8))]
+            struct S {
+                #[get]
+                #[get_mut]
+                f: u8,
+            }
+        });
+        assert_eq!(fn_names(output), vec!["f".to_owned(), "f_mut".to_owned()]);
+
+        let output = derive_input(parse_quote! {
+            #[repr(packed)]
+            struct S {
+                #[get(getter_ty = "copy")]
+                f: u8,
+            }
+        });
+        assert_eq!(fn_names(output), vec!["f".to_owned()]);
+    }
 }
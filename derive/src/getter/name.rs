@@ -1,7 +1,10 @@
-//! Contains [`FunctionName`]
+//! Contains [`FunctionName`] and the option [`Prefix`] of its default name
 
-use macro_utils::field::FieldName;
+use std::fmt::{self, Display};
+
+use macro_utils::{case::Case, field::FieldName};
 use proc_macro2::{Ident, Span};
+use syn::ext::IdentExt;
 
 use super::attribute_option::ParseOptionUtils;
 
@@ -11,44 +14,168 @@ use super::attribute_option::ParseOptionUtils;
 pub struct FunctionName {
     /// Wrapped ident value
     name: Option<Ident>,
+    /// case of the default name given by the container option `rename_all`, the field
+    /// name is used as is if [`None`]
+    case: Option<Case>,
+    /// prefix of the default name of a field of a tuple struct given by the container option
+    /// `tuple_names`, such a field has no default name if [`None`]
+    tuple_names: Option<String>,
+    /// prefix of the default name of the getter given by the option `prefix`, like `get_`
+    prefix: Option<String>,
 }
 
 impl FunctionName {
     /// Path string for the name option
-    const NAME_PATH: &'static str = "name";
+    pub const NAME_PATH: &'static str = "name";
 
     /// wrap a new [`Option::<Ident>`] into a new [`Self`]
     #[inline]
     #[must_use]
     const fn new(name: Option<Ident>) -> Self {
-        Self { name }
+        Self {
+            name,
+            case: None,
+            tuple_names: None,
+            prefix: None,
+        }
+    }
+
+    /// Returns `true` if the name is given in the attribute.
+    #[inline]
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        self.name.is_some()
+    }
+
+    /// Get the name given in the attribute, [`None`] if the default name is used.
+    #[inline]
+    #[must_use]
+    pub const fn given(&self) -> Option<&Ident> {
+        self.name.as_ref()
+    }
+
+    /// Set the name to `name` if it is not given in the attribute.
+    #[must_use]
+    pub fn with_name_if_unset(mut self, name: &str) -> Self {
+        if self.name.is_none() {
+            self.name = Some(Ident::new(name, Span::call_site()));
+        }
+        self
+    }
+
+    /// Set the case of the default names, see [`Self::default_name`]. The name given in
+    /// the attribute is not affected.
+    #[must_use]
+    pub const fn with_case(mut self, case: Option<Case>) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Set the prefix of the default names of the fields of a tuple struct, see
+    /// [`Self::default_name`]. The name given in the attribute is not affected.
+    #[must_use]
+    pub fn with_tuple_names(mut self, tuple_names: Option<&str>) -> Self {
+        self.tuple_names = tuple_names.map(ToOwned::to_owned);
+        self
+    }
+
+    /// Set the prefix of the default name of the getter given by the option `prefix`, see
+    /// [`Self::name`]. The name given in the attribute is not affected.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: Option<String>) -> Self {
+        self.prefix = prefix;
+        self
     }
 
-    /// Get the getter function name as an [`Ident`]. see [`Self::name`]
+    /// Get the prefix of the default name of the getter given by the option `prefix`.
+    #[inline]
     #[must_use]
-    fn ident<'a>(&'a self, field: &'a FieldName) -> Option<&'a Ident> {
-        self.name.as_ref().or_else(|| field.require_ident())
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Get the default name `{prefix}{field}{suffix}`, converted to the case of the
+    /// container option `rename_all` if it is set. It keeps the span of the field ident.
+    /// A raw field ident like `r#type` gives `type_mut`, and the name is raw again if it
+    /// is a keyword. The field `0` of a tuple struct is named `{tuple_names}0` if the
+    /// container option `tuple_names` is set.
+    ///
+    /// Return [`None`] if the field is identless and `tuple_names` is not set.
+    #[must_use]
+    pub fn default_name(&self, field: &FieldName, prefix: &str, suffix: &str) -> Option<Ident> {
+        let (field, span) = match field {
+            FieldName::Ident(ident) => {
+                if self.case.is_none() && prefix.is_empty() && suffix.is_empty() {
+                    return Some(ident.clone());
+                }
+                (ident.unraw().to_string(), ident.span())
+            }
+            FieldName::Index(index) => (
+                format!("{}{}", self.tuple_names.as_ref()?, index.index),
+                index.span,
+            ),
+        };
+        let name = format!("{prefix}{field}{suffix}");
+        let name = match self.case {
+            Some(case) => case.convert(&name),
+            None => name,
+        };
+        if syn::parse_str::<Ident>(&name).is_ok() {
+            Some(Ident::new(&name, span))
+        } else {
+            Some(Ident::new_raw(&name, span))
+        }
     }
 
     // cspell: ignore identless
-    /// Get the getter function name as an [`Ident`].
+    /// Get the getter function name as an [`Ident`], the field name if the name is not given,
+    /// after the prefix of the option `prefix` if it is set.
     ///
-    /// Return [`None`] if the field is identless and the name option is left unset.
+    /// Return [`None`] if the field is identless and the name option is left unset, unless
+    /// the container option `tuple_names` is set.
     #[must_use]
-    pub fn name<'a>(&'a self, field: &'a FieldName) -> Option<&'a Ident> {
-        self.ident(field)
+    pub fn name(&self, field: &FieldName) -> Option<Ident> {
+        self.name
+            .clone()
+            .or_else(|| self.default_name(field, self.prefix().unwrap_or_default(), ""))
     }
 
     /// Get the mut getter function name as an [`Ident`].
     ///
     /// Return [`None`] if the field is identless and the name option is left unset.
+    /// The default name `{prefix}{field}_mut` keeps the span of the field ident.
     #[must_use]
     pub fn name_mut(&self, field: &FieldName) -> Option<Ident> {
-        self.name.clone().or_else(|| {
-            field
-                .require_ident()
-                .map(|ident| Ident::new(&format!("{ident}_mut"), Span::call_site()))
-        })
+        self.name
+            .clone()
+            .or_else(|| self.default_name(field, self.prefix().unwrap_or_default(), "_mut"))
+    }
+
+    /// Get the name option of the mutable getter deduced from the name of the immutable getter,
+    /// i.e. `{name}_mut` if the name is set, unset otherwise so that [`Self::name_mut`]
+    /// gives the default name.
+    #[must_use]
+    pub fn mut_name_option(&self) -> Self {
+        Self::new(
+            self.name
+                .as_ref()
+                .map(|ident| Ident::new(&format!("{ident}_mut"), ident.span())),
+        )
+        .with_case(self.case)
+        .with_tuple_names(self.tuple_names.as_deref())
+        .with_prefix(self.prefix.clone())
+    }
+}
+
+/// Display the name given in the attribute, or that the default name is used.
+impl Display for FunctionName {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.name, &self.prefix) {
+            (Some(name), _) => write!(f, "`{name}`"),
+            (None, Some(prefix)) => write!(f, "with the default name prefixed by `{prefix}`"),
+            (None, None) => write!(f, "with the default name"),
+        }
     }
 }
 
@@ -59,7 +186,16 @@ impl ParseOptionUtils for FunctionName {
     }
 
     fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
-        Some(Self::new(Some(Ident::new(path, Span::call_site()))))
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    /// The ident of the getter is created with the span of the value given in the attribute,
+    /// so that going to the definition of the getter leads back to the attribute.
+    /// An invalid ident, like `name = "1f"`, is rejected instead of panicking.
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        syn::parse_str::<Ident>(path)
+            .ok()
+            .map(|ident| Self::new(Some(Ident::new(&ident.to_string(), span))))
     }
 
     #[inline]
@@ -67,3 +203,47 @@ impl ParseOptionUtils for FunctionName {
         path == Self::NAME_PATH
     }
 }
+
+/// Prefix of the default name of the getter, like `#[get(prefix = "get_")]` that names the
+/// getter of the field `size` `get_size` and its mutable getter `get_size_mut`. The name given
+/// with the option `name` is used as is.
+///
+/// The prefix cannot be empty and has to start an identifier, like `get_` but not `1`.
+///
+/// Accept value : `#[get(prefix = "{prefix}")]` or `#[get(prefix({prefix}))]`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Prefix(String);
+
+impl Prefix {
+    /// Path string for the prefix option
+    pub const PATH: &'static str = "prefix";
+
+    /// Get the prefix.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl ParseOptionUtils for Prefix {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    /// The prefix is valid if a name starting with it is an identifier that is not raw.
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        (!path.is_empty()
+            && path
+                .chars()
+                .all(|char| char == '_' || char.is_alphanumeric())
+            && syn::parse_str::<Ident>(&format!("{path}field")).is_ok())
+        .then(|| Self(path.to_owned()))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::PATH
+    }
+}
@@ -0,0 +1,161 @@
+//! Contains [`Nested`] and [`NestedTy`], the attribute options that create delegating
+//! getters on the fields of a nested struct.
+
+use proc_macro2::{Ident, Span};
+use syn::{punctuated::Punctuated, LitStr, Token, Type};
+
+use super::attribute_option::ParseOptionUtils;
+
+/// Option to create getters on fields of the field instead of a getter on the field itself.
+/// `#[get(nested = "timeout")]` on a field `config` creates `fn timeout(&self) -> &Ty` returning
+/// `&self.config.timeout`.
+///
+/// The derive cannot see the definition of the type of the field so the types of the nested
+/// fields must be given with [`NestedTy`], one type per nested field in the same order.
+///
+/// Accept value : like `#[get(nested = "timeout, retries", nested_ty = "Duration, u8")]`
+/// or `#[get(nested(timeout), nested_ty = "Duration")]` for a single field.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub struct Nested {
+    /// The names of the nested fields, also used as the names of the getters
+    fields: Vec<Ident>,
+}
+
+impl Nested {
+    /// Path string for the option
+    const PATH: &'static str = "nested";
+
+    /// Returns `true` if the option is set.
+    #[inline]
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        !self.fields.is_empty()
+    }
+
+    /// Get the names of the nested fields.
+    #[inline]
+    #[must_use]
+    pub fn fields(&self) -> &[Ident] {
+        &self.fields
+    }
+}
+
+impl ParseOptionUtils for Nested {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    /// The idents are created with the span of the value given in the attribute so that
+    /// the errors on the nested fields point back to the attribute.
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        let fields = LitStr::new(path, span)
+            .parse_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+            .ok()?;
+        (!fields.is_empty()).then(|| Self {
+            fields: fields.into_iter().collect(),
+        })
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::PATH
+    }
+}
+
+/// Option giving the types of the fields of [`Nested`], see there.
+///
+/// Accept value : like `#[get(nested_ty = "Duration, HashMap<String, u8>")]`.
+#[derive(Clone, Default)]
+pub struct NestedTy {
+    /// The types of the nested fields
+    types: Vec<Type>,
+}
+
+impl NestedTy {
+    /// Path string for the option
+    const PATH: &'static str = "nested_ty";
+
+    /// Get the types of the nested fields.
+    #[inline]
+    #[must_use]
+    pub fn types(&self) -> &[Type] {
+        &self.types
+    }
+}
+
+impl ParseOptionUtils for NestedTy {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    /// The types are created with the span of the value given in the attribute so that the
+    /// type errors point back to the attribute.
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        let types = LitStr::new(path, span)
+            .parse_with(Punctuated::<Type, Token![,]>::parse_terminated)
+            .ok()?;
+        (!types.is_empty()).then(|| Self {
+            types: types.into_iter().collect(),
+        })
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::PATH || path == "nested_type"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    use super::{Nested, NestedTy};
+    use crate::getter::attribute_option::ParseOption;
+
+    #[test]
+    fn parse() {
+        let nested =
+            Nested::parse_option(&parse_quote!(nested = "timeout, retries")).expect("valid option");
+        assert_eq!(nested.fields().len(), 2);
+        assert_eq!(nested.fields()[0], "timeout");
+        assert_eq!(nested.fields()[1], "retries");
+        assert!(nested.is_set());
+
+        let nested = Nested::parse_option(&parse_quote!(nested(timeout))).expect("valid option");
+        assert_eq!(nested.fields().len(), 1);
+        assert!(!Nested::default().is_set());
+
+        Nested::parse_option(&parse_quote!(nested = "")).expect_err("empty list");
+        Nested::parse_option(&parse_quote!(nested = "a b")).expect_err("missing comma");
+        Nested::parse_option(&parse_quote!(nested)).expect_err("no value");
+
+        let nested_ty =
+            NestedTy::parse_option(&parse_quote!(nested_ty = "Duration, HashMap<String, u8>"))
+                .expect("valid option");
+        let types = nested_ty
+            .types()
+            .iter()
+            .map(|ty| ty.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(types, vec!["Duration", "HashMap < String , u8 >"]);
+
+        // the type is not Debug
+        NestedTy::parse_option(&parse_quote!(nested_ty = ""))
+            .map(|_| ())
+            .expect_err("empty list");
+        NestedTy::parse_option(&parse_quote!(nested_ty = "1"))
+            .map(|_| ())
+            .expect_err("not a type");
+    }
+}
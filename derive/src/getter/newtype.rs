@@ -0,0 +1,43 @@
+//! Contains [`NewtypeFlag`], the attribute option that generates the conventional getters of a
+//! newtype.
+
+use super::attribute_option::ParseOptionUtils;
+
+/// Option to generate the conventional getters of a single field struct, a newtype like
+/// `struct Meters(f64);`:
+/// - the getter `fn get(&self) -> &T`,
+/// - the consuming getter `fn into_inner(self) -> T`,
+/// - and `impl AsRef<T> for Struct`.
+///
+/// The names of the getters can be changed with `name` and `into_inner`. It is also given to
+/// the single field by the container option `#[getter(newtype)]`.
+///
+/// Accept value : `#[get(newtype)]`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct NewtypeFlag;
+
+impl NewtypeFlag {
+    /// Path string for the option
+    pub const PATH: &'static str = "newtype";
+    /// Default name of the getter
+    pub const GETTER_NAME: &'static str = "get";
+    /// Default name of the consuming getter
+    pub const CONSUMING_GETTER_NAME: &'static str = "into_inner";
+}
+
+impl ParseOptionUtils for NewtypeFlag {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == Self::PATH).then_some(Self)
+    }
+
+    #[inline]
+    fn parse_option_from_str_assignment(_path: &str) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(_path: &str) -> bool {
+        false
+    }
+}
@@ -3,22 +3,45 @@
 
 #![allow(clippy::module_name_repetitions)] // TODO
 
-use std::{collections::HashSet, hash::Hash};
+use std::{
+    collections::HashSet,
+    fmt::{self, Debug, Display},
+    hash::Hash,
+};
 
-use macro_utils::field::{Field, FieldInformation};
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens};
-use syn::{punctuated::Punctuated, Meta, Path, Token};
+use macro_utils::{
+    case::Case,
+    field::{Field, FieldInformation, FieldName},
+    getter::GetterSpec,
+};
+use proc_macro2::{Delimiter, Group, Ident, Spacing, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprLit, Generics, Lit, Member,
+    Meta, MetaNameValue, Path, Token, Type, TypePath,
+};
 
 use super::{
+    add_mut::AddMut,
     attribute_option::ToCode,
+    conflict::{Conflict, ConflictRule, CONFLICTS},
     const_ty::ConstTy,
-    error::{AddConfigError, GetterParseError, OptionValidationError, ParseAttributeOptionError},
+    error::{
+        AddConfigError, GetterParseError, OptionValidationError, ParseAttributeOptionError,
+        SpannedError, UnacceptableParseError,
+    },
     getter_ty::GetterTy,
-    name::FunctionName,
+    hidden::Hidden,
+    into_inner::IntoInner,
+    missing::{option_inner_ty, MissingValue},
+    name::{FunctionName, Prefix},
+    nested::{Nested, NestedTy},
+    newtype::NewtypeFlag,
     option_enum::{ImmutableOptionList, MutableOptionList, OptionList},
     self_ty::SelfTy,
+    try_into::TryIntoTarget,
     which_getter::WhichGetter,
+    with::WithSetter,
     OptionParseError, ParseOption, Visibility,
 };
 
@@ -29,6 +52,32 @@ pub struct GetterOption {
     field: FieldInformation,
     /// the attribute option
     which: WhichGetter,
+    /// the attributes of the field that do not change the generated getters
+    redundant_attributes: Vec<RedundantAttribute>,
+}
+
+/// An attribute of a field that generates the same getters as the previous attributes of
+/// the field, like `#[get(name = "field")]` after `#[get]` on the field `field`.
+#[derive(Clone)]
+struct RedundantAttribute {
+    /// the path of the attribute, `get`, `get_mut` or their long spelling
+    path: String,
+    /// the span of the attribute
+    span: Span,
+}
+
+impl RedundantAttribute {
+    /// Get the path and the span of the attribute
+    fn new(attribute: &Attribute) -> Self {
+        Self {
+            path: attribute
+                .path()
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            span: attribute.span(),
+        }
+    }
 }
 
 impl GetterOption {
@@ -36,39 +85,59 @@ impl GetterOption {
     #[inline]
     #[must_use]
     const fn new(field: FieldInformation, which: WhichGetter) -> Self {
-        Self { field, which }
+        Self {
+            field,
+            which,
+            redundant_attributes: Vec::new(),
+        }
     }
 
-    /// Path string for immutable getter
-    const IMMUTABLE: &'static str = "get";
-    /// Path string for mutable reference getter
-    const MUTABLE: &'static str = "get_mut";
+    /// Path strings for immutable getter, the short and the long spelling
+    const IMMUTABLE: [&'static str; 2] = ["get", "getter_get"];
+    /// Path strings for mutable reference getter, the short and the long spelling
+    const MUTABLE: [&'static str; 2] = ["get_mut", "getter_get_mut"];
 
-    /// Get valid attribute path string
-    #[inline]
+    /// determine if the given path is an immutable getter attribute, `get` or `getter_get`
     #[must_use]
-    const fn valid_attribute() -> [&'static str; 2] {
-        [Self::IMMUTABLE, Self::MUTABLE]
+    fn is_immutable_path(path: &Path) -> bool {
+        Self::IMMUTABLE.into_iter().any(|s| path.is_ident(s))
+    }
+
+    /// determine if the given path is a mutable getter attribute, `get_mut` or
+    /// `getter_get_mut`
+    #[must_use]
+    fn is_mutable_path(path: &Path) -> bool {
+        Self::MUTABLE.into_iter().any(|s| path.is_ident(s))
     }
 
     /// determine if the given path is a valid getter attribute
     #[must_use]
     fn is_valid_path_attribute(path: &Path) -> bool {
-        Self::valid_attribute()
-            .into_iter()
-            .any(|s| path.is_ident(s))
+        Self::is_immutable_path(path) || Self::is_mutable_path(path)
     }
 
     // TODO
     // - if we want a mutable we write `#[get_mut]` with th same above rule or `#[get(mut)]`.
-    // - if we want both mut and mut we write `#[get(add_mut)]` or `#[get_mut(add_imut)]`
-    //  or `#[get(both)]`.
+    // - `#[get_mut(add_imut)]` to have both from the mutable getter attribute.
 
     /// - by default we would have `#[get]` it create a private getter.
     /// - if we want a public we have `#[get(pub)]`  or `#[get(visibility = pub)]`,
     /// possibilities are pub(...) public private.
-    /// - if we want to rename we write `#[get(rename = "...")]`.
-    pub fn parse(field: Field) -> Result<Self, OptionParseError> {
+    /// - if we want to rename we write `#[get(rename = "...")]`, or the shorthand `#[get = "..."]`.
+    /// - if we want both the immutable and mutable getter we write `#[get(both)]` or
+    ///   `#[get(add_mut)]`, an explicit `#[get_mut]` overrides the mutable getter.
+    /// - the fields of a tuple struct are named `{tuple_names}{index}` by default if
+    ///   `tuple_names` is set, like `field_0`. It is given by the container option
+    ///   `tuple_names` and applied before the validation so that the name is not missing.
+    /// - if `newtype` is set by the container option `newtype`, the field has the getters of
+    ///   `#[get(newtype)]` even without attribute, see [`WhichGetter::with_newtype`].
+    ///
+    /// The error is given with the span of the attribute (or attribute option) that caused it.
+    pub fn parse(
+        field: Field,
+        tuple_names: Option<&str>,
+        newtype: bool,
+    ) -> Result<Self, SpannedError<OptionParseError>> {
         /// merge a configuration with an option of a which getter
         #[must_use]
         fn add_option_config(out: Option<WhichGetter>, which: WhichGetter) -> WhichGetter {
@@ -79,54 +148,128 @@ impl GetterOption {
             }
         }
 
+        let span = field.field().span();
+        let attributes = field.field().attrs.clone();
+        let information = FieldInformation::from_field(field);
         let mut out = None;
+        let mut redundant_attributes = Vec::new();
 
-        for attribute in &field.field().attrs {
-            match &attribute.meta {
+        for attribute in &attributes {
+            let which = match &attribute.meta {
                 Meta::List(meta_list) => {
+                    if !Self::is_valid_path_attribute(&meta_list.path) {
+                        continue;
+                    }
                     // FIXE ME
                     let list = meta_list
-                        .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
-                    if meta_list.path.is_ident(Self::IMMUTABLE) {
-                        out = Some(add_option_config(
-                            out,
-                            WhichGetter::Immutable(ImmutableGetterOption::parse(list)?),
-                        ));
-                    } else if meta_list.path.is_ident(Self::MUTABLE) {
-                        out = Some(add_option_config(
-                            out,
-                            WhichGetter::Mutable(MutableGetterOption::parse(list)?),
-                        ));
+                        .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                        .map_err(|err| {
+                            let span = err.span();
+                            SpannedError::new(err.into(), span)
+                        })?;
+                    if Self::is_immutable_path(&meta_list.path) {
+                        let immutable = ImmutableGetterOption::from_metas(list)?;
+                        if let Some(mutable) = immutable.mutable_option() {
+                            WhichGetter::Both { immutable, mutable }
+                        } else {
+                            WhichGetter::Immutable(immutable)
+                        }
+                    } else {
+                        WhichGetter::Mutable(MutableGetterOption::from_metas(list)?)
                     }
                 }
                 Meta::Path(path) => {
-                    if path.is_ident(Self::IMMUTABLE) {
-                        out = Some(add_option_config(
-                            out,
-                            WhichGetter::Immutable(ImmutableGetterOption::default()),
-                        ));
-                    } else if path.is_ident(Self::MUTABLE) {
-                        out = Some(add_option_config(
-                            out,
-                            WhichGetter::Mutable(MutableGetterOption::default()),
-                        ));
+                    if Self::is_immutable_path(path) {
+                        WhichGetter::Immutable(ImmutableGetterOption::default())
+                    } else if Self::is_mutable_path(path) {
+                        WhichGetter::Mutable(MutableGetterOption::default())
+                    } else {
+                        continue;
                     }
                 }
                 Meta::NameValue(name_value) => {
-                    if Self::is_valid_path_attribute(&name_value.path) {
-                        return Err(OptionParseError::NameValue);
+                    if !Self::is_valid_path_attribute(&name_value.path) {
+                        continue;
+                    }
+                    let name = Self::name_value_shorthand(name_value)?;
+                    if Self::is_immutable_path(&name_value.path) {
+                        WhichGetter::Immutable(ImmutableGetterOption::from_metas(name)?)
+                    } else {
+                        WhichGetter::Mutable(MutableGetterOption::from_metas(name)?)
                     }
                 }
+            };
+
+            let merged = add_option_config(out.clone(), which.with_tuple_names(tuple_names));
+            if out
+                .as_ref()
+                .is_some_and(|previous| Self::same_getters(&information, previous, &merged))
+            {
+                redundant_attributes.push(RedundantAttribute::new(attribute));
             }
+            out = Some(merged);
         }
 
-        let out = out.ok_or(OptionParseError::NotFound)?;
+        let out = match out {
+            Some(which) if newtype => which.with_newtype(),
+            Some(which) => which,
+            None if newtype => {
+                WhichGetter::Immutable(ImmutableGetterOption::default().with_newtype())
+            }
+            None => return Err(SpannedError::new(OptionParseError::NotFound, span)),
+        };
 
-        let getter_option = Self::new(FieldInformation::from_field(field), out);
-        getter_option.validate()?;
+        let getter_option = Self {
+            field: information,
+            which: out,
+            redundant_attributes,
+        };
+        getter_option
+            .validate()
+            .map_err(|err| SpannedError::new(err.into(), span))?;
         Ok(getter_option)
     }
 
+    /// Returns `true` if the two configurations are valid and generate the same getters,
+    /// i.e. the attribute merged into `previous` to give `merged` has no effect.
+    fn same_getters(
+        information: &FieldInformation,
+        previous: &WhichGetter,
+        merged: &WhichGetter,
+    ) -> bool {
+        let code = |which: &WhichGetter| {
+            let option = Self::new(information.clone(), which.clone());
+            option
+                .validate()
+                .is_ok()
+                .then(|| option.which.to_code(&option.field).to_string())
+        };
+        code(previous).is_some_and(|previous| code(merged) == Some(previous))
+    }
+
+    /// Convert the shorthand `#[get = "name"]` into the option `name = "name"`.
+    ///
+    /// Only a literal string is accepted on the right hand side, any other name value
+    /// attribute gives [`OptionParseError::NameValue`].
+    fn name_value_shorthand(
+        name_value: &MetaNameValue,
+    ) -> Result<[Meta; 1], SpannedError<OptionParseError>> {
+        let Expr::Lit(ExprLit {
+            lit: Lit::Str(_), ..
+        }) = &name_value.value
+        else {
+            return Err(SpannedError::new(
+                OptionParseError::NameValue,
+                name_value.span(),
+            ));
+        };
+        Ok([Meta::NameValue(MetaNameValue {
+            path: Ident::new(FunctionName::NAME_PATH, name_value.path.span()).into(),
+            eq_token: name_value.eq_token,
+            value: name_value.value.clone(),
+        })])
+    }
+
     // /// Merge two configuration giving the priority to the `other` config, see [`WhichGetter::add_config`]
     // fn add_config(self, other: WhichGetter) -> Self {
     //     Self::new(self.field, self.which.add_config(other))
@@ -136,14 +279,10 @@ impl GetterOption {
     fn validate(&self) -> Result<(), OptionValidationError> {
         match &self.which {
             WhichGetter::Immutable(immutable) => {
-                if immutable
-                    .option
-                    .name()
-                    .name(self.field.field_name())
-                    .is_none()
-                {
+                if immutable.name_missing(self.field.field_name()) {
                     return Err(OptionValidationError::FunctionNameMissing);
                 }
+                immutable.validate_field(&self.field)?;
             }
             WhichGetter::Mutable(mutable) => {
                 if mutable.name().name_mut(self.field.field_name()).is_none() {
@@ -151,15 +290,12 @@ impl GetterOption {
                 }
             }
             WhichGetter::Both { immutable, mutable } => {
-                if immutable
-                    .option
-                    .name()
-                    .name(self.field.field_name())
-                    .is_none()
+                if immutable.name_missing(self.field.field_name())
                     || mutable.name().name_mut(self.field.field_name()).is_none()
                 {
                     return Err(OptionValidationError::FunctionNameMissing);
                 }
+                immutable.validate_field(&self.field)?;
             }
         }
 
@@ -167,6 +303,183 @@ impl GetterOption {
     }
 }
 
+impl GetterOption {
+    /// Access the field through the path `via` from `self`, for getters generated on a
+    /// wrapper of the struct, see [`FieldInformation::access`].
+    #[must_use]
+    pub fn with_via(mut self, via: &[Member]) -> Self {
+        self.field = self.field.with_via(via.to_vec());
+        self
+    }
+
+    /// Convert the default names of the getters to the case of the container option
+    /// `rename_all`, see [`FunctionName::default_name`]. The names given in the attribute
+    /// and the delegating getters of the option `nested` are not affected.
+    #[must_use]
+    pub fn with_rename_all(mut self, case: Option<Case>) -> Self {
+        self.which = self.which.with_rename_all(case);
+        self
+    }
+
+    /// Give the generics of the struct to the getters, so that the documentation does not
+    /// link to a type parameter of the struct, see [`type_doc`].
+    #[must_use]
+    pub fn with_generics(mut self, generics: &Generics) -> Self {
+        self.which = self.which.with_generics(generics);
+        self
+    }
+
+    /// Replace `Self` in the type of the field by `struct_ty`, the type of the struct with its
+    /// generics. In the trait of the options `module` and `trait_def`, and in the inherent
+    /// implementation on the type of the option `for`, `Self` is not the struct, so a field
+    /// like `next: Option<Box<Self>>` would get a getter with the wrong type.
+    #[must_use]
+    pub fn with_struct_type(mut self, struct_ty: &TokenStream2) -> Self {
+        let ty = replace_self(self.field.ty().to_token_stream(), struct_ty);
+        if let Ok(ty) = syn::parse2(ty) {
+            self.field.ty = ty;
+        }
+        self
+    }
+
+    /// Get the code emitting a deprecation warning on each attribute of the field that does
+    /// not change the generated getters, for the container option `warn_redundant`. A proc
+    /// macro cannot emit a warning, so each warning is the use of a deprecated function
+    /// spanned on the redundant attribute, in an anonymous constant.
+    #[must_use]
+    pub fn redundant_attribute_warnings(&self) -> TokenStream2 {
+        let field_name = self.field.field_name();
+        self.redundant_attributes
+            .iter()
+            .map(|attribute| {
+                let note = format!(
+                    "redundant #[{}] attribute: the getters of the field `{field_name}` are \
+                    the same without it",
+                    attribute.path
+                );
+                let call = quote_spanned! {attribute.span=> redundant_attribute();};
+                quote! {
+                    const _: () = {
+                        #[deprecated(note = #note)]
+                        const fn redundant_attribute() {}
+                        #call
+                    };
+                }
+            })
+            .collect()
+    }
+
+    /// Get the declaration of the getters as trait methods, see [`ToCode::to_trait_declaration_code`]
+    #[must_use]
+    pub fn trait_declaration(&self) -> TokenStream2 {
+        self.which.to_trait_declaration_code(&self.field)
+    }
+
+    /// Get the implementation of the getters as trait methods, see [`ToCode::to_trait_impl_code`]
+    #[must_use]
+    pub fn trait_implementation(&self) -> TokenStream2 {
+        self.which.to_trait_impl_code(&self.field)
+    }
+
+    /// Verify that the immutable getter can be a method of the object safe trait of the
+    /// container option `trait_def`, i.e. it takes `self` by reference.
+    pub fn validate_trait_def(&self) -> Result<(), OptionValidationError> {
+        match self.which.immutable() {
+            Some(immutable)
+                if immutable.self_ty == SelfTy::Value
+                    || immutable.into_inner.is_set()
+                    || immutable.with.is_set() =>
+            {
+                Err(OptionValidationError::ByValueSelfInTraitDef)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Verify that no getter takes a reference to the field of a `#[repr(packed)]` struct, as
+    /// the field can be unaligned. Only the copy getter, reading the field by value, is
+    /// accepted, without the `AsRef` implementation of the option `newtype`.
+    pub fn validate_packed(&self) -> Result<(), OptionValidationError> {
+        if self
+            .which
+            .immutable()
+            .is_some_and(|immutable| immutable.ty != GetterTy::Copy || immutable.newtype)
+        {
+            Err(OptionValidationError::ReferenceInPackedStruct)
+        } else if matches!(self.which, WhichGetter::Immutable(_)) {
+            Ok(())
+        } else {
+            Err(OptionValidationError::MutableGetterInPackedStruct)
+        }
+    }
+
+    /// Verify that the option `newtype` is only used on the field of a single field struct.
+    pub fn validate_newtype(&self, single_field: bool) -> Result<(), OptionValidationError> {
+        if !single_field
+            && self
+                .which
+                .immutable()
+                .is_some_and(|immutable| immutable.newtype)
+        {
+            Err(OptionValidationError::NewtypeNotSingleField)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Get the implementation of `AsRef<Ty>` for `target` if the option `newtype` is set,
+    /// empty otherwise. `generics` are the generics of the struct.
+    #[must_use]
+    pub fn newtype_implementation(
+        &self,
+        generics: &Generics,
+        target: &TokenStream2,
+    ) -> TokenStream2 {
+        if !self
+            .which
+            .immutable()
+            .is_some_and(|immutable| immutable.newtype)
+        {
+            return TokenStream2::new();
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let ty = self.field.ty();
+        let access = self.field.access();
+        let cfg = cfg_code(&self.field);
+        quote! {
+            #cfg
+            /// Automatically generated implementation for the newtype getters
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::AsRef<#ty> for #target #where_clause {
+                #[inline]
+                fn as_ref(&self) -> &#ty {
+                    &#access
+                }
+            }
+        }
+    }
+
+    /// Get the declaration of the immutable getter as a method of the trait of the container
+    /// option `trait_def`, empty if there is only a mutable getter.
+    #[must_use]
+    pub fn trait_def_declaration(&self) -> TokenStream2 {
+        self.which
+            .immutable()
+            .map(|immutable| immutable.to_trait_declaration_code(&self.field))
+            .unwrap_or_default()
+    }
+
+    /// Get the implementation of the immutable getter as a method of the trait of the
+    /// container option `trait_def`, empty if there is only a mutable getter.
+    #[must_use]
+    pub fn trait_def_implementation(&self) -> TokenStream2 {
+        self.which
+            .immutable()
+            .map(|immutable| immutable.to_trait_impl_code(&self.field))
+            .unwrap_or_default()
+    }
+}
+
 impl ToTokens for GetterOption {
     #[inline]
     fn to_tokens(&self, tokens: &mut TokenStream2) {
@@ -174,6 +487,20 @@ impl ToTokens for GetterOption {
     }
 }
 
+/// Get the `#[cfg(...)]` attributes of the field to forward them on the getters, so that
+/// the getters are removed alongside the field.
+///
+/// The `#[cfg_attr(...)]` are not forwarded as the attributes they expand to are
+/// usually only valid on a field.
+fn cfg_code(field_information: &FieldInformation) -> TokenStream2 {
+    let cfg = field_information
+        .cfg_attrs()
+        .filter(|attr| attr.path().is_ident("cfg"));
+    quote! {
+        #(#cfg)*
+    }
+}
+
 //-------------------------
 
 // TODO move
@@ -187,9 +514,13 @@ pub(super) trait ParseGetterOption: Sized + Default {
     type Option: OptionList + Hash + Eq;
 
     /// Try tp parse an iterator of [`Meta`] into a Option
+    ///
+    /// The error is given with the span of the [`Meta`] that caused it.
     fn parse<T: IntoIterator<Item = Meta>>(
         tokens: T,
-    ) -> Result<Self, GetterParseError<Self::Option>> {
+    ) -> Result<Self, SpannedError<GetterParseError<Self::Option>>> {
+        // the set is only used to detect duplicates, the options are applied in the order
+        // of the iterator so the iteration order of the set does not matter
         let mut set = HashSet::new();
         let mut s = Self::default();
         for meta in tokens {
@@ -199,15 +530,26 @@ pub(super) trait ParseGetterOption: Sized + Default {
                     // this replace function save us to do one clone
                     // as we get back the option
                     if let Some(option) = set.replace(option) {
-                        return Err(GetterParseError::FieldAttributeOptionSetMultipleTimes(
-                            option,
+                        return Err(SpannedError::new(
+                            GetterParseError::FieldAttributeOptionSetMultipleTimes(option),
+                            meta.span(),
                         ));
                     }
                 }
                 Err(AddConfigError::Acceptable(_)) => { //continue;
                 }
+                Err(AddConfigError::ImmutableOnly(option)) => {
+                    return Err(SpannedError::new(
+                        GetterParseError::ImmutableOnlyOption(option),
+                        meta.span(),
+                    ));
+                }
                 Err(AddConfigError::Unacceptable(err, option)) => {
-                    return Err(GetterParseError::AddConfigError(err, option))
+                    let span = option_error_span(&meta, &err);
+                    return Err(SpannedError::new(
+                        GetterParseError::AddConfigError(err, option),
+                        span,
+                    ));
                 }
             }
         }
@@ -218,6 +560,19 @@ pub(super) trait ParseGetterOption: Sized + Default {
     fn add_config(&mut self, option: &Meta) -> Result<Self::Option, AddConfigError<Self::Option>>;
 }
 
+/// Get the span where an error on the given option should be reported: the left hand side
+/// path if it is the faulty part, the right hand value otherwise.
+fn option_error_span(option: &Meta, err: &UnacceptableParseError) -> Span {
+    match (option, err) {
+        (_, UnacceptableParseError::IdentParseError(err)) => err.span(),
+        (_, UnacceptableParseError::LeftHandSideValueNotIdent) | (Meta::Path(_), _) => {
+            option.path().span()
+        }
+        (Meta::NameValue(name_value), _) => name_value.value.span(),
+        (Meta::List(meta_list), _) => meta_list.tokens.span(),
+    }
+}
+
 /// Option for immutable getter
 #[derive(Clone, Default)]
 pub struct ImmutableGetterOption {
@@ -229,28 +584,317 @@ pub struct ImmutableGetterOption {
     ty: GetterTy,
     /// if the self value is borrowed or moved(or copied)
     self_ty: SelfTy,
+    /// if the mutable getter is also created
+    add_mut: AddMut,
+    /// if the consuming getter is also created
+    into_inner: IntoInner,
+    /// if the getter unwraps an `Option` field
+    missing: MissingValue,
+    /// the fields of the field on which delegating getters are created instead
+    nested: Nested,
+    /// the types of the nested fields
+    nested_ty: NestedTy,
+    /// if the conventional getters of a newtype are created
+    newtype: bool,
+    /// if the consuming setter is also created
+    with: WithSetter,
+    /// the type the field is converted into with `TryInto`
+    try_into: TryIntoTarget,
+    /// the generics of the struct, see [`GetterOption::with_generics`]
+    generics: Box<Generics>,
+}
+
+impl Debug for ImmutableGetterOption {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImmutableGetterOption")
+            .field("option", &self.option)
+            .field("const_ty", &self.const_ty)
+            .field("ty", &self.ty)
+            .field("self_ty", &self.self_ty)
+            .field("add_mut", &self.add_mut)
+            .field("into_inner", &self.into_inner)
+            .field("missing", &self.missing.to_string())
+            .field("nested", &self.nested)
+            .field(
+                "nested_ty",
+                &self
+                    .nested_ty
+                    .types()
+                    .iter()
+                    .map(type_string)
+                    .collect::<Vec<_>>(),
+            )
+            .field("newtype", &self.newtype)
+            .field("with", &self.with)
+            .field("try_into", &self.try_into.to_string())
+            .field("generics", &tokens_string(self.generics.to_token_stream()))
+            .finish()
+    }
+}
+
+/// Describe the configuration, like "public constant getter `x` returning a reference and
+/// taking self by reference", followed by the additional getters and the handling of an unset
+/// `Option` field if any.
+impl Display for ImmutableGetterOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} getter {} returning a {} and taking self by {}",
+            self.option.visibility, self.const_ty, self.option.name, self.ty, self.self_ty
+        )?;
+        if self.add_mut == AddMut::Both {
+            write!(f, ", with a mutable getter")?;
+        }
+        match &self.into_inner {
+            IntoInner::None => {}
+            IntoInner::DefaultName => write!(f, ", with a consuming getter")?,
+            IntoInner::Named(name) => write!(f, ", with the consuming getter `{name}`")?,
+        }
+        match &self.with {
+            WithSetter::None => {}
+            WithSetter::DefaultName => write!(f, ", with a consuming setter")?,
+            WithSetter::Named(name) => write!(f, ", with the consuming setter `{name}`")?,
+        }
+        if self.missing.is_set() {
+            write!(f, ", {}", self.missing)?;
+        }
+        if self.try_into.is_set() {
+            write!(f, ", {}", self.try_into)?;
+        }
+        if self.nested.is_set() {
+            let fields = self
+                .nested
+                .fields()
+                .iter()
+                .map(|field| format!("`{field}`"))
+                .collect::<Vec<_>>();
+            write!(f, ", delegating to the nested fields {}", fields.join(", "))?;
+        }
+        if self.newtype {
+            write!(f, ", implementing AsRef for the newtype")?;
+        }
+        if self.option.hidden.is_hidden() {
+            write!(f, ", hidden from the documentation")?;
+        }
+        Ok(())
+    }
 }
 
 impl ImmutableGetterOption {
-    /// Verify that the option is valid
-    pub fn validate(&self) -> Result<(), OptionValidationError> {
-        self.option.validate()?;
-        if self.self_ty == SelfTy::Value && self.ty == GetterTy::Ref {
-            Err(OptionValidationError::SelfMoveOnReturnRef)
+    /// Parse the options of a `#[get(...)]` attribute, like the [`Meta`] of
+    /// `parse_quote!(name = "x")` or `parse_quote!(Const)`.
+    ///
+    /// The options are not validated, see [`Self::validate`]. The names of the getters of the
+    /// option `newtype` are given once all the options are parsed, so that `name` and
+    /// `into_inner` override them wherever they are in the list.
+    ///
+    /// # Errors
+    /// Returns the error with the span of the option that caused it if an option is invalid
+    /// or set multiple times. An option that is not recognized is ignored.
+    #[inline]
+    pub fn from_metas<T: IntoIterator<Item = Meta>>(
+        metas: T,
+    ) -> Result<Self, SpannedError<OptionParseError>> {
+        Self::parse(metas)
+            .map(|option| {
+                if option.newtype {
+                    option.with_newtype()
+                } else {
+                    option
+                }
+            })
+            .map_err(SpannedError::err_into)
+    }
+
+    /// Create the conventional getters of a newtype: the getter is named `get` and the
+    /// consuming getter `into_inner` unless their names are given, and `AsRef` is
+    /// implemented, see [`GetterOption::newtype_implementation`].
+    #[must_use]
+    pub fn with_newtype(mut self) -> Self {
+        self.newtype = true;
+        self.option.name = self
+            .option
+            .name
+            .with_name_if_unset(NewtypeFlag::GETTER_NAME);
+        self.into_inner = self
+            .into_inner
+            .with_name_if_unnamed(NewtypeFlag::CONSUMING_GETTER_NAME);
+        self
+    }
+
+    /// Set the case of the default names, see [`GetterOption::with_rename_all`].
+    #[must_use]
+    pub fn with_rename_all(mut self, case: Option<Case>) -> Self {
+        self.option = self.option.with_rename_all(case);
+        self
+    }
+
+    /// Set the prefix of the default names of the fields of a tuple struct, see
+    /// [`GetterOption::parse`].
+    #[must_use]
+    pub fn with_tuple_names(mut self, tuple_names: Option<&str>) -> Self {
+        self.option = self.option.with_tuple_names(tuple_names);
+        self
+    }
+
+    /// Set the generics of the struct, see [`GetterOption::with_generics`].
+    #[must_use]
+    pub fn with_generics(mut self, generics: &Generics) -> Self {
+        self.option = self.option.with_generics(generics);
+        self.generics = Box::new(generics.clone());
+        self
+    }
+
+    /// Get the option of the mutable getter if the option `both` (or `add_mut`) is set.
+    /// The mutable getter has the same visibility and is named `{name}_mut`.
+    #[must_use]
+    pub fn mutable_option(&self) -> Option<MutableGetterOption> {
+        (self.add_mut == AddMut::Both).then(|| MutableGetterOption {
+            visibility: self.option.visibility.clone(),
+            name: self.option.name.mut_name_option(),
+            hidden: self.option.hidden,
+            generics: self.generics.clone(),
+        })
+    }
+
+    /// Returns `true` if the name of the getter, or of the consuming getter or the consuming
+    /// setter if they are set, cannot be determined, i.e. the field is identless and the name
+    /// is not given.
+    #[must_use]
+    fn name_missing(&self, field_name: &FieldName) -> bool {
+        if self.nested.is_set() {
+            // the getters are named after the nested fields
+            return false;
+        }
+        self.option.name().name(field_name).is_none()
+            || (self.into_inner.is_set()
+                && self
+                    .into_inner
+                    .name(field_name, self.option.name())
+                    .is_none())
+            || (self.with.is_set() && self.with.name(field_name, self.option.name()).is_none())
+    }
+
+    /// Verify that the option is valid for the given field, i.e. that the field is an `Option`
+    /// if `expect` or `unwrap` is set.
+    fn validate_field(
+        &self,
+        field_information: &FieldInformation,
+    ) -> Result<(), OptionValidationError> {
+        if self.missing.is_set() && option_inner_ty(field_information.ty()).is_none() {
+            Err(OptionValidationError::MissingValueNotOption)
         } else {
             Ok(())
         }
     }
+
+    /// Verify that the option is valid, i.e. that no pair of options of the validation matrix
+    /// [`CONFLICTS`] conflicts. The first conflict found is returned.
+    pub fn validate(&self) -> Result<(), OptionValidationError> {
+        self.option.validate()?;
+        CONFLICTS
+            .iter()
+            .find(|conflict| self.violates(conflict))
+            .map_or(Ok(()), |conflict| Err(conflict.error))
+    }
+
+    /// Returns `true` if the two options of the conflict are set in a way that conflicts.
+    fn violates(&self, conflict: &Conflict) -> bool {
+        let [first, second] = conflict.options;
+        match conflict.rule {
+            ConflictRule::BothSet => self.is_set(first) && self.is_set(second),
+            ConflictRule::ConstClone => {
+                self.const_ty == ConstTy::Constant && self.ty == GetterTy::Clone
+            }
+            ConflictRule::SelfValueReturnRef => {
+                self.self_ty == SelfTy::Value && self.ty == GetterTy::Ref
+            }
+            ConflictRule::NestedCountMismatch => {
+                self.nested.fields().len() != self.nested_ty.types().len()
+            }
+            ConflictRule::TryIntoReference => {
+                self.try_into.is_set() && !TryIntoTarget::accepts(self.ty)
+            }
+            ConflictRule::SameName => self
+                .given_name(first)
+                .is_some_and(|name| self.given_name(second).as_ref() == Some(&name)),
+        }
+    }
+
+    /// Returns `true` if the option is set to a value other than its default.
+    fn is_set(&self, option: ImmutableOptionList) -> bool {
+        match option {
+            ImmutableOptionList::MutableOption(MutableOptionList::Visibility) => {
+                !matches!(self.option.visibility, Visibility::Private)
+            }
+            ImmutableOptionList::MutableOption(MutableOptionList::IdentOption) => {
+                self.option.name.is_set()
+            }
+            ImmutableOptionList::MutableOption(MutableOptionList::Hidden) => {
+                self.option.hidden.is_hidden()
+            }
+            ImmutableOptionList::MutableOption(MutableOptionList::Prefix) => {
+                self.option.name.prefix().is_some()
+            }
+            ImmutableOptionList::ConstTy => self.const_ty == ConstTy::Constant,
+            ImmutableOptionList::GetterTy => self.ty != GetterTy::default(),
+            ImmutableOptionList::SelfTy => self.self_ty == SelfTy::Value,
+            ImmutableOptionList::AddMut => self.add_mut == AddMut::Both,
+            ImmutableOptionList::IntoInner => self.into_inner.is_set(),
+            ImmutableOptionList::MissingValue => self.missing.is_set(),
+            ImmutableOptionList::Nested => self.nested.is_set(),
+            ImmutableOptionList::NestedTy => !self.nested_ty.types().is_empty(),
+            ImmutableOptionList::Newtype => self.newtype,
+            ImmutableOptionList::With => self.with.is_set(),
+            ImmutableOptionList::TryInto => self.try_into.is_set(),
+        }
+    }
+
+    /// Get the name of the function created by the option if it is given explicitly, the
+    /// name of the mutable getter is `{name}_mut` if the name is given.
+    fn given_name(&self, option: ImmutableOptionList) -> Option<Ident> {
+        match option {
+            ImmutableOptionList::MutableOption(MutableOptionList::IdentOption) => {
+                self.option.name.given().cloned()
+            }
+            ImmutableOptionList::AddMut => self
+                .mutable_option()
+                .and_then(|mutable| mutable.name.given().cloned()),
+            ImmutableOptionList::IntoInner => match &self.into_inner {
+                IntoInner::Named(name) => Some(name.clone()),
+                IntoInner::None | IntoInner::DefaultName => None,
+            },
+            ImmutableOptionList::With => match &self.with {
+                WithSetter::Named(name) => Some(name.clone()),
+                WithSetter::None | WithSetter::DefaultName => None,
+            },
+            ImmutableOptionList::MutableOption(
+                MutableOptionList::Visibility
+                | MutableOptionList::Hidden
+                | MutableOptionList::Prefix,
+            )
+            | ImmutableOptionList::ConstTy
+            | ImmutableOptionList::GetterTy
+            | ImmutableOptionList::SelfTy
+            | ImmutableOptionList::MissingValue
+            | ImmutableOptionList::Nested
+            | ImmutableOptionList::NestedTy
+            | ImmutableOptionList::Newtype
+            | ImmutableOptionList::TryInto => None,
+        }
+    }
 }
 
 impl ParseGetterOption for ImmutableGetterOption {
     type Option = ImmutableOptionList;
 
+    #[allow(clippy::too_many_lines)] // reason = "one block per option"
     fn add_config(&mut self, option: &Meta) -> Result<Self::Option, AddConfigError<Self::Option>> {
-        match self.option.add_config(option) {
+        match self.option.add_common_config(option) {
             Ok(option) => return Ok(option.into()),
-            Err(err @ AddConfigError::Unacceptable(_, _)) => return Err(err.into()),
             Err(AddConfigError::Acceptable(_)) => {}
+            Err(err) => return Err(err.into()),
         }
         match ConstTy::parse_option(option) {
             Ok(const_ty) => {
@@ -281,62 +925,578 @@ impl ParseGetterOption for ImmutableGetterOption {
         match SelfTy::parse_option(option) {
             Ok(self_ty) => {
                 self.self_ty = self_ty;
-                Ok(ImmutableOptionList::SelfTy)
+                return Ok(ImmutableOptionList::SelfTy);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::SelfTy,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match AddMut::parse_option(option) {
+            Ok(add_mut) => {
+                self.add_mut = add_mut;
+                return Ok(ImmutableOptionList::AddMut);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::AddMut,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match MissingValue::parse_option(option) {
+            Ok(missing) => {
+                self.missing = missing;
+                return Ok(ImmutableOptionList::MissingValue);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::MissingValue,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match TryIntoTarget::parse_option(option) {
+            Ok(try_into) => {
+                self.try_into = try_into;
+                return Ok(ImmutableOptionList::TryInto);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::TryInto,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match Nested::parse_option(option) {
+            Ok(nested) => {
+                self.nested = nested;
+                return Ok(ImmutableOptionList::Nested);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::Nested,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match NestedTy::parse_option(option) {
+            Ok(nested_ty) => {
+                self.nested_ty = nested_ty;
+                return Ok(ImmutableOptionList::NestedTy);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::NestedTy,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match NewtypeFlag::parse_option(option) {
+            Ok(NewtypeFlag) => {
+                self.newtype = true;
+                return Ok(ImmutableOptionList::Newtype);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::Newtype,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match IntoInner::parse_option(option) {
+            Ok(into_inner) => {
+                self.into_inner = into_inner;
+                return Ok(ImmutableOptionList::IntoInner);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(
+                    err,
+                    ImmutableOptionList::IntoInner,
+                ));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match WithSetter::parse_option(option) {
+            Ok(with) => {
+                self.with = with;
+                Ok(ImmutableOptionList::With)
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                Err(AddConfigError::Unacceptable(err, ImmutableOptionList::With))
             }
-            Err(ParseAttributeOptionError::Unacceptable(err)) => Err(AddConfigError::Unacceptable(
-                err,
-                ImmutableOptionList::SelfTy,
-            )),
             Err(ParseAttributeOptionError::Acceptable(err)) => Err(err.into()),
         }
     }
 }
 
-impl ToCode for ImmutableGetterOption {
-    fn to_code(&self, field_information: &FieldInformation) -> TokenStream2 {
-        let visibility = self.option.visibility();
-        // TODO improve
+impl ImmutableGetterOption {
+    /// Get the documentation comment of the getter
+    fn comment(&self, field_information: &FieldInformation) -> String {
+        let conversion = match &self.try_into {
+            TryIntoTarget::None => String::new(),
+            TryIntoTarget::Target(target) => format!(
+                " The value is converted into {} with [`TryInto`].\n\n# Errors\nReturns the \
+                error of the conversion if the value cannot be converted.",
+                type_doc(target, &self.generics)
+            ),
+        };
+        format!(
+            "Getter on a {} of the field `{}` with type {}.{}{conversion}",
+            self.ty,
+            field_information.field_name(),
+            type_doc(field_information.ty(), &self.generics),
+            self.missing.comment()
+        )
+    }
 
+    /// Get the [`GetterSpec`] of the getter, the documentation included.
+    fn spec(&self, field_information: &FieldInformation) -> GetterSpec {
+        // TODO improve
         let fn_name = self
             .option
             .name()
             .name(field_information.field_name())
             .expect("no field name");
-        let ty = field_information.ty();
-        let field_name = field_information.field_name();
+        GetterSpec::new(fn_name)
+            .with_visibility(self.option.visibility().into())
+            .with_constant(self.const_ty.into())
+            .with_getter_ty(self.ty.into())
+            .with_self_ty(self.self_ty.into())
+            .with_doc(self.comment(field_information))
+            .with_hidden(self.option.hidden.is_hidden())
+    }
 
-        let const_ty = self.const_ty;
-        let getter_ty_prefix = self.ty.prefix_quote();
-        let getter_ty_suffix = self.ty.suffix_quote();
-        let self_ty_code = self.self_ty;
+    /// Get the signature of the getter without the visibility and the const modifier,
+    /// i.e. `fn name(&self) -> &Ty`. The return type is adapted if the getter unwraps an
+    /// `Option` field or converts the field.
+    fn signature(&self, spec: &GetterSpec, field_information: &FieldInformation) -> TokenStream2 {
+        let ty = if self.missing.is_set() {
+            option_inner_ty(field_information.ty()).expect("the field type is not an option")
+        } else {
+            field_information.ty()
+        };
+        let return_ty = self.try_into.return_ty(
+            field_information.ty(),
+            self.missing.return_ty(&spec.getter_ty().return_ty(ty)),
+        );
+        spec.signature_returning(&return_ty)
+    }
 
-        let comment = format!(
-            "Getter on a {} of the field `{field_name}` with type [`{}`].",
+    /// Get the body of the getter
+    fn body(&self, field_information: &FieldInformation) -> TokenStream2 {
+        self.try_into.body(self.missing.body(
             self.ty,
-            ty.to_token_stream()
-        );
+            &field_information.access(),
+            field_information.field_name(),
+        ))
+    }
+
+    /// Get the [`GetterSpec`], the signature and the body of each delegating getter created by
+    /// the option `nested`, empty if the option is unset.
+    fn nested_getters(
+        &self,
+        field_information: &FieldInformation,
+    ) -> Vec<(GetterSpec, TokenStream2, TokenStream2)> {
+        let field_name = field_information.field_name();
+        self.nested
+            .fields()
+            .iter()
+            .zip(self.nested_ty.types())
+            .map(|(nested, ty)| {
+                let spec = GetterSpec::new(nested.clone())
+                    .with_visibility(self.option.visibility().into())
+                    .with_constant(self.const_ty.into())
+                    .with_getter_ty(self.ty.into())
+                    .with_self_ty(self.self_ty.into())
+                    .with_hidden(self.option.hidden.is_hidden())
+                    .with_doc(format!(
+                        "Getter on a {} of the field `{nested}` of the field `{field_name}` \
+                        with type {}.",
+                        self.ty,
+                        type_doc(ty, &self.generics)
+                    ));
+                let signature = spec.signature_returning(&spec.getter_ty().return_ty(ty));
+                let access = field_information.access();
+                let body = self.ty.body_quote(&quote! {#access.#nested});
+                (spec, signature, body)
+            })
+            .collect()
+    }
+
+    /// Get the attribute `#[doc(hidden)]` if the option `hidden` is set, for the functions
+    /// that are not created from a [`GetterSpec`].
+    fn doc_hidden(&self) -> Option<TokenStream2> {
+        self.option
+            .hidden
+            .is_hidden()
+            .then(|| quote! {#[doc(hidden)]})
+    }
+
+    /// Get the documentation comment of the consuming getter
+    fn consuming_getter_comment(&self, field_information: &FieldInformation) -> String {
+        format!(
+            "Consume `self` and return the field `{}` with type {}.",
+            field_information.field_name(),
+            type_doc(field_information.ty(), &self.generics)
+        )
+    }
+
+    /// Get the signature of the consuming getter without the visibility,
+    /// i.e. `fn into_name(self) -> Ty`. Returns [`None`] if the option `into_inner` is unset.
+    fn consuming_getter_signature(
+        &self,
+        field_information: &FieldInformation,
+    ) -> Option<TokenStream2> {
+        let fn_name = self
+            .into_inner
+            .name(field_information.field_name(), self.option.name())?;
+        let ty = field_information.ty();
+
+        Some(quote! {
+            fn #fn_name(self) -> #ty
+        })
+    }
+
+    /// Get the code of the consuming getter, empty if the option `into_inner` is unset
+    fn consuming_getter_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        let Some(signature) = self.consuming_getter_signature(field_information) else {
+            return TokenStream2::new();
+        };
+        let visibility = self.option.visibility();
+        let comment = self.consuming_getter_comment(field_information);
+        let hidden = self.doc_hidden();
+        let access = field_information.access();
+        let cfg = cfg_code(field_information);
 
         quote! {
+            #cfg
             #[doc=#comment]
+            #hidden
             #[inline]
             #[must_use]
-            #visibility #const_ty fn #fn_name(#self_ty_code self) -> #getter_ty_prefix #ty {
-                #getter_ty_prefix self.#field_name #getter_ty_suffix
+            #visibility #signature {
+                #access
             }
         }
     }
-}
 
-/// Option for mutable reference getter
+    /// Get the declaration of the consuming getter as a trait method,
+    /// empty if the option `into_inner` is unset
+    fn consuming_getter_trait_declaration_code(
+        &self,
+        field_information: &FieldInformation,
+    ) -> TokenStream2 {
+        let Some(signature) = self.consuming_getter_signature(field_information) else {
+            return TokenStream2::new();
+        };
+        let comment = self.consuming_getter_comment(field_information);
+        let hidden = self.doc_hidden();
+        let cfg = cfg_code(field_information);
+
+        quote! {
+            #cfg
+            #[doc=#comment]
+            #hidden
+            #[must_use]
+            #signature;
+        }
+    }
+
+    /// Get the documentation comment of the consuming setter
+    fn consuming_setter_comment(&self, field_information: &FieldInformation) -> String {
+        format!(
+            "Set the field `{}` with type {} and return `self`.",
+            field_information.field_name(),
+            type_doc(field_information.ty(), &self.generics)
+        )
+    }
+
+    /// Get the signature of the consuming setter without the visibility,
+    /// i.e. `fn with_name(mut self, value: Ty) -> Self`. The receiver is only `mut` if
+    /// `with_body` is set as a trait method declaration cannot have a pattern. Returns
+    /// [`None`] if the option `with` is unset.
+    fn consuming_setter_signature(
+        &self,
+        field_information: &FieldInformation,
+        with_body: bool,
+    ) -> Option<TokenStream2> {
+        let fn_name = self
+            .with
+            .name(field_information.field_name(), self.option.name())?;
+        let ty = field_information.ty();
+        let mutability = with_body.then(|| quote! {mut});
+
+        Some(quote! {
+            fn #fn_name(#mutability self, value: #ty) -> Self
+        })
+    }
+
+    /// Get the body of the consuming setter
+    fn consuming_setter_body(field_information: &FieldInformation) -> TokenStream2 {
+        let access = field_information.access();
+        quote! {
+            #access = value;
+            self
+        }
+    }
+
+    /// Get the code of the consuming setter, empty if the option `with` is unset
+    fn consuming_setter_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        let Some(signature) = self.consuming_setter_signature(field_information, true) else {
+            return TokenStream2::new();
+        };
+        let visibility = self.option.visibility();
+        let comment = self.consuming_setter_comment(field_information);
+        let hidden = self.doc_hidden();
+        let body = Self::consuming_setter_body(field_information);
+        let cfg = cfg_code(field_information);
+
+        quote! {
+            #cfg
+            #[doc=#comment]
+            #hidden
+            #[inline]
+            #[must_use]
+            #visibility #signature {
+                #body
+            }
+        }
+    }
+
+    /// Get the declaration of the consuming setter as a trait method,
+    /// empty if the option `with` is unset
+    fn consuming_setter_trait_declaration_code(
+        &self,
+        field_information: &FieldInformation,
+    ) -> TokenStream2 {
+        let Some(signature) = self.consuming_setter_signature(field_information, false) else {
+            return TokenStream2::new();
+        };
+        let comment = self.consuming_setter_comment(field_information);
+        let hidden = self.doc_hidden();
+        let cfg = cfg_code(field_information);
+
+        quote! {
+            #cfg
+            #[doc=#comment]
+            #hidden
+            #[must_use]
+            #signature;
+        }
+    }
+
+    /// Get the implementation of the consuming setter as a trait method,
+    /// empty if the option `with` is unset
+    fn consuming_setter_trait_impl_code(
+        &self,
+        field_information: &FieldInformation,
+    ) -> TokenStream2 {
+        let Some(signature) = self.consuming_setter_signature(field_information, true) else {
+            return TokenStream2::new();
+        };
+        let body = Self::consuming_setter_body(field_information);
+        let cfg = cfg_code(field_information);
+
+        quote! {
+            #cfg
+            #[inline]
+            #signature {
+                #body
+            }
+        }
+    }
+
+    /// Get the implementation of the consuming getter as a trait method,
+    /// empty if the option `into_inner` is unset
+    fn consuming_getter_trait_impl_code(
+        &self,
+        field_information: &FieldInformation,
+    ) -> TokenStream2 {
+        let Some(signature) = self.consuming_getter_signature(field_information) else {
+            return TokenStream2::new();
+        };
+        let access = field_information.access();
+        let cfg = cfg_code(field_information);
+
+        quote! {
+            #cfg
+            #[inline]
+            #signature {
+                #access
+            }
+        }
+    }
+}
+
+impl ToCode for ImmutableGetterOption {
+    fn to_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        if self.nested.is_set() {
+            let cfg = cfg_code(field_information);
+            return self
+                .nested_getters(field_information)
+                .into_iter()
+                .map(|(spec, signature, body)| {
+                    let getter = spec.item(&signature, &body);
+                    quote! {
+                        #cfg
+                        #getter
+                    }
+                })
+                .collect();
+        }
+
+        let spec = self.spec(field_information);
+        let getter = spec.item(
+            &self.signature(&spec, field_information),
+            &self.body(field_information),
+        );
+        let cfg = cfg_code(field_information);
+
+        let into_inner = self.consuming_getter_code(field_information);
+        let with = self.consuming_setter_code(field_information);
+
+        quote! {
+            #cfg
+            #getter
+
+            #into_inner
+
+            #with
+        }
+    }
+
+    fn to_trait_declaration_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        if self.nested.is_set() {
+            let cfg = cfg_code(field_information);
+            return self
+                .nested_getters(field_information)
+                .into_iter()
+                .map(|(spec, signature, _)| {
+                    let declaration = spec.trait_declaration_item(&signature);
+                    quote! {
+                        #cfg
+                        #declaration
+                    }
+                })
+                .collect();
+        }
+
+        let spec = self.spec(field_information);
+        let declaration = spec.trait_declaration_item(&self.signature(&spec, field_information));
+        let cfg = cfg_code(field_information);
+
+        let into_inner = self.consuming_getter_trait_declaration_code(field_information);
+        let with = self.consuming_setter_trait_declaration_code(field_information);
+
+        quote! {
+            #cfg
+            #declaration
+
+            #into_inner
+
+            #with
+        }
+    }
+
+    fn to_trait_impl_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        if self.nested.is_set() {
+            let cfg = cfg_code(field_information);
+            return self
+                .nested_getters(field_information)
+                .into_iter()
+                .map(|(_, signature, body)| {
+                    let implementation = GetterSpec::trait_impl_item(&signature, &body);
+                    quote! {
+                        #cfg
+                        #implementation
+                    }
+                })
+                .collect();
+        }
+
+        let spec = self.spec(field_information);
+        let implementation = GetterSpec::trait_impl_item(
+            &self.signature(&spec, field_information),
+            &self.body(field_information),
+        );
+        let cfg = cfg_code(field_information);
+        let into_inner = self.consuming_getter_trait_impl_code(field_information);
+        let with = self.consuming_setter_trait_impl_code(field_information);
+
+        quote! {
+            #cfg
+            #implementation
+
+            #into_inner
+
+            #with
+        }
+    }
+}
+
+/// Option for mutable reference getter
 #[derive(Clone, Default)]
 pub struct MutableGetterOption {
     /// visibility
     visibility: Visibility,
     /// name of the getter
     name: FunctionName,
+    /// if the getter is hidden from the documentation
+    hidden: Hidden,
+    /// the generics of the struct, see [`GetterOption::with_generics`]
+    generics: Box<Generics>,
+}
+
+impl Debug for MutableGetterOption {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MutableGetterOption")
+            .field("visibility", &self.visibility.to_string())
+            .field("name", &self.name)
+            .field("hidden", &self.hidden)
+            .field("generics", &tokens_string(self.generics.to_token_stream()))
+            .finish()
+    }
+}
+
+/// Describe the configuration, like "public mutable getter `x_mut`".
+impl Display for MutableGetterOption {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} mutable getter {}", self.visibility, self.name)?;
+        if self.hidden.is_hidden() {
+            write!(f, ", hidden from the documentation")?;
+        }
+        Ok(())
+    }
 }
 
 impl MutableGetterOption {
+    /// Parse the options of a `#[get_mut(...)]` attribute, like the [`Meta`] of
+    /// `parse_quote!(name = "x_mut")` or `parse_quote!(public)`.
+    ///
+    /// The options are not validated, see [`Self::validate`].
+    ///
+    /// # Errors
+    /// Returns the error with the span of the option that caused it if an option is invalid
+    /// or set multiple times. An option that is not recognized is ignored.
+    #[inline]
+    pub fn from_metas<T: IntoIterator<Item = Meta>>(
+        metas: T,
+    ) -> Result<Self, SpannedError<OptionParseError>> {
+        Self::parse(metas).map_err(SpannedError::err_into)
+    }
+
     /// getter on the visibility
     #[inline]
     #[must_use]
@@ -351,6 +1511,28 @@ impl MutableGetterOption {
         &self.name
     }
 
+    /// Set the case of the default name, see [`GetterOption::with_rename_all`].
+    #[must_use]
+    pub fn with_rename_all(mut self, case: Option<Case>) -> Self {
+        self.name = self.name.with_case(case);
+        self
+    }
+
+    /// Set the prefix of the default name of the fields of a tuple struct, see
+    /// [`GetterOption::parse`].
+    #[must_use]
+    pub fn with_tuple_names(mut self, tuple_names: Option<&str>) -> Self {
+        self.name = self.name.with_tuple_names(tuple_names);
+        self
+    }
+
+    /// Set the generics of the struct, see [`GetterOption::with_generics`].
+    #[must_use]
+    pub fn with_generics(mut self, generics: &Generics) -> Self {
+        self.generics = Box::new(generics.clone());
+        self
+    }
+
     /// Verify that the option is valid
     #[allow(clippy::unnecessary_wraps)]
     #[allow(clippy::unused_self)]
@@ -363,8 +1545,36 @@ impl MutableGetterOption {
 impl ParseGetterOption for MutableGetterOption {
     type Option = MutableOptionList;
 
-    /// try to add a option from a meta. Return true if it is a valid option, false otherwise.
+    /// Try to add an option from a meta, see [`Self::add_common_config`].
+    ///
+    /// An option only valid on the immutable getter, like `Const`, gives the error
+    /// [`AddConfigError::ImmutableOnly`] instead of being skipped. It is recognized by
+    /// parsing the meta as an option of `#[get]`, only for the diagnosis.
     fn add_config(&mut self, option: &Meta) -> Result<Self::Option, AddConfigError<Self::Option>> {
+        match self.add_common_config(option) {
+            Err(AddConfigError::Acceptable(err)) => {
+                match ImmutableGetterOption::default().add_config(option) {
+                    Ok(immutable) | Err(AddConfigError::Unacceptable(_, immutable)) => {
+                        Err(AddConfigError::ImmutableOnly(immutable))
+                    }
+                    Err(AddConfigError::Acceptable(_) | AddConfigError::ImmutableOnly(_)) => {
+                        Err(AddConfigError::Acceptable(err))
+                    }
+                }
+            }
+            result => result,
+        }
+    }
+}
+
+impl MutableGetterOption {
+    /// Try to add an option shared by the mutable and the immutable getter from a meta, the
+    /// visibility, `hidden`, the prefix or the name. The prefix and the name are kept whatever
+    /// their order in the attribute.
+    fn add_common_config(
+        &mut self,
+        option: &Meta,
+    ) -> Result<MutableOptionList, AddConfigError<MutableOptionList>> {
         match Visibility::parse_option(option) {
             Ok(vis) => {
                 self.visibility = vis;
@@ -378,9 +1588,29 @@ impl ParseGetterOption for MutableGetterOption {
             }
             Err(ParseAttributeOptionError::Acceptable(_)) => {}
         }
+        match Hidden::parse_option(option) {
+            Ok(hidden) => {
+                self.hidden = hidden;
+                return Ok(MutableOptionList::Hidden);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(err, MutableOptionList::Hidden));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
+        match Prefix::parse_option(option) {
+            Ok(prefix) => {
+                self.name = self.name.clone().with_prefix(Some(prefix.into_inner()));
+                return Ok(MutableOptionList::Prefix);
+            }
+            Err(ParseAttributeOptionError::Unacceptable(err)) => {
+                return Err(AddConfigError::Unacceptable(err, MutableOptionList::Prefix));
+            }
+            Err(ParseAttributeOptionError::Acceptable(_)) => {}
+        }
         match FunctionName::parse_option(option) {
             Ok(name) => {
-                self.name = name;
+                self.name = name.with_prefix(self.name.prefix().map(ToOwned::to_owned));
                 Ok(MutableOptionList::IdentOption)
             }
             Err(ParseAttributeOptionError::Unacceptable(err)) => Err(AddConfigError::Unacceptable(
@@ -392,29 +1622,1086 @@ impl ParseGetterOption for MutableGetterOption {
     }
 }
 
-impl ToCode for MutableGetterOption {
-    fn to_code(&self, field_information: &FieldInformation) -> TokenStream2 {
-        let visibility = self.visibility();
+impl MutableGetterOption {
+    /// Get the documentation comment of the getter
+    fn comment(&self, field_information: &FieldInformation) -> String {
+        format!(
+            "Getter on a mutable reference of the field {} with type {}.",
+            field_information.field_name(),
+            type_doc(field_information.ty(), &self.generics)
+        )
+    }
+
+    /// Get the [`GetterSpec`] of the getter, i.e. `fn name_mut(&mut self) -> &mut Ty`, the
+    /// documentation included.
+    fn spec(&self, field_information: &FieldInformation) -> GetterSpec {
         // TODO improve
         let fn_name = self
             .name()
             .name_mut(field_information.field_name())
             .expect("no field name");
-        let ty = &field_information.ty();
-        let field_name = field_information.field_name();
+        GetterSpec::new_mut(fn_name)
+            .with_visibility(self.visibility().into())
+            .with_doc(self.comment(field_information))
+            .with_hidden(self.hidden.is_hidden())
+    }
+}
 
-        let comment = format!(
-            "Getter on a mutable reference of the field {field_name} with type [`{}`].",
-            ty.to_token_stream()
-        );
+impl ToCode for MutableGetterOption {
+    fn to_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        let getter = self.spec(field_information).to_tokens(field_information);
+        let cfg = cfg_code(field_information);
 
         quote! {
-            #[doc=#comment]
-            #[inline]
-            #[must_use]
-            #visibility fn #fn_name(&mut self) -> &mut #ty {
-                &mut self.#field_name
+            #cfg
+            #getter
+        }
+    }
+
+    fn to_trait_declaration_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        let declaration = self
+            .spec(field_information)
+            .to_trait_declaration(field_information);
+        let cfg = cfg_code(field_information);
+
+        quote! {
+            #cfg
+            #declaration
+        }
+    }
+
+    fn to_trait_impl_code(&self, field_information: &FieldInformation) -> TokenStream2 {
+        let implementation = self
+            .spec(field_information)
+            .to_trait_impl(field_information);
+        let cfg = cfg_code(field_information);
+
+        quote! {
+            #cfg
+            #implementation
+        }
+    }
+}
+
+/// Replace the ident `Self` in the tokens by `struct_ty`, or by `<struct_ty>` if it is the
+/// start of a path like `Self::Item`, see [`GetterOption::with_struct_type`].
+fn replace_self(tokens: TokenStream2, struct_ty: &TokenStream2) -> TokenStream2 {
+    let mut out = TokenStream2::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "Self" => {
+                if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == ':')
+                {
+                    out.extend(quote! {<#struct_ty>});
+                } else {
+                    out.extend(struct_ty.clone());
+                }
             }
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), struct_ty));
+                replaced.set_span(group.span());
+                out.extend([TokenTree::Group(replaced)]);
+            }
+            token => out.extend([token]),
+        }
+    }
+    out
+}
+
+/// Render a type for the documentation of a getter. A path type without generics, like
+/// `usize` or `std::string::String`, is a link to the type. Any other type is only rendered as
+/// code as rustdoc cannot always resolve a link to it: an array `[T; N]`, a tuple, a type with
+/// generics like `Option<Box<Self>>`, `Self` which is not the struct in the documentation
+/// of a trait, or a path starting with a type parameter of the struct like `T` or `T::Output`.
+fn type_doc(ty: &Type, generics: &Generics) -> String {
+    let is_link = match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            path.segments
+                .iter()
+                .all(|segment| segment.arguments.is_none() && segment.ident != "Self")
+                && path.segments.first().is_none_or(|first| {
+                    generics
+                        .type_params()
+                        .all(|param| param.ident != first.ident)
+                })
+        }
+        _ => false,
+    };
+    let ty = type_string(ty);
+    if is_link {
+        format!("[`{ty}`]")
+    } else {
+        format!("`{ty}`")
+    }
+}
+
+/// Render a type as it is written in the source, i.e. `Option<Box<Self>>` instead of the
+/// spaced `Option < Box < Self > >` of [`ToString`] on the tokens.
+fn type_string(ty: &Type) -> String {
+    tokens_string(ty.to_token_stream())
+}
+
+/// Render tokens as they are written in the source, see [`type_string`].
+pub(super) fn tokens_string(tokens: TokenStream2) -> String {
+    let mut out = String::new();
+    let mut previous: Option<TokenTree> = None;
+    render_tokens(tokens, &mut out, &mut previous);
+    out
+}
+
+/// Append the tokens to `out`, with a space between two tokens only where it is needed to
+/// read them, see [`type_string`]. `previous` is the last token appended.
+fn render_tokens(tokens: TokenStream2, out: &mut String, previous: &mut Option<TokenTree>) {
+    for token in tokens {
+        let space = out.ends_with("->")
+            || match (previous.as_ref(), &token) {
+                (None, _) => false,
+                // a joint punctuation is the start of `::`, `->` or of a lifetime `'a`
+                (Some(TokenTree::Punct(punct)), _) => {
+                    punct.spacing() == Spacing::Alone
+                        && (matches!(punct.as_char(), ',' | ';' | '=' | '+')
+                            // `for<'a> fn(&'a u8)`
+                            || (punct.as_char() == '>' && matches!(token, TokenTree::Ident(_))))
+                }
+                (_, TokenTree::Punct(punct)) => matches!(punct.as_char(), '=' | '+' | '-'),
+                // `*const [u8]` but `Fn(u8)`
+                (Some(TokenTree::Ident(ident)), TokenTree::Group(_)) => {
+                    ident == "const" || ident == "mut" || ident == "dyn" || ident == "impl"
+                }
+                (_, TokenTree::Group(_)) => false,
+                (Some(_), _) => true,
+            };
+        if space {
+            out.push(' ');
+        }
+        if let TokenTree::Group(group) = &token {
+            let (open, close) = match group.delimiter() {
+                Delimiter::Parenthesis => ("(", ")"),
+                Delimiter::Bracket => ("[", "]"),
+                Delimiter::Brace => ("{ ", " }"),
+                Delimiter::None => ("", ""),
+            };
+            out.push_str(open);
+            render_tokens(group.stream(), out, &mut None);
+            out.push_str(close);
+        } else {
+            out.push_str(&token.to_string());
+        }
+        *previous = Some(token);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use macro_utils::field::Field;
+    use quote::ToTokens;
+    use syn::{parse_quote, punctuated::Punctuated, Generics, Meta, Token, Type};
+
+    use super::{GetterOption, ImmutableGetterOption, MutableGetterOption, ParseGetterOption};
+    use crate::getter::{
+        error::{
+            AcceptableParseError, AddConfigErrorKind, GetterParseErrorKind, OptionParseErrorKind,
+            OptionValidationError, ParseAttributeOptionErrorKind, UnacceptableParseErrorKind,
+        },
+        option_enum::{ImmutableOptionList, MutableOptionList},
+        ParseOption, Visibility,
+    };
+
+    /// Get the error of a result, [`GetterOption`] is not [`Debug`] so `expect_err` cannot be
+    /// used.
+    fn error<T, E>(result: Result<T, E>) -> E {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        }
+    }
+
+    /// Get the kind of the error of the parsing of the given options of a `#[get]` attribute.
+    fn immutable_error(
+        options: Punctuated<Meta, Token![,]>,
+    ) -> GetterParseErrorKind<ImmutableOptionList> {
+        error(ImmutableGetterOption::parse(options)).error().kind()
+    }
+
+    /// Get the kind of the error of the parsing of the field.
+    fn field_error(field: syn::Field) -> OptionParseErrorKind {
+        error(GetterOption::parse(Field::new(field, 0), None, false))
+            .error()
+            .kind()
+    }
+
+    /// Get the generated code of the getters of the field as a string, `newtype` is the
+    /// container option `newtype`.
+    fn code(field: syn::Field, newtype: bool) -> String {
+        let Ok(option) = GetterOption::parse(Field::new(field, 0), None, newtype) else {
+            panic!("the field attribute should be valid");
+        };
+        option.to_token_stream().to_string()
+    }
+
+    /// Remove the name of the field, as for the fields of a tuple struct.
+    fn unnamed(mut field: syn::Field) -> syn::Field {
+        field.ident = None;
+        field
+    }
+
+    #[test]
+    fn parse_attribute_option_error_kind() {
+        assert_eq!(
+            error(Visibility::parse_option(&parse_quote!(visibility = 1))).kind(),
+            ParseAttributeOptionErrorKind::Unacceptable(
+                UnacceptableParseErrorKind::RightHandNameValueExprNotLitString
+            )
+        );
+        assert_eq!(
+            error(Visibility::parse_option(&parse_quote!(
+                visibility = "protected"
+            )))
+            .kind(),
+            ParseAttributeOptionErrorKind::Unacceptable(
+                UnacceptableParseErrorKind::RightHandValueInvalid
+            )
+        );
+        assert_eq!(
+            error(Visibility::parse_option(&parse_quote!(name = "f"))).kind(),
+            ParseAttributeOptionErrorKind::Acceptable(
+                AcceptableParseError::LeftHandSideValueNotRecognized
+            )
+        );
+        assert_eq!(
+            error(Visibility::parse_option(&parse_quote!(f))).kind(),
+            ParseAttributeOptionErrorKind::Acceptable(AcceptableParseError::PathNotRecognized)
+        );
+        assert!(matches!(
+            error(Visibility::parse_option(&parse_quote!(visibility(1)))).kind(),
+            ParseAttributeOptionErrorKind::Unacceptable(UnacceptableParseErrorKind::SynError(_))
+        ));
+    }
+
+    #[test]
+    fn add_config_error_kind() {
+        assert_eq!(
+            error(ImmutableGetterOption::default().add_config(&parse_quote!(unknown))).kind(),
+            AddConfigErrorKind::Acceptable(AcceptableParseError::PathNotRecognized)
+        );
+        assert_eq!(
+            error(ImmutableGetterOption::default().add_config(&parse_quote!(unknown = "f"))).kind(),
+            AddConfigErrorKind::Acceptable(AcceptableParseError::LeftHandSideValueNotRecognized)
+        );
+        assert_eq!(
+            error(ImmutableGetterOption::default().add_config(&parse_quote!(getter_ty = "box")))
+                .kind(),
+            AddConfigErrorKind::Unacceptable(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ImmutableOptionList::GetterTy
+            )
+        );
+        assert_eq!(
+            error(MutableGetterOption::default().add_config(&parse_quote!(name = "1f"))).kind(),
+            AddConfigErrorKind::Unacceptable(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                MutableOptionList::IdentOption
+            )
+        );
+    }
+
+    #[test]
+    fn getter_parse_error_kind() {
+        assert_eq!(
+            immutable_error(parse_quote!(name = "1f")),
+            GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                MutableOptionList::IdentOption.into()
+            )
+        );
+        assert_eq!(
+            immutable_error(parse_quote!(name = 1)),
+            GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandNameValueExprNotLitString,
+                MutableOptionList::IdentOption.into()
+            )
+        );
+        // the first option parser, the visibility, rejects the path
+        assert_eq!(
+            immutable_error(parse_quote!(a::name = "f")),
+            GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::LeftHandSideValueNotIdent,
+                MutableOptionList::Visibility.into()
+            )
+        );
+        assert_eq!(
+            immutable_error(parse_quote!(Pub, public)),
+            GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                MutableOptionList::Visibility.into()
+            )
+        );
+        assert_eq!(
+            immutable_error(parse_quote!(expect = "0")),
+            GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ImmutableOptionList::MissingValue
+            )
+        );
+        assert!(matches!(
+            immutable_error(parse_quote!(name(1))),
+            GetterParseErrorKind::AddConfigError(UnacceptableParseErrorKind::SynError(_), _)
+        ));
+        assert_eq!(
+            error(MutableGetterOption::parse::<Punctuated<Meta, Token![,]>>(
+                parse_quote!(name = "f", name = "g")
+            ))
+            .error()
+            .kind(),
+            GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                MutableOptionList::IdentOption
+            )
+        );
+    }
+
+    #[test]
+    fn option_parse_error_kind() {
+        assert_eq!(
+            field_error(parse_quote!(f: usize)),
+            OptionParseErrorKind::NotFound
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get = 1] f: usize)),
+            OptionParseErrorKind::NameValue
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get_mut = "1f"] f: usize)),
+            OptionParseErrorKind::GetterParseError(GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                MutableOptionList::IdentOption.into()
+            ))
+        );
+        assert!(matches!(
+            field_error(parse_quote!(#[get(1)] f: usize)),
+            OptionParseErrorKind::SynError(_)
+        ));
+        assert_eq!(
+            field_error(parse_quote!(#[get(Pub, Pub)] f: usize)),
+            OptionParseErrorKind::GetterParseError(
+                GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                    MutableOptionList::Visibility.into()
+                )
+            )
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get_mut(name = "1f")] f: usize)),
+            OptionParseErrorKind::GetterParseError(GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                MutableOptionList::IdentOption.into()
+            ))
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(both, into_inner)] f: usize)),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::IntoInnerWithMut)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(by_ref, self_ty = "value")] f: usize)),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::SelfMoveOnReturnRef)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(Const, clone)] f: ())),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::ConstClone)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(Const, clone, unwrap)] f: Option<()>)),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::ConstClone)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(unwrap)] f: usize)),
+            OptionParseErrorKind::OptionValidationError(
+                OptionValidationError::MissingValueNotOption
+            )
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(nested = "a, b", nested_ty = "usize")] f: S)),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::NestedTyMismatch)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(nested_ty = "usize")] f: S)),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::NestedTyMismatch)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(nested = "a", nested_ty = "usize", both)] f: S)),
+            OptionParseErrorKind::OptionValidationError(
+                OptionValidationError::NestedWithFieldGetterOption(ImmutableOptionList::AddMut)
+            )
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(nested = "a", nested_ty = "usize", name = "g")] f: S)),
+            OptionParseErrorKind::OptionValidationError(
+                OptionValidationError::NestedWithFieldGetterOption(
+                    ImmutableOptionList::MutableOption(MutableOptionList::IdentOption)
+                )
+            )
+        );
+
+        let mut field: syn::Field = parse_quote!(#[get] f: usize);
+        field.ident = None;
+        assert_eq!(
+            field_error(field),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::FunctionNameMissing)
+        );
+    }
+
+    #[test]
+    fn type_doc() {
+        let doc = |ty: Type| super::type_doc(&ty, &Generics::default());
+        assert_eq!(doc(parse_quote! {usize}), "[`usize`]");
+        assert_eq!(
+            doc(parse_quote! {std::string::String}),
+            "[`std::string::String`]"
+        );
+        assert_eq!(doc(parse_quote! {Option<T>}), "`Option<T>`");
+        assert_eq!(doc(parse_quote! {Option<Box<Self>>}), "`Option<Box<Self>>`");
+        assert_eq!(doc(parse_quote! {Self}), "`Self`");
+        assert_eq!(
+            doc(parse_quote! {<Self as Iterator>::Item}),
+            "`<Self as Iterator>::Item`"
+        );
+        assert_eq!(doc(parse_quote! {[u8; 32]}), "`[u8; 32]`");
+        assert_eq!(doc(parse_quote! {[T; N]}), "`[T; N]`");
+        assert_eq!(doc(parse_quote! {(u8, u8)}), "`(u8, u8)`");
+
+        // a type parameter of the struct cannot be linked
+        let generics: Generics = parse_quote! {<'a, T: Clone, const N: usize>};
+        let doc = |ty: Type| super::type_doc(&ty, &generics);
+        assert_eq!(doc(parse_quote! {T}), "`T`");
+        assert_eq!(doc(parse_quote! {T::Output}), "`T::Output`");
+        assert_eq!(doc(parse_quote! {U}), "[`U`]");
+        assert_eq!(doc(parse_quote! {N}), "[`N`]");
+        assert_eq!(doc(parse_quote! {std::T}), "[`std::T`]");
+    }
+
+    #[test]
+    fn replace_self() {
+        let struct_ty = quote::quote! {Node<T>};
+        for (ty, expected) in [
+            ("Option<Box<Self>>", "Option<Box<Node<T>>>"),
+            ("Self", "Node<T>"),
+            ("[Self; 2]", "[Node<T>; 2]"),
+            ("fn(&Self) -> SelfRef", "fn(&Node<T>) -> SelfRef"),
+            ("<Self as Iterator>::Item", "<Node<T> as Iterator>::Item"),
+            ("Vec<Self::Item>", "Vec<<Node<T>>::Item>"),
+        ] {
+            let ty: syn::Type = syn::parse_str(ty).expect("valid type");
+            let replaced: syn::Type =
+                syn::parse2(super::replace_self(ty.to_token_stream(), &struct_ty))
+                    .expect("valid type");
+            assert_eq!(super::type_string(&replaced), expected);
+        }
+    }
+
+    #[test]
+    fn type_string() {
+        for ty in [
+            "Vec<(u8, [u16; 2])>",
+            "&'a mut Vec<&'static str>",
+            "Box<dyn Fn(u8) -> (u8, u8) + Send + 'a>",
+            "Box<dyn for<'a> Fn(&'a u8) -> Option<&'a u8>>",
+            "HashMap<String, Vec<u8>, RandomState>",
+            "Box<dyn Iterator<Item = u8>>",
+            "*const [u8]",
+            "fn() -> !",
+            "impl Into<u8>",
+            "PhantomData<fn(Self) -> Self>",
+            "Array<u8, { N + 1 }>",
+        ] {
+            assert_eq!(
+                super::type_string(&syn::parse_str(ty).expect("valid type")),
+                ty
+            );
+        }
+    }
+
+    #[test]
+    fn name_value_shorthand() {
+        for (shorthand, explicit) in [
+            (
+                parse_quote!(#[get = "value"] f: usize),
+                parse_quote!(#[get(name = "value")] f: usize),
+            ),
+            (
+                parse_quote!(#[get_mut = "value_mut"] f: usize),
+                parse_quote!(#[get_mut(name = "value_mut")] f: usize),
+            ),
+            (
+                parse_quote!(#[get = "value"] #[get_mut = "value_mut"] f: usize),
+                parse_quote!(#[get(name = "value")] #[get_mut(name(value_mut))] f: usize),
+            ),
+        ] {
+            assert_eq!(
+                code(unnamed(shorthand), false),
+                code(unnamed(explicit), false)
+            );
+        }
+    }
+
+    #[test]
+    fn immutable_only_option() {
+        for (field, option) in [
+            (
+                parse_quote!(#[get_mut(Const)] f: usize),
+                ImmutableOptionList::ConstTy,
+            ),
+            (
+                parse_quote!(#[get_mut(Pub, clone)] f: usize),
+                ImmutableOptionList::GetterTy,
+            ),
+            (
+                parse_quote!(#[get_mut(getter_ty = "copy")] f: usize),
+                ImmutableOptionList::GetterTy,
+            ),
+            (
+                parse_quote!(#[get] #[get_mut(self_ty = "value")] f: usize),
+                ImmutableOptionList::SelfTy,
+            ),
+            // the value is not parsed for the diagnosis
+            (
+                parse_quote!(#[get_mut(self_ty = "invalid")] f: usize),
+                ImmutableOptionList::SelfTy,
+            ),
+            (
+                parse_quote!(#[get_mut(with)] f: usize),
+                ImmutableOptionList::With,
+            ),
+        ] {
+            assert_eq!(
+                field_error(field),
+                OptionParseErrorKind::GetterParseError(GetterParseErrorKind::ImmutableOnlyOption(
+                    option
+                ))
+            );
+        }
+
+        // the immutable getter accepts them and unknown options are still skipped
+        let _option = immutable(parse_quote!(Const, clone, self_ty = "value"));
+        let Ok(_option) = MutableGetterOption::from_metas([parse_quote!(unknown)]) else {
+            panic!("the unknown option should be skipped");
+        };
+    }
+
+    #[test]
+    fn long_attribute_names() {
+        assert_eq!(
+            code(parse_quote!(#[getter_get] f: usize), false),
+            code(parse_quote!(#[get] f: usize), false)
+        );
+        assert_eq!(
+            code(
+                parse_quote!(#[getter_get(Pub, Copy, name = "value", with)] f: usize),
+                false
+            ),
+            code(
+                parse_quote!(#[get(Pub, Copy, name = "value", with)] f: usize),
+                false
+            )
+        );
+        assert_eq!(
+            code(
+                parse_quote!(#[getter_get = "value"] #[getter_get_mut = "value_mut"] f: usize),
+                false
+            ),
+            code(
+                parse_quote!(#[get = "value"] #[get_mut = "value_mut"] f: usize),
+                false
+            )
+        );
+        assert_eq!(
+            code(parse_quote!(#[getter_get_mut(Pub)] f: usize), false),
+            code(parse_quote!(#[get_mut(Pub)] f: usize), false)
+        );
+        // mixed spellings
+        assert_eq!(
+            code(
+                parse_quote!(#[get(Pub)] #[getter_get_mut(Pub)] f: usize),
+                false
+            ),
+            code(
+                parse_quote!(#[getter_get(Pub)] #[get_mut(Pub)] f: usize),
+                false
+            )
+        );
+        assert_eq!(
+            code(
+                parse_quote!(#[getter_get(add_mut)] #[get_mut(name = "m")] f: usize),
+                false
+            ),
+            code(
+                parse_quote!(#[get(add_mut)] #[getter_get_mut(name = "m")] f: usize),
+                false
+            )
+        );
+
+        assert_eq!(
+            field_error(parse_quote!(#[getter_get(both, into_inner)] f: usize)),
+            field_error(parse_quote!(#[get(both, into_inner)] f: usize))
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[getter] f: usize)),
+            OptionParseErrorKind::NotFound
+        );
+    }
+
+    /// Parse the options of a `#[get]` attribute, panicking on an error.
+    fn immutable(options: Punctuated<Meta, Token![,]>) -> ImmutableGetterOption {
+        let Ok(option) = ImmutableGetterOption::from_metas(options) else {
+            panic!("the options should be valid");
+        };
+        option
+    }
+
+    /// Parse the options of a `#[get_mut]` attribute, panicking on an error.
+    fn mutable(options: Punctuated<Meta, Token![,]>) -> MutableGetterOption {
+        let Ok(option) = MutableGetterOption::from_metas(options) else {
+            panic!("the options should be valid");
+        };
+        option
+    }
+
+    #[test]
+    fn with() {
+        let field = code(
+            parse_quote!(#[get(Copy, self_ty = "value", with)] f: usize),
+            false,
+        );
+        assert!(field.contains("fn f (self) -> usize"));
+        assert!(field.contains("fn with_f (mut self , value : usize) -> Self"));
+        assert!(field.contains("self . f = value ; self"));
+        assert!(
+            code(parse_quote!(#[get(with = "set")] f: usize), false).contains("fn set (mut self")
+        );
+        assert!(code(parse_quote!(#[get(with)] r#type: usize), false)
+            .contains("fn with_type (mut self"));
+
+        assert_eq!(
+            immutable_error(parse_quote!(with, with = "set")),
+            GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(ImmutableOptionList::With)
+        );
+        assert_eq!(
+            field_error(parse_quote!(
+                #[get(with)]
+                usize
+            )),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::FunctionNameMissing)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(nested = "a", nested_ty = "u8", with)] f: usize)),
+            OptionParseErrorKind::OptionValidationError(
+                OptionValidationError::NestedWithFieldGetterOption(ImmutableOptionList::With)
+            )
+        );
+    }
+
+    #[test]
+    fn function_name_clash() {
+        let name = ImmutableOptionList::MutableOption(MutableOptionList::IdentOption);
+        let clash = |first, second| {
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::FunctionNameClash(
+                first, second,
+            ))
+        };
+        assert_eq!(
+            field_error(parse_quote!(#[get(name = "v", into_inner = "v")] f: usize)),
+            clash(name, ImmutableOptionList::IntoInner)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(name = "v", with = "v")] f: usize)),
+            clash(name, ImmutableOptionList::With)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(into_inner = "v", with = "v")] f: usize)),
+            clash(ImmutableOptionList::IntoInner, ImmutableOptionList::With)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(name = "v", both, with = "v_mut")] f: usize)),
+            clash(ImmutableOptionList::AddMut, ImmutableOptionList::With)
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(newtype, into_inner = "get")] f: usize)),
+            clash(name, ImmutableOptionList::IntoInner)
+        );
+    }
+
+    #[test]
+    fn immutable_display() {
+        assert_eq!(
+            immutable(Punctuated::new()).to_string(),
+            "private non-constant getter with the default name returning a reference and taking \
+             self by reference"
+        );
+        assert_eq!(
+            immutable(parse_quote!(visibility = "pub", name = "x", Const)).to_string(),
+            "public constant getter `x` returning a reference and taking self by reference"
+        );
+        assert_eq!(
+            immutable(parse_quote!(visibility = "pub(super)", clone, both)).to_string(),
+            "pub(super) non-constant getter with the default name returning a cloned value and \
+             taking self by reference, with a mutable getter"
+        );
+        assert_eq!(
+            immutable(parse_quote!(copy, self_ty = "value", into_inner = "take")).to_string(),
+            "private non-constant getter with the default name returning a copied value and \
+             taking self by value, with the consuming getter `take`"
+        );
+        assert_eq!(
+            immutable(parse_quote!(expect = "error::Error::Missing")).to_string(),
+            "private non-constant getter with the default name returning a reference and taking \
+             self by reference, returning the error `error::Error` if the field is not set"
+        );
+        assert_eq!(
+            immutable(parse_quote!(unwrap, into_inner)).to_string(),
+            "private non-constant getter with the default name returning a reference and taking \
+             self by reference, with a consuming getter, panicking if the field is not set"
+        );
+        assert_eq!(
+            immutable(parse_quote!(nested = "a, b", nested_ty = "u8, Vec<u8>")).to_string(),
+            "private non-constant getter with the default name returning a reference and taking \
+             self by reference, delegating to the nested fields `a`, `b`"
+        );
+        assert_eq!(
+            immutable(parse_quote!(name = "value", newtype)).to_string(),
+            "private non-constant getter `value` returning a reference and taking self by \
+             reference, with the consuming getter `into_inner`, implementing AsRef for the newtype"
+        );
+        assert_eq!(
+            immutable(parse_quote!(with)).to_string(),
+            "private non-constant getter with the default name returning a reference and taking \
+             self by reference, with a consuming setter"
+        );
+    }
+
+    #[test]
+    fn immutable_debug() {
+        let option = immutable(parse_quote!(Const, nested = "a", nested_ty = "Vec<u8>"));
+        let debug = format!("{option:?}");
+        assert!(debug.starts_with("ImmutableGetterOption { option: MutableGetterOption {"));
+        assert!(debug.contains("const_ty: Constant"));
+        assert!(debug.contains("missing: \"returning the option\""));
+        assert!(debug.contains("nested_ty: [\"Vec<u8>\"]"));
+    }
+
+    #[test]
+    fn mutable_display_debug() {
+        assert_eq!(
+            mutable(Punctuated::new()).to_string(),
+            "private mutable getter with the default name"
+        );
+        assert_eq!(
+            mutable(parse_quote!(public, name = "x_mut")).to_string(),
+            "public mutable getter `x_mut`"
+        );
+        assert_eq!(
+            mutable(parse_quote!(Crate)).to_string(),
+            "pub(crate) mutable getter with the default name"
+        );
+        assert!(format!("{:?}", mutable(parse_quote!(Pub)))
+            .starts_with("MutableGetterOption { visibility: \"public\", name: FunctionName {"));
+        assert_eq!(
+            error(MutableGetterOption::from_metas([
+                parse_quote!(public),
+                parse_quote!(private)
+            ]))
+            .error()
+            .kind(),
+            OptionParseErrorKind::GetterParseError(
+                GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                    MutableOptionList::Visibility.into()
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn newtype() {
+        let expected = code(
+            parse_quote!(
+                #[get(name = "get", into_inner = "into_inner")]
+                usize
+            ),
+            false,
+        );
+        assert_eq!(
+            code(
+                parse_quote!(
+                    #[get(newtype)]
+                    usize
+                ),
+                false
+            ),
+            expected
+        );
+        assert_eq!(code(parse_quote!(usize), true), expected);
+        assert_eq!(
+            code(
+                parse_quote!(
+                    #[get]
+                    usize
+                ),
+                true
+            ),
+            expected
+        );
+        assert_eq!(
+            code(
+                parse_quote!(
+                    #[get(into_inner, newtype)]
+                    usize
+                ),
+                false
+            ),
+            expected
+        );
+        assert_eq!(
+            code(
+                parse_quote!(
+                    #[get(into_inner = "take", newtype)]
+                    usize
+                ),
+                false
+            ),
+            code(
+                parse_quote!(
+                    #[get(name = "get", into_inner = "take")]
+                    usize
+                ),
+                false
+            )
+        );
+        assert_eq!(
+            field_error(parse_quote!(#[get(newtype, both)] f: usize)),
+            OptionParseErrorKind::OptionValidationError(OptionValidationError::NewtypeWithMut)
+        );
+    }
+
+    #[test]
+    fn hidden() {
+        let field = code(parse_quote!(#[get(hidden, Pub)] f: usize), false);
+        assert!(field.contains("# [doc (hidden)] # [inline] # [must_use] pub fn f (& self)"));
+        assert!(!code(parse_quote!(#[get(Pub)] f: usize), false).contains("hidden"));
+        assert!(!code(parse_quote!(#[get(hidden = "false")] f: usize), false).contains("hidden"));
+        assert_eq!(
+            code(parse_quote!(#[get(hidden = "true")] f: usize), false),
+            code(parse_quote!(#[get(hidden)] f: usize), false)
+        );
+
+        // every function created by the attribute is hidden
+        let field = code(parse_quote!(#[get(hidden, both)] f: usize), false);
+        assert_eq!(field.matches("# [doc (hidden)]").count(), 2);
+        assert!(field.contains("# [doc (hidden)] # [inline] # [must_use] fn f_mut (& mut self)"));
+        let field = code(
+            parse_quote!(#[get(hidden, Copy, into_inner, with)] f: usize),
+            false,
+        );
+        assert_eq!(field.matches("# [doc (hidden)]").count(), 3);
+        let field = code(
+            parse_quote!(#[get(hidden, nested = "a, b", nested_ty = "u8, u8")] f: S),
+            false,
+        );
+        assert_eq!(field.matches("# [doc (hidden)]").count(), 2);
+
+        // on the mutable getter only
+        let field = code(
+            parse_quote!(#[get(Pub)] #[get_mut(Pub, hidden)] f: usize),
+            false,
+        );
+        assert_eq!(field.matches("# [doc (hidden)]").count(), 1);
+        assert!(field.contains("# [doc (hidden)] # [inline] # [must_use] pub fn f_mut"));
+
+        assert_eq!(
+            immutable(parse_quote!(hidden, Pub, Const)).to_string(),
+            "public constant getter with the default name returning a reference and taking \
+            self by reference, hidden from the documentation"
+        );
+        assert_eq!(
+            error(MutableGetterOption::from_metas([
+                parse_quote!(hidden),
+                parse_quote!(hidden = "false")
+            ]))
+            .error()
+            .kind(),
+            OptionParseErrorKind::GetterParseError(
+                GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                    MutableOptionList::Hidden.into()
+                )
+            )
+        );
+        assert_eq!(
+            immutable_error(parse_quote!(hidden, hidden)),
+            GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                MutableOptionList::Hidden.into()
+            )
+        );
+        assert!(matches!(
+            immutable_error(parse_quote!(hidden = "yes")),
+            GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ImmutableOptionList::MutableOption(MutableOptionList::Hidden)
+            )
+        ));
+    }
+
+    #[test]
+    fn prefix() {
+        assert!(
+            code(parse_quote!(#[get(prefix = "get_")] size: usize), false)
+                .contains("fn get_size (& self) -> & usize")
+        );
+        assert_eq!(
+            code(parse_quote!(#[get(prefix(get_))] size: usize), false),
+            code(parse_quote!(#[get(prefix = "get_")] size: usize), false)
+        );
+        assert!(
+            code(parse_quote!(#[get_mut(prefix = "get_")] size: usize), false)
+                .contains("fn get_size_mut (& mut self) -> & mut usize")
+        );
+        assert!(
+            code(parse_quote!(#[get(prefix = "get_", both)] size: u8), false)
+                .contains("fn get_size_mut (& mut self)")
+        );
+        let field = code(
+            parse_quote!(#[get(prefix = "get_", into_inner, with)] size: u8),
+            false,
+        );
+        assert!(field.contains("fn get_size (& self)"));
+        // the consuming getter and setter keep their default names
+        assert!(field.contains("fn into_size (self)"));
+        assert!(field.contains("fn with_size (mut self"));
+        assert!(code(parse_quote!(#[get(prefix = "typ")] e: usize), false)
+            .contains("fn r#type (& self)"));
+
+        // the name wins whatever the order
+        assert!(code(
+            parse_quote!(#[get(prefix = "get_", name = "len")] size: usize),
+            false
+        )
+        .contains("fn len (& self)"));
+        assert!(code(
+            parse_quote!(#[get(name = "len", prefix = "get_")] size: usize),
+            false
+        )
+        .contains("fn len (& self)"));
+        assert!(code(
+            parse_quote!(#[get(name = "len", prefix = "get_", both)] size: usize),
+            false
+        )
+        .contains("fn len_mut (& mut self)"));
+
+        assert_eq!(
+            immutable(parse_quote!(prefix = "get_")).to_string(),
+            "private non-constant getter with the default name prefixed by `get_` returning a \
+            reference and taking self by reference"
+        );
+        for prefix in ["", "1", "get-", "r#"] {
+            assert!(
+                matches!(
+                    immutable_error(parse_quote!(prefix = #prefix)),
+                    GetterParseErrorKind::AddConfigError(
+                        UnacceptableParseErrorKind::RightHandValueInvalid,
+                        ImmutableOptionList::MutableOption(MutableOptionList::Prefix)
+                    )
+                ),
+                "{prefix:?} should be rejected"
+            );
         }
+        assert_eq!(
+            immutable_error(parse_quote!(prefix = "get_", prefix = "get_")),
+            GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                MutableOptionList::Prefix.into()
+            )
+        );
+        assert_eq!(
+            field_error(parse_quote!(
+                #[get(prefix = "get_")]
+                usize
+            )),
+            field_error(parse_quote!(
+                #[get]
+                usize
+            ))
+        );
+    }
+
+    #[test]
+    fn try_into() {
+        let field = code(
+            parse_quote!(#[get(try_into = "u32", getter_ty = "copy")] f: u64),
+            false,
+        );
+        assert!(field.contains(
+            "fn f (& self) -> :: core :: result :: Result < u32 , < u64 as :: core :: convert \
+            :: TryInto < u32 >> :: Error >"
+        ));
+        assert!(field.contains(":: core :: convert :: TryInto :: < u32 > :: try_into (self . f)"));
+        assert!(field.contains("The value is converted into [`u32`] with [`TryInto`]."));
+        let field = code(
+            parse_quote!(#[get(try_into = "Box<str>", clone)] f: String),
+            false,
+        );
+        assert!(field.contains("try_into (:: core :: clone :: Clone :: clone (& self . f))"));
+        let field = code(
+            parse_quote!(#[get(try_into = "u8", Copy, self_ty = "value")] f: u64),
+            false,
+        );
+        assert!(field.contains("fn f (self) -> :: core :: result :: Result < u8"));
+        // the mutable getter is not converted
+        let field = code(
+            parse_quote!(#[get(try_into = "u8", Copy, both)] f: u64),
+            false,
+        );
+        assert!(field.contains("fn f_mut (& mut self) -> & mut u64"));
+
+        assert_eq!(
+            immutable(parse_quote!(try_into = "u32", Copy)).to_string(),
+            "private non-constant getter with the default name returning a copied value and \
+            taking self by reference, converted into `u32`"
+        );
+        assert!(matches!(
+            immutable_error(parse_quote!(try_into = "1")),
+            GetterParseErrorKind::AddConfigError(
+                UnacceptableParseErrorKind::RightHandValueInvalid,
+                ImmutableOptionList::TryInto
+            )
+        ));
+        assert_eq!(
+            immutable_error(parse_quote!(try_into = "u8", try_into = "u16")),
+            GetterParseErrorKind::FieldAttributeOptionSetMultipleTimes(
+                ImmutableOptionList::TryInto
+            )
+        );
+        for (field, error) in [
+            (
+                parse_quote!(#[get(try_into = "u32")] f: u64),
+                OptionValidationError::TryIntoByReference,
+            ),
+            (
+                parse_quote!(#[get(try_into = "u32", Copy, Const)] f: u64),
+                OptionValidationError::ConstTryInto,
+            ),
+            (
+                parse_quote!(#[get(try_into = "u32", Copy, unwrap)] f: Option<u64>),
+                OptionValidationError::TryIntoWithMissingValue,
+            ),
+            (
+                parse_quote!(#[get(try_into = "u32", Copy, nested = "a", nested_ty = "u8")] f: S),
+                OptionValidationError::NestedWithFieldGetterOption(ImmutableOptionList::TryInto),
+            ),
+        ] {
+            assert_eq!(
+                field_error(field),
+                OptionParseErrorKind::OptionValidationError(error)
+            );
+        }
+        assert_eq!(
+            field_error(parse_quote!(#[get_mut(try_into = "u32")] f: u64)),
+            OptionParseErrorKind::GetterParseError(GetterParseErrorKind::ImmutableOnlyOption(
+                ImmutableOptionList::TryInto
+            ))
+        );
     }
 }
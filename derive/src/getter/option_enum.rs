@@ -1,10 +1,10 @@
-//! Contains the enums for the list of options [`MutableOptionList`]
-//! and [`ImmutableOptionList`] and the trait [`OptionList`].
+//! Contains the enums for the list of options [`MutableOptionList`],
+//! [`ImmutableOptionList`] and [`ContainerOptionList`] and the trait [`OptionList`].
 
 use std::fmt::{self, Display};
 
 /// Trait for common code for listing option:
-/// [`MutableOptionList`] and [`ImmutableOptionList`].
+/// [`MutableOptionList`], [`ImmutableOptionList`] and [`ContainerOptionList`].
 pub trait OptionList {}
 
 /// List option for [`super::option::MutableGetterOption`]
@@ -14,6 +14,10 @@ pub enum MutableOptionList {
     Visibility,
     /// name
     IdentOption,
+    /// if the getter is hidden from the documentation
+    Hidden,
+    /// prefix of the default name
+    Prefix,
 }
 
 impl OptionList for MutableOptionList {}
@@ -24,6 +28,8 @@ impl Display for MutableOptionList {
         match self {
             Self::Visibility => write!(f, "visibility"),
             Self::IdentOption => write!(f, "name"),
+            Self::Hidden => write!(f, "hidden"),
+            Self::Prefix => write!(f, "prefix"),
         }
     }
 }
@@ -34,6 +40,8 @@ pub enum ImmutableOptionList {
     /// Common option with mut getter:
     /// - name
     /// - visibility
+    /// - hidden
+    /// - prefix
     MutableOption(MutableOptionList),
     /// if the function is constant or not
     ConstTy,
@@ -41,6 +49,45 @@ pub enum ImmutableOptionList {
     GetterTy,
     /// if the self value is by ref or moved
     SelfTy,
+    /// if the mutable getter is also created
+    AddMut,
+    /// if the consuming getter is also created
+    IntoInner,
+    /// if the getter unwraps an `Option` field, with `expect` or `unwrap`
+    MissingValue,
+    /// the fields of the field for which delegating getters are created
+    Nested,
+    /// the types of the nested fields
+    NestedTy,
+    /// if the conventional getters of a newtype are created
+    Newtype,
+    /// if the consuming setter is also created
+    With,
+    /// the type the field is converted into with `TryInto`
+    TryInto,
+}
+
+impl ImmutableOptionList {
+    /// Every option, used to verify that the validation matrix covers each pair of options,
+    /// see [`super::conflict::CONFLICTS`].
+    #[cfg(test)]
+    pub const ALL: [Self; 15] = [
+        Self::MutableOption(MutableOptionList::Visibility),
+        Self::MutableOption(MutableOptionList::IdentOption),
+        Self::MutableOption(MutableOptionList::Hidden),
+        Self::MutableOption(MutableOptionList::Prefix),
+        Self::ConstTy,
+        Self::GetterTy,
+        Self::SelfTy,
+        Self::AddMut,
+        Self::IntoInner,
+        Self::MissingValue,
+        Self::Nested,
+        Self::NestedTy,
+        Self::Newtype,
+        Self::With,
+        Self::TryInto,
+    ];
 }
 
 impl OptionList for ImmutableOptionList {}
@@ -53,6 +100,14 @@ impl Display for ImmutableOptionList {
             Self::ConstTy => write!(f, "const"),
             Self::GetterTy => write!(f, "getter type"),
             Self::SelfTy => write!(f, "self type"),
+            Self::AddMut => write!(f, "add mut"),
+            Self::IntoInner => write!(f, "into inner"),
+            Self::MissingValue => write!(f, "expect or unwrap"),
+            Self::Nested => write!(f, "nested"),
+            Self::NestedTy => write!(f, "nested type"),
+            Self::Newtype => write!(f, "newtype"),
+            Self::With => write!(f, "with"),
+            Self::TryInto => write!(f, "try into"),
         }
     }
 }
@@ -63,3 +118,51 @@ impl From<MutableOptionList> for ImmutableOptionList {
         Self::MutableOption(value)
     }
 }
+
+/// List option for [`super::container::ContainerOption`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum ContainerOptionList {
+    /// name of the module containing the getter trait
+    Module,
+    /// if the getter trait is sealed
+    Sealed,
+    /// if the lint allow shim is not emitted
+    NoLintAllow,
+    /// the type on which the getters are generated
+    Target,
+    /// the path from the target type to the struct
+    Via,
+    /// name of the object safe trait defined with the getters
+    TraitDef,
+    /// if no code is generated when no field has a getter attribute
+    AllowEmpty,
+    /// case of the default names of the getters
+    RenameAll,
+    /// if a warning is emitted on the redundant field attributes
+    WarnRedundant,
+    /// prefix of the default names of the fields of a tuple struct
+    TupleNames,
+    /// if the single field has the conventional getters of a newtype
+    Newtype,
+}
+
+impl OptionList for ContainerOptionList {}
+
+impl Display for ContainerOptionList {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Module => write!(f, "module"),
+            Self::Sealed => write!(f, "sealed"),
+            Self::NoLintAllow => write!(f, "no lint allow"),
+            Self::Target => write!(f, "for"),
+            Self::Via => write!(f, "via"),
+            Self::TraitDef => write!(f, "trait_def"),
+            Self::AllowEmpty => write!(f, "allow_empty"),
+            Self::RenameAll => write!(f, "rename_all"),
+            Self::WarnRedundant => write!(f, "warn_redundant"),
+            Self::TupleNames => write!(f, "tuple_names"),
+            Self::Newtype => write!(f, "newtype"),
+        }
+    }
+}
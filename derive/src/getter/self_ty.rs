@@ -1,5 +1,8 @@
 //! Contains [`SelfTy`]
 
+use std::fmt::{self, Display};
+
+use macro_utils::getter::SelfTy as SpecSelfTy;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens};
 
@@ -90,6 +93,26 @@ impl ParseOptionUtils for SelfTy {
     }
 }
 
+impl Display for SelfTy {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ref => write!(f, "reference"),
+            Self::Value => write!(f, "value"),
+        }
+    }
+}
+
+impl From<SelfTy> for SpecSelfTy {
+    #[inline]
+    fn from(value: SelfTy) -> Self {
+        match value {
+            SelfTy::Ref => Self::Ref,
+            SelfTy::Value => Self::Value,
+        }
+    }
+}
+
 impl ToTokens for SelfTy {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         tokens.extend(self.quote());
@@ -0,0 +1,113 @@
+//! Contains [`TryIntoTarget`], the attribute option that determine if the `#[get]` attribute
+//! converts the field with [`TryInto`].
+
+use std::fmt::{self, Display};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{LitStr, Type};
+
+use super::{attribute_option::ParseOptionUtils, getter_ty::GetterTy, option::tokens_string};
+
+/// Option to convert the field into another type with [`TryInto`], like a `u64` field
+/// returned as an `u32`. By default the getter returns the field without conversion.
+///
+/// Accept value : like `#[get(try_into = "u32", getter_ty = "copy")]`.
+/// - `try_into = "{type}"`: the getter returns `Result<Target, <Ty as TryInto<Target>>::Error>`
+///   with `Ty` the type of the field.
+///
+/// The field is converted by value so the getter has to be by copy or by clone.
+#[derive(Clone, Default)]
+pub enum TryIntoTarget {
+    /// The getter returns the field.
+    #[default]
+    None,
+    /// The getter converts the field into the given type.
+    Target(Box<Type>),
+}
+
+impl TryIntoTarget {
+    /// Path string for the option
+    const PATH: &'static str = "try_into";
+
+    /// Returns `true` if the getter converts the field.
+    #[inline]
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Get the return type of the getter from the type of the field and the return type of
+    /// the getter without conversion. The latter is returned if the option is unset.
+    #[must_use]
+    pub fn return_ty(&self, field_ty: &Type, inner: TokenStream2) -> TokenStream2 {
+        match self {
+            Self::None => inner,
+            Self::Target(target) => quote! {
+                ::core::result::Result<
+                    #target,
+                    <#field_ty as ::core::convert::TryInto<#target>>::Error
+                >
+            },
+        }
+    }
+
+    /// Get the body of the getter from the body without conversion, i.e. the copy or the
+    /// clone of the field. The body is unchanged if the option is unset.
+    #[must_use]
+    pub fn body(&self, inner: TokenStream2) -> TokenStream2 {
+        match self {
+            Self::None => inner,
+            Self::Target(target) => quote! {
+                ::core::convert::TryInto::<#target>::try_into(#inner)
+            },
+        }
+    }
+
+    /// Returns `true` if the getter type gives the field by value, as required to convert it.
+    #[inline]
+    #[must_use]
+    pub const fn accepts(getter_ty: GetterTy) -> bool {
+        matches!(getter_ty, GetterTy::Copy | GetterTy::Clone)
+    }
+}
+
+/// Display the target of the conversion.
+impl Display for TryIntoTarget {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "without conversion"),
+            Self::Target(target) => write!(
+                f,
+                "converted into `{}`",
+                tokens_string(target.to_token_stream())
+            ),
+        }
+    }
+}
+
+impl ParseOptionUtils for TryIntoTarget {
+    #[inline]
+    fn parse_option_from_str(_path: &str) -> Option<Self> {
+        None
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    /// The type is created with the span of the value given in the attribute so the errors
+    /// in the type point back to the attribute.
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        LitStr::new(path, span)
+            .parse::<Type>()
+            .ok()
+            .map(|target| Self::Target(Box::new(target)))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::PATH
+    }
+}
@@ -1,10 +1,12 @@
 //! Contains [`Visibility`]
 
-use proc_macro2::TokenStream as TokenStream2;
+use std::fmt::{self, Display};
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
-use syn::Path;
+use syn::{token::Paren, Path, Token, VisRestricted};
 
-use super::attribute_option::ParseOptionUtils;
+use super::{attribute_option::ParseOptionUtils, option::tokens_string};
 
 /// Visibility option
 ///
@@ -83,6 +85,38 @@ impl ParseOptionUtils for Visibility {
     }
 }
 
+impl From<&Visibility> for syn::Visibility {
+    #[inline]
+    fn from(value: &Visibility) -> Self {
+        match value {
+            Visibility::Private => Self::Inherited,
+            Visibility::Public => Self::Public(<Token![pub]>::default()),
+            Visibility::Crate(path) => Self::Restricted(VisRestricted {
+                pub_token: <Token![pub]>::default(),
+                paren_token: Paren::default(),
+                in_token: None,
+                path: Box::new(
+                    path.clone()
+                        .unwrap_or_else(|| Path::from(Ident::from(<Token![crate]>::default()))),
+                ),
+            }),
+        }
+    }
+}
+
+/// Display the visibility as `private`, `public` or the restricted modifier like `pub(crate)`.
+impl Display for Visibility {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Private => write!(f, "private"),
+            Self::Public => write!(f, "public"),
+            Self::Crate(None) => write!(f, "pub(crate)"),
+            Self::Crate(Some(path)) => write!(f, "pub({})", tokens_string(path.to_token_stream())),
+        }
+    }
+}
+
 impl ToTokens for Visibility {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         tokens.extend(self.quote());
@@ -1,9 +1,10 @@
 //! Contains [`WhichGetter`], a type which enumerate between
 //! [`ImmutableGetterOption`] and [`MutableGetterOption`].
 
-use macro_utils::field::FieldInformation;
+use macro_utils::{case::Case, field::FieldInformation};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
+use syn::Generics;
 
 use super::{
     attribute_option::ToCode, error::OptionValidationError, ImmutableGetterOption,
@@ -27,7 +28,71 @@ pub enum WhichGetter {
 }
 
 impl WhichGetter {
-    /// Merge two config with other being the one being prioritized
+    /// Set the case of the default names of the getters, see
+    /// [`super::option::GetterOption::with_rename_all`].
+    #[must_use]
+    pub fn with_rename_all(self, case: Option<Case>) -> Self {
+        match self {
+            Self::Immutable(immutable) => Self::Immutable(immutable.with_rename_all(case)),
+            Self::Mutable(mutable) => Self::Mutable(mutable.with_rename_all(case)),
+            Self::Both { immutable, mutable } => Self::Both {
+                immutable: immutable.with_rename_all(case),
+                mutable: mutable.with_rename_all(case),
+            },
+        }
+    }
+
+    /// Set the prefix of the default names of the fields of a tuple struct, see
+    /// [`super::option::GetterOption::parse`].
+    #[must_use]
+    pub fn with_tuple_names(self, tuple_names: Option<&str>) -> Self {
+        match self {
+            Self::Immutable(immutable) => Self::Immutable(immutable.with_tuple_names(tuple_names)),
+            Self::Mutable(mutable) => Self::Mutable(mutable.with_tuple_names(tuple_names)),
+            Self::Both { immutable, mutable } => Self::Both {
+                immutable: immutable.with_tuple_names(tuple_names),
+                mutable: mutable.with_tuple_names(tuple_names),
+            },
+        }
+    }
+
+    /// Set the generics of the struct, see
+    /// [`super::option::GetterOption::with_generics`].
+    #[must_use]
+    pub fn with_generics(self, generics: &Generics) -> Self {
+        match self {
+            Self::Immutable(immutable) => Self::Immutable(immutable.with_generics(generics)),
+            Self::Mutable(mutable) => Self::Mutable(mutable.with_generics(generics)),
+            Self::Both { immutable, mutable } => Self::Both {
+                immutable: immutable.with_generics(generics),
+                mutable: mutable.with_generics(generics),
+            },
+        }
+    }
+
+    /// Give the conventional getters of a newtype to the immutable getter, see
+    /// [`ImmutableGetterOption::with_newtype`]. A mutable getter alone gets the immutable
+    /// getter with the default options.
+    #[must_use]
+    pub fn with_newtype(self) -> Self {
+        match self {
+            Self::Immutable(immutable) => Self::Immutable(immutable.with_newtype()),
+            Self::Mutable(mutable) => Self::Both {
+                immutable: ImmutableGetterOption::default().with_newtype(),
+                mutable,
+            },
+            Self::Both { immutable, mutable } => Self::Both {
+                immutable: immutable.with_newtype(),
+                mutable,
+            },
+        }
+    }
+
+    /// Merge two config with other being the one being prioritized.
+    ///
+    /// The only exception is when `other` is [`Self::Both`] and `self` is [`Self::Mutable`]:
+    /// as [`Self::Both`] comes from the option `both` of `#[get]`, the mutable getter
+    /// explicitly configured with `#[get_mut]` is kept.
     #[inline]
     pub fn add_config(self, other: Self) -> Self {
         #[allow(clippy::match_same_arms)] // readability (it is already not great)
@@ -59,10 +124,29 @@ impl WhichGetter {
             },
             (Self::Immutable(_), Self::Immutable(i)) => Self::Immutable(i),
             // other is Self::Both
+            (
+                Self::Mutable(m),
+                Self::Both {
+                    immutable: i,
+                    mutable: _,
+                },
+            ) => Self::Both {
+                immutable: i,
+                mutable: m,
+            },
             (_, output @ Self::Both { .. }) => output,
         }
     }
 
+    /// Get the option of the immutable getter, [`None`] for [`Self::Mutable`].
+    #[must_use]
+    pub const fn immutable(&self) -> Option<&ImmutableGetterOption> {
+        match self {
+            Self::Immutable(immutable) | Self::Both { immutable, .. } => Some(immutable),
+            Self::Mutable(_) => None,
+        }
+    }
+
     /// Verify that the option is valid
     pub fn validate(&self) -> Result<(), OptionValidationError> {
         match self {
@@ -93,6 +177,40 @@ impl ToCode for WhichGetter {
             }
         }
     }
+
+    #[inline]
+    fn to_trait_declaration_code(&self, field: &FieldInformation) -> TokenStream2 {
+        match self {
+            Self::Immutable(i) => i.to_trait_declaration_code(field),
+            Self::Mutable(m) => m.to_trait_declaration_code(field),
+            Self::Both { immutable, mutable } => {
+                let i_code = immutable.to_trait_declaration_code(field);
+                let m_code = mutable.to_trait_declaration_code(field);
+                quote! {
+                    #i_code
+
+                    #m_code
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn to_trait_impl_code(&self, field: &FieldInformation) -> TokenStream2 {
+        match self {
+            Self::Immutable(i) => i.to_trait_impl_code(field),
+            Self::Mutable(m) => m.to_trait_impl_code(field),
+            Self::Both { immutable, mutable } => {
+                let i_code = immutable.to_trait_impl_code(field);
+                let m_code = mutable.to_trait_impl_code(field);
+                quote! {
+                    #i_code
+
+                    #m_code
+                }
+            }
+        }
+    }
 }
 
 impl Default for WhichGetter {
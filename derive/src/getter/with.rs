@@ -0,0 +1,78 @@
+//! Contains [`WithSetter`], the attribute option that determine if the `#[get]` attribute
+//! also creates a consuming setter.
+
+use macro_utils::field::FieldName;
+use proc_macro2::{Ident, Span};
+
+use super::{attribute_option::ParseOptionUtils, name::FunctionName};
+
+/// Option to determine if the `#[get]` attribute also creates a consuming setter
+/// `fn with_{field}(mut self, value: Ty) -> Self`, setting the field and returning `self` so
+/// that the calls can be chained. By default no consuming setter is created.
+///
+/// The consuming setter shares the visibility with the immutable getter.
+///
+/// Accept value : like `#[get(with)]` or `#[get(with = "name")]`.
+/// - `with` for the default name `with_{field}`
+/// - `with = "{name}"` to set the name of the consuming setter
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum WithSetter {
+    /// No consuming setter.
+    #[default]
+    None,
+    /// A consuming setter with the default name `with_{field}`.
+    DefaultName,
+    /// A consuming setter with the given name.
+    Named(Ident),
+}
+
+impl WithSetter {
+    /// Path string for the option
+    const PATH: &'static str = "with";
+
+    /// Returns `true` if the consuming setter is created.
+    #[inline]
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Get the consuming setter function name as an [`Ident`].
+    ///
+    /// Return [`None`] if the option is unset or if the field is identless and the
+    /// name is not given. The default name `with_{field}` keeps the span of the field ident
+    /// and follows the case of the name of the getter `getter_name`, see
+    /// [`FunctionName::default_name`].
+    #[must_use]
+    pub fn name(&self, field: &FieldName, getter_name: &FunctionName) -> Option<Ident> {
+        match self {
+            Self::None => None,
+            Self::DefaultName => getter_name.default_name(field, "with_", ""),
+            Self::Named(ident) => Some(ident.clone()),
+        }
+    }
+}
+
+impl ParseOptionUtils for WithSetter {
+    #[inline]
+    fn parse_option_from_str(path: &str) -> Option<Self> {
+        (path == Self::PATH).then_some(Self::DefaultName)
+    }
+
+    fn parse_option_from_str_assignment(path: &str) -> Option<Self> {
+        Self::parse_option_from_str_assignment_spanned(path, Span::call_site())
+    }
+
+    /// The ident of the setter is created with the span of the value given in the attribute,
+    /// like for [`super::name::FunctionName`].
+    fn parse_option_from_str_assignment_spanned(path: &str, span: Span) -> Option<Self> {
+        syn::parse_str::<Ident>(path)
+            .ok()
+            .map(|ident| Self::Named(Ident::new(&ident.to_string(), span)))
+    }
+
+    #[inline]
+    fn left_hand_path_accepted(path: &str) -> bool {
+        path == Self::PATH
+    }
+}
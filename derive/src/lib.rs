@@ -129,9 +129,11 @@
 //#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize)]
 
 mod getter;
+mod new;
 mod sealed;
 #[cfg(any(test, doctest))] // cspell: ignore doctest
 mod test;
+mod variants;
 
 use proc_macro::TokenStream;
 
@@ -161,9 +163,36 @@ use proc_macro::TokenStream;
 /// impl Trait for S {}
 /// # fn main() {}
 /// ```
+///
+/// # Generics
+///
+/// The generics and the full where clause of the type are forwarded to the
+/// implementation, predicates using `Self` are mapped to the concrete type.
+/// Additional bounds can be given with `#[sealed(bound = "...")]`.
+///
+/// ```
+/// use utils_lib_derive::{trait_sealed, Sealed};
+///
+/// trait_sealed!();
+///
+/// #[derive(Sealed)]
+/// #[sealed(bound = "T: 'static")]
+/// struct Wrapper<'a, T>
+/// where
+///     T: Iterator<Item = &'a u8>,
+/// {
+///     iter: T,
+/// }
+///
+/// fn is_sealed<S: private::Sealed>() {}
+///
+/// fn main() {
+///     is_sealed::<Wrapper<'static, std::slice::Iter<'static, u8>>>();
+/// }
+/// ```
 #[inline]
 #[must_use]
-#[proc_macro_derive(Sealed)]
+#[proc_macro_derive(Sealed, attributes(sealed))]
 pub fn derive_sealed(item: TokenStream) -> TokenStream {
     sealed::derive(item)
 }
@@ -197,6 +226,126 @@ pub fn trait_sealed(item: TokenStream) -> TokenStream {
     sealed::trait_sealed(item)
 }
 
+/// Derive the list of the variants of a fieldless enum.
+///
+/// It generates, in the declaration order of the variants:
+/// - `const VARIANTS: [Self; N]`, all the variants;
+/// - `fn variants() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator`;
+/// - `const fn variant_count() -> usize`;
+/// - `const fn from_index(usize) -> Option<Self>` and `const fn to_index(self) -> usize`;
+/// - `TryFrom<usize>`.
+///
+/// The index is the position in the declaration, not the discriminant. The error of
+/// `TryFrom<usize>` is `()` by default, another type implementing [`Default`] can be given
+/// with `#[variants(error = "...")]`. An enum with a variant with fields gives a compile error.
+///
+/// # Example
+///
+/// ```
+/// use utils_lib_derive::Variants;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Variants)]
+/// enum Color {
+///     Red,
+///     Green = 4,
+///     Blue,
+/// }
+///
+/// assert_eq!(Color::VARIANTS, [Color::Red, Color::Green, Color::Blue]);
+/// assert_eq!(Color::variant_count(), 3);
+/// assert_eq!(Color::variants().rev().next(), Some(Color::Blue));
+/// assert_eq!(Color::Green.to_index(), 1);
+/// assert_eq!(Color::try_from(2), Ok(Color::Blue));
+/// assert_eq!(Color::try_from(3), Err(()));
+/// ```
+#[inline]
+#[must_use]
+#[proc_macro_derive(Variants, attributes(variants))]
+pub fn derive_variants(item: TokenStream) -> TokenStream {
+    variants::derive(item)
+}
+
+/// Derive the constructor of a struct with named fields.
+///
+/// It generates `pub fn new(...) -> Self` taking each field as an argument, in the declaration
+/// order and with the type of the field. A field with the attribute `#[new(default)]` is not an
+/// argument and is filled with [`Default::default`], and a field with the attribute
+/// `#[new(default = "expr")]` is filled with the expression `expr`. The generics and the where
+/// clause of the struct are forwarded to the implementation. An enum, a union or a tuple struct
+/// gives a compile error.
+///
+/// The option `#[new(const)]` on the struct generates `pub const fn new` instead, so that the
+/// constructor can initialize a `static`. It gives a compile error with a field
+/// `#[new(default)]`, as [`Default::default`] is not const, and with a validator.
+///
+/// The options on the struct check the invariants of the constructed value:
+/// - `#[new(validate = "path", error = "Type")]` generates
+///   `pub fn try_new(...) -> Result<Self, Type>` that constructs the value and runs the
+///   validator `path`, a function `fn(&Self) -> Result<(), Type>`. `new` then panics with the
+///   [`Display`](std::fmt::Display) of the error if the validator rejects the value.
+/// - `#[new(fallible_only)]` only generates `try_new` and not `new`.
+///
+/// The validator and the error type have to be given together.
+///
+/// # Example
+///
+/// ```
+/// use utils_lib_derive::New;
+///
+/// #[derive(Debug, PartialEq, New)]
+/// struct Particle<T> {
+///     position: [T; 2],
+///     mass: f64,
+///     #[new(default)]
+///     history: Vec<[T; 2]>,
+/// }
+///
+/// let particle = Particle::new([1_i32, -2_i32], 0.5_f64);
+/// assert_eq!(
+///     particle,
+///     Particle {
+///         position: [1_i32, -2_i32],
+///         mass: 0.5_f64,
+///         history: Vec::new(),
+///     }
+/// );
+/// ```
+///
+/// With a validator:
+///
+/// ```
+/// use utils_lib_derive::New;
+///
+/// #[derive(Debug, New)]
+/// #[new(validate = "Self::check", error = "&'static str")]
+/// struct Interval {
+///     min: f64,
+///     max: f64,
+/// }
+///
+/// impl Interval {
+///     fn check(&self) -> Result<(), &'static str> {
+///         if self.min <= self.max {
+///             Ok(())
+///         } else {
+///             Err("the minimum is greater than the maximum")
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Interval::new(0_f64, 1_f64).max, 1_f64);
+/// assert_eq!(
+///     Interval::try_new(1_f64, 0_f64).map(|interval| interval.min),
+///     Err("the minimum is greater than the maximum")
+/// );
+/// ```
+#[inline]
+#[must_use]
+#[proc_macro_derive(New, attributes(new))]
+pub fn derive_new(item: TokenStream) -> TokenStream {
+    new::derive(item)
+}
+
 // TODO doc
 /// Derive getter macro
 ///
@@ -206,20 +355,41 @@ pub fn trait_sealed(item: TokenStream) -> TokenStream {
 ///
 /// Valid option for mutable getter :
 /// - Name
+/// - Prefix
 /// - Visibility
+/// - Hidden
 ///
 /// Valid option for immutable getter :
 /// - Name
+/// - Prefix
 /// - Visibility
+/// - Hidden
 /// - Constant type
 /// - Getter type
 /// - Self Type
+/// - Both
+/// - Into inner
+/// - Expect and unwrap
+/// - Try into
+/// - Nested
+///
+/// An option only valid for the immutable getter given on `#[get_mut]`, like
+/// `#[get_mut(Const)]`, is an error pointing at the option.
+///
+/// The `#[cfg(...)]` attributes of the field are forwarded on its getters.
+///
+/// `Self` in the type of a field, like `next: Option<Box<Self>>`, is replaced by the struct
+/// type in the getters, as `Self` is another type in the generated traits and on the target
+/// of `#[getter(for(...))]`. The documentation of a getter gives the type of the field as code,
+/// with a link if it is a path without generics like `usize`.
 ///
 /// ## Name
 ///
 /// determine the name og the getter. By default it is the name of the field for
 /// immutable getter and `{name}_mut` for mutable getter. It can be rename using
 /// the option `name = "{name}"` or `name({name})` with `{name}` the name of the getter.
+/// When the name is the only option, the shorthand `#[get = "{name}"]` (or
+/// `#[get_mut = "{name}"]`) can be used instead of `#[get(name = "{name}")]`.
 ///
 /// ### Example
 /// ```
@@ -260,6 +430,47 @@ pub fn trait_sealed(item: TokenStream) -> TokenStream {
 /// let t = Tuple(0_f32);
 /// assert_eq!(t.field(), &0_f32);
 /// ```
+/// or, with the shorthand,
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// struct Tuple(
+///     #[get = "field"]
+///     #[get_mut = "field_mut"]
+///     f32,
+/// );
+///
+/// let mut t = Tuple(0_f32);
+/// *t.field_mut() = 1_f32;
+/// assert_eq!(t.field(), &1_f32);
+/// ```
+///
+/// ## Prefix
+///
+/// Add a prefix to the default name of the getter with `prefix = "{prefix}"` or
+/// `prefix({prefix})`, so that `#[get(prefix = "get_")]` on the field `size` creates
+/// `fn get_size(&self)` and the mutable getter `get_size_mut`. A name given with the option
+/// `name` is used as is, the prefix only applies on the default name. The prefix cannot be
+/// empty and has to start an identifier.
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// struct S {
+///     #[get(prefix = "get_", both)]
+///     size: usize,
+///     #[get(prefix = "get_", name = "len")]
+///     length: usize,
+/// }
+///
+/// let mut s = S { size: 0, length: 1 };
+/// *s.get_size_mut() = 2;
+/// assert_eq!(s.get_size(), &2);
+/// assert_eq!(s.len(), &1);
+/// ```
 ///
 /// ## Visibility
 ///
@@ -297,6 +508,32 @@ pub fn trait_sealed(item: TokenStream) -> TokenStream {
 /// assert_eq!(s.f(), &0);
 /// ```
 ///
+/// ## Hidden
+///
+/// Add `#[doc(hidden)]` on the getter so that it does not appear in the documentation, for
+/// getters only meant for the code generated by other macros. It applies on every function
+/// created by the attribute, including the mutable getter of `both`, the consuming getter and
+/// the consuming setter, and it can be combined with any visibility.
+/// accepted option :
+/// - `hidden`
+/// - `hidden = "{bool}"`
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// pub struct S {
+///     #[get(Pub, hidden)]
+///     #[get_mut(Pub, hidden)]
+///     internal: usize,
+/// }
+///
+/// let mut s = S { internal: 0 };
+/// *s.internal_mut() = 1;
+/// assert_eq!(s.internal(), &1);
+/// ```
+///
 /// ## Constant type
 ///
 /// Determine if the function is constant or not. By default it is not but I would strongly
@@ -309,6 +546,10 @@ pub fn trait_sealed(item: TokenStream) -> TokenStream {
 /// - `{value}({bool})` (wip)
 /// with `{bool}` a boolean.
 ///
+/// A getter by clone cannot be constant as [`Clone::clone`] is not a const function, use the
+/// getter by copy instead. A constant getter taking `self` by value, with `self_ty = "value"`
+/// or `into_inner`, needs a struct without destructor, like a [`Copy`] struct.
+///
 /// ### Example
 ///
 /// ```
@@ -557,9 +798,609 @@ pub fn trait_sealed(item: TokenStream) -> TokenStream {
 /// It is only recommended for Type that implement [`Copy`] and is smaller or equal in size
 /// of an [`usize`] of your targeted platforms. Note also that the `getter_type` must be `by_value`
 /// (or `clone`) and will give an error if left by default or set `by_ref`.
+///
+/// ## Both
+///
+/// Create the mutable getter in addition to the immutable getter from a single `#[get]`
+/// attribute. The mutable getter shares the visibility of the immutable getter and is named
+/// `{name}_mut` where `{name}` is the name of the immutable getter. The mutable getter can
+/// still be configured independently with a separate `#[get_mut]` attribute, which takes
+/// priority.
+/// accepted option :
+/// - `both`
+/// - `add_mut`
+/// - `add_mut = "{bool}"`
+///
+/// ### Example
+/// ```
+/// mod private {
+///     use utils_lib_derive::Getter;
+///
+///     #[derive(Getter, Default)]
+///     pub struct S {
+///         #[get(both, Pub)]
+///         f: usize,
+///         #[get(both, Pub)]
+///         #[get_mut(name = "g_mutable")] // this one is private
+///         g: usize,
+///     }
+/// }
+///
+/// let mut s = private::S::default();
+/// *s.f_mut() = 1;
+/// assert_eq!(s.f(), &1);
+/// assert_eq!(s.g(), &0);
+/// ```
+/// 
+/// ## Into inner
+///
+/// Create a consuming getter `fn into_{field}(self) -> Ty` in addition to the immutable
+/// getter from a single `#[get]` attribute. It shares the visibility of the immutable getter.
+/// It cannot be combined with `both` or `add_mut` in the same attribute, use a separate
+/// `#[get_mut]` attribute instead.
+/// accepted option :
+/// - `into_inner`
+/// - `into_inner = "{name}"`
+///
+/// ### Example
+/// ```
+/// mod private {
+///     use utils_lib_derive::Getter;
+///
+///     #[derive(Getter)]
+///     pub struct Name {
+///         #[get(Pub, into_inner)]
+///         name: String,
+///     }
+///
+///     impl Name {
+///         pub fn new(name: String) -> Self {
+///             Self { name }
+///         }
+///     }
+///
+///     #[derive(Getter)]
+///     pub struct Wrapper(#[get(Pub, name = "value", into_inner = "into_value")] pub Vec<u8>);
+/// }
+///
+/// let name = private::Name::new("name".to_owned());
+/// assert_eq!(name.name(), "name");
+/// let string: String = name.into_name();
+/// assert_eq!(string, "name");
+///
+/// let wrapper = private::Wrapper(vec![1, 2]);
+/// assert_eq!(wrapper.value(), &[1, 2]);
+/// assert_eq!(wrapper.into_value(), vec![1, 2]);
+/// ```
+/// 
+/// ## With
+///
+/// Create a consuming setter `fn with_{field}(mut self, value: Ty) -> Self` in addition to the
+/// immutable getter from a single `#[get]` attribute, so that a struct can be updated by chaining
+/// calls without a builder. It shares the visibility of the immutable getter and is never
+/// constant as the previous value of the field is dropped.
+/// accepted option :
+/// - `with`
+/// - `with = "{name}"`
+///
+/// ### Example
+/// ```
+/// mod private {
+///     use utils_lib_derive::Getter;
+///
+///     #[derive(Getter, Default)]
+///     pub struct Request {
+///         #[get(Pub, with)]
+///         url: String,
+///         #[get(Pub, Copy, with = "retrying")]
+///         retries: u8,
+///     }
+/// }
+///
+/// let request = private::Request::default()
+///     .with_url("localhost".to_owned())
+///     .retrying(3);
+/// assert_eq!(request.url(), "localhost");
+/// assert_eq!(request.retries(), 3);
+/// ```
+/// 
+/// ## Expect and unwrap
+///
+/// For builder-like structs where an `Option<T>` field means "not yet set", the getter can
+/// unwrap the option. It requires the type of the field to be `Option<...>`.
+/// accepted option :
+/// - `expect = "{expr}"`: the getter returns `Result<&T, E>` with the expression as the error
+///   if the field is [`None`]. The error type `E` is the path of the expression without its
+///   last segment if it has more than one segment (`MyError::FieldMissing`,
+///   `MyError::new(...)` or `MyError::Missing { .. }` give `MyError`), otherwise the whole
+///   path (`FieldMissing` or `FieldMissing(...)` give `FieldMissing`).
+/// - `unwrap`: the getter returns `&T` and panics with a message naming the field if it is
+///   [`None`].
+///
+/// The getter type applies on the inner value, for instance `Copy` gives `Result<T, E>`.
+///
+/// ### Example
+/// ```
+/// mod private {
+///     use utils_lib_derive::Getter;
+///
+///     #[derive(Debug, PartialEq, Eq)]
+///     pub enum BuildError {
+///         NameMissing,
+///     }
+///
+///     #[derive(Getter, Default)]
+///     pub struct Builder {
+///         #[get(Pub, expect = "BuildError::NameMissing")]
+///         pub name: Option<String>,
+///         #[get(Pub, Copy, unwrap)]
+///         pub size: Option<usize>,
+///     }
+/// }
+///
+/// use private::{BuildError, Builder};
+///
+/// let mut builder = Builder::default();
+/// assert_eq!(builder.name(), Err(BuildError::NameMissing));
+/// builder.name = Some("name".to_owned());
+/// builder.size = Some(2);
+/// assert_eq!(builder.name().map(String::as_str), Ok("name"));
+/// assert_eq!(builder.size(), 2);
+/// ```
+/// 
+/// ## Try into
+///
+/// The getter converts the field into another type with [`TryInto`], like a `u64` field
+/// returned as an `u32`. The getter returns `Result<Target, <Ty as TryInto<Target>>::Error>`
+/// with `Ty` the type of the field. The field is converted by value so the getter type has to
+/// be by copy or by clone, and the getter cannot be constant. It is not accepted on
+/// `#[get_mut]`.
+/// accepted option :
+/// - `try_into = "{type}"`
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// struct File {
+///     #[get(try_into = "u32", getter_ty = "copy")]
+///     size: u64,
+///     #[get(try_into = "Box<str>", Clone, name = "boxed_name")]
+///     name: String,
+/// }
+///
+/// let file = File {
+///     size: 42,
+///     name: "file".to_owned(),
+/// };
+/// assert_eq!(file.size(), Ok(42_u32));
+/// assert_eq!(file.boxed_name().as_deref(), Ok("file"));
+///
+/// let file = File {
+///     size: u64::MAX,
+///     name: String::new(),
+/// };
+/// assert!(file.size().is_err());
+/// ```
+/// 
+/// ## Nested
+///
+/// Instead of a getter on the field, create delegating getters on the fields of the field,
+/// `fn {nested}(&self) -> &Ty` returning `&self.{field}.{nested}`. The derive cannot see the
+/// definition of the type of the field, so the types of the nested fields have to be given
+/// with `nested_ty`, one per nested field in the same order. The other options (visibility,
+/// constant type, getter type and self type) apply on each delegating getter. It cannot be
+/// combined with the options `name`, `expect`, `unwrap`, `into_inner`, `with`, `newtype`,
+/// `both`, `add_mut` or `try_into`.
+/// accepted option :
+/// - `nested = "{field}, {field}"` or `nested({field})`
+/// - `nested_ty = "{type}, {type}"` (or `nested_type`)
+///
+/// ### Example
+/// ```
+/// mod private {
+///     use std::time::Duration;
+///
+///     use utils_lib_derive::Getter;
+///
+///     pub struct Config {
+///         pub timeout: Duration,
+///         pub retries: u8,
+///     }
+///
+///     #[derive(Getter)]
+///     pub struct Client {
+///         #[get(Pub, Copy, nested = "timeout, retries", nested_ty = "Duration, u8")]
+///         config: Config,
+///     }
+///
+///     impl Client {
+///         pub fn new(config: Config) -> Self {
+///             Self { config }
+///         }
+///     }
+/// }
+///
+/// use std::time::Duration;
+///
+/// let client = private::Client::new(private::Config {
+///     timeout: Duration::from_secs(1),
+///     retries: 3,
+/// });
+/// assert_eq!(client.timeout(), Duration::from_secs(1));
+/// assert_eq!(client.retries(), 3);
+/// ```
+/// 
+/// ## Newtype
+///
+/// The option `newtype` creates the conventional getters of a single field struct like
+/// `struct Meters(f64);`: the getter `fn get(&self) -> &Ty`, the consuming getter
+/// `fn into_inner(self) -> Ty` and the implementation of `AsRef<Ty>` for the struct. The names
+/// of the getters can be changed with `name` and `into_inner`, the other options apply on the
+/// getter as usual. The struct attribute `#[getter(newtype)]` gives these getters to the single
+/// field without a field attribute. It is an error on a struct with more than one field, and
+/// it cannot be combined with `both` or `add_mut` in the same attribute, use a separate
+/// `#[get_mut]` attribute instead.
+/// accepted option :
+/// - `newtype`
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// struct Meters(#[get(newtype)] f64);
+///
+/// #[derive(Getter)]
+/// #[getter(newtype)]
+/// struct Name(String);
+///
+/// let meters = Meters(1.5);
+/// assert_eq!(meters.get(), &1.5);
+/// assert_eq!(meters.as_ref(), &1.5);
+/// assert_eq!(meters.into_inner(), 1.5);
+///
+/// let name = Name("name".to_owned());
+/// assert_eq!(AsRef::<String>::as_ref(&name), "name");
+/// assert_eq!(name.into_inner(), "name");
+/// ```
+/// 
+/// ## Conflicting options
+///
+/// Some pairs of options of a `#[get]` attribute cannot be combined and give an error naming
+/// both options, instead of generating code that the compiler rejects. The pairs are:
+/// - self type and getter type: a getter taking self by value cannot return a reference
+/// - const and getter type: a constant getter cannot clone the field
+/// - try into and getter type: the field is converted by value, use a getter by copy or by clone
+/// - const and try into: a constant getter cannot call `TryInto::try_into`
+/// - expect or unwrap and try into: both change the return type of the getter
+/// - newtype and add mut: use a separate `#[get_mut]` attribute for the mutable getter
+/// - into inner and add mut: use a separate `#[get_mut]` attribute for the mutable getter
+/// - nested and nested type: one type has to be given per nested field
+/// - nested and name: no getter is created on the field itself
+/// - nested and prefix: no getter is created on the field itself
+/// - nested and expect or unwrap: no getter is created on the field itself
+/// - nested and into inner: no getter is created on the field itself
+/// - nested and with: no getter is created on the field itself
+/// - nested and newtype: no getter is created on the field itself
+/// - nested and add mut: no getter is created on the field itself
+/// - nested and try into: no getter is created on the field itself
+/// - name and into inner: the names given to the two functions must differ
+/// - name and with: the names given to the two functions must differ
+/// - into inner and with: the names given to the two functions must differ
+/// - add mut and with: the names given to the two functions must differ
+///
+/// Only the names given explicitly are compared, the mutable getter of `both` is named
+/// `{name}_mut`. The names of the option `newtype` count as given.
+/// ```compile_fail
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// struct Value {
+///     #[get(name = "value", into_inner = "value")]
+///     field: u8,
+/// }
+/// ```
+/// 
+/// ## Getter module
+///
+/// By default the getters are generated in an inherent implementation of the struct. With the
+/// struct attribute `#[getter(module = "{name}")]` (or `#[getter(module({name}))]`) they are
+/// instead generated as methods of the trait `{Struct}Getters` in a new child module `{name}`
+/// with the same visibility as the struct. The trait is implemented for the struct and has to
+/// be imported to call the getters, keeping the inherent namespace of the struct clean.
+///
+/// Trait methods cannot be constant or have their own visibility so the options
+/// constant type and visibility are ignored in this mode. As the module is a child of the
+/// module where the struct is defined, the struct has to be defined in a module and not
+/// inside a function.
+///
+/// With the additional option `sealed` the trait is sealed, the same way [`trait_sealed`]
+/// does, and cannot be implemented outside of the generated module.
+///
+/// ### Example
+/// ```
+/// mod def {
+///     use utils_lib_derive::Getter;
+///
+///     #[derive(Getter)]
+///     #[getter(module = "accessors", sealed)]
+///     pub struct S {
+///         #[get]
+///         #[get_mut]
+///         pub f: usize,
+///     }
+/// }
+///
+/// use def::accessors::SGetters;
+///
+/// let mut s = def::S { f: 0 };
+/// assert_eq!(s.f(), &0);
+/// *s.f_mut() = 1;
+/// assert_eq!(s.f(), &1);
+/// ```
+/// 
+/// ## Wrapper type
+///
+/// With the struct attribute `#[getter(for = "{type}", via = "{path}")]` the getters are
+/// generated on the wrapper type `{type}` instead of the struct, and the fields are accessed
+/// through the path `{path}` from the wrapper, like `self.0.{field}` for `via = "0"`. The path
+/// can have more than one member, like `via = "inner.0"`. Without `via` the fields are
+/// accessed directly on the wrapper, which works if it dereferences to the struct. It can be
+/// combined with `module`, the trait is then implemented for the wrapper type. The generics
+/// of the struct are used for the implementation.
+///
+/// ### Example
+/// ```
+/// mod private {
+///     use utils_lib_derive::Getter;
+///
+///     #[derive(Getter)]
+///     #[getter(for = "Handle", via = "0")]
+///     pub struct Inner {
+///         #[get(Pub)]
+///         name: String,
+///     }
+///
+///     pub struct Handle(Inner);
+///
+///     impl Handle {
+///         pub fn new(name: String) -> Self {
+///             Self(Inner { name })
+///         }
+///     }
+/// }
+///
+/// let handle = private::Handle::new("name".to_owned());
+/// assert_eq!(handle.name(), "name");
+/// ```
+/// 
+/// ## Trait definition
+///
+/// With the struct attribute `#[getter(trait_def = "{name}")]` the trait `{name}` is also
+/// defined, with the visibility of the struct and one method per immutable getter, and it is
+/// implemented for the struct. The mutable getters are not part of the trait. The trait can
+/// be used as a trait object, so the getters must take `self` by reference: `self_ty = "value"`,
+/// `into_inner` and `with` give an error. Like the trait of `module`, it has the generics of the
+/// struct.
+///
+/// ### Example
+/// ```
+/// mod private {
+///     use utils_lib_derive::Getter;
+///
+///     #[derive(Getter)]
+///     #[getter(trait_def = "HasPosition")]
+///     pub struct Point {
+///         #[get(Pub)]
+///         x: f64,
+///         #[get(Pub, Copy)]
+///         y: f64,
+///     }
+///
+///     impl Point {
+///         pub fn new(x: f64, y: f64) -> Self {
+///             Self { x, y }
+///         }
+///     }
+///
+///     pub struct Origin;
+///
+///     impl HasPosition for Origin {
+///         fn x(&self) -> &f64 {
+///             &0_f64
+///         }
+///
+///         fn y(&self) -> f64 {
+///             0_f64
+///         }
+///     }
+/// }
+///
+/// use private::{HasPosition, Origin, Point};
+///
+/// let positions: Vec<Box<dyn HasPosition>> =
+///     vec![Box::new(Point::new(1_f64, 2_f64)), Box::new(Origin)];
+/// assert_eq!(positions[0].y(), 2_f64);
+/// assert_eq!(positions[1].x(), &0_f64);
+/// ```
+/// 
+/// ## Rename all
+///
+/// The struct attribute `#[getter(rename_all = "{case}")]` converts the default names of the
+/// getters to the given case: `snake_case` (the field names are already in it), `camelCase`,
+/// `PascalCase` or `SCREAMING_SNAKE_CASE`. The words are split on `_` and before an upper
+/// case letter. A name given with `name = "..."` is kept as is.
+///
+/// The default names `{field}_mut` of the mutable getter and `into_{field}` of the consuming
+/// getter are converted as a whole, so the field `my_field` gives `myField`, `myFieldMut`
+/// and `intoMyField` in camel case. The lint `non_snake_case` is allowed on the generated
+/// code if the case is not `snake_case`.
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// #[getter(rename_all = "camelCase")]
+/// struct S {
+///     #[get]
+///     #[get_mut]
+///     my_field: usize,
+///     #[get(name = "other")]
+///     other_field: usize,
+/// }
+///
+/// let mut s = S {
+///     my_field: 1,
+///     other_field: 2,
+/// };
+/// *s.myFieldMut() += 1;
+/// assert_eq!(s.myField(), &2);
+/// assert_eq!(s.other(), &2);
+/// ```
+/// 
+/// ## Tuple names
+///
+/// The fields of a tuple struct have no name, so each getter attribute needs the option
+/// `name = "..."`. The struct attribute `#[getter(tuple_names = "{prefix}")]` gives them the
+/// default names `{prefix}0`, `{prefix}1`, ..., with the mutable getter `{prefix}0_mut` and
+/// the consuming getter `into_{prefix}0`. `#[getter(tuple_names)]` uses the prefix `field_`.
+/// A name given with `name = "..."` is kept as is, and `rename_all` also applies to these
+/// default names.
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// #[getter(tuple_names)]
+/// struct S(
+///     #[get]
+///     #[get_mut]
+///     usize,
+///     #[get(name = "second")] usize,
+/// );
+///
+/// let mut s = S(1, 2);
+/// *s.field_0_mut() += 1;
+/// assert_eq!(s.field_0(), &2);
+/// assert_eq!(s.second(), &2);
+/// ```
+/// 
+/// ## Conditional getters
+///
+/// The attributes can be added with `#[cfg_attr(...)]`, like `#[cfg_attr(test, get)]`.
+/// If no field has a getter attribute once the `cfg_attr` are expanded the derive gives an
+/// error, the struct attribute `#[getter(allow_empty)]` makes it generate nothing instead.
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// #[getter(allow_empty)]
+/// struct S {
+///     #[cfg_attr(test, get)]
+///     f: usize,
+/// }
+/// #
+/// # let s = S { f: 0 };
+/// # assert_eq!(s.f, 0);
+/// ```
+/// 
+/// ## Packed struct
+///
+/// The fields of a `#[repr(packed)]` struct can be unaligned and no reference to them can be
+/// taken. On such a struct only the copy getter, `getter_ty = "copy"`, which reads the field
+/// by value, is accepted: a getter by reference or by clone and a mutable getter give an
+/// error.
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// #[repr(C, packed)]
+/// struct Header {
+///     #[get(getter_ty = "copy")]
+///     tag: u8,
+///     #[get(getter_ty = "copy")]
+///     length: u32,
+/// }
+///
+/// let header = Header { tag: 1, length: 4 };
+/// assert_eq!(header.tag(), 1);
+/// assert_eq!(header.length(), 4);
+/// ```
+/// 
+/// ## Long attribute names
+///
+/// `#[getter_get(...)]` and `#[getter_get_mut(...)]` are accepted interchangeably with
+/// `#[get(...)]` and `#[get_mut(...)]`, with the same options. They avoid the clash with another
+/// derive macro reading a `get` attribute, and both spellings can be mixed on one struct, even
+/// on one field.
+///
+/// ### Example
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// struct S {
+///     #[getter_get(Pub, Copy)]
+///     #[getter_get_mut]
+///     a: u8,
+///     #[get(Pub)]
+///     b: u8,
+/// }
+///
+/// let mut s = S { a: 1, b: 2 };
+/// *s.a_mut() += 1;
+/// assert_eq!(s.a(), 2);
+/// assert_eq!(s.b(), &2);
+/// ```
+/// 
+/// ## Lint allow
+///
+/// The generated implementation is annotated with
+/// `#[allow(clippy::missing_const_for_fn, clippy::must_use_candidate,
+/// clippy::trivially_copy_pass_by_ref)]` so that downstream crates enabling the pedantic and
+/// nursery lints do not get warnings inside the generated code, for instance on a getter that
+/// could be `Const`. The struct attribute `#[getter(no_lint_allow)]` removes it, so that the
+/// lints report on the generated getters.
+///
+/// ## Redundant attributes
+///
+/// A field may carry several getter attributes, the later ones being merged into the earlier
+/// ones. With the struct attribute `#[getter(warn_redundant)]`, an attribute that does not change
+/// the getters generated for its field, like a second `#[get]` or a `#[get(name = "field")]`
+/// repeating the default name, raises a `deprecated` warning pointing at it. The warning is
+/// emitted on the user crate so it can be denied like any other lint. Without the option these
+/// attributes are accepted silently.
+/// ```
+/// use utils_lib_derive::Getter;
+///
+/// #[derive(Getter)]
+/// #[getter(warn_redundant)]
+/// struct S {
+///     // warning: redundant #[get] attribute
+///     #[get]
+///     #[get]
+///     field: u8,
+/// }
+/// # assert_eq!(S { field: 0 }.field(), &0);
+/// ```
+/// 
+/// ## Output order
+///
+/// The expansion is deterministic. The getters are generated in the declaration order of the
+/// fields, with the immutable getter of a field before its mutable getter, and the attributes
+/// of a field are processed in their order, so a later `#[get_mut]` replaces an earlier one.
+/// Two builds of the same struct give the same expanded code.
 #[inline]
 #[must_use]
-#[proc_macro_derive(Getter, attributes(get, get_mut))]
+#[proc_macro_derive(Getter, attributes(get, get_mut, getter, getter_get, getter_get_mut))]
 pub fn derive_getter(item: TokenStream) -> TokenStream {
     getter::derive(item)
 }
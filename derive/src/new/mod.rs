@@ -0,0 +1,438 @@
+//! Contain proc macro for the `New` derive, creating the constructor of a struct with named
+//! fields.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Expr, ExprPath, Field,
+    Fields, LitStr, Type,
+};
+
+/// Path of the attribute for the options of the struct and of a field
+const NEW_ATTRIBUTE: &str = "new";
+
+/// Options `#[new(...)]` of the struct.
+struct Options {
+    /// Validation of the constructed value, see [`Validation`].
+    validation: Option<Validation>,
+    /// Span of `#[new(const)]` if the constructor is a `const fn`.
+    const_fn: Option<Span>,
+}
+
+/// Validation of the constructed value given by `#[new(validate = "...", error = "...")]`.
+struct Validation {
+    /// Path of the validator, a function `fn(&Self) -> Result<(), Error>`.
+    validator: ExprPath,
+    /// Error type returned by the validator and by `try_new`.
+    error: Type,
+    /// `#[new(fallible_only)]`, only `try_new` is generated and not `new`.
+    fallible_only: bool,
+}
+
+/// Value of a field that is not an argument of the constructor.
+enum FieldDefault {
+    /// `#[new(default)]`, the field is filled with [`Default::default`]. The span is the one
+    /// of the option.
+    Default(Span),
+    /// `#[new(default = "...")]`, the field is filled with the expression.
+    Expr(Box<Expr>),
+}
+
+/// Derive the constructor `new` of a struct with named fields, see [`crate::derive_new`].
+///
+/// A compile error is emitted if the input is not a struct with named fields or if a field
+/// option is unknown.
+#[inline]
+#[must_use]
+pub fn derive(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    derive_input(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generate the code of the derive from the parsed input.
+fn derive_input(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "New can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "New can only be derived for structs with named fields",
+        ));
+    };
+    let options = parse_options(&input.attrs)?;
+
+    let mut idents = Vec::with_capacity(fields.named.len());
+    let mut arguments = Vec::with_capacity(fields.named.len());
+    let mut values = Vec::with_capacity(fields.named.len());
+    for field in &fields.named {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        match field_default(field)? {
+            Some(FieldDefault::Default(span)) => {
+                if options.const_fn.is_some() {
+                    return Err(syn::Error::new(
+                        span,
+                        "`Default::default` is not const, use `#[new(default = \"...\")]` \
+                         with a const expression for a const constructor",
+                    ));
+                }
+                values.push(quote! { #ident: ::core::default::Default::default() });
+            }
+            Some(FieldDefault::Expr(expr)) => values.push(quote! { #ident: #expr }),
+            None => {
+                arguments.push(quote! { #ident: #ty });
+                values.push(quote! { #ident });
+                idents.push(ident);
+            }
+        }
+    }
+    let constness = options.const_fn.map(|span| quote_spanned! { span=> const });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let doc = format!("Create a new [`{name}`] from its fields.");
+
+    let constructors = match &options.validation {
+        None => quote! {
+            #[doc = #doc]
+            #[inline]
+            #[must_use]
+            pub #constness fn new(#(#arguments),*) -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+        },
+        Some(Validation {
+            validator,
+            error,
+            fallible_only,
+        }) => {
+            let doc_try_new =
+                format!("Create a new [`{name}`] from its fields if the validator accepts it.");
+            let try_new = quote! {
+                #[doc = #doc_try_new]
+                ///
+                /// # Errors
+                /// Returns the error of the validator if it rejects the value.
+                #[inline]
+                pub fn try_new(#(#arguments),*) -> ::core::result::Result<Self, #error> {
+                    let value = Self {
+                        #(#values,)*
+                    };
+                    #validator(&value).map(|()| value)
+                }
+            };
+            if *fallible_only {
+                try_new
+            } else {
+                quote! {
+                    #try_new
+
+                    #[doc = #doc]
+                    ///
+                    /// # Panics
+                    /// Panics with the [`Display`](::core::fmt::Display) of the error if the
+                    /// validator rejects the value, see [`Self::try_new`].
+                    #[inline]
+                    #[must_use]
+                    #[track_caller]
+                    pub fn new(#(#arguments),*) -> Self {
+                        match Self::try_new(#(#idents),*) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => ::core::panic!("{}", error),
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        /// Automatically generated constructor
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #constructors
+        }
+    })
+}
+
+/// Parse the options `#[new(...)]` of the struct.
+///
+/// The validator and the error type have to be given together, `fallible_only` requires
+/// a validator and `const` cannot be used with a validator.
+fn parse_options(attributes: &[Attribute]) -> syn::Result<Options> {
+    let mut validator = None;
+    let mut error = None;
+    let mut fallible_only = None;
+    let mut const_fn = None;
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(NEW_ATTRIBUTE))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("validate") {
+                let string: LitStr = meta.value()?.parse()?;
+                validator = Some((string.parse::<ExprPath>()?, string.span()));
+                Ok(())
+            } else if meta.path.is_ident("error") {
+                let string: LitStr = meta.value()?.parse()?;
+                error = Some((string.parse::<Type>()?, string.span()));
+                Ok(())
+            } else if meta.path.is_ident("fallible_only") {
+                fallible_only = Some(meta.path.span());
+                Ok(())
+            } else if meta.path.is_ident("const") {
+                const_fn = Some(meta.path.span());
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unknown option for #[new] on a struct, expected `validate = \"...\"`, \
+                     `error = \"...\"`, `fallible_only` or `const`",
+                ))
+            }
+        })?;
+    }
+
+    let validation =
+        match (validator, error) {
+            (Some((_, span)), Some(_)) if const_fn.is_some() => return Err(syn::Error::new(
+                span,
+                "the option `validate` cannot be used with `const`, the validation is not const",
+            )),
+            (Some((validator, _)), Some((error, _))) => Some(Validation {
+                validator,
+                error,
+                fallible_only: fallible_only.is_some(),
+            }),
+            (Some((_, span)), None) => {
+                return Err(syn::Error::new(
+                    span,
+                    "the option `validate` requires the error type `#[new(error = \"...\")]`",
+                ))
+            }
+            (None, Some((_, span))) => {
+                return Err(syn::Error::new(
+                    span,
+                    "the option `error` requires a validator `#[new(validate = \"...\")]`",
+                ))
+            }
+            (None, None) => {
+                if let Some(span) = fallible_only {
+                    return Err(syn::Error::new(
+                    span,
+                    "the option `fallible_only` requires a validator `#[new(validate = \"...\")]`",
+                ));
+                }
+                None
+            }
+        };
+    Ok(Options {
+        validation,
+        const_fn,
+    })
+}
+
+/// Parse the options `#[new(...)]` of a field, return the value of the field if it is not an
+/// argument of the constructor.
+fn field_default(field: &Field) -> syn::Result<Option<FieldDefault>> {
+    let mut default = None;
+    for attribute in field
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(NEW_ATTRIBUTE))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                default = Some(if meta.input.peek(syn::Token![=]) {
+                    let string: LitStr = meta.value()?.parse()?;
+                    FieldDefault::Expr(Box::new(string.parse()?))
+                } else {
+                    FieldDefault::Default(meta.path.span())
+                });
+                Ok(())
+            } else {
+                Err(meta
+                    .error("unknown option for #[new], expected `default` or `default = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(default)
+}
+
+#[cfg(test)]
+mod test {
+    use syn::{parse_quote, DeriveInput};
+
+    use super::derive_input;
+
+    #[test]
+    fn error() {
+        let input: DeriveInput = parse_quote! {
+            enum E {
+                A,
+            }
+        };
+        let error = derive_input(&input).expect_err("enum");
+        assert!(error.to_string().contains("only be derived for structs"));
+
+        let input: DeriveInput = parse_quote! {
+            struct S(u8);
+        };
+        let error = derive_input(&input).expect_err("tuple struct");
+        assert!(error.to_string().contains("named fields"));
+
+        let input: DeriveInput = parse_quote! {
+            struct S {
+                #[new(skip)]
+                a: u8,
+            }
+        };
+        derive_input(&input).expect_err("unknown option");
+
+        let input: DeriveInput = parse_quote! {
+            #[new(validate = "Self::check")]
+            struct S {
+                a: u8,
+            }
+        };
+        let error = derive_input(&input).expect_err("validator without error");
+        assert!(error.to_string().contains("requires the error type"));
+
+        let input: DeriveInput = parse_quote! {
+            #[new(error = "E")]
+            struct S {
+                a: u8,
+            }
+        };
+        let error = derive_input(&input).expect_err("error without validator");
+        assert!(error.to_string().contains("`error` requires a validator"));
+
+        let input: DeriveInput = parse_quote! {
+            #[new(fallible_only)]
+            struct S {
+                a: u8,
+            }
+        };
+        let error = derive_input(&input).expect_err("fallible only without validator");
+        assert!(error
+            .to_string()
+            .contains("`fallible_only` requires a validator"));
+
+        let input: DeriveInput = parse_quote! {
+            #[new(validate = "not a path", error = "E")]
+            struct S {
+                a: u8,
+            }
+        };
+        derive_input(&input).expect_err("invalid validator");
+
+        let input: DeriveInput = parse_quote! {
+            #[new(const)]
+            struct S {
+                #[new(default)]
+                a: u8,
+            }
+        };
+        let error = derive_input(&input).expect_err("const with Default::default");
+        assert!(error.to_string().contains("is not const"));
+
+        let input: DeriveInput = parse_quote! {
+            #[new(const, validate = "Self::check", error = "E")]
+            struct S {
+                a: u8,
+            }
+        };
+        let error = derive_input(&input).expect_err("const with a validator");
+        assert!(error.to_string().contains("cannot be used with `const`"));
+    }
+
+    #[test]
+    fn output() {
+        let input: DeriveInput = parse_quote! {
+            struct S<T: Clone> where T: Copy {
+                a: T,
+                #[new(default)]
+                b: Vec<u8>,
+                c: u8,
+            }
+        };
+        let output = derive_input(&input).expect("named struct").to_string();
+        assert!(output.contains("impl < T : Clone > S < T > where T : Copy"));
+        assert!(output.contains("pub fn new (a : T , c : u8) -> Self"));
+        assert!(output.contains("b : :: core :: default :: Default :: default ()"));
+
+        let input: DeriveInput = parse_quote! {
+            struct Empty {}
+        };
+        let output = derive_input(&input).expect("named struct").to_string();
+        assert!(output.contains("pub fn new () -> Self"));
+        assert!(!output.contains("try_new"));
+    }
+
+    #[test]
+    fn validation() {
+        let input: DeriveInput = parse_quote! {
+            #[new(validate = "Self::check", error = "RangeError")]
+            struct Range {
+                min: u8,
+                max: u8,
+                #[new(default)]
+                len: usize,
+            }
+        };
+        let output = derive_input(&input).expect("named struct").to_string();
+        assert!(output.contains(
+            "pub fn try_new (min : u8 , max : u8) -> :: core :: result :: Result < Self , \
+             RangeError >"
+        ));
+        assert!(output.contains("Self :: check (& value) . map (| () | value)"));
+        assert!(output.contains("pub fn new (min : u8 , max : u8) -> Self"));
+        assert!(output.contains("match Self :: try_new (min , max)"));
+
+        let input: DeriveInput = parse_quote! {
+            #[new(validate = "check::<u8>", error = "RangeError")]
+            #[new(fallible_only)]
+            struct Range {
+                min: u8,
+                max: u8,
+            }
+        };
+        let output = derive_input(&input).expect("named struct").to_string();
+        assert!(output.contains("check :: < u8 > (& value)"));
+        assert!(output.contains("pub fn try_new"));
+        assert!(!output.contains("pub fn new"));
+    }
+
+    #[test]
+    fn const_fn() {
+        let input: DeriveInput = parse_quote! {
+            #[new(const)]
+            struct S<T> {
+                a: T,
+                #[new(default = "Vec::new()")]
+                b: Vec<u8>,
+            }
+        };
+        let output = derive_input(&input).expect("named struct").to_string();
+        assert!(output.contains("pub const fn new (a : T) -> Self"));
+        assert!(output.contains("b : Vec :: new ()"));
+
+        let input: DeriveInput = parse_quote! {
+            struct S {
+                #[new(default = "4 * 2")]
+                a: u8,
+            }
+        };
+        let output = derive_input(&input).expect("named struct").to_string();
+        assert!(output.contains("pub fn new () -> Self"));
+        assert!(output.contains("a : 4 * 2"));
+    }
+}
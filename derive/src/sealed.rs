@@ -1,11 +1,21 @@
 //! Contain proc macro for the `Sealed` trait derive and definition
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use proc_macro2::{Group, Spacing, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Attribute, DeriveInput, LitStr, Token,
+    WherePredicate,
+};
+
+/// Path of the attribute for the options of the `Sealed` derive
+const SEALED_ATTRIBUTE: &str = "sealed";
 
 /// Derive the `Sealed` trait, see [`crate::derive_sealed`]
 ///
+/// The where clause of the type is re-emitted on the implementation, with `Self` replaced
+/// by the concrete type, followed by the predicates given by `#[sealed(bound = "...")]`.
+///
 /// # Panic
 ///
 /// panic if the derive macro is not applied to an struct, enum or union
@@ -13,11 +23,30 @@ use syn::{parse_macro_input, DeriveInput};
 #[must_use]
 pub fn derive(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
+
+    let additional_bounds = match parse_bounds(&input.attrs) {
+        Ok(bounds) => bounds,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let name = input.ident;
     let generics = input.generics;
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let self_ty = quote!(#name #ty_generics);
+    let predicates = where_clause
+        .into_iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .map(|predicate| replace_self(predicate.to_token_stream(), &self_ty))
+        .chain(additional_bounds.iter().map(ToTokens::to_token_stream))
+        .collect::<Vec<_>>();
+    let where_clause = if predicates.is_empty() {
+        quote!()
+    } else {
+        quote!(where #(#predicates),*)
+    };
+
     quote!(
         #[automatically_derived]
         impl #impl_generics crate::private::Sealed for #name #ty_generics #where_clause {}
@@ -25,6 +54,61 @@ pub fn derive(item: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Parse the additional where predicates given by `#[sealed(bound = "...")]`. The string
+/// can contain multiple predicates separated by commas and the option can be repeated.
+fn parse_bounds(attributes: &[Attribute]) -> syn::Result<Vec<WherePredicate>> {
+    let mut bounds = Vec::new();
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(SEALED_ATTRIBUTE))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bound") {
+                let string: LitStr = meta.value()?.parse()?;
+                bounds.extend(
+                    string.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?,
+                );
+                Ok(())
+            } else {
+                Err(meta.error("unknown option for #[sealed], expected `bound = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(bounds)
+}
+
+/// Replace the `Self` keyword by the type `self_ty` in a token stream, recursively in groups.
+///
+/// `Self::Item` is replaced by `<Type>::Item` so that the path stays valid.
+fn replace_self(tokens: TokenStream2, self_ty: &TokenStream2) -> TokenStream2 {
+    let mut output = TokenStream2::new();
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(token) = iter.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "Self" => {
+                let is_path_start = matches!(
+                    iter.peek(),
+                    Some(TokenTree::Punct(punct))
+                        if punct.as_char() == ':' && punct.spacing() == Spacing::Joint
+                );
+                if is_path_start {
+                    output.extend(quote!(<#self_ty>));
+                } else {
+                    output.extend(self_ty.clone());
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), replace_self(group.stream(), self_ty));
+                new_group.set_span(group.span());
+                output.extend([TokenTree::Group(new_group)]);
+            }
+            token => output.extend([token]),
+        }
+    }
+    output
+}
+
 /// Creates a trait `Sealed` into a private module `private`.
 #[inline]
 #[must_use]
@@ -32,13 +116,21 @@ pub fn derive(item: TokenStream) -> TokenStream {
 #[allow(clippy::needless_pass_by_value)] // the signature of a proc macro is to take by value
 pub fn trait_sealed(item: TokenStream) -> TokenStream {
     if item.is_empty() {
-        quote!(
-            mod private {
-                pub trait Sealed {}
-            }
-        )
+        private_module()
     } else {
-        quote!(compile_error!("trait_sealed!() does not take any arguments");)
+        quote!(::core::compile_error!("trait_sealed!() does not take any arguments");)
     }
     .into()
 }
+
+/// The code of the private module `private` containing the trait `Sealed`.
+///
+/// It is also used by the `Getter` derive to seal the getter trait.
+#[must_use]
+pub fn private_module() -> TokenStream2 {
+    quote!(
+        mod private {
+            pub trait Sealed {}
+        }
+    )
+}
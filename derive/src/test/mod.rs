@@ -29,16 +29,49 @@ mod trybuild {
     }
 }
 
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_const_clone.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_conflict.rs")]
+/// ```
 /// ```compile_fail
 #[doc = include_str!("../../ui_test/fail/get_enum.rs")]
 /// ```
 /// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_expect.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_into_inner.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_module_sealed.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_module.rs")]
+/// ```
+/// ```compile_fail
 #[doc = include_str!("../../ui_test/fail/get_move_on_ref.rs")]
 /// ```
 /// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_mut_immutable_option.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_newtype.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_prefix.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_redundant.rs")]
+/// ```
+/// ```compile_fail
 #[doc = include_str!("../../ui_test/fail/get_repetition.rs")]
 /// ```
 /// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_try_into.rs")]
+/// ```
+/// ```compile_fail
 #[doc = include_str!("../../ui_test/fail/get_type.rs")]
 /// ```
 /// ```compile_fail
@@ -48,31 +81,118 @@ mod trybuild {
 #[doc = include_str!("../../ui_test/fail/get_visibility.rs")]
 /// ```
 /// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/get_with.rs")]
+/// ```
+/// ```compile_fail
 #[doc = include_str!("../../ui_test/fail/get.rs")]
 /// ```
 /// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/new.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/new_const.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/new_validate.rs")]
+/// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/sealed_bound.rs")]
+/// ```
+/// ```compile_fail
 #[doc = include_str!("../../ui_test/fail/trait_sealed.rs")]
 /// ```
+/// ```compile_fail
+#[doc = include_str!("../../ui_test/fail/variants.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_both.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_cfg.rs")]
+/// ```
 /// ```
 #[doc = include_str!("../../ui_test/pass/get_const.rs")]
 /// ```
 /// ```
+#[doc = include_str!("../../ui_test/pass/get_expect.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_hidden.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_into_inner.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_long_name.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_module.rs")]
+/// ```
+/// ```
 #[doc = include_str!("../../ui_test/pass/get_mut.rs")]
 /// ```
 /// ```
 #[doc = include_str!("../../ui_test/pass/get_name.rs")]
 /// ```
 /// ```
+#[doc = include_str!("../../ui_test/pass/get_newtype.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_prefix.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_recursive.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_shadowing.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_try_into.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_tuple_names.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_unit.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/get_unwrap.rs")]
+/// ```
+/// ```
 #[doc = include_str!("../../ui_test/pass/get_visibility.rs")]
 /// ```
 /// ```
+#[doc = include_str!("../../ui_test/pass/get_with.rs")]
+/// ```
+/// ```
 #[doc = include_str!("../../ui_test/pass/get.rs")]
 /// ```
 /// ```
+#[doc = include_str!("../../ui_test/pass/new.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/new_const.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/new_default.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/new_generic.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/new_validate.rs")]
+/// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/sealed_where.rs")]
+/// ```
+/// ```
 #[doc = include_str!("../../ui_test/pass/sealed.rs")]
 /// ```
 /// ```
 #[doc = include_str!("../../ui_test/pass/trait_sealed.rs")]
 /// ```
+/// ```
+#[doc = include_str!("../../ui_test/pass/variants.rs")]
+/// ```
 #[cfg(all(feature = "coverage", doctest))] // cspell: ignore doctest
 mod coverage {}
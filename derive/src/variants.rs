@@ -0,0 +1,193 @@
+//! Contain proc macro for the `Variants` derive, listing the variants of a fieldless enum.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, LitStr, Type,
+};
+
+/// Path of the attribute for the options of the `Variants` derive
+const VARIANTS_ATTRIBUTE: &str = "variants";
+
+/// Derive the variant list of a fieldless enum, see [`crate::derive_variants`].
+///
+/// A compile error is emitted if the input is not an enum or if a variant has fields.
+#[inline]
+#[must_use]
+pub fn derive(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    derive_input(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generate the code of the derive from the parsed input.
+fn derive_input(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "Variants can only be derived for enums",
+        ));
+    };
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(syn::Error::new(
+            variant.fields.span(),
+            format!(
+                "Variants can only be derived for fieldless enums, the variant `{}` has fields",
+                variant.ident
+            ),
+        ));
+    }
+    let error = parse_error_type(&input.attrs)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+    let count = variants.len();
+    let indices = 0..count;
+    let indices_back = 0..count;
+    let doc_variants = format!("All the variants of [`{name}`] in the declaration order.");
+
+    Ok(quote! {
+        /// Automatically generated variant list
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #doc_variants]
+            pub const VARIANTS: [Self; #count] = [#(Self::#variants),*];
+
+            /// Get an iterator over the variants in the declaration order.
+            #[inline]
+            #[must_use]
+            pub fn variants(
+            ) -> impl ::core::iter::DoubleEndedIterator<Item = Self>
+                   + ::core::iter::ExactSizeIterator {
+                Self::VARIANTS.into_iter()
+            }
+
+            /// Get the number of variants.
+            #[inline]
+            #[must_use]
+            pub const fn variant_count() -> usize {
+                #count
+            }
+
+            /// Get the variant at the given index in the declaration order, [`None`] if the
+            /// index is out of range.
+            #[inline]
+            #[must_use]
+            pub const fn from_index(index: usize) -> ::core::option::Option<Self> {
+                match index {
+                    #(#indices => ::core::option::Option::Some(Self::#variants),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// Get the index of the variant in the declaration order.
+            #[inline]
+            #[must_use]
+            pub const fn to_index(self) -> usize {
+                match self {
+                    #(Self::#variants => #indices_back,)*
+                }
+            }
+        }
+
+        /// Automatically generated conversion from the index of the variant
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::TryFrom<usize> for #name #ty_generics #where_clause {
+            type Error = #error;
+
+            #[inline]
+            fn try_from(value: usize) -> ::core::result::Result<Self, Self::Error> {
+                Self::from_index(value).ok_or_else(::core::default::Default::default)
+            }
+        }
+    })
+}
+
+/// Parse the error type of the `TryFrom<usize>` implementation given by
+/// `#[variants(error = "...")]`, `()` if it is not given.
+fn parse_error_type(attributes: &[Attribute]) -> syn::Result<Type> {
+    let mut error = None;
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(VARIANTS_ATTRIBUTE))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                let string: LitStr = meta.value()?.parse()?;
+                error = Some(string.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown option for #[variants], expected `error = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(error.unwrap_or_else(|| syn::parse_quote!(())))
+}
+
+#[cfg(test)]
+mod test {
+    use syn::{parse_quote, DeriveInput};
+
+    use super::derive_input;
+
+    #[test]
+    fn error() {
+        let input: DeriveInput = parse_quote! {
+            enum E {
+                A,
+                B(u8),
+            }
+        };
+        let error = derive_input(&input).expect_err("variant with fields");
+        assert!(error.to_string().contains("`B` has fields"));
+
+        let input: DeriveInput = parse_quote! {
+            struct S;
+        };
+        derive_input(&input).expect_err("not an enum");
+
+        let input: DeriveInput = parse_quote! {
+            #[variants(err = "E")]
+            enum E {
+                A,
+            }
+        };
+        derive_input(&input).expect_err("unknown option");
+    }
+
+    #[test]
+    fn output() {
+        let input: DeriveInput = parse_quote! {
+            #[variants(error = "NoneError")]
+            enum E {
+                A,
+                B = 4,
+                C,
+            }
+        };
+        let output = derive_input(&input).expect("fieldless enum").to_string();
+        assert!(output.contains(
+            "pub const VARIANTS : [Self ; 3usize] = [Self :: A , Self :: B , Self :: C]"
+        ));
+        assert!(output.contains("type Error = NoneError"));
+        assert!(output.contains("Self :: C => 2usize"));
+
+        let input: DeriveInput = parse_quote! {
+            enum Empty {}
+        };
+        let output = derive_input(&input).expect("fieldless enum").to_string();
+        assert!(output.contains("[Self ; 0usize] = []"));
+        assert!(output.contains("type Error = ()"));
+    }
+}
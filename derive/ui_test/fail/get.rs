@@ -2,10 +2,13 @@
 use utils_lib_derive::Getter;
 
 #[derive(Getter)]
-struct Zst; // error on field less struct
+struct Zst; // error on unit struct
 
 #[derive(Getter)]
-struct NoGet {} // error on field less struct
+struct NoGet {} // error on empty braces
+
+#[derive(Getter)]
+struct NoGetTuple(); // error on empty parentheses
 
 // error on unnamed getter
 #[derive(Getter)]
@@ -17,17 +20,21 @@ struct Wrapper(
 
 #[derive(Getter)]
 struct S {
-    #[get = "not valid"] // named value is not supported we are expecting #[get(...)]
+    #[get = 1] // only a literal string is supported, #[get = "name"]
     #[get_mut = "not valid"]
     f: usize,
 }
 
-// no #[get] or #[get_mut] found
+// no #[get] or #[get_mut] found, the fields that could be annotated are listed
 #[derive(Getter)]
 struct S2 {
     f: usize,
+    g: usize,
 }
 
+#[derive(Getter)]
+struct TupleNoAttribute(usize, String);
+
 // test the case of multiple field attribute on the same field
 // see ../pass/get.rs for the pass test
 #[derive(Getter)]
@@ -0,0 +1,36 @@
+// fail test for the pairs of options of the validation matrix giving the same function name
+use utils_lib_derive::Getter;
+
+// the getter and the consuming getter
+#[derive(Getter)]
+pub struct IntoInner {
+    #[get(name = "value", into_inner = "value")]
+    f: usize,
+}
+
+// the getter and the consuming setter
+#[derive(Getter)]
+pub struct With {
+    #[get(with = "value", name = "value")]
+    f: usize,
+}
+
+// the consuming getter and the consuming setter
+#[derive(Getter)]
+pub struct IntoInnerWith {
+    #[get(into_inner = "take", with = "take")]
+    f: usize,
+}
+
+// the mutable getter `{name}_mut` and the consuming setter
+#[derive(Getter)]
+pub struct Mutable {
+    #[get(name = "value", both, with = "value_mut")]
+    f: usize,
+}
+
+// the getter `get` of newtype and the consuming setter
+#[derive(Getter)]
+pub struct Newtype(#[get(newtype, with = "get")] usize);
+
+fn main() {}
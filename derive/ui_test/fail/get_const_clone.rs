@@ -0,0 +1,11 @@
+// fail test for a constant getter by clone.
+// `Clone::clone` cannot be called in a const fn so it returns a custom error.
+use utils_lib_derive::Getter;
+
+#[derive(Getter)]
+struct S {
+    #[get(Const, clone)]
+    f: (),
+}
+
+fn main() {}
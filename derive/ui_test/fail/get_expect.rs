@@ -0,0 +1,35 @@
+// fail test for the expect and unwrap options
+use utils_lib_derive::Getter;
+
+pub enum Error {
+    Missing,
+}
+
+// the field is not an Option
+#[derive(Getter)]
+pub struct NotOption {
+    #[get(expect = "Error::Missing")]
+    f: usize,
+}
+
+#[derive(Getter)]
+pub struct NotOptionUnwrap {
+    #[get(unwrap)]
+    f: Vec<Option<usize>>,
+}
+
+// the error type cannot be determined from the expression
+#[derive(Getter)]
+pub struct Literal {
+    #[get(expect = "0")]
+    f: Option<usize>,
+}
+
+// expect and unwrap cannot both be set
+#[derive(Getter)]
+pub struct Both {
+    #[get(expect = "Error::Missing", unwrap)]
+    f: Option<usize>,
+}
+
+fn main() {}
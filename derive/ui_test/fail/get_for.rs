@@ -0,0 +1,30 @@
+// fail test for the options for and via
+use utils_lib_derive::Getter;
+
+// via requires for
+#[derive(Getter)]
+#[getter(via = "0")]
+pub struct S {
+    #[get]
+    f: usize,
+}
+
+// not a type
+#[derive(Getter)]
+#[getter(for = "1")]
+pub struct Target {
+    #[get]
+    f: usize,
+}
+
+// not a path of fields
+#[derive(Getter)]
+#[getter(for = "Wrapper", via = "0 1")]
+pub struct Path {
+    #[get]
+    f: usize,
+}
+
+pub struct Wrapper(pub Path);
+
+fn main() {}
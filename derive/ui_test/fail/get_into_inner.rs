@@ -0,0 +1,22 @@
+// fail test for the into_inner option
+use utils_lib_derive::Getter;
+
+// into_inner cannot be combined with the mutable getter in the same attribute
+#[derive(Getter)]
+pub struct S {
+    #[get(both, into_inner)]
+    f: usize,
+}
+
+// no default name for the consuming getter of a tuple struct
+#[derive(Getter)]
+pub struct Tuple(#[get(name = "value", into_inner)] usize);
+
+// not a valid name
+#[derive(Getter)]
+pub struct Name {
+    #[get(into_inner = "1name")]
+    f: usize,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// fail test for the getter trait generated in a module: the trait needs to be imported
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct S {
+        #[get]
+        pub f: usize,
+    }
+}
+
+fn main() {
+    let s = def::S { f: 0 };
+    assert_eq!(s.f(), &0);
+}
@@ -0,0 +1,28 @@
+// fail test for the sealed getter trait, it cannot be implemented outside of the generated module
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    #[getter(module = "accessors", sealed)]
+    pub struct S {
+        #[get]
+        pub f: usize,
+    }
+}
+
+struct Other;
+
+impl def::accessors::SGetters for Other {
+    fn f(&self) -> &usize {
+        &0
+    }
+}
+
+#[derive(utils_lib_derive::Getter)]
+#[getter(sealed)]
+struct NoModule {
+    #[get]
+    f: usize,
+}
+
+fn main() {}
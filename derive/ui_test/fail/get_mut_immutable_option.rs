@@ -0,0 +1,39 @@
+// fail test for the options only valid on #[get] given on #[get_mut]
+use utils_lib_derive::Getter;
+
+// a mutable getter cannot be constant
+#[derive(Getter)]
+pub struct Const {
+    #[get_mut(Pub, Const)]
+    f: usize,
+}
+
+// a mutable getter always returns a mutable reference
+#[derive(Getter)]
+pub struct GetterTy {
+    #[get_mut(getter_ty = "clone")]
+    f: usize,
+}
+
+#[derive(Getter)]
+pub struct GetterTyShort {
+    #[get_mut(Copy)]
+    f: usize,
+}
+
+// a mutable getter always borrows self mutably
+#[derive(Getter)]
+pub struct SelfTy {
+    #[get_mut(self_ty = "value")]
+    f: usize,
+}
+
+// an invalid value of an option of #[get] is reported as given on #[get_mut]
+#[derive(Getter)]
+pub struct SelfTyInvalid {
+    #[get(Pub)]
+    #[getter_get_mut(name = "field", self_ty = "invalid")]
+    f: usize,
+}
+
+fn main() {}
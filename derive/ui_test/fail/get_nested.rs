@@ -0,0 +1,30 @@
+// fail test for the nested option
+use utils_lib_derive::Getter;
+
+pub struct Config {
+    pub timeout: u64,
+    pub retries: u8,
+}
+
+// the types of the nested fields are missing
+#[derive(Getter)]
+pub struct S {
+    #[get(nested = "timeout, retries")]
+    config: Config,
+}
+
+// one type per nested field
+#[derive(Getter)]
+pub struct Count {
+    #[get(nested = "timeout, retries", nested_ty = "u64")]
+    config: Config,
+}
+
+// no getter is created on the field itself
+#[derive(Getter)]
+pub struct Both {
+    #[get(nested = "timeout", nested_ty = "u64", both)]
+    config: Config,
+}
+
+fn main() {}
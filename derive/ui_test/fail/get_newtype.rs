@@ -0,0 +1,20 @@
+// fail test for the newtype option
+use utils_lib_derive::Getter;
+
+// newtype requires a single field
+#[derive(Getter)]
+pub struct Pair(#[get(newtype)] f64, f64);
+
+// also with the container option
+#[derive(Getter)]
+#[getter(newtype)]
+pub struct Point {
+    x: f64,
+    y: f64,
+}
+
+// newtype cannot be combined with the mutable getter in the same attribute
+#[derive(Getter)]
+pub struct Both(#[get(newtype, both)] f64);
+
+fn main() {}
@@ -0,0 +1,19 @@
+// fail test for getters taking a reference on a packed struct
+use utils_lib_derive::Getter;
+
+#[derive(Getter)]
+#[repr(packed)]
+struct Packed {
+    #[get]
+    by_ref: u64,
+    #[get(getter_ty = "clone")]
+    by_clone: u64,
+    #[get_mut]
+    mutable: u64,
+    #[get(getter_ty = "copy", add_mut)]
+    copy_and_mutable: u64,
+    #[get(getter_ty = "copy")]
+    copy: u64,
+}
+
+fn main() {}
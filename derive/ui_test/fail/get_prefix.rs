@@ -0,0 +1,40 @@
+// fail test for the prefix option
+use utils_lib_derive::Getter;
+
+// an empty prefix is rejected even with a name
+#[derive(Getter)]
+pub struct S {
+    #[get(name = "size", prefix = "")]
+    f: usize,
+}
+
+// not the start of an identifier
+#[derive(Getter)]
+pub struct Digit {
+    #[get(prefix = "1")]
+    f: usize,
+}
+
+// the option is set twice
+#[derive(Getter)]
+pub struct Twice {
+    #[get_mut(prefix = "get_", prefix = "set_")]
+    f: usize,
+}
+
+// no default name for a tuple struct
+#[derive(Getter)]
+pub struct Tuple(#[get(prefix = "get_")] usize);
+
+// no getter is created on a field with nested getters
+pub struct Inner {
+    pub a: usize,
+}
+
+#[derive(Getter)]
+pub struct Nested {
+    #[get(nested = "a", nested_ty = "usize", prefix = "get_")]
+    inner: Inner,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![deny(deprecated)]
+
+use utils_lib_derive::Getter;
+
+#[derive(Getter)]
+#[getter(warn_redundant)]
+struct S {
+    // the second attribute generates the same getter as the first one
+    #[get]
+    #[get(name = "field")]
+    field: u8,
+    // the second attribute changes the visibility so it is not redundant
+    #[get]
+    #[get(Pub)]
+    other: u8,
+}
+
+#[derive(Getter)]
+struct NoWarning {
+    // without the option the redundant attribute is accepted silently
+    #[get]
+    #[get(name = "field")]
+    field: u8,
+}
+
+fn main() {
+    let s = S { field: 1, other: 2 };
+    assert_eq!(s.field(), &1);
+    assert_eq!(s.other(), &2);
+    let s = NoWarning { field: 3 };
+    assert_eq!(s.field(), &3);
+}
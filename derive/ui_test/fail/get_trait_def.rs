@@ -0,0 +1,28 @@
+// fail test for the option trait_def
+use utils_lib_derive::Getter;
+
+// self by value cannot be called on a trait object
+#[derive(Getter, Clone, Copy)]
+#[getter(trait_def = "HasValue")]
+pub struct ByValue {
+    #[get(Copy, self_ty = "value")]
+    f: usize,
+}
+
+// the consuming getter takes self by value
+#[derive(Getter)]
+#[getter(trait_def = "HasName")]
+pub struct Consuming {
+    #[get(into_inner)]
+    name: String,
+}
+
+// not an ident
+#[derive(Getter)]
+#[getter(trait_def = "Has::Name")]
+pub struct Path {
+    #[get]
+    f: usize,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// fail test for the try_into option
+use utils_lib_derive::Getter;
+
+// the mutable getter cannot convert the field
+#[derive(Getter)]
+pub struct Mut {
+    #[get_mut(try_into = "u32")]
+    f: u64,
+}
+
+// the field is converted by value
+#[derive(Getter)]
+pub struct Ref {
+    #[get(try_into = "u32")]
+    f: u64,
+}
+
+// TryInto::try_into is not const
+#[derive(Getter)]
+pub struct Const {
+    #[get(try_into = "u32", Copy, Const)]
+    f: u64,
+}
+
+// not a type
+#[derive(Getter)]
+pub struct Type {
+    #[get(try_into = "1", Copy)]
+    f: u64,
+}
+
+fn main() {}
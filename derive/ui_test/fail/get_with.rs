@@ -0,0 +1,33 @@
+// fail test for the with option
+use utils_lib_derive::Getter;
+
+// the option is set twice
+#[derive(Getter)]
+pub struct S {
+    #[get(with, with = "set_f")]
+    f: usize,
+}
+
+// no default name for the consuming setter of a tuple struct
+#[derive(Getter)]
+pub struct Tuple(#[get(name = "value", with)] usize);
+
+// not a valid name
+#[derive(Getter)]
+pub struct Name {
+    #[get(with = "1name")]
+    f: usize,
+}
+
+// no setter is created on a field with nested getters
+pub struct Inner {
+    pub a: usize,
+}
+
+#[derive(Getter)]
+pub struct Nested {
+    #[get(nested = "a", nested_ty = "usize", with)]
+    inner: Inner,
+}
+
+fn main() {}
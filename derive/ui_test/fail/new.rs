@@ -0,0 +1,19 @@
+// New fails to be derived for enums, tuple structs and with an unknown field option
+use utils_lib_derive::New;
+
+#[derive(New)]
+enum Shape {
+    Point,
+    Circle { radius: f64 },
+}
+
+#[derive(New)]
+struct Tuple(u8, u8);
+
+#[derive(New)]
+struct S {
+    #[new(skip)]
+    field: u8,
+}
+
+fn main() {}
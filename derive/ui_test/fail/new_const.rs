@@ -0,0 +1,24 @@
+// The const constructor of New cannot call `Default::default` nor a validator
+use utils_lib_derive::New;
+
+#[derive(New)]
+#[new(const)]
+struct DefaultField {
+    name: &'static str,
+    #[new(default)]
+    retries: u8,
+}
+
+#[derive(New)]
+#[new(const, validate = "Self::check", error = "String")]
+struct Validated {
+    field: u8,
+}
+
+impl Validated {
+    fn check(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+fn main() {}
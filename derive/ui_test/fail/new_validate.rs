@@ -0,0 +1,41 @@
+// The validation options of New are given together, and `new` is not generated with
+// `fallible_only`
+use utils_lib_derive::New;
+
+#[derive(New)]
+#[new(validate = "Self::check")]
+struct MissingError {
+    field: u8,
+}
+
+#[derive(New)]
+#[new(error = "String")]
+struct MissingValidator {
+    field: u8,
+}
+
+#[derive(New)]
+#[new(fallible_only)]
+struct FallibleOnly {
+    field: u8,
+}
+
+#[derive(New)]
+#[new(validate = "Self::check", error = "String", fallible_only)]
+struct Checked {
+    field: u8,
+}
+
+impl Checked {
+    fn check(&self) -> Result<(), String> {
+        if self.field == 0 {
+            Err("the field is zero".to_owned())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let _checked = Checked::new(1);
+}
@@ -0,0 +1,35 @@
+// fail test for the options of the Sealed derive
+use utils_lib_derive::Sealed;
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub trait Sealed: private::Sealed {}
+
+impl<T: private::Sealed> Sealed for T {}
+
+#[derive(Sealed)]
+#[sealed(bound = "T: Copy")]
+struct S<T> {
+    t: T,
+}
+
+#[derive(Sealed)]
+#[sealed(bounds = "T: Copy")]
+struct UnknownOption<T> {
+    t: T,
+}
+
+#[derive(Sealed)]
+#[sealed(bound = "T Copy")]
+struct InvalidBound<T> {
+    t: T,
+}
+
+fn assert_sealed<T: Sealed>() {}
+
+fn main() {
+    assert_sealed::<S<u8>>();
+    assert_sealed::<S<String>>();
+}
@@ -0,0 +1,20 @@
+// Variants fail to be derived for enums with data-carrying variants and for structs
+use utils_lib_derive::Variants;
+
+#[derive(Variants)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+}
+
+#[derive(Variants)]
+struct S;
+
+#[derive(Variants)]
+#[variants(bound = "T: Copy")]
+enum Unit {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+// pass test for the option allow_empty with getter attributes added by cfg_attr.
+// The ui tests are not compiled with cfg(test) so the attributes behind cfg_attr(test, ...)
+// are removed like in a non test build.
+mod def {
+    use utils_lib_derive::Getter;
+
+    // no getter outside of the tests, no error
+    #[derive(Getter)]
+    #[getter(allow_empty)]
+    pub struct Conditional {
+        #[cfg_attr(test, get)]
+        f: usize,
+        #[cfg_attr(test, get(Pub, Copy))]
+        g: usize,
+    }
+
+    // the getters enabled by cfg_attr are generated
+    #[derive(Getter)]
+    #[cfg_attr(all(), getter(allow_empty))]
+    pub struct Enabled {
+        #[cfg_attr(all(), get(Pub))]
+        f: usize,
+        #[cfg_attr(test, get(Pub))]
+        g: usize,
+    }
+
+    impl Conditional {
+        pub const fn new() -> Self {
+            Self { f: 0, g: 1 }
+        }
+
+        pub const fn sum(&self) -> usize {
+            self.f + self.g
+        }
+    }
+
+    impl Enabled {
+        pub const fn new() -> Self {
+            Self { f: 2, g: 3 }
+        }
+
+        pub const fn g(&self) -> usize {
+            self.g
+        }
+    }
+}
+
+fn main() {
+    let conditional = def::Conditional::new();
+    assert_eq!(conditional.sum(), 1);
+
+    let enabled = def::Enabled::new();
+    assert_eq!(enabled.f(), &2);
+    // not a generated getter
+    assert_eq!(enabled.g(), 3);
+}
@@ -0,0 +1,63 @@
+// pass test for array fields, with const generics
+use utils_lib_derive::Getter;
+
+#[derive(Getter)]
+struct Buf<const N: usize> {
+    #[get]
+    data: [u8; N],
+    #[get(getter_ty = "copy", constant)]
+    copied: [u8; N],
+    #[get(getter_ty = "clone")]
+    cloned: [String; 2],
+}
+
+#[derive(Getter)]
+struct Hash {
+    #[get(getter_ty = "copy", constant)]
+    data: [u8; 32],
+    #[get(constant)]
+    by_ref: [u8; 32],
+}
+
+#[derive(Getter)]
+struct Generic<T: Copy, const N: usize> {
+    #[get(getter_ty = "copy")]
+    data: [T; N],
+    #[get_mut]
+    mutable: [[T; N]; 2],
+}
+
+const HASH: Hash = Hash {
+    data: [1; 32],
+    by_ref: [2; 32],
+};
+
+const fn const_ref<const N: usize>(buf: &Buf<N>) -> [u8; N] {
+    buf.copied()
+}
+
+const fn const_hash(hash: &Hash) -> &[u8; 32] {
+    hash.by_ref()
+}
+
+fn main() {
+    let buf = Buf {
+        data: [1_u8, 2, 3],
+        copied: [4, 5, 6],
+        cloned: [String::from("a"), String::from("b")],
+    };
+    assert_eq!(buf.data(), &[1, 2, 3]);
+    assert_eq!(const_ref(&buf), [4, 5, 6]);
+    assert_eq!(buf.cloned(), [String::from("a"), String::from("b")]);
+
+    assert_eq!(HASH.data(), [1; 32]);
+    assert_eq!(const_hash(&HASH), &[2; 32]);
+
+    let mut generic = Generic {
+        data: [1.5_f64; 4],
+        mutable: [[0_f64; 4]; 2],
+    };
+    assert_eq!(generic.data(), [1.5; 4]);
+    generic.mutable_mut()[1][2] = 1_f64;
+    assert_eq!(generic.mutable[1], [0_f64, 0_f64, 1_f64, 0_f64]);
+}
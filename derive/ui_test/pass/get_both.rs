@@ -0,0 +1,43 @@
+// pass test for the both option creating the immutable and mutable getter
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    pub struct S {
+        #[get(both, Pub)]
+        f: usize,
+        #[get(add_mut, public, name = "field")]
+        g: usize,
+        #[get(both, Pub)]
+        #[get_mut(name = "h_mutable", public)]
+        h: usize,
+    }
+
+    #[derive(Getter)]
+    pub struct Tuple(#[get(add_mut = "true", name = "value", public)] pub u32);
+
+    impl S {
+        pub const fn new() -> Self {
+            Self { f: 0, g: 1, h: 2 }
+        }
+    }
+}
+
+fn main() {
+    let mut s = def::S::new();
+    assert_eq!(s.f(), &0);
+    *s.f_mut() = 3;
+    assert_eq!(s.f(), &3);
+
+    assert_eq!(s.field(), &1);
+    *s.field_mut() = 4;
+    assert_eq!(s.field(), &4);
+
+    assert_eq!(s.h(), &2);
+    *s.h_mutable() = 5;
+    assert_eq!(s.h(), &5);
+
+    let mut t = def::Tuple(0);
+    *t.value_mut() += 1;
+    assert_eq!(t.value(), &1);
+}
@@ -0,0 +1,51 @@
+// pass test for the forwarding of the cfg attributes of the field on the getters
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    pub struct S {
+        #[get(Pub)]
+        f: usize,
+        #[cfg(any())]
+        #[get(both, Pub)]
+        removed: usize,
+        #[cfg(all())]
+        #[get(both, Pub)]
+        kept: usize,
+    }
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct T {
+        #[cfg(any())]
+        #[get(Pub)]
+        removed: usize,
+        #[get(Pub)]
+        g: usize,
+    }
+
+    impl S {
+        pub const fn new() -> Self {
+            Self { f: 0, kept: 1 }
+        }
+    }
+
+    impl T {
+        pub const fn new() -> Self {
+            Self { g: 2 }
+        }
+    }
+}
+
+use def::accessors::TGetters;
+
+fn main() {
+    let mut s = def::S::new();
+    assert_eq!(s.f(), &0);
+    assert_eq!(s.kept(), &1);
+    *s.kept_mut() = 3;
+    assert_eq!(s.kept(), &3);
+
+    let t = def::T::new();
+    assert_eq!(t.g(), &2);
+}
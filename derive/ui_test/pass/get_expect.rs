@@ -0,0 +1,69 @@
+// pass test for the expect option returning a Result for late-initialized Option fields
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum BuildError {
+        NameMissing,
+        SizeMissing,
+        Missing(&'static str),
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct StructError {
+        pub field: &'static str,
+    }
+
+    #[derive(Getter, Default)]
+    pub struct Builder {
+        #[get(Pub, expect = "BuildError::NameMissing")]
+        name: Option<String>,
+        #[get(Pub, Copy, expect = "BuildError::SizeMissing")]
+        size: Option<usize>,
+        #[get(Pub, Clone, name = "tags_cloned", expect = "BuildError::Missing(\"tags\")")]
+        tags: Option<Vec<String>>,
+        #[get(Pub, expect = "StructError { field: \"id\" }")]
+        id: core::option::Option<u64>,
+    }
+
+    impl Builder {
+        pub fn set(&mut self) {
+            self.name = Some("name".to_owned());
+            self.size = Some(2);
+            self.tags = Some(vec!["tag".to_owned()]);
+            self.id = Some(3);
+        }
+    }
+
+    // the getter can be const
+    #[derive(Getter)]
+    pub struct Size(
+        #[get(Pub, Copy, Const, name = "size", expect = "BuildError::SizeMissing")] pub Option<usize>,
+    );
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct Tuple(#[get(name = "value", expect = "BuildError::Missing(\"value\")")] pub Option<u8>);
+}
+
+use def::{accessors::TupleGetters, BuildError, Builder, Size, StructError, Tuple};
+
+const SIZE: Result<usize, BuildError> = Size(None).size();
+const SET_SIZE: Result<usize, BuildError> = Size(Some(2)).size();
+
+fn main() {
+    let mut builder = Builder::default();
+    assert_eq!(builder.name(), Err(BuildError::NameMissing));
+    assert_eq!(builder.size(), SIZE);
+    assert_eq!(builder.tags_cloned(), Err(BuildError::Missing("tags")));
+    assert_eq!(builder.id(), Err(StructError { field: "id" }));
+
+    builder.set();
+    assert_eq!(builder.name().map(String::as_str), Ok("name"));
+    assert_eq!(builder.size(), SET_SIZE);
+    assert_eq!(builder.tags_cloned(), Ok(vec!["tag".to_owned()]));
+    assert_eq!(builder.id(), Ok(&3));
+
+    assert_eq!(Tuple(Some(1)).value(), Ok(&1));
+    assert_eq!(Tuple(None).value(), Err(BuildError::Missing("value")));
+}
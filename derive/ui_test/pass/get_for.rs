@@ -0,0 +1,100 @@
+// pass test for the getters generated on a wrapper type with the options for and via
+mod def {
+    use std::ops::Deref;
+
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    #[getter(for = "Handle", via = "0")]
+    pub struct Inner {
+        #[get(Pub)]
+        #[get_mut(Pub)]
+        name: String,
+        #[get(Pub, Copy, into_inner)]
+        count: usize,
+    }
+
+    pub struct Handle(pub Inner);
+
+    #[derive(Getter)]
+    #[getter(for = "Named", via = "inner")]
+    pub struct Data(#[get(Pub, name = "value")] pub u8);
+
+    pub struct Named {
+        pub inner: Data,
+    }
+
+    #[derive(Getter)]
+    #[getter(for = "Facade", via = "state.0")]
+    pub struct State {
+        #[get(Pub, Copy)]
+        flag: bool,
+    }
+
+    pub struct Facade {
+        pub state: (State,),
+    }
+
+    // without via the field is accessed through Deref
+    #[derive(Getter)]
+    #[getter(for(Smart), module = "smart_accessors")]
+    pub struct Target {
+        #[get]
+        id: u32,
+    }
+
+    pub struct Smart(pub Target);
+
+    impl Deref for Smart {
+        type Target = Target;
+
+        fn deref(&self) -> &Target {
+            &self.0
+        }
+    }
+
+    impl Inner {
+        pub fn new() -> Self {
+            Self {
+                name: "name".to_owned(),
+                count: 2,
+            }
+        }
+    }
+
+    impl State {
+        pub fn new() -> Self {
+            Self { flag: true }
+        }
+    }
+
+    impl Target {
+        pub fn new() -> Self {
+            Self { id: 4 }
+        }
+    }
+}
+
+use def::smart_accessors::TargetGetters;
+
+fn main() {
+    let mut handle = def::Handle(def::Inner::new());
+    assert_eq!(handle.name(), "name");
+    handle.name_mut().push('s');
+    assert_eq!(handle.name(), "names");
+    assert_eq!(handle.count(), 2);
+    assert_eq!(handle.into_count(), 2);
+
+    let named = def::Named {
+        inner: def::Data(3),
+    };
+    assert_eq!(named.value(), &3);
+
+    let facade = def::Facade {
+        state: (def::State::new(),),
+    };
+    assert!(facade.flag());
+
+    let smart = def::Smart(def::Target::new());
+    assert_eq!(smart.id(), &4);
+}
@@ -0,0 +1,32 @@
+// pass test for the option hidden adding #[doc(hidden)] on the getters, with any visibility
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter, Default)]
+    pub struct S {
+        #[get(Pub, hidden)]
+        a: u8,
+        #[get(hidden = "true", Crate, Const, both)]
+        b: u8,
+        #[get(Pub, hidden = "false")]
+        c: u8,
+        #[get(Pub)]
+        #[get_mut(Pub, hidden)]
+        d: String,
+        #[get(hidden, Pub, Copy, into_inner, with)]
+        e: u8,
+    }
+}
+
+fn main() {
+    let mut s = def::S::default();
+    assert_eq!(s.a(), &0);
+    *s.b_mut() = 1;
+    assert_eq!(s.b(), &1);
+    assert_eq!(s.c(), &0);
+    s.d_mut().push('d');
+    assert_eq!(s.d(), "d");
+    let s = s.with_e(2);
+    assert_eq!(s.e(), 2);
+    assert_eq!(s.into_e(), 2);
+}
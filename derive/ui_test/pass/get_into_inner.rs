@@ -0,0 +1,50 @@
+// pass test for the into_inner option creating a consuming getter alongside the getter
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    pub struct S {
+        #[get(Pub, into_inner)]
+        f: String,
+        #[get(public, name = "field", into_inner = "take_field")]
+        g: Vec<usize>,
+        #[get(Pub, into_inner)]
+        #[get_mut(Pub)]
+        h: usize,
+    }
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct Tuple(#[get(name = "value", into_inner = "into_value")] pub String);
+
+    impl S {
+        pub fn new() -> Self {
+            Self {
+                f: "f".to_owned(),
+                g: vec![1],
+                h: 2,
+            }
+        }
+    }
+}
+
+use def::accessors::TupleGetters;
+
+fn main() {
+    let s = def::S::new();
+    assert_eq!(s.f(), "f");
+    assert_eq!(s.into_f(), "f".to_owned());
+
+    let s = def::S::new();
+    assert_eq!(s.field(), &vec![1]);
+    assert_eq!(s.take_field(), vec![1]);
+
+    let mut s = def::S::new();
+    *s.h_mut() = 3;
+    assert_eq!(s.h(), &3);
+    assert_eq!(s.into_h(), 3);
+
+    let t = def::Tuple("t".to_owned());
+    assert_eq!(t.value(), "t");
+    assert_eq!(t.into_value(), "t".to_owned());
+}
@@ -0,0 +1,53 @@
+// pass test for the long attribute names getter_get and getter_get_mut, mixed with the short ones
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter, Default)]
+    pub struct S {
+        #[getter_get]
+        a: u8,
+        #[getter_get(Pub, Const)]
+        b: u8,
+        #[getter_get(Pub, name = "field_c", add_mut)]
+        c: String,
+        #[getter_get = "field_d"]
+        d: Vec<u8>,
+        #[getter_get_mut(Pub)]
+        e: u8,
+        #[get(Pub)]
+        #[getter_get_mut(Pub)]
+        f: u8,
+        #[getter_get(Pub, Const)]
+        #[get_mut(Pub)]
+        g: u8,
+        #[get(Pub)]
+        h: u8,
+    }
+
+    impl S {
+        pub fn private(&self) -> (&u8, &Vec<u8>) {
+            (self.a(), self.field_d())
+        }
+    }
+
+    #[derive(Getter, Default)]
+    pub struct Tuple(#[getter_get(Pub, name = "value")] u8, #[get(Pub, name = "other")] u8);
+}
+
+fn main() {
+    let mut s = def::S::default();
+    assert_eq!(s.private(), (&0, &Vec::new()));
+    assert_eq!(s.b(), &0);
+    s.field_c_mut().push('c');
+    assert_eq!(s.field_c(), "c");
+    *s.e_mut() = 1;
+    *s.f_mut() = 2;
+    *s.g_mut() = 3;
+    assert_eq!(s.f(), &2);
+    assert_eq!(s.g(), &3);
+    assert_eq!(s.h(), &0);
+
+    let t = def::Tuple::default();
+    assert_eq!(t.value(), &0);
+    assert_eq!(t.other(), &0);
+}
@@ -0,0 +1,46 @@
+// pass test for the getter trait generated in a module
+use utils_lib_derive::Getter;
+
+#[derive(Getter)]
+#[getter(module = "accessors")]
+pub struct S {
+    #[get]
+    #[get_mut]
+    f: usize,
+    #[get(name = "string", getter_ty = "clone")]
+    s: String,
+}
+
+#[derive(Getter, Clone, Copy)]
+#[getter(module(generic_accessors), sealed)]
+struct Generic<T: Copy> {
+    #[get(by_copy, self_ty = "value")]
+    t: T,
+    #[get_mut(name = "count")]
+    c: u32,
+}
+
+mod other {
+    use super::accessors::SGetters;
+    use super::generic_accessors::GenericGetters;
+
+    pub fn call() {
+        let mut s = super::S {
+            f: 0,
+            s: "s".to_owned(),
+        };
+        assert_eq!(s.f(), &0);
+        *s.f_mut() = 1;
+        assert_eq!(s.f(), &1);
+        assert_eq!(s.string(), "s".to_owned());
+
+        let mut g = super::Generic { t: 'c', c: 0 };
+        assert_eq!(g.t(), 'c');
+        *g.count() += 1;
+        assert_eq!(g.c, 1);
+    }
+}
+
+fn main() {
+    other::call();
+}
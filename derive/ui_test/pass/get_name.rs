@@ -11,10 +11,22 @@ struct S {
     f: usize,
 }
 
+// shorthand for the name option
+#[derive(Getter)]
+struct Shorthand(
+    #[get = "value"]
+    #[get_mut = "value_mut"]
+    usize,
+);
+
 fn main() {
     let w = Wrapper(0);
     assert_eq!(w.f(), &0);
     let mut s = S { f: 0 };
     assert_eq!(s.field(), &0);
     assert_eq!(s.field_mut(), &mut 0);
+    let mut shorthand = Shorthand(1);
+    assert_eq!(shorthand.value(), &1);
+    *shorthand.value_mut() = 2;
+    assert_eq!(shorthand.value(), &2);
 }
@@ -0,0 +1,76 @@
+// pass test for the nested option creating getters on the fields of a field
+mod def {
+    use std::time::Duration;
+
+    use utils_lib_derive::Getter;
+
+    pub struct Config {
+        pub timeout: Duration,
+        pub retries: u8,
+        pub name: String,
+    }
+
+    #[derive(Getter)]
+    pub struct Client {
+        #[get(Pub, nested = "timeout, retries", nested_ty = "Duration, u8", by_value)]
+        config: Config,
+        #[get(Pub)]
+        #[get_mut(Pub)]
+        id: usize,
+    }
+
+    #[derive(Getter)]
+    pub struct Server {
+        #[get(Pub, nested(name), nested_type = "String")]
+        config: Config,
+    }
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct Tuple(#[get(nested = "retries", nested_ty = "u8", by_value)] pub Config);
+
+    impl Config {
+        pub fn new() -> Self {
+            Self {
+                timeout: Duration::from_secs(5),
+                retries: 3,
+                name: "config".to_owned(),
+            }
+        }
+    }
+
+    impl Client {
+        pub fn new() -> Self {
+            Self {
+                config: Config::new(),
+                id: 1,
+            }
+        }
+    }
+
+    impl Server {
+        pub fn new() -> Self {
+            Self {
+                config: Config::new(),
+            }
+        }
+    }
+}
+
+use std::time::Duration;
+
+use def::accessors::TupleGetters;
+
+fn main() {
+    let mut client = def::Client::new();
+    assert_eq!(client.timeout(), Duration::from_secs(5));
+    assert_eq!(client.retries(), 3);
+    *client.id_mut() = 2;
+    assert_eq!(client.id(), &2);
+
+    let server = def::Server::new();
+    assert_eq!(server.name(), "config");
+
+    let tuple = def::Tuple(def::Config::new());
+    assert_eq!(tuple.retries(), 3);
+}
@@ -0,0 +1,50 @@
+// pass test for the newtype option creating the getter, the consuming getter and AsRef
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    pub struct Meters(#[get(Pub, Copy, newtype)] pub f64);
+
+    #[derive(Getter)]
+    pub struct Renamed(#[get(Pub, newtype, name = "value", into_inner = "into_value")] pub String);
+
+    #[derive(Getter)]
+    #[getter(newtype)]
+    pub struct Named {
+        #[get(Pub)]
+        #[get_mut(Pub)]
+        pub name: String,
+    }
+}
+
+use def::{Meters, Named, Renamed};
+use utils_lib_derive::Getter;
+
+// the getters are private by default
+#[derive(Getter)]
+#[getter(newtype)]
+struct Wrapper<T>(Vec<T>);
+
+fn main() {
+    let meters = Meters(1.5);
+    assert_eq!(meters.get(), 1.5);
+    assert_eq!(meters.as_ref(), &1.5);
+    assert_eq!(meters.into_inner(), 1.5);
+
+    let renamed = Renamed("r".to_owned());
+    assert_eq!(renamed.value(), "r");
+    assert_eq!(AsRef::<String>::as_ref(&renamed), "r");
+    assert_eq!(renamed.into_value(), "r".to_owned());
+
+    let wrapper = Wrapper(vec![1_u8, 2]);
+    assert_eq!(wrapper.get(), &[1, 2]);
+    assert_eq!(wrapper.as_ref(), &vec![1, 2]);
+    assert_eq!(wrapper.into_inner(), vec![1, 2]);
+
+    let mut named = Named {
+        name: "n".to_owned(),
+    };
+    named.name_mut().push('!');
+    assert_eq!(named.get(), "n!");
+    assert_eq!(named.into_inner(), "n!".to_owned());
+}
@@ -0,0 +1,42 @@
+// pass test for copy getters on a packed struct
+use utils_lib_derive::Getter;
+
+#[derive(Getter)]
+#[repr(packed)]
+struct Packed {
+    #[get(getter_ty = "copy")]
+    tag: u8,
+    #[get(getter_ty = "copy", constant)]
+    length: u64,
+    #[get(getter_ty = "copy", unwrap)]
+    checksum: Option<u32>,
+    #[get(getter_ty = "copy", into_inner)]
+    data: [u8; 3],
+}
+
+#[derive(Getter)]
+#[repr(C, packed(2))]
+struct Tuple(#[get(getter_ty = "copy", name = "value")] u8, u64);
+
+const fn length(packed: &Packed) -> u64 {
+    packed.length()
+}
+
+fn main() {
+    let packed = Packed {
+        tag: 1,
+        length: 2,
+        checksum: Some(3),
+        data: [4, 5, 6],
+    };
+    assert_eq!(packed.tag(), 1);
+    assert_eq!(length(&packed), 2);
+    assert_eq!(packed.checksum(), 3);
+    assert_eq!(packed.data(), [4, 5, 6]);
+    assert_eq!(packed.into_data(), [4, 5, 6]);
+
+    let tuple = Tuple(7, 8);
+    assert_eq!(tuple.value(), 7);
+    let Tuple(_, second) = tuple;
+    assert_eq!(second, 8);
+}
@@ -0,0 +1,43 @@
+// pass test for the generated code in a crate denying the pedantic and nursery lints
+#![deny(clippy::pedantic, clippy::nursery)]
+#![deny(unused_attributes)]
+
+use utils_lib_derive::Getter;
+
+#[derive(Getter, Clone, Copy)]
+pub struct Small {
+    #[get(Pub)]
+    #[get_mut(Pub)]
+    f: u8,
+    #[get(Pub, by_value, self_ty = "value")]
+    g: u16,
+}
+
+#[derive(Getter)]
+#[getter(module = "accessors")]
+pub struct Module {
+    #[get]
+    f: u8,
+}
+
+#[derive(Getter)]
+#[getter(no_lint_allow)]
+pub struct NoAllow {
+    #[get(Pub, Const)]
+    f: u8,
+}
+
+use accessors::ModuleGetters;
+
+fn main() {
+    let mut small = Small { f: 0, g: 1 };
+    *small.f_mut() = 2;
+    assert_eq!(small.f(), &2);
+    assert_eq!(small.g(), 1);
+
+    let module = Module { f: 3 };
+    assert_eq!(module.f(), &3);
+
+    let no_allow = NoAllow { f: 4 };
+    assert_eq!(no_allow.f(), &4);
+}
@@ -0,0 +1,51 @@
+// pass test for the prefix option of the default name, combined with Const, the visibility
+// and the name
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter, Default)]
+    pub struct S {
+        #[get(Pub, Const, prefix = "get_")]
+        size: usize,
+        #[get(prefix(get_), Crate, both)]
+        r#type: u8,
+        #[get(Pub, prefix = "get_", name = "len")]
+        length: usize,
+        #[get(Pub)]
+        #[get_mut(Pub, prefix = "get_")]
+        data: Vec<u8>,
+    }
+
+    impl S {
+        // the getter is constant
+        pub const fn size_in_const(&self) -> &usize {
+            self.get_size()
+        }
+    }
+
+    #[derive(Getter, Default)]
+    pub struct Tuple(
+        #[get(Pub, Const, prefix = "get_", name = "value")] u8,
+        #[get(Pub, name = "other", prefix = "get_")] u8,
+    );
+
+    #[derive(Getter, Default)]
+    #[getter(tuple_names = "field")]
+    pub struct Named(#[get(Pub, prefix = "get_")] u8);
+}
+
+fn main() {
+    let mut s = def::S::default();
+    assert_eq!(s.get_size(), &0);
+    assert_eq!(s.size_in_const(), &0);
+    *s.get_type_mut() = 1;
+    assert_eq!(s.get_type(), &1);
+    assert_eq!(s.len(), &0);
+    s.get_data_mut().push(2);
+    assert_eq!(s.data(), &[2]);
+
+    let t = def::Tuple::default();
+    assert_eq!(t.value(), &0);
+    assert_eq!(t.other(), &0);
+    assert_eq!(def::Named::default().get_field0(), &0);
+}
@@ -0,0 +1,109 @@
+// pass test for the getters of fields whose type uses `Self`, like a linked list, or a type
+// parameter of the struct, which is not linked in the documentation of the getter
+#![deny(rustdoc::broken_intra_doc_links)]
+
+mod def {
+    use std::ops::Neg;
+
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    pub struct Node {
+        #[get(Pub, Const)]
+        #[get_mut(Pub)]
+        value: u8,
+        #[get(Pub, Const)]
+        #[get_mut(Pub)]
+        next: Option<Box<Self>>,
+        #[get(Pub, Const, name = "children")]
+        children: Vec<Self>,
+    }
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct List {
+        #[get(Pub)]
+        next: Option<Box<Self>>,
+        #[get(Pub, Copy)]
+        value: u8,
+    }
+
+    #[derive(Getter)]
+    #[getter(trait_def = "HasNext")]
+    pub struct Tree {
+        #[get(Pub)]
+        next: Vec<Self>,
+    }
+
+    #[derive(Getter)]
+    pub struct Generic<T: Neg> {
+        #[get(Pub)]
+        #[get_mut(Pub)]
+        value: T,
+        #[get(Pub, into_inner, with)]
+        negated: T::Output,
+        #[get(Pub)]
+        next: Option<Box<Self>>,
+    }
+
+    impl<T: Neg> Generic<T> {
+        pub const fn new(value: T, negated: T::Output) -> Self {
+            Self {
+                value,
+                negated,
+                next: None,
+            }
+        }
+    }
+
+    impl Node {
+        pub const fn new(value: u8, next: Option<Box<Self>>) -> Self {
+            Self {
+                value,
+                next,
+                children: Vec::new(),
+            }
+        }
+    }
+
+    impl List {
+        pub const fn new(value: u8, next: Option<Box<Self>>) -> Self {
+            Self { value, next }
+        }
+    }
+
+    impl Tree {
+        pub const fn new(next: Vec<Self>) -> Self {
+            Self { next }
+        }
+    }
+}
+
+use def::{accessors::ListGetters, HasNext};
+
+const fn first_value(node: &def::Node) -> u8 {
+    *node.value()
+}
+
+fn main() {
+    let mut node = def::Node::new(1, Some(Box::new(def::Node::new(2, None))));
+    assert_eq!(first_value(&node), 1);
+    assert_eq!(node.next().as_ref().map(|next| *next.value()), Some(2));
+    *node.next_mut() = None;
+    assert!(node.next().is_none());
+    assert!(node.children().is_empty());
+
+    let list = def::List::new(3, Some(Box::new(def::List::new(4, None))));
+    assert_eq!(list.value(), 3);
+    assert_eq!(list.next().as_ref().map(|next| next.value()), Some(4));
+
+    let tree = def::Tree::new(vec![def::Tree::new(Vec::new())]);
+    assert_eq!(HasNext::next(&tree).len(), 1);
+    assert!(HasNext::next(&tree.next()[0]).is_empty());
+
+    let mut generic = def::Generic::new(5_i8, -5_i8);
+    *generic.value_mut() = 6;
+    assert_eq!(generic.value(), &6);
+    assert!(generic.next().is_none());
+    assert_eq!(generic.with_negated(-6).into_negated(), -6);
+}
@@ -0,0 +1,94 @@
+// pass test for the container option rename_all converting the default names of the getters
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    #[getter(rename_all = "camelCase")]
+    pub struct Camel {
+        #[get(Pub)]
+        #[get_mut(Pub)]
+        my_field: usize,
+        #[get(Pub, name = "explicit_name")]
+        #[get_mut(Pub, name = "explicit_mut")]
+        other_field: usize,
+        #[get(Pub, into_inner)]
+        r#type: String,
+    }
+
+    #[derive(Getter)]
+    #[getter(rename_all = "PascalCase", module = "accessors")]
+    pub struct Pascal {
+        #[get(Pub, Copy)]
+        my_field: usize,
+        #[get(Pub, both)]
+        http_code2: u16,
+    }
+
+    #[derive(Getter)]
+    #[getter(rename_all = "SCREAMING_SNAKE_CASE", trait_def = "HasValue")]
+    pub struct Screaming {
+        #[get(Pub, Copy)]
+        max_value: usize,
+    }
+
+    #[derive(Getter)]
+    #[getter(rename_all = "snake_case")]
+    pub struct Snake {
+        #[get(Pub, Copy)]
+        my_field: usize,
+    }
+
+    impl Camel {
+        pub fn new() -> Self {
+            Self {
+                my_field: 1,
+                other_field: 2,
+                r#type: "t".to_owned(),
+            }
+        }
+    }
+
+    impl Pascal {
+        pub const fn new() -> Self {
+            Self {
+                my_field: 3,
+                http_code2: 200,
+            }
+        }
+    }
+
+    impl Screaming {
+        pub const fn new() -> Self {
+            Self { max_value: 4 }
+        }
+    }
+
+    impl Snake {
+        pub const fn new() -> Self {
+            Self { my_field: 5 }
+        }
+    }
+}
+
+use def::{accessors::PascalGetters, HasValue};
+
+fn main() {
+    let mut camel = def::Camel::new();
+    *camel.myFieldMut() += 1;
+    assert_eq!(camel.myField(), &2);
+    *camel.explicit_mut() += 1;
+    assert_eq!(camel.explicit_name(), &3);
+    assert_eq!(camel.r#type(), "t");
+    assert_eq!(camel.intoType(), "t".to_owned());
+
+    let mut pascal = def::Pascal::new();
+    assert_eq!(pascal.MyField(), 3);
+    *pascal.HttpCode2Mut() += 1;
+    assert_eq!(pascal.HttpCode2(), &201);
+
+    let screaming = def::Screaming::new();
+    assert_eq!(screaming.MAX_VALUE(), 4);
+    assert_eq!(HasValue::MAX_VALUE(&screaming), 4);
+
+    assert_eq!(def::Snake::new().my_field(), 5);
+}
@@ -0,0 +1,70 @@
+// test that the generated getters do not depend on the prelude of the user crate
+#![allow(dead_code)] // reason = "the shadowing items are never used"
+
+// a local module `core` does not shadow the `::core` crate
+mod core {}
+
+mod shadow_struct {
+    use utils_lib_derive::Getter;
+
+    struct Clone;
+
+    #[derive(Getter)]
+    pub struct ShadowStruct {
+        #[get(Clone, public)]
+        name: String,
+        #[get(public)]
+        #[get_mut(public)]
+        value: usize,
+    }
+
+    impl ShadowStruct {
+        pub fn new(name: String, value: usize) -> Self {
+            Self { name, value }
+        }
+    }
+}
+
+mod shadow_trait {
+    use utils_lib_derive::Getter;
+
+    // a trait with the same name and a method `clone` for every type, so a bare
+    // `.clone()` call would be ambiguous
+    pub trait Clone {
+        fn clone(&self) -> u8 {
+            0
+        }
+    }
+
+    impl<T> Clone for T {}
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct ShadowTrait {
+        #[get(Clone)]
+        name: String,
+        #[get(Copy, into_inner)]
+        value: usize,
+    }
+
+    impl ShadowTrait {
+        pub fn new(name: String, value: usize) -> Self {
+            Self { name, value }
+        }
+    }
+}
+
+use shadow_struct::ShadowStruct;
+use shadow_trait::{accessors::ShadowTraitGetters, ShadowTrait};
+
+fn main() {
+    let mut s = ShadowStruct::new("name".to_owned(), 1_usize);
+    assert_eq!(s.name(), "name");
+    *s.value_mut() = 2_usize;
+    assert_eq!(s.value(), &2_usize);
+
+    let t = ShadowTrait::new("name".to_owned(), 3_usize);
+    assert_eq!(t.name(), "name");
+    assert_eq!(t.value(), 3_usize);
+    assert_eq!(t.into_value(), 3_usize);
+}
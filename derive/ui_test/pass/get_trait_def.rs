@@ -0,0 +1,102 @@
+// pass test for the object safe trait defined with the option trait_def
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    #[getter(trait_def = "HasPosition")]
+    pub struct Point {
+        #[get(Pub)]
+        #[get_mut(Pub)]
+        x: f64,
+        #[get(Pub, Const, Copy)]
+        y: f64,
+        // only mutable getter, not in the trait
+        #[get_mut(Pub)]
+        label: String,
+    }
+
+    #[derive(Getter)]
+    pub struct Body {
+        #[get(Pub, name = "x")]
+        position_x: f64,
+        #[get(Pub, name = "y", Copy)]
+        position_y: f64,
+        #[get(Pub, Copy)]
+        mass: f64,
+    }
+
+    #[derive(Getter)]
+    #[getter(trait_def(HasValue))]
+    pub struct Wrapper<T: Clone> {
+        #[get(Pub)]
+        value: T,
+        #[get(Pub, Clone)]
+        backup: T,
+    }
+
+    impl Point {
+        pub fn new(x: f64, y: f64) -> Self {
+            Self {
+                x,
+                y,
+                label: String::new(),
+            }
+        }
+    }
+
+    impl Body {
+        pub fn new(x: f64, y: f64) -> Self {
+            Self {
+                position_x: x,
+                position_y: y,
+                mass: 1_f64,
+            }
+        }
+
+    }
+
+    impl<T: Clone> Wrapper<T> {
+        pub fn new(value: T) -> Self {
+            Self {
+                backup: value.clone(),
+                value,
+            }
+        }
+    }
+
+    // the trait can be implemented for other types, here with the getters of Body
+    impl HasPosition for Body {
+        fn x(&self) -> &f64 {
+            Body::x(self)
+        }
+
+        fn y(&self) -> f64 {
+            Body::y(self)
+        }
+    }
+}
+
+use def::{Body, HasPosition, HasValue, Point, Wrapper};
+
+fn main() {
+    let mut point = Point::new(1_f64, 2_f64);
+    *point.x_mut() = 3_f64;
+    point.label_mut().push('p');
+    assert_eq!(point.y(), 2_f64);
+
+    let body = Body::new(4_f64, 5_f64);
+    assert_eq!(body.mass(), 1_f64);
+
+    let positions: Vec<Box<dyn HasPosition>> = vec![Box::new(point), Box::new(body)];
+    let xs = positions.iter().map(|p| *p.x()).collect::<Vec<_>>();
+    let ys = positions.iter().map(|p| p.y()).collect::<Vec<_>>();
+    assert_eq!(xs, vec![3_f64, 4_f64]);
+    assert_eq!(ys, vec![2_f64, 5_f64]);
+
+    let values: Vec<Box<dyn HasValue<String>>> = vec![
+        Box::new(Wrapper::new("a".to_owned())),
+        Box::new(Wrapper::new("b".to_owned())),
+    ];
+    assert_eq!(values[1].value(), "b");
+    assert_eq!(values[0].backup(), "a");
+}
@@ -0,0 +1,73 @@
+// pass test for the try_into option converting the field with TryInto
+mod def {
+    use std::num::TryFromIntError;
+
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    pub struct File {
+        #[get(Pub, try_into = "u32", getter_ty = "copy")]
+        size: u64,
+        #[get(Pub, try_into = "i8", Copy, name = "small_offset")]
+        #[get_mut(Pub)]
+        offset: i64,
+        #[get(Pub, try_into = "Box<str>", Clone)]
+        name: String,
+    }
+
+    impl File {
+        pub fn new(size: u64, offset: i64, name: &str) -> Self {
+            Self {
+                size,
+                offset,
+                name: name.to_owned(),
+            }
+        }
+    }
+
+    #[derive(Getter, Clone, Copy)]
+    pub struct Index {
+        #[get(Pub, try_into = "usize", Copy, self_ty = "value", into_inner)]
+        value: u128,
+    }
+
+    impl Index {
+        pub const fn new(value: u128) -> Self {
+            Self { value }
+        }
+    }
+
+    #[derive(Getter)]
+    #[getter(module = "accessors")]
+    pub struct Tuple(#[get(name = "value", try_into = "u8", Copy)] pub u16);
+
+    // the error type is the one of the conversion
+    pub fn error(file: &File) -> Option<TryFromIntError> {
+        file.size().err()
+    }
+}
+
+use def::{accessors::TupleGetters, File, Index, Tuple};
+
+fn main() {
+    let mut file = File::new(42, -3, "file");
+    assert_eq!(file.size(), Ok(42_u32));
+    assert_eq!(file.small_offset(), Ok(-3_i8));
+    assert_eq!(file.name().as_deref(), Ok("file"));
+    assert!(def::error(&file).is_none());
+
+    // the error path at runtime
+    *file.offset_mut() = 1_000;
+    assert!(file.small_offset().is_err());
+    let file = File::new(u64::from(u32::MAX) + 1, 0, "");
+    assert!(file.size().is_err());
+    assert!(def::error(&file).is_some());
+
+    let index = Index::new(7);
+    assert_eq!(index.value(), Ok(7_usize));
+    assert_eq!(index.into_value(), 7_u128);
+    assert!(Index::new(u128::MAX).value().is_err());
+
+    assert_eq!(Tuple(255).value(), Ok(255_u8));
+    assert!(Tuple(256).value().is_err());
+}
@@ -0,0 +1,41 @@
+// pass test for the container option tuple_names giving default names to the getters of the
+// fields of a tuple struct
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter)]
+    #[getter(tuple_names)]
+    pub struct Triple(
+        #[get(Pub)]
+        #[get_mut(Pub)]
+        pub usize,
+        #[get(Pub, name = "explicit")] pub usize,
+        #[get(Pub, Copy, into_inner)] pub u8,
+    );
+
+    #[derive(Getter)]
+    #[getter(tuple_names = "item_", rename_all = "camelCase")]
+    pub struct Pair(#[get(Pub, both)] pub usize, #[get(Pub, Copy)] pub usize);
+
+    #[derive(Getter)]
+    #[getter(tuple_names = "_", module = "accessors")]
+    pub struct Single(#[get(Pub)] pub String);
+}
+
+use def::accessors::SingleGetters;
+
+fn main() {
+    let mut triple = def::Triple(1, 2, 3);
+    *triple.field_0_mut() += 1;
+    assert_eq!(triple.field_0(), &2);
+    assert_eq!(triple.explicit(), &2);
+    assert_eq!(triple.field_2(), 3);
+    assert_eq!(triple.into_field_2(), 3);
+
+    let mut pair = def::Pair(4, 5);
+    *pair.item0Mut() += 1;
+    assert_eq!(pair.item0(), &5);
+    assert_eq!(pair.item1(), 5);
+
+    assert_eq!(def::Single("s".to_owned())._0(), "s");
+}
@@ -0,0 +1,111 @@
+// pass test for the getters on `()` fields, with one field per legal combination of the getter
+// type, the self type and the constant type, in a crate denying the warnings and the pedantic
+// and nursery lints
+#![deny(warnings)]
+#![deny(clippy::pedantic, clippy::nursery)]
+
+use utils_lib_derive::Getter;
+
+/// Create the struct `Unit` with one `()` field per attribute, and the functions calling every
+/// getter, the constant ones also in a const fn.
+macro_rules! unit_matrix {
+    (
+        non_const { $($field:ident: #[get($($option:tt)*)]),* $(,)? }
+        constant { $($const_field:ident: #[get($($const_option:tt)*)]),* $(,)? }
+    ) => {
+        #[derive(Getter, Clone, Copy, Default)]
+        pub struct Unit {
+            $(
+                #[get(Pub, $($option)*)]
+                $field: (),
+            )*
+            $(
+                #[get(Pub, Const, $($const_option)*)]
+                $const_field: (),
+            )*
+        }
+
+        fn call_getters(unit: Unit) {
+            $(
+                let _ = unit.$field();
+            )*
+            call_const_getters(unit);
+        }
+
+        const fn call_const_getters(unit: Unit) {
+            $(
+                let _ = unit.$const_field();
+            )*
+        }
+    };
+}
+
+// by clone and const is rejected, like by reference with self by value
+unit_matrix! {
+    non_const {
+        by_ref: #[get(by_ref)],
+        by_ref_both: #[get(by_ref, both)],
+        by_ref_into_inner: #[get(by_ref, into_inner)],
+        by_copy: #[get(copy)],
+        by_copy_both: #[get(copy, both)],
+        by_copy_into_inner: #[get(copy, into_inner)],
+        by_copy_value: #[get(copy, self_ty = "value")],
+        by_copy_value_into_inner: #[get(copy, self_ty = "value", into_inner)],
+        by_clone: #[get(clone)],
+        by_clone_both: #[get(clone, both)],
+        by_clone_into_inner: #[get(clone, into_inner)],
+        by_clone_value: #[get(clone, self_ty = "value")],
+        by_clone_value_into_inner: #[get(clone, self_ty = "value", into_inner)],
+    }
+    constant {
+        const_by_ref: #[get(by_ref)],
+        const_by_ref_both: #[get(by_ref, both)],
+        const_by_ref_into_inner: #[get(by_ref, into_inner)],
+        const_by_copy: #[get(copy)],
+        const_by_copy_both: #[get(copy, both)],
+        const_by_copy_into_inner: #[get(copy, into_inner)],
+        const_by_copy_value: #[get(copy, self_ty = "value")],
+        const_by_copy_value_into_inner: #[get(copy, self_ty = "value", into_inner)],
+    }
+}
+
+/// The getter by reference on a `()` field of a non [`Copy`] struct with a destructor.
+#[derive(Getter, Default)]
+pub struct WithDrop {
+    #[get(Pub, Const)]
+    #[get_mut(Pub)]
+    unit: (),
+    #[get(Pub, copy, into_inner)]
+    consumed: (),
+    name: String,
+}
+
+const fn const_unit(with_drop: &WithDrop) -> &() {
+    with_drop.unit()
+}
+
+fn main() {
+    let mut unit = Unit::default();
+    call_getters(unit);
+
+    let &mut () = unit.by_ref_both_mut();
+    let &mut () = unit.by_copy_both_mut();
+    let &mut () = unit.by_clone_both_mut();
+    let &mut () = unit.const_by_ref_both_mut();
+    let &mut () = unit.const_by_copy_both_mut();
+    let () = unit.into_by_ref_into_inner();
+    let () = unit.into_by_copy_into_inner();
+    let () = unit.into_by_copy_value_into_inner();
+    let () = unit.into_by_clone_into_inner();
+    let () = unit.into_by_clone_value_into_inner();
+    let () = unit.into_const_by_ref_into_inner();
+    let () = unit.into_const_by_copy_into_inner();
+    let () = unit.into_const_by_copy_value_into_inner();
+
+    let mut with_drop = WithDrop::default();
+    let &() = const_unit(&with_drop);
+    let &mut () = with_drop.unit_mut();
+    let () = with_drop.consumed();
+    assert!(with_drop.name.is_empty());
+    let () = with_drop.into_consumed();
+}
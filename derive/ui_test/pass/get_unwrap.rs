@@ -0,0 +1,42 @@
+// pass test for the unwrap option panicking on unset Option fields
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter, Default)]
+    pub struct Builder {
+        #[get(Pub, unwrap)]
+        name: Option<String>,
+        #[get(Pub, Copy, Const, unwrap)]
+        size: Option<usize>,
+        #[get(Pub, Clone, name = "tags_cloned", unwrap)]
+        #[get_mut(Pub)]
+        tags: Option<Vec<String>>,
+    }
+
+    impl Builder {
+        pub fn set(&mut self) {
+            self.name = Some("name".to_owned());
+            self.size = Some(2);
+        }
+    }
+}
+
+use def::Builder;
+
+fn main() {
+    let mut builder = Builder::default();
+    builder.set();
+    assert_eq!(builder.name(), "name");
+    assert_eq!(builder.size(), 2);
+    *builder.tags_mut() = Some(vec!["tag".to_owned()]);
+    assert_eq!(builder.tags_cloned(), vec!["tag".to_owned()]);
+
+    // do not print the expected panic
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(|| Builder::default().tags_cloned());
+    let message = result.expect_err("the field is not set");
+    assert_eq!(
+        message.downcast_ref::<&str>(),
+        Some(&"the field `tags` is not set")
+    );
+}
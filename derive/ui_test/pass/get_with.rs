@@ -0,0 +1,53 @@
+// pass test for the with option creating a consuming setter alongside the getter
+mod def {
+    use utils_lib_derive::Getter;
+
+    #[derive(Getter, Default)]
+    pub struct S {
+        #[get(Pub, with)]
+        f: String,
+        #[get(public, name = "field", with = "set_field")]
+        g: Vec<usize>,
+        #[get(Pub, with, into_inner)]
+        #[get_mut(Pub)]
+        h: u8,
+    }
+
+    #[derive(Getter, Clone, Copy, Default)]
+    pub struct Point {
+        #[get(Pub, Copy, self_ty = "value", with)]
+        x: i32,
+        #[get(Pub, Copy, self_ty = "value", with = "at_y")]
+        y: i32,
+    }
+
+    #[derive(Getter, Default)]
+    #[getter(module = "accessors")]
+    pub struct Tuple(#[get(name = "value", with = "with_value")] pub String);
+}
+
+use def::accessors::TupleGetters;
+
+fn main() {
+    let s = def::S::default()
+        .with_f("f".to_owned())
+        .set_field(vec![1])
+        .with_h(2);
+    assert_eq!(s.f(), "f");
+    assert_eq!(s.field(), &vec![1]);
+
+    let mut s = s.with_f("g".to_owned()).with_h(3);
+    assert_eq!(s.f(), "g");
+    *s.h_mut() += 1;
+    assert_eq!(s.into_h(), 4);
+
+    let point = def::Point::default().with_x(1).at_y(2);
+    assert_eq!(point.x(), 1);
+    assert_eq!(point.y(), 2);
+    let point = point.with_x(3).with_x(4);
+    assert_eq!(point.x(), 4);
+    assert_eq!(point.y(), 2);
+
+    let t = def::Tuple::default().with_value("t".to_owned());
+    assert_eq!(t.value(), "t");
+}
@@ -0,0 +1,35 @@
+// pass test for the New derive on a plain struct
+use utils_lib_derive::New;
+
+#[derive(Debug, Clone, PartialEq, New)]
+pub struct Point {
+    x: f64,
+    y: f64,
+    label: String,
+}
+
+#[derive(Debug, PartialEq, Eq, New)]
+struct Empty {}
+
+#[derive(New)]
+struct Raw {
+    r#type: u8,
+    r#ref: &'static str,
+}
+
+fn main() {
+    let point = Point::new(1_f64, -2_f64, "p".to_owned());
+    assert_eq!(
+        point,
+        Point {
+            x: 1_f64,
+            y: -2_f64,
+            label: "p".to_owned(),
+        }
+    );
+    assert_eq!(Empty::new(), Empty {});
+
+    let raw = Raw::new(3_u8, "ref");
+    assert_eq!(raw.r#type, 3_u8);
+    assert_eq!(raw.r#ref, "ref");
+}
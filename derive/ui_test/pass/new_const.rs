@@ -0,0 +1,43 @@
+// pass test for the New derive generating a const constructor used to initialize statics
+use utils_lib_derive::New;
+
+#[derive(Debug, PartialEq, New)]
+#[new(const)]
+struct Config {
+    name: &'static str,
+    retries: u8,
+    #[new(default = "Vec::new()")]
+    tags: Vec<&'static str>,
+    #[new(default = "Config::TIMEOUT * 2")]
+    timeout: u32,
+}
+
+impl Config {
+    const TIMEOUT: u32 = 15;
+}
+
+#[derive(Debug, New)]
+#[new(const)]
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+static CONFIG: Config = Config::new("config", 3_u8);
+static PAIR: Pair<u8, &str> = Pair::new(1_u8, "second");
+const EMPTY: Pair<(), ()> = Pair::new((), ());
+
+fn main() {
+    assert_eq!(
+        CONFIG,
+        Config {
+            name: "config",
+            retries: 3_u8,
+            tags: Vec::new(),
+            timeout: 30_u32,
+        }
+    );
+    assert_eq!(PAIR.first, 1_u8);
+    assert_eq!(PAIR.second, "second");
+    assert_eq!(EMPTY.first, ());
+}
@@ -0,0 +1,32 @@
+// pass test for the New derive with fields filled with `Default::default()`
+use std::collections::HashMap;
+
+use utils_lib_derive::New;
+
+#[derive(Debug, New)]
+struct Cache<K, V> {
+    name: String,
+    #[new(default)]
+    map: HashMap<K, V>,
+    capacity: usize,
+    #[new(default)]
+    hits: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, New)]
+struct AllDefault {
+    #[new(default)]
+    a: u8,
+    #[new(default)]
+    b: Option<String>,
+}
+
+fn main() {
+    let cache = Cache::<u8, String>::new("cache".to_owned(), 4_usize);
+    assert_eq!(cache.name, "cache");
+    assert!(cache.map.is_empty());
+    assert_eq!(cache.capacity, 4_usize);
+    assert_eq!(cache.hits, 0_u64);
+
+    assert_eq!(AllDefault::new(), AllDefault { a: 0_u8, b: None });
+}
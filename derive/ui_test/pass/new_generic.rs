@@ -0,0 +1,42 @@
+// pass test for the New derive on structs with generics and where clauses
+use std::fmt::Debug;
+
+use utils_lib_derive::New;
+
+#[derive(Debug, PartialEq, New)]
+struct Pair<A, B: Clone> {
+    first: A,
+    second: B,
+}
+
+#[derive(Debug, New)]
+struct Borrowed<'a, T, const N: usize>
+where
+    T: Debug + ?Sized,
+{
+    value: &'a T,
+    array: [u8; N],
+}
+
+#[derive(New)]
+struct Boxed<F: Fn(u8) -> u8> {
+    function: F,
+}
+
+fn main() {
+    let pair = Pair::new(1_u8, "b".to_owned());
+    assert_eq!(
+        pair,
+        Pair {
+            first: 1_u8,
+            second: "b".to_owned(),
+        }
+    );
+
+    let borrowed = Borrowed::new("str", [1_u8, 2_u8]);
+    assert_eq!(borrowed.value, "str");
+    assert_eq!(borrowed.array, [1_u8, 2_u8]);
+
+    let boxed = Boxed::new(|x| x + 1_u8);
+    assert_eq!((boxed.function)(1_u8), 2_u8);
+}
@@ -0,0 +1,75 @@
+// pass test for the New derive with a validator generating `try_new`
+use std::fmt::{self, Display};
+
+use utils_lib_derive::New;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InvertedBoundsError;
+
+impl Display for InvertedBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the minimum is greater than the maximum")
+    }
+}
+
+#[derive(Debug, PartialEq, New)]
+#[new(validate = "Self::check", error = "InvertedBoundsError")]
+struct Bounds {
+    min: i32,
+    max: i32,
+    #[new(default)]
+    hits: u32,
+}
+
+impl Bounds {
+    fn check(&self) -> Result<(), InvertedBoundsError> {
+        if self.min <= self.max {
+            Ok(())
+        } else {
+            Err(InvertedBoundsError)
+        }
+    }
+}
+
+#[derive(Debug, New)]
+#[new(validate = "not_empty", error = "&'static str", fallible_only)]
+struct Name<T: AsRef<str>> {
+    name: T,
+}
+
+fn not_empty<T: AsRef<str>>(name: &Name<T>) -> Result<(), &'static str> {
+    if name.name.as_ref().is_empty() {
+        Err("the name is empty")
+    } else {
+        Ok(())
+    }
+}
+
+fn main() {
+    assert_eq!(
+        Bounds::try_new(-1_i32, 3_i32),
+        Ok(Bounds {
+            min: -1_i32,
+            max: 3_i32,
+            hits: 0_u32,
+        })
+    );
+    assert_eq!(Bounds::try_new(3_i32, 3_i32).map(|bounds| bounds.max), Ok(3_i32));
+    assert_eq!(Bounds::try_new(4_i32, 3_i32), Err(InvertedBoundsError));
+    assert_eq!(Bounds::new(0_i32, 1_i32).max, 1_i32);
+
+    // do not print the expected panic
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(|| Bounds::new(4_i32, 3_i32));
+    let message = result.expect_err("the bounds are inverted");
+    assert_eq!(
+        message.downcast_ref::<String>().map(String::as_str),
+        Some("the minimum is greater than the maximum")
+    );
+
+    assert_eq!(Name::try_new("name").map(|name| name.name), Ok("name"));
+    assert_eq!(
+        Name::try_new(String::new()).map(|name| name.name),
+        Err("the name is empty")
+    );
+}
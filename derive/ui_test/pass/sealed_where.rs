@@ -0,0 +1,79 @@
+// pass test for Sealed derive with where clauses and additional bounds
+use std::fmt::Debug;
+
+use utils_lib_derive::Sealed;
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub trait Sealed: private::Sealed {}
+
+impl<T: private::Sealed> Sealed for T {}
+
+#[derive(Sealed)]
+struct AssociatedEq<I>
+where
+    I: Iterator<Item = u32>,
+{
+    iter: I,
+}
+
+#[derive(Sealed)]
+struct Lifetimes<'a, 'b: 'a, T>
+where
+    T: 'b + ?Sized,
+    &'a T: Debug,
+{
+    r: &'a &'b T,
+}
+
+#[derive(Sealed)]
+struct SelfWhere<T>
+where
+    T: Into<Self>,
+    Self: Send,
+{
+    t: Option<T>,
+}
+
+impl From<u8> for SelfWhere<u8> {
+    fn from(value: u8) -> Self {
+        Self { t: Some(value) }
+    }
+}
+
+trait HasItem {
+    type Item;
+}
+
+impl HasItem for Wrapper<u32> {
+    type Item = u32;
+}
+
+#[derive(Sealed)]
+struct Wrapper<T>
+where
+    Self: HasItem<Item = T>,
+    <Self as HasItem>::Item: Copy,
+{
+    t: T,
+}
+
+#[derive(Sealed)]
+#[sealed(bound = "T: 'static")]
+#[sealed(bound = "T: Debug, U: Clone")]
+enum Bounded<T, U> {
+    A(T),
+    B(U),
+}
+
+fn assert_sealed<T: Sealed>() {}
+
+fn main() {
+    assert_sealed::<AssociatedEq<std::iter::Once<u32>>>();
+    assert_sealed::<Lifetimes<'_, '_, str>>();
+    assert_sealed::<SelfWhere<u8>>();
+    assert_sealed::<Wrapper<u32>>();
+    assert_sealed::<Bounded<u8, String>>();
+}
@@ -0,0 +1,50 @@
+// pass test for the Variants derive on a fieldless enum
+use utils_lib_derive::Variants;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InvalidIndex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Variants)]
+#[variants(error = "InvalidIndex")]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday = 10,
+    Thursday,
+    Friday,
+}
+
+#[derive(Debug, PartialEq, Eq, Variants)]
+enum Single {
+    Only,
+}
+
+// the functions are constant
+const COUNT: usize = Weekday::variant_count();
+const LAST: Option<Weekday> = Weekday::from_index(COUNT - 1);
+const INDEX: usize = Weekday::Thursday.to_index();
+
+fn main() {
+    assert_eq!(COUNT, 5);
+    assert_eq!(Weekday::VARIANTS.len(), 5);
+    assert_eq!(Weekday::VARIANTS[2], Weekday::Wednesday);
+    assert_eq!(LAST, Some(Weekday::Friday));
+    assert_eq!(INDEX, 3);
+
+    let mut iter = Weekday::variants();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(Weekday::Monday));
+    assert_eq!(iter.next_back(), Some(Weekday::Friday));
+    assert_eq!(iter.len(), 3);
+
+    for (index, day) in Weekday::variants().enumerate() {
+        assert_eq!(day.to_index(), index);
+        assert_eq!(Weekday::try_from(index), Ok(day));
+    }
+    assert_eq!(Weekday::try_from(5), Err(InvalidIndex));
+    assert_eq!(Weekday::from_index(usize::MAX), None);
+
+    assert_eq!(Single::VARIANTS, [Single::Only]);
+    assert_eq!(Single::try_from(1), Err(()));
+    assert_eq!(Single::Only.to_index(), 0);
+}
@@ -0,0 +1,168 @@
+//! Contains [`Case`], the naming conventions used to rename identifiers, like the
+//! `rename_all` option of serde.
+
+use std::fmt::{self, Display};
+
+/// A naming convention of identifiers.
+///
+/// The conversion splits the name in words at the underscores and at the lower case to upper
+/// case transitions, so a name in any of the cases can be converted to any other. The leading
+/// underscores are kept.
+///
+/// # Example
+/// ```
+/// use macro_utils::case::Case;
+///
+/// assert_eq!(Case::Camel.convert("my_field"), "myField");
+/// assert_eq!(Case::Pascal.convert("my_field"), "MyField");
+/// assert_eq!(Case::ScreamingSnake.convert("myField"), "MY_FIELD");
+/// assert_eq!(Case::Snake.convert("MyField"), "my_field");
+/// assert_eq!(Case::from_name("camelCase"), Some(Case::Camel));
+/// ```
+#[allow(clippy::exhaustive_enums)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum Case {
+    /// `snake_case`, the convention of functions and fields
+    #[default]
+    Snake,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`, the convention of types
+    Pascal,
+    /// `SCREAMING_SNAKE_CASE`, the convention of constants
+    ScreamingSnake,
+}
+
+impl Case {
+    /// All the cases.
+    pub const ALL: [Self; 4] = [Self::Snake, Self::Camel, Self::Pascal, Self::ScreamingSnake];
+
+    /// Get the case from its name as written in the case itself, `snake_case`, `camelCase`,
+    /// `PascalCase` or `SCREAMING_SNAKE_CASE`, like serde's `rename_all`.
+    #[inline]
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|case| case.name() == name)
+    }
+
+    /// Get the name of the case written in the case itself, see [`Self::from_name`].
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Snake => "snake_case",
+            Self::Camel => "camelCase",
+            Self::Pascal => "PascalCase",
+            Self::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+        }
+    }
+
+    /// Convert the name to the case.
+    #[inline]
+    #[must_use]
+    pub fn convert(self, name: &str) -> String {
+        let trimmed = name.trim_start_matches('_');
+        let mut out = name[..name.len() - trimmed.len()].to_owned();
+        for (index, word) in words(trimmed).into_iter().enumerate() {
+            if index > 0 && matches!(self, Self::Snake | Self::ScreamingSnake) {
+                out.push('_');
+            }
+            match self {
+                Self::Snake => out.push_str(&word.to_lowercase()),
+                Self::ScreamingSnake => out.push_str(&word.to_uppercase()),
+                Self::Camel if index == 0 => out.push_str(&word.to_lowercase()),
+                Self::Camel | Self::Pascal => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        out.extend(first.to_uppercase());
+                        out.push_str(&chars.as_str().to_lowercase());
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Display for Case {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Split a name in words at the underscores and at the transitions from a lower case letter
+/// or a digit to an upper case letter. The empty words are removed.
+fn words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in name.split('_') {
+        let mut start = 0;
+        let mut previous_lower = false;
+        for (index, char) in part.char_indices() {
+            if char.is_uppercase() && previous_lower {
+                words.push(&part[start..index]);
+                start = index;
+            }
+            previous_lower = char.is_lowercase() || char.is_ascii_digit();
+        }
+        words.push(&part[start..]);
+    }
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+#[cfg(test)]
+mod test {
+    use super::{words, Case};
+
+    #[test]
+    fn split_words() {
+        assert_eq!(words("my_field"), vec!["my", "field"]);
+        assert_eq!(words("myField"), vec!["my", "Field"]);
+        assert_eq!(words("MY_FIELD"), vec!["MY", "FIELD"]);
+        assert_eq!(words("field2D"), vec!["field2", "D"]);
+        assert_eq!(words("a__b_"), vec!["a", "b"]);
+        assert_eq!(words("x"), vec!["x"]);
+        assert_eq!(words(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn convert() {
+        for (name, snake, camel, pascal, screaming) in [
+            ("my_field", "my_field", "myField", "MyField", "MY_FIELD"),
+            ("myField", "my_field", "myField", "MyField", "MY_FIELD"),
+            ("MyField", "my_field", "myField", "MyField", "MY_FIELD"),
+            ("MY_FIELD", "my_field", "myField", "MyField", "MY_FIELD"),
+            ("x", "x", "x", "X", "X"),
+            ("field_2", "field_2", "field2", "Field2", "FIELD_2"),
+            ("a_b_c", "a_b_c", "aBC", "ABC", "A_B_C"),
+            (
+                "my_field_mut",
+                "my_field_mut",
+                "myFieldMut",
+                "MyFieldMut",
+                "MY_FIELD_MUT",
+            ),
+            ("_private", "_private", "_private", "_Private", "_PRIVATE"),
+            ("", "", "", "", ""),
+        ] {
+            assert_eq!(Case::Snake.convert(name), snake, "{name}");
+            assert_eq!(Case::Camel.convert(name), camel, "{name}");
+            assert_eq!(Case::Pascal.convert(name), pascal, "{name}");
+            assert_eq!(Case::ScreamingSnake.convert(name), screaming, "{name}");
+        }
+    }
+
+    #[test]
+    fn name() {
+        for case in Case::ALL {
+            assert_eq!(Case::from_name(case.name()), Some(case));
+            assert_eq!(case.to_string(), case.name());
+            // the name is written in its own case
+            assert_eq!(case.convert(case.name()), case.name());
+        }
+        assert_eq!(Case::from_name("kebab-case"), None);
+        assert_eq!(Case::from_name("camelcase"), None);
+        assert_eq!(Case::default(), Case::Snake);
+    }
+}
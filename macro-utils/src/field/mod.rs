@@ -3,8 +3,8 @@
 use std::fmt::{self, Display};
 
 use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::ToTokens;
-use syn::{Index, Type};
+use quote::{quote, ToTokens};
+use syn::{Attribute, Index, Member, Type, Visibility};
 
 /// Contain a [`syn::Field`] and an index that track the index of the field to
 /// getter working getter on tuple structure
@@ -124,7 +124,7 @@ impl Display for FieldName {
     }
 }
 
-/// Contain the [`FieldName`] and [`Type`] of a field
+/// Contain the [`FieldName`], [`Type`], attributes and [`Visibility`] of a field
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone)]
 pub struct FieldInformation {
@@ -132,6 +132,14 @@ pub struct FieldInformation {
     pub field_name: FieldName,
     /// The type of the field
     pub ty: Type,
+    /// The attributes on the field, including the doc comments
+    pub attrs: Vec<Attribute>,
+    /// The visibility of the field
+    pub vis: Visibility,
+    /// The path from `self` to the struct containing the field, empty by default.
+    /// It is not empty when the code is generated on a wrapper of the struct,
+    /// see [`Self::access`].
+    pub via: Vec<Member>,
 }
 
 impl FieldInformation {
@@ -141,9 +149,37 @@ impl FieldInformation {
         Self {
             field_name: FieldName::from_field_part(field.field.ident, field.index),
             ty: field.field.ty,
+            attrs: field.field.attrs,
+            vis: field.field.vis,
+            via: Vec::new(),
         }
     }
 
+    /// Set the path from `self` to the struct containing the field, see [`Self::via`].
+    #[inline]
+    #[must_use]
+    pub fn with_via(mut self, via: Vec<Member>) -> Self {
+        self.via = via;
+        self
+    }
+
+    /// Getter on the path from `self` to the struct containing the field.
+    #[inline]
+    #[must_use]
+    pub fn via(&self) -> &[Member] {
+        &self.via
+    }
+
+    /// Get the expression accessing the field from `self`, i.e. `self.field`, or
+    /// `self.0.field` if [`Self::via`] is `0`.
+    #[inline]
+    #[must_use]
+    pub fn access(&self) -> TokenStream2 {
+        let via = &self.via;
+        let field_name = &self.field_name;
+        quote! {self #(.#via)* .#field_name}
+    }
+
     /// Getter on the field name.
     #[inline]
     #[must_use]
@@ -157,4 +193,90 @@ impl FieldInformation {
     pub const fn ty(&self) -> &Type {
         &self.ty
     }
+
+    /// Getter on the attributes of the field.
+    #[inline]
+    #[must_use]
+    pub fn attrs(&self) -> &[Attribute] {
+        &self.attrs
+    }
+
+    /// Getter on the [`Visibility`] of the field.
+    #[inline]
+    #[must_use]
+    pub const fn vis(&self) -> &Visibility {
+        &self.vis
+    }
+
+    /// Iterator over the `#[cfg(...)]` and `#[cfg_attr(...)]` attributes of the field.
+    #[inline]
+    pub fn cfg_attrs(&self) -> impl Iterator<Item = &Attribute> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg") || attr.path().is_ident("cfg_attr"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use quote::ToTokens;
+    use syn::{parse_quote, Visibility};
+
+    use super::{Field, FieldInformation};
+
+    #[test]
+    fn field_information_attributes() {
+        let field: syn::Field = parse_quote! {
+            /// some doc
+            #[cfg(feature = "serde")]
+            #[deprecated]
+            #[cfg_attr(test, allow(dead_code))]
+            pub(crate) field: u32
+        };
+        let info = FieldInformation::from_field(Field::new(field, 0));
+
+        assert_eq!(info.field_name().to_string(), "field");
+        assert_eq!(info.ty().to_token_stream().to_string(), "u32");
+        assert_eq!(info.attrs().len(), 4);
+        assert!(info.attrs()[0].path().is_ident("doc"));
+        assert!(matches!(info.vis(), Visibility::Restricted(_)));
+
+        let cfg = info
+            .cfg_attrs()
+            .map(|attr| attr.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cfg,
+            vec![
+                "# [cfg (feature = \"serde\")]".to_owned(),
+                "# [cfg_attr (test , allow (dead_code))]".to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn field_information_no_attributes() {
+        let field: syn::Field = parse_quote!(u8);
+        let info = FieldInformation::from_field(Field::new(field, 2));
+
+        assert_eq!(info.field_name().to_string(), "2");
+        assert!(info.attrs().is_empty());
+        assert_eq!(info.cfg_attrs().count(), 0);
+        assert!(matches!(info.vis(), Visibility::Inherited));
+    }
+
+    #[test]
+    fn field_information_access() {
+        let info = FieldInformation::from_field(Field::new(parse_quote!(count: usize), 0));
+        assert!(info.via().is_empty());
+        assert_eq!(info.access().to_string(), "self . count");
+
+        let info = info.with_via(vec![parse_quote!(0), parse_quote!(inner)]);
+        assert_eq!(info.via().len(), 2);
+        assert_eq!(info.access().to_string(), "self . 0 . inner . count");
+
+        let info = FieldInformation::from_field(Field::new(parse_quote!(u8), 1))
+            .with_via(vec![parse_quote!(inner)]);
+        assert_eq!(info.access().to_string(), "self . inner . 1");
+    }
 }
@@ -0,0 +1,530 @@
+//! Contains [`GetterSpec`], a description of a getter method that can generate its code
+//! from a [`FieldInformation`]. It is the code generation used by the `Getter` derive and
+//! it can be used by other procedural macros to emit getters.
+//!
+//! # Example
+//! ```
+//! use macro_utils::{
+//!     field::{Field, FieldInformation},
+//!     getter::{GetterSpec, GetterTy},
+//! };
+//! use proc_macro2::{Ident, Span};
+//! use syn::parse_quote;
+//!
+//! let field: syn::Field = parse_quote!(count: usize);
+//! let field = FieldInformation::from_field(Field::new(field, 0));
+//! let spec = GetterSpec::new(Ident::new("count", Span::call_site()))
+//!     .with_visibility(parse_quote!(pub))
+//!     .with_constant(true)
+//!     .with_getter_ty(GetterTy::Copy);
+//!
+//! assert_eq!(
+//!     spec.to_tokens(&field).to_string(),
+//!     "# [inline] # [must_use] pub const fn count (& self) -> usize { self . count }"
+//! );
+//! ```
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::Visibility;
+
+use crate::field::FieldInformation;
+
+/// How the value is returned by the getter.
+#[allow(clippy::exhaustive_enums)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum GetterTy {
+    /// By reference, `fn field(&self) -> &T`.
+    #[default]
+    Ref,
+    /// By mutable reference, `fn field_mut(&mut self) -> &mut T`.
+    Mut,
+    /// By copy, `fn field(&self) -> T`, the type of the field must implement [`Copy`].
+    Copy,
+    /// By clone, `fn field(&self) -> T`, the type of the field must implement [`Clone`].
+    Clone,
+}
+
+impl GetterTy {
+    /// Get the return type of the getter for a field of type `ty`, i.e. `&ty`, `&mut ty` or
+    /// `ty`.
+    #[must_use]
+    #[inline]
+    pub fn return_ty<T: ToTokens>(self, ty: &T) -> TokenStream2 {
+        match self {
+            Self::Ref => quote! {&#ty},
+            Self::Mut => quote! {&mut #ty},
+            Self::Copy | Self::Clone => quote! {#ty},
+        }
+    }
+
+    /// Get the body of the getter from the field access `self.field`.
+    ///
+    /// The trait methods are called with fully qualified `::core` paths so the generated
+    /// code is not affected by items shadowing the prelude in the user crate.
+    #[must_use]
+    #[inline]
+    pub fn body(self, field_access: &TokenStream2) -> TokenStream2 {
+        match self {
+            Self::Ref => quote! {&#field_access},
+            Self::Mut => quote! {&mut #field_access},
+            Self::Copy => quote! {#field_access},
+            Self::Clone => quote! {::core::clone::Clone::clone(&#field_access)},
+        }
+    }
+}
+
+/// How `self` is taken by the getter.
+#[allow(clippy::exhaustive_enums)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum SelfTy {
+    /// `&self`
+    #[default]
+    Ref,
+    /// `&mut self`
+    Mut,
+    /// `self`, it is recommended only for types that implement [`Copy`].
+    Value,
+}
+
+impl ToTokens for SelfTy {
+    #[inline]
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            Self::Ref => quote! {&self},
+            Self::Mut => quote! {&mut self},
+            Self::Value => quote! {self},
+        });
+    }
+}
+
+/// Description of a getter method: its name, visibility, constness, how the value is
+/// returned and how `self` is taken, and optionally its documentation and `#[doc(hidden)]`.
+///
+/// [`Self::to_tokens`] gives the getter as an inherent method,
+/// [`Self::to_trait_declaration`] and [`Self::to_trait_impl`] as a trait method. The
+/// functions [`Self::item`], [`Self::trait_declaration_item`] and [`Self::trait_impl_item`]
+/// accept a custom signature and body to customize the getter.
+///
+/// The consistency of the getter and self types is not checked, for instance a
+/// [`GetterTy::Ref`] with a [`SelfTy::Value`] does not compile.
+#[derive(Clone)]
+pub struct GetterSpec {
+    /// The name of the getter
+    name: Ident,
+    /// The visibility of the getter
+    visibility: Visibility,
+    /// If the getter is `const`
+    constant: bool,
+    /// How the value is returned
+    getter_ty: GetterTy,
+    /// How `self` is taken
+    self_ty: SelfTy,
+    /// The documentation of the getter
+    doc: Option<String>,
+    /// If the getter is hidden from the documentation with `#[doc(hidden)]`
+    hidden: bool,
+}
+
+impl GetterSpec {
+    /// Create a private non constant getter by reference, `fn name(&self) -> &T`.
+    #[must_use]
+    #[inline]
+    pub const fn new(name: Ident) -> Self {
+        Self {
+            name,
+            visibility: Visibility::Inherited,
+            constant: false,
+            getter_ty: GetterTy::Ref,
+            self_ty: SelfTy::Ref,
+            doc: None,
+            hidden: false,
+        }
+    }
+
+    /// Create a private getter by mutable reference, `fn name(&mut self) -> &mut T`.
+    #[must_use]
+    #[inline]
+    pub const fn new_mut(name: Ident) -> Self {
+        Self::new(name)
+            .with_getter_ty(GetterTy::Mut)
+            .with_self_ty(SelfTy::Mut)
+    }
+
+    /// Set the visibility of the getter.
+    #[must_use]
+    #[inline]
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Set if the getter is `const`.
+    #[must_use]
+    #[inline]
+    pub const fn with_constant(mut self, constant: bool) -> Self {
+        self.constant = constant;
+        self
+    }
+
+    /// Set how the value is returned.
+    #[must_use]
+    #[inline]
+    pub const fn with_getter_ty(mut self, getter_ty: GetterTy) -> Self {
+        self.getter_ty = getter_ty;
+        self
+    }
+
+    /// Set how `self` is taken.
+    #[must_use]
+    #[inline]
+    pub const fn with_self_ty(mut self, self_ty: SelfTy) -> Self {
+        self.self_ty = self_ty;
+        self
+    }
+
+    /// Set the documentation of the getter.
+    #[must_use]
+    #[inline]
+    pub fn with_doc(mut self, doc: String) -> Self {
+        self.doc = Some(doc);
+        self
+    }
+
+    /// Set if the getter is hidden from the documentation with `#[doc(hidden)]`.
+    #[must_use]
+    #[inline]
+    pub const fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Getter on the name of the getter.
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Getter on the visibility of the getter.
+    #[must_use]
+    #[inline]
+    pub const fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    /// Returns `true` if the getter is `const`.
+    #[must_use]
+    #[inline]
+    pub const fn constant(&self) -> bool {
+        self.constant
+    }
+
+    /// Getter on how the value is returned.
+    #[must_use]
+    #[inline]
+    pub const fn getter_ty(&self) -> GetterTy {
+        self.getter_ty
+    }
+
+    /// Getter on how `self` is taken.
+    #[must_use]
+    #[inline]
+    pub const fn self_ty(&self) -> SelfTy {
+        self.self_ty
+    }
+
+    /// Getter on the documentation of the getter.
+    #[must_use]
+    #[inline]
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    /// Returns `true` if the getter is hidden from the documentation.
+    #[must_use]
+    #[inline]
+    pub const fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Get the signature of the getter with the given return type, without the visibility
+    /// and the const modifier, i.e. `fn name(&self) -> return_ty`.
+    #[must_use]
+    #[inline]
+    pub fn signature_returning(&self, return_ty: &TokenStream2) -> TokenStream2 {
+        let name = &self.name;
+        let self_ty = self.self_ty;
+        quote! {
+            fn #name(#self_ty) -> #return_ty
+        }
+    }
+
+    /// Get the signature of the getter of the field, without the visibility and the const
+    /// modifier, i.e. `fn name(&self) -> &Ty`.
+    #[must_use]
+    #[inline]
+    pub fn signature(&self, field: &FieldInformation) -> TokenStream2 {
+        self.signature_returning(&self.getter_ty.return_ty(field.ty()))
+    }
+
+    /// Get the body of the getter of the field, i.e. `&self.field`, see
+    /// [`FieldInformation::access`].
+    #[must_use]
+    #[inline]
+    pub fn body(&self, field: &FieldInformation) -> TokenStream2 {
+        self.getter_ty.body(&field.access())
+    }
+
+    /// Get the documentation attribute, empty if no documentation is set, followed by
+    /// `#[doc(hidden)]` if the getter is hidden.
+    fn doc_attribute(&self) -> TokenStream2 {
+        let doc = self.doc.as_ref().map(|doc| quote! {#[doc = #doc]});
+        let hidden = self.hidden.then(|| quote! {#[doc(hidden)]});
+        quote! {
+            #doc
+            #hidden
+        }
+    }
+
+    /// Get the getter as an inherent method with the given signature and body, with the
+    /// documentation, the visibility and the const modifier.
+    #[must_use]
+    #[inline]
+    pub fn item(&self, signature: &TokenStream2, body: &TokenStream2) -> TokenStream2 {
+        let doc = self.doc_attribute();
+        let visibility = &self.visibility;
+        let constant = self.constant.then(|| quote! {const});
+        quote! {
+            #doc
+            #[inline]
+            #[must_use]
+            #visibility #constant #signature {
+                #body
+            }
+        }
+    }
+
+    /// Get the declaration of the getter as a trait method with the given signature.
+    #[must_use]
+    #[inline]
+    pub fn trait_declaration_item(&self, signature: &TokenStream2) -> TokenStream2 {
+        let doc = self.doc_attribute();
+        quote! {
+            #doc
+            #[must_use]
+            #signature;
+        }
+    }
+
+    /// Get the implementation of the getter as a trait method with the given signature and
+    /// body. The visibility and the const modifier are not valid on a trait method and are
+    /// ignored.
+    #[must_use]
+    #[inline]
+    pub fn trait_impl_item(signature: &TokenStream2, body: &TokenStream2) -> TokenStream2 {
+        quote! {
+            #[inline]
+            #signature {
+                #body
+            }
+        }
+    }
+
+    /// Get the getter of the field as an inherent method.
+    #[must_use]
+    #[inline]
+    pub fn to_tokens(&self, field: &FieldInformation) -> TokenStream2 {
+        self.item(&self.signature(field), &self.body(field))
+    }
+
+    /// Get the declaration of the getter of the field as a trait method.
+    #[must_use]
+    #[inline]
+    pub fn to_trait_declaration(&self, field: &FieldInformation) -> TokenStream2 {
+        self.trait_declaration_item(&self.signature(field))
+    }
+
+    /// Get the implementation of the getter of the field as a trait method.
+    #[must_use]
+    #[inline]
+    pub fn to_trait_impl(&self, field: &FieldInformation) -> TokenStream2 {
+        Self::trait_impl_item(&self.signature(field), &self.body(field))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+    use quote::quote;
+    use syn::parse_quote;
+
+    use super::{GetterSpec, GetterTy, SelfTy};
+    use crate::field::{Field, FieldInformation};
+
+    /// Create a [`FieldInformation`] from a field.
+    fn field(field: syn::Field) -> FieldInformation {
+        FieldInformation::from_field(Field::new(field, 1))
+    }
+
+    /// Create an ident with a call site span.
+    fn ident(name: &str) -> Ident {
+        Ident::new(name, Span::call_site())
+    }
+
+    /// Compare token streams through their string representation.
+    fn assert_tokens_eq(left: &TokenStream2, right: &TokenStream2) {
+        assert_eq!(left.to_string(), right.to_string());
+    }
+
+    #[test]
+    fn getter_spec_default() {
+        let spec = GetterSpec::new(ident("name"));
+        assert_eq!(spec.name(), "name");
+        assert!(matches!(spec.visibility(), syn::Visibility::Inherited));
+        assert!(!spec.constant());
+        assert_eq!(spec.getter_ty(), GetterTy::Ref);
+        assert_eq!(spec.self_ty(), SelfTy::Ref);
+        assert_eq!(spec.doc(), None);
+        assert!(!spec.hidden());
+
+        let info = field(parse_quote!(name: String));
+        assert_tokens_eq(
+            &spec.to_tokens(&info),
+            &quote! {
+                #[inline]
+                #[must_use]
+                fn name(&self) -> &String {
+                    &self.name
+                }
+            },
+        );
+        assert_tokens_eq(
+            &spec.to_trait_declaration(&info),
+            &quote! {
+                #[must_use]
+                fn name(&self) -> &String;
+            },
+        );
+        assert_tokens_eq(
+            &spec.to_trait_impl(&info),
+            &quote! {
+                #[inline]
+                fn name(&self) -> &String {
+                    &self.name
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn getter_spec_options() {
+        let info = field(parse_quote!(Vec<u8>));
+
+        let spec = GetterSpec::new_mut(ident("bytes_mut"))
+            .with_visibility(parse_quote!(pub(crate)))
+            .with_doc("Mutable bytes.".to_owned());
+        assert_tokens_eq(
+            &spec.to_tokens(&info),
+            &quote! {
+                #[doc = "Mutable bytes."]
+                #[inline]
+                #[must_use]
+                pub(crate) fn bytes_mut(&mut self) -> &mut Vec<u8> {
+                    &mut self.1
+                }
+            },
+        );
+
+        let spec = GetterSpec::new(ident("bytes"))
+            .with_visibility(parse_quote!(pub))
+            .with_getter_ty(GetterTy::Clone);
+        assert_tokens_eq(
+            &spec.to_tokens(&info),
+            &quote! {
+                #[inline]
+                #[must_use]
+                pub fn bytes(&self) -> Vec<u8> {
+                    ::core::clone::Clone::clone(&self.1)
+                }
+            },
+        );
+
+        let info = field(parse_quote!(count: u32));
+        let spec = GetterSpec::new(ident("count"))
+            .with_constant(true)
+            .with_getter_ty(GetterTy::Copy)
+            .with_self_ty(SelfTy::Value);
+        assert_tokens_eq(
+            &spec.to_tokens(&info),
+            &quote! {
+                #[inline]
+                #[must_use]
+                const fn count(self) -> u32 {
+                    self.count
+                }
+            },
+        );
+
+        let spec = GetterSpec::new(ident("count"))
+            .with_doc("Count.".to_owned())
+            .with_hidden(true);
+        assert!(spec.hidden());
+        assert_tokens_eq(
+            &spec.to_tokens(&info),
+            &quote! {
+                #[doc = "Count."]
+                #[doc(hidden)]
+                #[inline]
+                #[must_use]
+                fn count(&self) -> &u32 {
+                    &self.count
+                }
+            },
+        );
+        assert_tokens_eq(
+            &spec.to_trait_declaration(&info),
+            &quote! {
+                #[doc = "Count."]
+                #[doc(hidden)]
+                #[must_use]
+                fn count(&self) -> &u32;
+            },
+        );
+    }
+
+    #[test]
+    fn getter_spec_custom_item() {
+        let spec = GetterSpec::new(ident("value")).with_doc("Value.".to_owned());
+        let signature = spec.signature_returning(&quote! {Option<&u8>});
+        let body = quote! {self.value.as_ref()};
+        assert_tokens_eq(
+            &spec.item(&signature, &body),
+            &quote! {
+                #[doc = "Value."]
+                #[inline]
+                #[must_use]
+                fn value(&self) -> Option<&u8> {
+                    self.value.as_ref()
+                }
+            },
+        );
+        assert_tokens_eq(
+            &spec.trait_declaration_item(&signature),
+            &quote! {
+                #[doc = "Value."]
+                #[must_use]
+                fn value(&self) -> Option<&u8>;
+            },
+        );
+        assert_tokens_eq(
+            &GetterSpec::trait_impl_item(&signature, &body),
+            &quote! {
+                #[inline]
+                fn value(&self) -> Option<&u8> {
+                    self.value.as_ref()
+                }
+            },
+        );
+    }
+}
@@ -129,7 +129,9 @@
 
 #[macro_use]
 mod macro_def;
+pub mod case;
 pub mod field;
+pub mod getter;
 
 #[cfg(test)]
 mod test;
@@ -21,6 +21,6 @@ macro_rules! getter {
 #[macro_export]
 macro_rules! quote_compile_error {
     ($($tt:tt)* ) => {
-        quote::quote! {compile_error!($($tt)*);}.into()
+        quote::quote! {::core::compile_error!($($tt)*);}.into()
     };
 }
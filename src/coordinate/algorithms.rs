@@ -0,0 +1,384 @@
+//! Contains geometry algorithms on integer points: [`convex_hull`], [`closest_pair`] and
+//! [`points_on_segment`].
+//!
+//! The module requires the `alloc` feature. The computations are exact, they cannot overflow
+//! even for components close to [`i64::MIN`] or [`i64::MAX`].
+
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::cmp::Ordering;
+
+use super::Coordinate;
+use crate::number::{gcd, Sign};
+
+/// Get the convex hull of `points`, i.e. the vertices of the smallest convex polygon
+/// containing all the points, using Andrew's monotone chain algorithm in `O(n log n)`.
+///
+/// The vertices are given in counter-clockwise order, with the y axis pointing up, starting
+/// from the smallest point in the order of [`Ord`] for [`Coordinate`], i.e. the one with the
+/// smallest `x` then the smallest `y`. The duplicated points and the points on an edge of
+/// the hull are not vertices. If there are less than three distinct points or they are all
+/// collinear the hull is degenerated: it is the distinct points, or the two ends of the line,
+/// in increasing order.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{algorithms::convex_hull, Coordinate};
+///
+/// let points = [
+///     Coordinate::new(0_i64, 0_i64),
+///     Coordinate::new(2_i64, 2_i64),
+///     Coordinate::new(1_i64, 1_i64),
+///     Coordinate::new(0_i64, 2_i64),
+///     Coordinate::new(2_i64, 0_i64),
+///     Coordinate::new(1_i64, 0_i64),
+/// ];
+/// assert_eq!(
+///     convex_hull(&points),
+///     vec![
+///         Coordinate::new(0_i64, 0_i64),
+///         Coordinate::new(2_i64, 0_i64),
+///         Coordinate::new(2_i64, 2_i64),
+///         Coordinate::new(0_i64, 2_i64),
+///     ]
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn convex_hull(points: &[Coordinate<i64>]) -> Vec<Coordinate<i64>> {
+    let mut points = points.to_vec();
+    points.sort_unstable();
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull = Vec::with_capacity(points.len() + 1);
+    // lower hull from left to right
+    for &point in &points {
+        push_hull_point(&mut hull, point, 2);
+    }
+    // upper hull from right to left, without removing the lower hull
+    let lower_length = hull.len() + 1;
+    for &point in points.iter().rev().skip(1) {
+        push_hull_point(&mut hull, point, lower_length);
+    }
+    // the first point is repeated at the end of the upper hull
+    hull.pop();
+    hull
+}
+
+/// Push `point` in the chain `hull` of the monotone chain algorithm after removing the last
+/// points which do not make a strict left turn, keeping at least `minimum_length - 1` points.
+fn push_hull_point(hull: &mut Vec<Coordinate<i64>>, point: Coordinate<i64>, minimum_length: usize) {
+    while hull.len() >= minimum_length
+        && orientation(hull[hull.len() - 2], hull[hull.len() - 1], point) != Sign::Positive
+    {
+        hull.pop();
+    }
+    hull.push(point);
+}
+
+/// Get the two points of `points`, at distinct indices, with the smallest euclidean distance,
+/// [`None`] if there are less than two points. It uses a sweep line in `O(n log n)`.
+///
+/// The two points of the pair are in increasing order. If several pairs are at the smallest
+/// distance, which one is returned is unspecified. Duplicated points are at a distance of
+/// zero so they are the closest pair.
+///
+/// The squared distances are computed on [`u128`] and saturate at [`u128::MAX`], so the
+/// pair is only approximate if all the points are more than about `1.3E19` apart.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{algorithms::closest_pair, Coordinate};
+///
+/// let points = [
+///     Coordinate::new(0_i64, 0_i64),
+///     Coordinate::new(10_i64, 3_i64),
+///     Coordinate::new(4_i64, -2_i64),
+///     Coordinate::new(9_i64, 5_i64),
+/// ];
+/// assert_eq!(
+///     closest_pair(&points),
+///     Some((
+///         Coordinate::new(9_i64, 5_i64),
+///         Coordinate::new(10_i64, 3_i64)
+///     ))
+/// );
+/// assert_eq!(closest_pair(&points[..1]), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn closest_pair(points: &[Coordinate<i64>]) -> Option<(Coordinate<i64>, Coordinate<i64>)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable();
+
+    let (first, second) = (*sorted.first()?, *sorted.get(1)?);
+    let mut best = (distance_squared(first, second), first, second);
+    // the points whose x is close enough to the sweep line, ordered by (y, x)
+    let mut window = BTreeSet::new();
+    let mut left = 0;
+    for &point in &sorted {
+        // only the points with both components at most `reach` away can be closer, the
+        // square root of a u128 fits in an i128
+        let reach = i128::try_from(best.0.isqrt()).unwrap_or(i128::MAX);
+        while i128::from(point.x) - i128::from(sorted[left].x) > reach {
+            window.remove(&(sorted[left].y, sorted[left].x));
+            left += 1;
+        }
+        let bound = |y: i128| i64::try_from(y).unwrap_or(if y < 0 { i64::MIN } else { i64::MAX });
+        let low = bound(i128::from(point.y) - reach);
+        let high = bound(i128::from(point.y) + reach);
+        for &(y, x) in window.range((low, i64::MIN)..=(high, i64::MAX)) {
+            let candidate = Coordinate::new(x, y);
+            let distance = distance_squared(candidate, point);
+            if distance < best.0 {
+                best = (distance, candidate, point);
+            }
+        }
+        if best.0 == 0 {
+            break;
+        }
+        window.insert((point.y, point.x));
+    }
+    Some((best.1, best.2))
+}
+
+/// Get the number of integer points on the segment from `a` to `b`, both ends included. It
+/// is `gcd(|b.x - a.x|, |b.y - a.y|) + 1`, and `1` if `a == b`.
+///
+/// The count saturates at [`u64::MAX`], which is only reached by the segment from
+/// `i64::MIN` to `i64::MAX` along an axis.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{algorithms::points_on_segment, Coordinate};
+///
+/// assert_eq!(
+///     points_on_segment(
+///         Coordinate::new(0_i64, 0_i64),
+///         Coordinate::new(6_i64, -4_i64)
+///     ),
+///     3
+/// );
+/// assert_eq!(
+///     points_on_segment(Coordinate::new(1_i64, 1_i64), Coordinate::new(1_i64, 1_i64)),
+///     1
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn points_on_segment(a: Coordinate<i64>, b: Coordinate<i64>) -> u64 {
+    let steps = match (a.x.abs_diff(b.x), a.y.abs_diff(b.y)) {
+        (0, other) | (other, 0) => other,
+        (x, y) => gcd(x, y),
+    };
+    steps.saturating_add(1)
+}
+
+/// Get the squared euclidean distance between `a` and `b`, saturating at [`u128::MAX`].
+fn distance_squared(a: Coordinate<i64>, b: Coordinate<i64>) -> u128 {
+    let square = |n: u64| u128::from(n) * u128::from(n);
+    square(a.x.abs_diff(b.x)).saturating_add(square(a.y.abs_diff(b.y)))
+}
+
+/// Get the orientation of the turn `origin -> a -> b`, i.e. the sign of the cross product of
+/// `a - origin` and `b - origin`: [`Sign::Positive`] for a left turn (counter-clockwise with
+/// the y axis pointing up), [`Sign::Negative`] for a right turn and [`Sign::Zero`] if the
+/// points are collinear.
+///
+/// The products are computed as a sign and a [`u128`] magnitude so they cannot overflow.
+fn orientation(origin: Coordinate<i64>, a: Coordinate<i64>, b: Coordinate<i64>) -> Sign {
+    /// the sign and the absolute value of `to - from`
+    fn delta(from: i64, to: i64) -> (Sign, u128) {
+        (to.cmp(&from).into(), u128::from(from.abs_diff(to)))
+    }
+    /// the sign and the absolute value of a product
+    fn product(left: (Sign, u128), right: (Sign, u128)) -> (Sign, u128) {
+        (left.0 * right.0, left.1 * right.1)
+    }
+
+    let (first_sign, first) = product(delta(origin.x, a.x), delta(origin.y, b.y));
+    let (second_sign, second) = product(delta(origin.y, a.y), delta(origin.x, b.x));
+    // compare the two signed products, the magnitude is zero when the sign is zero
+    match first_sign.to_i8().cmp(&second_sign.to_i8()) {
+        Ordering::Equal => match first_sign {
+            Sign::Positive => first.cmp(&second).into(),
+            Sign::Negative => second.cmp(&first).into(),
+            Sign::Zero => Sign::Zero,
+        },
+        ordering => ordering.into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use super::{closest_pair, convex_hull, distance_squared, orientation, points_on_segment};
+    use crate::{number::Sign, Coordinate};
+
+    /// shorthand to create a coordinate
+    const fn c(x: i64, y: i64) -> Coordinate<i64> {
+        Coordinate::new(x, y)
+    }
+
+    /// Pseudo random points with the coordinates in `-range..range`.
+    fn points(count: usize, range: i64, seed: u64) -> Vec<Coordinate<i64>> {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| c(rng.gen_range(-range..range), rng.gen_range(-range..range)))
+            .collect()
+    }
+
+    #[test]
+    fn orientation_sign() {
+        assert_eq!(orientation(c(0, 0), c(1, 0), c(1, 1)), Sign::Positive);
+        assert_eq!(orientation(c(0, 0), c(1, 1), c(1, 0)), Sign::Negative);
+        assert_eq!(orientation(c(0, 0), c(1, 1), c(3, 3)), Sign::Zero);
+        assert_eq!(orientation(c(5, 5), c(5, 5), c(3, 3)), Sign::Zero);
+
+        let min = c(i64::MIN, i64::MIN);
+        let max = c(i64::MAX, i64::MAX);
+        assert_eq!(orientation(min, max, c(i64::MIN, i64::MAX)), Sign::Positive);
+        assert_eq!(orientation(min, max, c(i64::MAX, i64::MIN)), Sign::Negative);
+        assert_eq!(orientation(min, max, c(0, 0)), Sign::Zero);
+        assert_eq!(orientation(min, max, c(0, 1)), Sign::Positive);
+        assert_eq!(orientation(max, min, c(-1, -1)), Sign::Zero);
+    }
+
+    #[test]
+    fn hull() {
+        // square with interior points and points on the edges
+        let mut square = vec![c(0, 0), c(4, 0), c(4, 4), c(0, 4)];
+        square.extend([
+            c(1, 1),
+            c(2, 3),
+            c(3, 2),
+            c(2, 0),
+            c(4, 1),
+            c(0, 3),
+            c(2, 2),
+        ]);
+        let expected = vec![c(0, 0), c(4, 0), c(4, 4), c(0, 4)];
+        assert_eq!(convex_hull(&square), expected);
+        square.reverse();
+        assert_eq!(convex_hull(&square), expected);
+
+        // duplicated points
+        let duplicated = [
+            c(1, 1),
+            c(0, 0),
+            c(1, 1),
+            c(2, 0),
+            c(0, 0),
+            c(2, 0),
+            c(1, 1),
+        ];
+        assert_eq!(convex_hull(&duplicated), vec![c(0, 0), c(2, 0), c(1, 1)]);
+        assert_eq!(convex_hull(&[c(3, 3), c(3, 3)]), vec![c(3, 3)]);
+
+        // collinear points
+        assert_eq!(
+            convex_hull(&[c(2, 2), c(0, 0), c(1, 1), c(3, 3), c(1, 1)]),
+            vec![c(0, 0), c(3, 3)]
+        );
+        assert_eq!(
+            convex_hull(&[c(0, 5), c(0, -1), c(0, 2)]),
+            vec![c(0, -1), c(0, 5)]
+        );
+
+        // degenerated
+        assert_eq!(convex_hull(&[]), Vec::new());
+        assert_eq!(convex_hull(&[c(1, 2)]), vec![c(1, 2)]);
+        assert_eq!(convex_hull(&[c(1, 2), c(0, 5)]), vec![c(0, 5), c(1, 2)]);
+
+        // extreme values
+        let min = c(i64::MIN, i64::MIN);
+        let max = c(i64::MAX, i64::MAX);
+        assert_eq!(
+            convex_hull(&[max, c(0, 0), min, c(i64::MIN, i64::MAX), c(0, 1)]),
+            vec![min, max, c(i64::MIN, i64::MAX)]
+        );
+    }
+
+    #[test]
+    fn hull_contains_points() {
+        let points = points(300, 1000, 7);
+        let hull = convex_hull(&points);
+        assert!(hull.len() >= 3);
+        for (index, &vertex) in hull.iter().enumerate() {
+            let next = hull[(index + 1) % hull.len()];
+            // all the points are on the left of every edge or on it
+            for &point in &points {
+                assert_ne!(orientation(vertex, next, point), Sign::Negative);
+            }
+            // the vertices are strict left turns
+            let after = hull[(index + 2) % hull.len()];
+            assert_eq!(orientation(vertex, next, after), Sign::Positive);
+        }
+    }
+
+    #[test]
+    fn closest() {
+        assert_eq!(closest_pair(&[]), None);
+        assert_eq!(closest_pair(&[c(1, 1)]), None);
+        assert_eq!(closest_pair(&[c(5, 1), c(1, 1)]), Some((c(1, 1), c(5, 1))));
+
+        // duplicated points
+        assert_eq!(
+            closest_pair(&[c(0, 0), c(9, 9), c(3, 4), c(1, 0), c(3, 4)]),
+            Some((c(3, 4), c(3, 4)))
+        );
+
+        // collinear points
+        assert_eq!(
+            closest_pair(&[c(0, 0), c(0, 10), c(0, 4), c(0, 8)]),
+            Some((c(0, 8), c(0, 10)))
+        );
+
+        // extreme values
+        let min = c(i64::MIN, i64::MIN);
+        let max = c(i64::MAX, i64::MAX);
+        assert_eq!(closest_pair(&[max, min]), Some((min, max)));
+        assert_eq!(
+            closest_pair(&[max, min, c(0, 0), c(i64::MAX, i64::MAX - 1)]),
+            Some((c(i64::MAX, i64::MAX - 1), max))
+        );
+    }
+
+    #[test]
+    fn closest_brute_force() {
+        for (count, range, seed) in [(2, 10, 1), (50, 1000, 2), (500, 100_000, 3), (300, 20, 4)] {
+            let points = points(count, range, seed);
+            let mut expected = u128::MAX;
+            for (index, &a) in points.iter().enumerate() {
+                for &b in &points[index + 1..] {
+                    expected = expected.min(distance_squared(a, b));
+                }
+            }
+            let (a, b) = closest_pair(&points).expect("at least two points");
+            assert!(a <= b);
+            assert_eq!(distance_squared(a, b), expected, "{count} {range} {seed}");
+        }
+    }
+
+    #[test]
+    fn segment() {
+        assert_eq!(points_on_segment(c(0, 0), c(0, 0)), 1);
+        assert_eq!(points_on_segment(c(0, 0), c(3, 0)), 4);
+        assert_eq!(points_on_segment(c(0, -2), c(0, 3)), 6);
+        assert_eq!(points_on_segment(c(0, 0), c(6, 4)), 3);
+        assert_eq!(points_on_segment(c(6, 4), c(0, 0)), 3);
+        assert_eq!(points_on_segment(c(-3, 7), c(2, -8)), 6);
+        assert_eq!(points_on_segment(c(0, 0), c(3, 5)), 2);
+        assert_eq!(points_on_segment(c(i64::MIN, 0), c(i64::MAX, 0)), u64::MAX);
+        assert_eq!(
+            points_on_segment(c(i64::MIN, i64::MIN), c(i64::MAX, i64::MAX)),
+            u64::MAX
+        );
+        assert_eq!(points_on_segment(c(i64::MIN, 0), c(0, 0)), 1 << 63_u32 | 1);
+    }
+}
@@ -1,17 +1,56 @@
 //! contains [`Axis2D`] an enumeration the of the x and y axis.
 
-use std::ops::Not;
+use core::{
+    fmt::{self, Display},
+    ops::Not,
+    str::FromStr,
+};
 
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use utils_lib_derive::Variants;
 
 use super::Coordinate;
-use crate::error::NoneError;
+use crate::error::ParseAxisError;
 
 /// Represent the Axis in 2 dimensions. It can be either in the `x` direction i.e. [`Self::Vertical`]
 /// or the `y` direction, i.e. [`Self::Horizontal`].
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+///
+/// **The names follow the matrix convention**: `x` is the row index, which changes along the
+/// vertical, and `y` is the column index, which changes along the horizontal. So
+/// [`Self::Vertical`] is the `x` coordinate and [`Self::Horizontal`] the `y` coordinate, see
+/// also [`super::CoordinateRange`]. The aliases [`Self::X`] and [`Self::Y`] name the axis by
+/// its coordinate instead, and it is displayed as `"x"` or `"y"`.
+///
+/// | variant              | alias       | coordinate | [`Self::letter`] | index |
+/// |----------------------|-------------|------------|------------------|-------|
+/// | [`Self::Vertical`]   | [`Self::X`] | `x`        | `'x'`            | 0     |
+/// | [`Self::Horizontal`] | [`Self::Y`] | `y`        | `'y'`            | 1     |
+///
+/// The list of the axis, the conversion from and to an index and `TryFrom<usize>` are given
+/// by the derive [`Variants`].
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::Axis2D;
+///
+/// assert_eq!(Axis2D::VARIANTS, [Axis2D::Vertical, Axis2D::Horizontal]);
+/// assert_eq!(Axis2D::from_index(0), Some(Axis2D::Vertical));
+/// assert_eq!(Axis2D::from_index(1), Some(Axis2D::Horizontal));
+/// assert_eq!(Axis2D::from_index(2), None);
+/// assert_eq!(Axis2D::Vertical.to_index(), 0);
+/// assert_eq!(Axis2D::Horizontal.to_index(), 1);
+///
+/// assert_eq!(Axis2D::X, Axis2D::Vertical);
+/// assert_eq!(Axis2D::Y.to_string(), "y");
+/// assert_eq!("vertical".parse(), Ok(Axis2D::X));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default, Variants)]
+#[variants(error = "crate::error::NoneError")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
 #[allow(clippy::exhaustive_enums)] // reason = "no more variant possible"
 pub enum Axis2D {
     /// X axis
@@ -22,46 +61,31 @@ pub enum Axis2D {
 }
 
 impl Axis2D {
-    /// All the possible axis
-    pub const AXIS: [Self; 2] = [Self::Vertical, Self::Horizontal];
+    /// All the possible axis, see [`Self::VARIANTS`].
+    pub const AXIS: [Self; 2] = Self::VARIANTS;
 
-    /// Convert an index into an [`Axis2D`]
-    ///
-    /// # Example
-    /// ```
-    /// use utils_lib::coordinate::Axis2D;
-    ///
-    /// assert_eq!(Axis2D::from_index(0), Some(Axis2D::Vertical));
-    /// assert_eq!(Axis2D::from_index(1), Some(Axis2D::Horizontal));
-    /// assert_eq!(Axis2D::from_index(2), None);
-    /// assert_eq!(Axis2D::from_index(3), None);
-    /// //...
-    /// ```
-    #[inline]
-    #[must_use]
-    pub const fn from_index(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::Vertical),
-            1 => Some(Self::Horizontal),
-            _ => None,
-        }
-    }
+    /// The axis of the `x` coordinate, an alias of [`Self::Vertical`].
+    pub const X: Self = Self::Vertical;
 
-    /// Convert an [`Axis2D`] into an index
+    /// The axis of the `y` coordinate, an alias of [`Self::Horizontal`].
+    pub const Y: Self = Self::Horizontal;
+
+    /// Get the letter of the coordinate of the axis, `'x'` for [`Self::Vertical`] and `'y'`
+    /// for [`Self::Horizontal`].
     ///
     /// # Example
     /// ```
     /// use utils_lib::coordinate::Axis2D;
     ///
-    /// assert_eq!(Axis2D::Vertical.to_index(), 0);
-    /// assert_eq!(Axis2D::Horizontal.to_index(), 1);
+    /// assert_eq!(Axis2D::Vertical.letter(), 'x');
+    /// assert_eq!(Axis2D::Y.letter(), 'y');
     /// ```
     #[inline]
     #[must_use]
-    pub const fn to_index(self) -> usize {
+    pub const fn letter(self) -> char {
         match self {
-            Self::Vertical => 0,
-            Self::Horizontal => 1,
+            Self::Vertical => 'x',
+            Self::Horizontal => 'y',
         }
     }
 
@@ -118,6 +142,31 @@ impl Axis2D {
             Self::Horizontal => Coordinate::new(0, 1),
         }
     }
+
+    /// Take the coordinate of the axis out of a [`Coordinate`]. It is the consuming version
+    /// of [`Coordinate::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::{Axis2D, Coordinate};
+    ///
+    /// assert_eq!(
+    ///     Axis2D::Vertical.select(Coordinate::new(1_i32, 2_i32)),
+    ///     1_i32
+    /// );
+    /// assert_eq!(
+    ///     Axis2D::Horizontal.select(Coordinate::new(1.5_f64, 2.5_f64)),
+    ///     2.5_f64
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn select<T>(self, coord: Coordinate<T>) -> T {
+        match self {
+            Self::Vertical => coord.x,
+            Self::Horizontal => coord.y,
+        }
+    }
 }
 
 /// private functions for iterator
@@ -171,13 +220,28 @@ impl From<Axis2D> for Coordinate<usize> {
     }
 }
 
-impl TryFrom<usize> for Axis2D {
-    // TODO
-    type Error = NoneError;
+/// Display the letter of the coordinate of the axis, see [`Axis2D::letter`].
+impl Display for Axis2D {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+/// Parse `"x"` or `"vertical"` as [`Axis2D::Vertical`] and `"y"` or `"horizontal"` as
+/// [`Axis2D::Horizontal`], ignoring the ASCII case.
+impl FromStr for Axis2D {
+    type Err = ParseAxisError;
 
     #[inline]
-    fn try_from(value: usize) -> Result<Self, Self::Error> {
-        Self::from_index(value).ok_or(NoneError)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("x") || s.eq_ignore_ascii_case("vertical") {
+            Ok(Self::Vertical)
+        } else if s.eq_ignore_ascii_case("y") || s.eq_ignore_ascii_case("horizontal") {
+            Ok(Self::Horizontal)
+        } else {
+            Err(ParseAxisError)
+        }
     }
 }
 
@@ -191,6 +255,7 @@ impl AsRef<usize> for Axis2D {
 #[cfg(test)]
 mod test {
     use super::Axis2D;
+    use crate::error::ParseAxisError;
 
     #[test]
     fn axis_2d_iter() {
@@ -204,4 +269,43 @@ mod test {
         );
         assert_eq!(Axis2D::next_back(Some(Axis2D::Vertical)), None);
     }
+
+    #[test]
+    fn names() {
+        assert_eq!(Axis2D::X, Axis2D::Vertical);
+        assert_eq!(Axis2D::Y, Axis2D::Horizontal);
+        assert_eq!(Axis2D::X.letter(), 'x');
+        assert_eq!(Axis2D::Y.letter(), 'y');
+        assert_eq!(Axis2D::X.coordinate_usize(), crate::Coordinate::new(1, 0));
+
+        for (axis, string) in [(Axis2D::X, "x"), (Axis2D::Y, "y")] {
+            assert_eq!(format!("{axis}"), string);
+            assert_eq!(string.parse(), Ok(axis));
+        }
+        for axis in Axis2D::AXIS {
+            assert_eq!(axis.to_string().parse(), Ok(axis));
+        }
+        assert_eq!("vertical".parse(), Ok(Axis2D::Vertical));
+        assert_eq!("horizontal".parse(), Ok(Axis2D::Horizontal));
+        assert_eq!("X".parse(), Ok(Axis2D::Vertical));
+        assert_eq!("Horizontal".parse(), Ok(Axis2D::Horizontal));
+
+        for string in ["", "z", " x", "xy", "vert", "column"] {
+            assert_eq!(string.parse::<Axis2D>(), Err(ParseAxisError));
+        }
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh() {
+        for (axis, byte) in [(Axis2D::Vertical, 0_u8), (Axis2D::Horizontal, 1_u8)] {
+            let bytes = borsh::to_vec(&axis).expect("serializable");
+            assert_eq!(bytes, vec![byte]);
+            assert_eq!(
+                borsh::from_slice::<Axis2D>(&bytes).expect("valid bytes"),
+                axis
+            );
+        }
+        borsh::from_slice::<Axis2D>(&[2_u8]).expect_err("invalid axis");
+    }
 }
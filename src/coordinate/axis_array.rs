@@ -0,0 +1,243 @@
+//! Contains [`AxisArray`], an array of two values indexed by [`Axis2D`].
+
+use core::{
+    array,
+    ops::{Deref, DerefMut, Index, IndexMut},
+    slice,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{Axis2D, Coordinate};
+
+/// An array of two values, one per axis, that can be indexed by [`Axis2D`] or by [`usize`].
+/// The value of the [`Axis2D::Vertical`] axis is the first one.
+///
+/// It is a newtype as the coherence rules forbid implementing [`Index<Axis2D>`] for
+/// `[T; 2]`. It dereferences to the array so the array and slice methods are available.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{Axis2D, AxisArray, Coordinate};
+///
+/// let mut speed = AxisArray([1_f64, 2_f64]);
+/// speed[Axis2D::Horizontal] *= 2_f64;
+/// assert_eq!(speed[Axis2D::Vertical], 1_f64);
+/// assert_eq!(speed[1], 4_f64);
+/// assert_eq!(Coordinate::from(speed), Coordinate::new(1_f64, 4_f64));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxisArray<T>(pub [T; 2]);
+
+impl<T> AxisArray<T> {
+    /// Create the array from the value of the vertical (x) axis and of the horizontal (y) axis.
+    #[inline]
+    #[must_use]
+    pub const fn new(vertical: T, horizontal: T) -> Self {
+        Self([vertical, horizontal])
+    }
+
+    /// Apply a function to both values.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::AxisArray;
+    ///
+    /// assert_eq!(
+    ///     AxisArray([1_u8, 2_u8]).map(u32::from),
+    ///     AxisArray([1_u32, 2_u32])
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> AxisArray<U> {
+        AxisArray(self.0.map(f))
+    }
+
+    /// Iterator over references of the values, the vertical axis first.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Iterator over mutable references of the values, the vertical axis first.
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    /// Get the inner array.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> [T; 2] {
+        self.0
+    }
+}
+
+impl<T> Index<Axis2D> for AxisArray<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Axis2D) -> &Self::Output {
+        &self.0[index.to_index()]
+    }
+}
+
+impl<T> IndexMut<Axis2D> for AxisArray<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Axis2D) -> &mut Self::Output {
+        &mut self.0[index.to_index()]
+    }
+}
+
+impl<T> Index<usize> for AxisArray<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T> IndexMut<usize> for AxisArray<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T> Deref for AxisArray<T> {
+    type Target = [T; 2];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for AxisArray<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<[T; 2]> for AxisArray<T> {
+    #[inline]
+    fn from(value: [T; 2]) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<AxisArray<T>> for [T; 2] {
+    #[inline]
+    fn from(value: AxisArray<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> From<Coordinate<T>> for AxisArray<T> {
+    #[inline]
+    fn from(value: Coordinate<T>) -> Self {
+        Self(value.into_array())
+    }
+}
+
+impl<T> From<AxisArray<T>> for Coordinate<T> {
+    #[inline]
+    fn from(value: AxisArray<T>) -> Self {
+        let [x, y] = value.0;
+        Self::new(x, y)
+    }
+}
+
+impl<T> IntoIterator for AxisArray<T> {
+    type Item = T;
+    type IntoIter = array::IntoIter<T, 2>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a AxisArray<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut AxisArray<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AxisArray;
+    use crate::coordinate::{Axis2D, Coordinate};
+
+    #[test]
+    fn index() {
+        let mut array = AxisArray::new(1_i32, 2_i32);
+        assert_eq!(array, AxisArray([1_i32, 2_i32]));
+        assert_eq!(array[Axis2D::Vertical], 1_i32);
+        assert_eq!(array[Axis2D::Horizontal], 2_i32);
+        assert_eq!(array[0], 1_i32);
+        assert_eq!(array[1], 2_i32);
+
+        array[Axis2D::Vertical] = -1_i32;
+        array[1] += 3_i32;
+        assert_eq!(array.into_inner(), [-1_i32, 5_i32]);
+
+        for axis in Axis2D::AXIS {
+            array[axis] *= 2_i32;
+        }
+        assert_eq!(array, AxisArray([-2_i32, 10_i32]));
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.iter().sum::<i32>(), 8_i32);
+    }
+
+    #[test]
+    fn conversion() {
+        let coord = Coordinate::new(3_u8, 4_u8);
+        let array = AxisArray::from(coord);
+        assert_eq!(array[Axis2D::Vertical], *coord.x());
+        assert_eq!(array[Axis2D::Horizontal], *coord.y());
+        for axis in Axis2D::AXIS {
+            assert_eq!(array[axis], coord[axis]);
+        }
+        assert_eq!(Coordinate::from(array), coord);
+
+        assert_eq!(AxisArray::from([5_u8, 6_u8]), AxisArray::new(5_u8, 6_u8));
+        assert_eq!(<[u8; 2]>::from(array), [3_u8, 4_u8]);
+        assert_eq!(array.map(u16::from), AxisArray([3_u16, 4_u16]));
+    }
+
+    #[test]
+    fn iter() {
+        let mut array = AxisArray([1_u32, 2_u32]);
+        for value in &mut array {
+            *value += 1_u32;
+        }
+        assert_eq!(
+            (&array).into_iter().copied().collect::<Vec<_>>(),
+            vec![2_u32, 3_u32]
+        );
+        assert_eq!(
+            array.into_iter().rev().collect::<Vec<_>>(),
+            vec![3_u32, 2_u32]
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Contains the display helper returned by [`super::Coordinate::display_padded`].
+
+use core::fmt::{self, Display};
+
+use super::Coordinate;
+
+/// Display a [`Coordinate`] with each component right-aligned in a field of a fixed width,
+/// like `"[  4, 105]"` for a width of 3.
+///
+/// The precision flag is forwarded to the components, the width flag is ignored as the width
+/// is given by the helper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PaddedDisplay<'a, T> {
+    /// the displayed coordinate
+    coordinate: &'a Coordinate<T>,
+    /// minimal width of each component
+    width: usize,
+}
+
+impl<'a, T> PaddedDisplay<'a, T> {
+    /// Create the display helper.
+    pub const fn new(coordinate: &'a Coordinate<T>, width: usize) -> Self {
+        Self { coordinate, width }
+    }
+}
+
+impl<T: Display> Display for PaddedDisplay<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.width;
+        if let Some(precision) = f.precision() {
+            write!(
+                f,
+                "[{:>width$.precision$}, {:>width$.precision$}]",
+                self.coordinate.x(),
+                self.coordinate.y()
+            )
+        } else {
+            write!(
+                f,
+                "[{:>width$}, {:>width$}]",
+                self.coordinate.x(),
+                self.coordinate.y()
+            )
+        }
+    }
+}
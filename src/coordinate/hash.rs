@@ -0,0 +1,218 @@
+//! Contains the packing of [`Coordinate<i32>`] and [`Coordinate<u32>`] into a [`u64`] key,
+//! and [`CoordHasher`], a fast [`BuildHasher`] for these coordinates used by `CoordHashMap`.
+
+use core::hash::{BuildHasher, Hasher};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use super::Coordinate;
+
+/// A [`HashMap`] keyed by [`Coordinate<i32>`] using the fast [`CoordHasher`] instead of
+/// `SipHash`.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{CoordHashMap, Coordinate};
+///
+/// let mut map = CoordHashMap::default();
+/// map.insert(Coordinate::new(-1_i32, 2_i32), "a");
+/// assert_eq!(map.get(&Coordinate::new(-1_i32, 2_i32)), Some(&"a"));
+/// ```
+#[cfg(feature = "std")]
+#[allow(clippy::module_name_repetitions)]
+pub type CoordHashMap<V> = HashMap<Coordinate<i32>, V, CoordHasher>;
+
+impl Coordinate<i32> {
+    /// Pack the two components in a [`u64`], `x` in the 32 high bits and `y` in the 32 low
+    /// bits. The packing is injective, see [`Self::from_key`] for the inverse.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(-1_i32, 2_i32);
+    /// assert_eq!(c.to_key(), 0xFFFF_FFFF_0000_0002);
+    /// assert_eq!(Coordinate::<i32>::from_key(c.to_key()), c);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_key(self) -> u64 {
+        Coordinate::new(self.x.cast_unsigned(), self.y.cast_unsigned()).to_key()
+    }
+
+    /// Unpack a key created by [`Self::to_key`].
+    #[inline]
+    #[must_use]
+    pub const fn from_key(key: u64) -> Self {
+        let coord = Coordinate::<u32>::from_key(key);
+        Self::new(coord.x.cast_signed(), coord.y.cast_signed())
+    }
+}
+
+impl Coordinate<u32> {
+    /// Pack the two components in a [`u64`], `x` in the 32 high bits and `y` in the 32 low
+    /// bits. The packing is injective, see [`Self::from_key`] for the inverse.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(1_u32, 2_u32);
+    /// assert_eq!(c.to_key(), 0x0000_0001_0000_0002);
+    /// assert_eq!(Coordinate::<u32>::from_key(c.to_key()), c);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_key(self) -> u64 {
+        ((self.x as u64) << 32_u32) | self.y as u64
+    }
+
+    /// Unpack a key created by [`Self::to_key`].
+    #[allow(clippy::cast_possible_truncation)] // reason = "the truncation is the unpacking"
+    #[inline]
+    #[must_use]
+    pub const fn from_key(key: u64) -> Self {
+        Self::new((key >> 32_u32) as u32, key as u32)
+    }
+}
+
+/// A [`BuildHasher`] creating [`CoordKeyHasher`], a fast non-cryptographic hasher for
+/// [`Coordinate<i32>`] and [`Coordinate<u32>`].
+///
+/// It is not resistant to collision attacks (`HashDoS`), only use it for keys that are not
+/// controlled by an adversary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CoordHasher;
+
+impl BuildHasher for CoordHasher {
+    type Hasher = CoordKeyHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        CoordKeyHasher::default()
+    }
+}
+
+/// The [`Hasher`] of [`CoordHasher`].
+///
+/// The [`Hash`](std::hash::Hash) implementation of [`Coordinate<i32>`] writes the two
+/// components, which are accumulated in the packed key of [`Coordinate::<i32>::to_key`]. The
+/// key is then mixed in [`Hasher::finish`] so that both the high and the low bits of the hash
+/// depend on both components. It also accepts any other input but it is not optimized for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CoordKeyHasher {
+    /// the accumulated key
+    state: u64,
+}
+
+impl Hasher for CoordKeyHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        // finalizer of `MurmurHash3`, a bijection with a good avalanche
+        let mut hash = self.state;
+        hash ^= hash >> 33_u32;
+        hash = hash.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+        hash ^= hash >> 33_u32;
+        hash = hash.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+        hash ^ (hash >> 33_u32)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state = self.state.rotate_left(8_u32) ^ u64::from(*byte);
+        }
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.state = self.state.rotate_left(32_u32) ^ u64::from(i);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.state = self.state.rotate_left(32_u32) ^ i;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashSet, hash::BuildHasher};
+
+    use super::CoordHasher;
+    use crate::Coordinate;
+
+    #[test]
+    fn key_round_trip() {
+        let values = [
+            0_i32,
+            1_i32,
+            -1_i32,
+            2_i32,
+            -2_i32,
+            i32::MAX,
+            i32::MIN,
+            1_i32 << 16_u32,
+            -(1_i32 << 16_u32),
+        ];
+        for x in values {
+            for y in values {
+                let c = Coordinate::new(x, y);
+                assert_eq!(Coordinate::<i32>::from_key(c.to_key()), c);
+
+                let c = Coordinate::new(x.cast_unsigned(), y.cast_unsigned());
+                assert_eq!(Coordinate::<u32>::from_key(c.to_key()), c);
+            }
+        }
+        assert_eq!(
+            Coordinate::new(0_i32, -1_i32).to_key(),
+            0x0000_0000_FFFF_FFFF
+        );
+        assert_eq!(
+            Coordinate::new(-1_i32, 0_i32).to_key(),
+            0xFFFF_FFFF_0000_0000
+        );
+        assert_eq!(
+            Coordinate::new(i32::MIN, i32::MAX).to_key(),
+            0x8000_0000_7FFF_FFFF
+        );
+    }
+
+    #[test]
+    fn hash_collision() {
+        const SIZE: i32 = 100;
+
+        let mut keys = HashSet::new();
+        let mut hashes = HashSet::new();
+        let mut low_bits = HashSet::new();
+        for x in -SIZE..SIZE {
+            for y in -SIZE..SIZE {
+                let c = Coordinate::new(x, y);
+                assert!(keys.insert(c.to_key()), "key collision for {c:?}");
+                let hash = CoordHasher.hash_one(c);
+                assert!(hashes.insert(hash), "hash collision for {c:?}");
+                low_bits.insert(hash & 0xFFFF);
+            }
+        }
+        // 40 000 values in 65 536 buckets, a good hash fills most of the buckets
+        assert!(low_bits.len() > 25_000, "only {} buckets", low_bits.len());
+
+        // the hash is the mixed packed key
+        let c = Coordinate::new(-3_i32, 7_i32);
+        assert_eq!(CoordHasher.hash_one(c.to_key()), CoordHasher.hash_one(c));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map() {
+        let mut map = super::CoordHashMap::default();
+        for x in -10_i32..10_i32 {
+            for y in -10_i32..10_i32 {
+                map.insert(Coordinate::new(x, y), x * y);
+            }
+        }
+        assert_eq!(map.len(), 400);
+        assert_eq!(map.get(&Coordinate::new(-3_i32, 4_i32)), Some(&-12_i32));
+        assert_eq!(map.get(&Coordinate::new(10_i32, 0_i32)), None);
+    }
+}
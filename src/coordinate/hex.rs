@@ -0,0 +1,601 @@
+//! Hexagonal grids with [`AxialCoordinate`], a coordinate `(q, r)` on a grid of hexagons, and
+//! [`HexDirection`] the six directions to the neighbors of a hexagon.
+//!
+//! The third cube coordinate is `s = -q - r`, see [`AxialCoordinate::to_cube`]. The directions
+//! are named for the pointy-top layout with the `y` axis pointing down, as on a screen, see
+//! [`AxialCoordinate::to_pixel`].
+//!
+//! # Example
+//! ```
+//! use utils_lib::coordinate::hex::{AxialCoordinate, HexDirection};
+//!
+//! let origin = AxialCoordinate::new(0, 0);
+//! let hex = AxialCoordinate::new(2, -1);
+//! assert_eq!(origin.distance(hex), 2);
+//! assert_eq!(
+//!     origin.neighbor(HexDirection::East),
+//!     AxialCoordinate::new(1, 0)
+//! );
+//! assert_eq!(origin.ring(2).count(), 12);
+//! assert!(origin.ring(2).any(|coord| coord == hex));
+//! assert_eq!(hex.rotate(6), hex);
+//! ```
+
+use core::{
+    iter::FusedIterator,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use utils_lib_derive::Variants;
+
+use super::Coordinate;
+use crate::PositiveFloat;
+
+/// `sqrt(3)`, the ratio between the width and the half height of a pointy-top hexagon.
+const SQRT_3: f64 = 1.732_050_807_568_877_2_f64;
+
+/// A coordinate on a grid of hexagons in the axial system, see the [module](self)
+/// documentation.
+///
+/// The arithmetic is the one of [`i64`]: it overflows, and panics in debug, for the
+/// coordinates whose cube coordinate `s = -q - r` does not fit in an [`i64`].
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::hex::AxialCoordinate;
+///
+/// let hex = AxialCoordinate::new(1, -3);
+/// assert_eq!(hex.to_cube(), [1, -3, 2]);
+/// assert_eq!(AxialCoordinate::from_cube([1, -3, 2]), Some(hex));
+/// assert_eq!(AxialCoordinate::from_cube([1, -3, 1]), None);
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+pub struct AxialCoordinate {
+    /// the column coordinate
+    pub q: i64,
+    /// the row coordinate
+    pub r: i64,
+}
+
+impl AxialCoordinate {
+    /// The origin `(0, 0)`.
+    pub const ORIGIN: Self = Self::new(0_i64, 0_i64);
+
+    /// Create a new [`AxialCoordinate`].
+    #[inline]
+    #[must_use]
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Get the third cube coordinate `s = -q - r`.
+    #[inline]
+    #[must_use]
+    pub const fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Convert to the cube coordinate `[q, r, s]` with `q + r + s = 0`.
+    #[inline]
+    #[must_use]
+    pub const fn to_cube(self) -> [i64; 3] {
+        [self.q, self.r, self.s()]
+    }
+
+    /// Convert from the cube coordinate `[q, r, s]`. Returns [`None`] if `q + r + s` is not
+    /// `0`.
+    #[inline]
+    #[must_use]
+    pub const fn from_cube(cube: [i64; 3]) -> Option<Self> {
+        let [q, r, s] = cube;
+        if q + r + s == 0 {
+            Some(Self::new(q, r))
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of steps between two hexagons, `max(|dq|, |dr|, |ds|)`.
+    #[inline]
+    #[must_use]
+    pub const fn distance(self, other: Self) -> u64 {
+        let dq = self.q.abs_diff(other.q);
+        let dr = self.r.abs_diff(other.r);
+        let ds = self.s().abs_diff(other.s());
+        let max = if dq > dr { dq } else { dr };
+        if max > ds {
+            max
+        } else {
+            ds
+        }
+    }
+
+    /// [`Add`] usable in a const context.
+    #[inline]
+    #[must_use]
+    const fn add_const(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+
+    /// Get the neighbor in the given direction.
+    #[inline]
+    #[must_use]
+    pub const fn neighbor(self, direction: HexDirection) -> Self {
+        self.add_const(direction.offset())
+    }
+
+    /// Get the six neighbors, in the order of [`HexDirection::VARIANTS`].
+    #[inline]
+    #[must_use]
+    pub fn neighbors(self) -> [Self; 6] {
+        HexDirection::VARIANTS.map(|direction| self.neighbor(direction))
+    }
+
+    /// Multiply both coordinates by `factor`.
+    #[inline]
+    #[must_use]
+    pub const fn scale(self, factor: i64) -> Self {
+        Self::new(self.q * factor, self.r * factor)
+    }
+
+    /// Rotate by 60° clockwise around the origin, `(q, r, s)` becomes `(-r, -s, -q)`.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_clockwise(self) -> Self {
+        Self::new(-self.r, -self.s())
+    }
+
+    /// Rotate by 60° counterclockwise around the origin, `(q, r, s)` becomes `(-s, -q, -r)`.
+    #[inline]
+    #[must_use]
+    pub const fn rotate_counterclockwise(self) -> Self {
+        Self::new(-self.s(), -self.q)
+    }
+
+    /// Rotate by `steps` times 60° clockwise around the origin, counterclockwise if `steps` is
+    /// negative. Six steps give back the same coordinate.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::hex::AxialCoordinate;
+    ///
+    /// let hex = AxialCoordinate::new(2, -1);
+    /// assert_eq!(hex.rotate(1), hex.rotate_clockwise());
+    /// assert_eq!(hex.rotate(-1), hex.rotate_counterclockwise());
+    /// assert_eq!(hex.rotate(3), -hex);
+    /// assert_eq!(hex.rotate(-6), hex);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rotate(self, steps: i64) -> Self {
+        let mut out = self;
+        let mut remaining = steps.rem_euclid(6_i64);
+        while remaining > 0_i64 {
+            out = out.rotate_clockwise();
+            remaining -= 1_i64;
+        }
+        out
+    }
+
+    /// Iterate over the hexagons at the distance `radius`, there are `6 * radius` of them.
+    /// The ring of radius `0` is the hexagon itself.
+    ///
+    /// The ring starts at `self + radius * SouthWest` and goes counterclockwise.
+    #[inline]
+    #[must_use]
+    pub const fn ring(self, radius: u32) -> HexRing {
+        HexRing::new(self, radius)
+    }
+
+    /// Iterate over the hexagons at a distance at most `radius`, ring by ring from the center,
+    /// see [`Self::ring`]. There are `1 + 3 * radius * (radius + 1)` of them.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::hex::AxialCoordinate;
+    ///
+    /// let center = AxialCoordinate::new(3, 4);
+    /// assert_eq!(center.spiral(2).count(), 19);
+    /// assert_eq!(center.spiral(2).next(), Some(center));
+    /// assert!(center.spiral(2).all(|hex| center.distance(hex) <= 2));
+    /// ```
+    #[inline]
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |radius| self.ring(radius))
+    }
+
+    /// Get the position of the center of the hexagon, with the origin at the center of
+    /// [`Self::ORIGIN`]. `size` is the distance between the center and a corner of a hexagon.
+    ///
+    /// The `y` axis points down, like on a screen.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{
+    ///     coordinate::{
+    ///         hex::{AxialCoordinate, HexOrientation},
+    ///         Coordinate,
+    ///     },
+    ///     PositiveFloat,
+    /// };
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let size = PositiveFloat::new(2_f64)?;
+    /// let hex = AxialCoordinate::new(0, 1);
+    /// assert_eq!(
+    ///     hex.to_pixel(size, HexOrientation::FlatTop),
+    ///     Coordinate::new(0_f64, 2_f64 * 3_f64.sqrt())
+    /// );
+    /// assert_eq!(hex.to_pixel(size, HexOrientation::PointyTop).y, 3_f64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_pixel(self, size: PositiveFloat, orientation: HexOrientation) -> Coordinate<f64> {
+        #[allow(clippy::cast_precision_loss)]
+        // reason = "the coordinates of a drawn grid are small"
+        let (q, r) = (self.q as f64, self.r as f64);
+        let size = size.float();
+        match orientation {
+            HexOrientation::PointyTop => {
+                Coordinate::new(size * SQRT_3 * (q + r / 2_f64), size * 1.5_f64 * r)
+            }
+            HexOrientation::FlatTop => {
+                Coordinate::new(size * 1.5_f64 * q, size * SQRT_3 * (r + q / 2_f64))
+            }
+        }
+    }
+}
+
+impl From<HexDirection> for AxialCoordinate {
+    #[inline]
+    fn from(value: HexDirection) -> Self {
+        value.offset()
+    }
+}
+
+impl Add for AxialCoordinate {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_const(rhs)
+    }
+}
+
+impl AddAssign for AxialCoordinate {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for AxialCoordinate {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for AxialCoordinate {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for AxialCoordinate {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+/// The six directions from a hexagon to its neighbors, named for the pointy-top layout and
+/// listed counterclockwise from [`Self::East`] by [`Self::VARIANTS`].
+///
+/// | variant               | offset `(q, r)` | index |
+/// |-----------------------|-----------------|-------|
+/// | [`Self::East`]        | `(1, 0)`        | 0     |
+/// | [`Self::NorthEast`]   | `(1, -1)`       | 1     |
+/// | [`Self::NorthWest`]   | `(0, -1)`       | 2     |
+/// | [`Self::West`]        | `(-1, 0)`       | 3     |
+/// | [`Self::SouthWest`]   | `(-1, 1)`       | 4     |
+/// | [`Self::SouthEast`]   | `(0, 1)`        | 5     |
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default, Variants)]
+#[variants(error = "crate::error::NoneError")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[allow(clippy::exhaustive_enums)] // reason = "a hexagon has six neighbors"
+pub enum HexDirection {
+    /// `(1, 0)`
+    #[default]
+    East,
+    /// `(1, -1)`
+    NorthEast,
+    /// `(0, -1)`
+    NorthWest,
+    /// `(-1, 0)`
+    West,
+    /// `(-1, 1)`
+    SouthWest,
+    /// `(0, 1)`
+    SouthEast,
+}
+
+impl HexDirection {
+    /// Get the offset to the neighbor in this direction.
+    #[inline]
+    #[must_use]
+    pub const fn offset(self) -> AxialCoordinate {
+        match self {
+            Self::East => AxialCoordinate::new(1_i64, 0_i64),
+            Self::NorthEast => AxialCoordinate::new(1_i64, -1_i64),
+            Self::NorthWest => AxialCoordinate::new(0_i64, -1_i64),
+            Self::West => AxialCoordinate::new(-1_i64, 0_i64),
+            Self::SouthWest => AxialCoordinate::new(-1_i64, 1_i64),
+            Self::SouthEast => AxialCoordinate::new(0_i64, 1_i64),
+        }
+    }
+
+    /// Get the opposite direction.
+    #[inline]
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::East => Self::West,
+            Self::NorthEast => Self::SouthWest,
+            Self::NorthWest => Self::SouthEast,
+            Self::West => Self::East,
+            Self::SouthWest => Self::NorthEast,
+            Self::SouthEast => Self::NorthWest,
+        }
+    }
+
+    /// Get the next direction counterclockwise.
+    #[inline]
+    #[must_use]
+    pub const fn next_counterclockwise(self) -> Self {
+        match self {
+            Self::East => Self::NorthEast,
+            Self::NorthEast => Self::NorthWest,
+            Self::NorthWest => Self::West,
+            Self::West => Self::SouthWest,
+            Self::SouthWest => Self::SouthEast,
+            Self::SouthEast => Self::East,
+        }
+    }
+}
+
+impl Neg for HexDirection {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.opposite()
+    }
+}
+
+/// The orientation of the hexagons of a grid, used by [`AxialCoordinate::to_pixel`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[allow(clippy::exhaustive_enums)] // reason = "a hexagon has a corner or a side on top"
+pub enum HexOrientation {
+    /// A corner on top, the rows are horizontal.
+    #[default]
+    PointyTop,
+    /// A side on top, the columns are vertical.
+    FlatTop,
+}
+
+/// [`Iterator`] over the hexagons of a ring, see [`AxialCoordinate::ring`].
+///
+/// Also implement [`FusedIterator`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct HexRing {
+    /// the next hexagon
+    current: AxialCoordinate,
+    /// the direction of the current side of the ring
+    direction: HexDirection,
+    /// the number of steps done on the current side
+    step: u32,
+    /// the radius of the ring
+    radius: u32,
+    /// the number of remaining hexagons
+    remaining: u64,
+}
+
+impl HexRing {
+    /// Create the iterator over the ring of the given radius around `center`.
+    #[inline]
+    #[must_use]
+    const fn new(center: AxialCoordinate, radius: u32) -> Self {
+        let remaining = if radius == 0 {
+            1_u64
+        } else {
+            6_u64 * radius as u64
+        };
+        Self {
+            current: center.add_const(HexDirection::SouthWest.offset().scale(radius as i64)),
+            direction: HexDirection::East,
+            step: 0_u32,
+            radius,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for HexRing {
+    type Item = AxialCoordinate;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let out = self.current;
+        if self.remaining > 0 {
+            self.current = self.current.neighbor(self.direction);
+            self.step += 1;
+            if self.step == self.radius {
+                self.step = 0;
+                self.direction = self.direction.next_counterclockwise();
+            }
+        }
+        Some(out)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        usize::try_from(self.remaining).map_or((usize::MAX, None), |len| (len, Some(len)))
+    }
+}
+
+impl FusedIterator for HexRing {}
+
+#[cfg(test)]
+mod test {
+    use super::{AxialCoordinate, HexDirection, HexOrientation};
+    use crate::{number::PositiveFloatConversionError, PositiveFloat};
+
+    /// A few hexagons around the origin.
+    fn samples() -> impl Iterator<Item = AxialCoordinate> {
+        AxialCoordinate::new(-2, 5).spiral(4)
+    }
+
+    #[test]
+    fn distance() {
+        for a in samples() {
+            assert_eq!(a.distance(a), 0);
+            for b in samples() {
+                assert_eq!(a.distance(b), b.distance(a));
+                assert_eq!(a.distance(b), (a - b).distance(AxialCoordinate::ORIGIN));
+            }
+            for direction in HexDirection::VARIANTS {
+                assert_eq!(a.distance(a.neighbor(direction)), 1);
+                assert_eq!(a.neighbor(direction).neighbor(-direction), a);
+            }
+        }
+        assert_eq!(
+            AxialCoordinate::new(0, 0).distance(AxialCoordinate::new(3, -1)),
+            3
+        );
+        assert_eq!(
+            AxialCoordinate::new(-1, -1).distance(AxialCoordinate::new(2, 1)),
+            5
+        );
+    }
+
+    #[test]
+    fn ring() {
+        let center = AxialCoordinate::new(7, -3);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), [center]);
+        for radius in 1_u32..10_u32 {
+            let ring = center.ring(radius).collect::<Vec<_>>();
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert_eq!(
+                center.ring(radius).size_hint(),
+                (ring.len(), Some(ring.len()))
+            );
+            assert!(ring
+                .iter()
+                .all(|hex| center.distance(*hex) == u64::from(radius)));
+            // consecutive hexagons are neighbors and the ring is closed
+            for (index, hex) in ring.iter().enumerate() {
+                assert_eq!(hex.distance(ring[(index + 1) % ring.len()]), 1);
+            }
+            let mut sorted = ring.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), ring.len());
+        }
+        assert_eq!(
+            center.ring(1).collect::<Vec<_>>(),
+            [
+                HexDirection::SouthWest,
+                HexDirection::SouthEast,
+                HexDirection::East,
+                HexDirection::NorthEast,
+                HexDirection::NorthWest,
+                HexDirection::West,
+            ]
+            .map(|direction| center.neighbor(direction))
+        );
+        assert_eq!(center.spiral(3).count(), 37);
+    }
+
+    #[test]
+    fn rotation() {
+        for hex in samples() {
+            assert_eq!(hex.rotate(6), hex);
+            assert_eq!(hex.rotate(0), hex);
+            assert_eq!(hex.rotate(-6), hex);
+            assert_eq!(hex.rotate(3), -hex);
+            assert_eq!(hex.rotate_clockwise().rotate_counterclockwise(), hex);
+            assert_eq!(hex.rotate(2), hex.rotate(-4));
+            assert_eq!(
+                hex.rotate(1).distance(AxialCoordinate::ORIGIN),
+                hex.distance(AxialCoordinate::ORIGIN)
+            );
+            let mut rotated = hex;
+            for _ in 0_u8..6_u8 {
+                rotated = rotated.rotate_counterclockwise();
+            }
+            assert_eq!(rotated, hex);
+        }
+        for direction in HexDirection::VARIANTS {
+            assert_eq!(
+                AxialCoordinate::from(direction).rotate_counterclockwise(),
+                direction.next_counterclockwise().into()
+            );
+        }
+    }
+
+    #[test]
+    fn cube() {
+        for hex in samples() {
+            let [q, r, s] = hex.to_cube();
+            assert_eq!(q + r + s, 0);
+            assert_eq!(AxialCoordinate::from_cube(hex.to_cube()), Some(hex));
+        }
+        assert_eq!(AxialCoordinate::from_cube([1, 1, 1]), None);
+    }
+
+    #[test]
+    fn to_pixel() -> Result<(), PositiveFloatConversionError> {
+        let size = PositiveFloat::new(1_f64)?;
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let origin = AxialCoordinate::ORIGIN.to_pixel(size, orientation);
+            assert_eq!((origin.x, origin.y), (0_f64, 0_f64));
+            // the centers of two neighbors are at a distance sqrt(3) * size
+            for direction in HexDirection::VARIANTS {
+                let pixel = AxialCoordinate::from(direction).to_pixel(size, orientation);
+                assert!((pixel.x.hypot(pixel.y) - 3_f64.sqrt()).abs() < 1E-12_f64);
+            }
+        }
+        let east =
+            AxialCoordinate::from(HexDirection::East).to_pixel(size, HexOrientation::PointyTop);
+        assert!(east.x > 0_f64 && east.y.abs() < 1E-12_f64);
+        let north_west = AxialCoordinate::from(HexDirection::NorthWest)
+            .to_pixel(size, HexOrientation::PointyTop);
+        assert!(north_west.x < 0_f64 && north_west.y < 0_f64);
+        Ok(())
+    }
+}
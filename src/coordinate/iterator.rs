@@ -2,19 +2,25 @@
 //! It is called by [`Coordinate::into_iter`], [`Coordinate::iter`]
 //! and [`Coordinate::iter_mut`].
 
-use std::iter::FusedIterator;
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::{Axis2D, Coordinate};
 
-/// [`Iterator`] on a coordinate [`Coordinate`]. It is the type return by [`Coordinate::into_iter`]
-/// (and [`Coordinate::iter`] and [`Coordinate::iter_mut`] thought behind implicit type) .
+/// [`Iterator`] on a coordinate [`Coordinate`]. It is the type return by [`Coordinate::into_iter`],
+/// [`Coordinate::iter`] and [`Coordinate::iter_mut`].
 ///
 /// Also implement [`DoubleEndedIterator`], [`FusedIterator`] and [`ExactSizeIterator`].
+///
+/// The [`Debug`] implementation shows the remaining elements, like `CoordinateIterator(2)`
+/// after one call to [`Iterator::next`] on the iterator of `Coordinate(1, 2)`.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, Eq, PartialEq, Hash)] // it should not be copy as it is an iterator (clippy::copy_iterator)
+#[derive(Clone, Eq, PartialEq, Hash)] // it should not be copy as it is an iterator (clippy::copy_iterator)
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoordinateIterator<T> {
     /// the storage of the iterator. As an [`Option`] in order to be able to move T and
@@ -58,6 +64,18 @@ impl<T> CoordinateIterator<T> {
     }
 }
 
+/// Show the remaining elements of the iterator.
+impl<T: Debug> Debug for CoordinateIterator<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("CoordinateIterator");
+        for element in self.as_ref() {
+            debug_tuple.field(element);
+        }
+        debug_tuple.finish()
+    }
+}
+
 /// Used for [`CoordinateIterator::new`].
 impl<T> From<Coordinate<T>> for Coordinate<Option<T>> {
     #[inline]
@@ -251,6 +269,62 @@ mod test {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn named_iterator() {
+        /// A struct storing the iterators by name
+        struct Holder<'a> {
+            /// iterator by reference
+            iter: CoordinateIterator<&'a u8>,
+            /// iterator by mutable reference
+            iter_mut: CoordinateIterator<&'a mut u16>,
+        }
+
+        let coord = Coordinate::new(1_u8, 2_u8);
+        let mut coord_mut = Coordinate::new(3_u16, 4_u16);
+        let mut holder = Holder {
+            iter: coord.iter(),
+            iter_mut: coord_mut.iter_mut(),
+        };
+
+        assert_eq!(holder.iter.len(), 2);
+        assert_eq!(holder.iter.next(), Some(&1_u8));
+        assert_eq!(holder.iter.len(), 1);
+        assert_eq!(holder.iter.next_back(), Some(&2_u8));
+        assert_eq!(holder.iter.len(), 0);
+        assert_eq!(holder.iter.next(), None);
+        assert_eq!(holder.iter.len(), 0);
+
+        assert_eq!(holder.iter_mut.len(), 2);
+        if let Some(y) = holder.iter_mut.next_back() {
+            *y += 10_u16;
+        }
+        assert_eq!(holder.iter_mut.len(), 1);
+        if let Some(x) = holder.iter_mut.next() {
+            *x += 10_u16;
+        }
+        assert_eq!(holder.iter_mut.len(), 0);
+        assert_eq!(holder.iter_mut.next_back(), None);
+        assert_eq!(coord_mut, Coordinate::new(13_u16, 14_u16));
+    }
+
+    #[test]
+    fn debug() {
+        let mut iter = Coordinate::new(1_u8, 2_u8).into_iter();
+        assert_eq!(format!("{iter:?}"), "CoordinateIterator(1, 2)");
+        assert_eq!(
+            format!("{iter:#?}"),
+            "CoordinateIterator(\n    1,\n    2,\n)"
+        );
+        assert_eq!(iter.next(), Some(1_u8));
+        assert_eq!(format!("{iter:?}"), "CoordinateIterator(2)");
+        assert_eq!(iter.next_back(), Some(2_u8));
+        assert_eq!(format!("{iter:?}"), "CoordinateIterator");
+
+        let mut iter = Coordinate::new(1_u8, 2_u8).into_iter();
+        assert_eq!(iter.next_back(), Some(2_u8));
+        assert_eq!(format!("{iter:?}"), "CoordinateIterator(1)");
+    }
+
     #[test]
     fn conversion_iter() {
         let c = Coordinate::new(0_usize, 1_usize);
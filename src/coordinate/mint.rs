@@ -0,0 +1,157 @@
+//! Contains the conversions between [`Coordinate`] and the [`mint`] interchange types
+//! [`Point2`] and [`Vector2`], used by `glam` and most of the graphics ecosystem.
+//!
+//! The module requires the `mint` feature.
+
+use mint::{Point2, Vector2};
+
+use super::Coordinate;
+
+impl<T> From<Coordinate<T>> for Point2<T> {
+    #[inline]
+    fn from(value: Coordinate<T>) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl<T> From<Point2<T>> for Coordinate<T> {
+    #[inline]
+    fn from(value: Point2<T>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl<T> From<Coordinate<T>> for Vector2<T> {
+    #[inline]
+    fn from(value: Coordinate<T>) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl<T> From<Vector2<T>> for Coordinate<T> {
+    #[inline]
+    fn from(value: Vector2<T>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl Coordinate<f64> {
+    /// Convert into a [`Point2<f32>`], the single precision point most graphics code expects.
+    ///
+    /// The components are cast with `as`: they are rounded to the nearest [`f32`], the values
+    /// out of the range of [`f32`] give an infinity and [`f64::NAN`] stays NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let point = Coordinate::new(0.5_f64, 1E300_f64).to_mint_point_f32();
+    /// assert_eq!(point.x, 0.5_f32);
+    /// assert_eq!(point.y, f32::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // reason = "the cast is documented"
+    pub const fn to_mint_point_f32(self) -> Point2<f32> {
+        Point2 {
+            x: self.x as f32,
+            y: self.y as f32,
+        }
+    }
+}
+
+impl Coordinate<f32> {
+    /// Convert into a [`Point2<f64>`], the conversion is lossless.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let point = Coordinate::new(0.1_f32, -2_f32).to_mint_point_f64();
+    /// assert_eq!(point.x, f64::from(0.1_f32));
+    /// assert_eq!(point.y, -2_f64);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_mint_point_f64(self) -> Point2<f64> {
+        Point2 {
+            x: f64::from(self.x),
+            y: f64::from(self.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mint::{Point2, Vector2};
+
+    use crate::Coordinate;
+
+    #[test]
+    fn point() {
+        let coordinate = Coordinate::new(1_i32, -2_i32);
+        let point = Point2::from(coordinate);
+        assert_eq!(point.x, 1_i32);
+        assert_eq!(point.y, -2_i32);
+        assert_eq!(Coordinate::from(point), coordinate);
+
+        let point = Point2 { x: 3_u8, y: 4_u8 };
+        assert_eq!(Coordinate::from(point), Coordinate::new(3_u8, 4_u8));
+        assert_eq!(Point2::from(Coordinate::from(point)), point);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "the values are exactly representable"
+    fn vector() {
+        let coordinate = Coordinate::new(1.5_f32, -2_f32);
+        let vector = Vector2::from(coordinate);
+        assert_eq!(
+            vector,
+            Vector2 {
+                x: 1.5_f32,
+                y: -2_f32
+            }
+        );
+        assert_eq!(Coordinate::from(vector), coordinate);
+
+        let vector = Vector2 { x: 3_i64, y: 4_i64 };
+        assert_eq!(Coordinate::from(vector), Coordinate::new(3_i64, 4_i64));
+        assert_eq!(Vector2::from(Coordinate::from(vector)), vector);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "the values are exactly representable"
+    fn float_cast() {
+        let point = Coordinate::new(0.25_f64, -3_f64).to_mint_point_f32();
+        assert_eq!(
+            point,
+            Point2 {
+                x: 0.25_f32,
+                y: -3_f32
+            }
+        );
+        assert!(Coordinate::new(f64::NAN, 0_f64)
+            .to_mint_point_f32()
+            .x
+            .is_nan());
+        assert_eq!(
+            Coordinate::new(-1E300_f64, 0_f64).to_mint_point_f32().x,
+            f32::NEG_INFINITY
+        );
+
+        let point = Coordinate::new(0.25_f32, -3_f32).to_mint_point_f64();
+        assert_eq!(
+            point,
+            Point2 {
+                x: 0.25_f64,
+                y: -3_f64
+            }
+        );
+    }
+}
@@ -1,29 +1,80 @@
 //! Module containing [`Coordinate`] a 2d coordinate and [`Axis2D`] an enumeration
-//! of the x and y axis.
+//! of the x and y axis, with [`AxisArray`] an array indexed by it. Path finding on a grid of coordinates is in [`search`],
+//! geometry algorithms on integer points like the convex hull are in [`algorithms`]. Hexagonal
+//! grids are in [`hex`]. Coordinates as the string keys of a serialized map are in
+//! [`serde_string_key`].
 
+#[cfg(feature = "alloc")]
+pub mod algorithms;
 mod axis_2d;
+mod axis_array;
+mod display;
+mod hash;
+pub mod hex;
 mod iterator;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "rand")]
+mod rand;
+mod range;
+#[cfg(feature = "std")]
+pub mod search;
+#[cfg(feature = "serde")]
+pub mod serde_string_key;
 
-use std::{
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
     fmt::{
-        self, Binary, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex,
+        self, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer, UpperExp,
+        UpperHex,
     },
     iter::FusedIterator,
-    ops::{Add, AddAssign, Index, IndexMut, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Range, Sub, SubAssign},
+    str::FromStr,
 };
 
-use num_traits::Zero;
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, One, PrimInt, SaturatingAdd, SaturatingMul, SaturatingSub,
+    Signed, Zero,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use self::hash::CoordHashMap;
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use self::serde_string_key::CoordinateKey;
 #[allow(clippy::module_name_repetitions)]
 #[doc(inline)]
-pub use self::{axis_2d::Axis2D, iterator::CoordinateIterator};
-use crate::number::abs_diff;
+pub use self::{
+    axis_2d::Axis2D,
+    axis_array::AxisArray,
+    hash::{CoordHasher, CoordKeyHasher},
+    iterator::CoordinateIterator,
+    range::{CoordinateRange, CoordinateRangeIterator},
+};
+#[cfg(feature = "std")]
+use crate::error::ZeroVectorError;
+use crate::{
+    error::{ParseCoordinateError, PerAxisError, WrongLengthError},
+    number::{abs_diff, gcd, ulp_diff},
+    PositiveFloat, ZeroOneBoundedFloat,
+};
 
 /// A two dimensional vector.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+///
+/// The [`Debug`] implementation is compact, `Coordinate(1, 2)`, and the alternate flag
+/// `{:#?}` gives the pretty struct form with the field names, as a derived [`Debug`] would.
+/// Note that `{:?}` does not print `Coordinate { x: 1, y: 2 }` like a derived implementation.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
 pub struct Coordinate<T> {
     /// the x coordinate
     pub x: T,
@@ -87,21 +138,31 @@ impl<T> Coordinate<T> {
         }
     }
 
-    // TODO own iterator for ExactSizeIterator
-    /// Get an iterator on the coordinate elements
+    /// Get an iterator on the coordinate elements. The iterator is a named
+    /// [`CoordinateIterator`] so it can be stored in a struct field.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::{Coordinate, CoordinateIterator};
+    ///
+    /// struct Components<'a> {
+    ///     iter: CoordinateIterator<&'a u8>,
+    /// }
+    ///
+    /// let coord = Coordinate::new(1_u8, 2_u8);
+    /// let mut components = Components { iter: coord.iter() };
+    /// assert_eq!(components.iter.len(), 2);
+    /// assert_eq!(components.iter.next_back(), Some(&2_u8));
+    /// assert_eq!(components.iter.len(), 1);
+    /// ```
     #[inline]
-    pub fn iter(
-        &self,
-    ) -> impl Iterator<Item = &T> + DoubleEndedIterator + FusedIterator + ExactSizeIterator {
+    pub fn iter(&self) -> CoordinateIterator<&T> {
         self.into_iter()
     }
 
-    /// Get an iterator on the coordinate elements as mutable reference
+    /// Get an iterator on the coordinate elements as mutable reference, see [`Self::iter`].
     #[inline]
-    pub fn iter_mut(
-        &mut self,
-    ) -> impl Iterator<Item = &mut T> + DoubleEndedIterator + FusedIterator + ExactSizeIterator
-    {
+    pub fn iter_mut(&mut self) -> CoordinateIterator<&mut T> {
         self.into_iter()
     }
 
@@ -163,10 +224,129 @@ impl<T> Coordinate<T> {
     pub fn into_array(self) -> [T; 2] {
         [self.x, self.y]
     }
+
+    /// Replace the coordinate given by the [`Axis2D`] direction and returns the [`Coordinate`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::{Axis2D, Coordinate};
+    ///
+    /// assert_eq!(
+    ///     Coordinate::new(1_i32, 2_i32).replace(Axis2D::Vertical, 5_i32),
+    ///     Coordinate::new(5_i32, 2_i32)
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::new(1.5_f64, 2.5_f64).replace(Axis2D::Horizontal, -1_f64),
+    ///     Coordinate::new(1.5_f64, -1_f64)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn replace(mut self, axis: Axis2D, value: T) -> Self {
+        *self.get_mut(axis) = value;
+        self
+    }
+}
+
+/// Constructors of vectors along a single axis
+impl<T: Zero> Coordinate<T> {
+    /// Create a [`Coordinate`] with `value` on the given [`Axis2D`] and zero on the other axis.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::{Axis2D, Coordinate};
+    ///
+    /// assert_eq!(
+    ///     Coordinate::from_axis(Axis2D::Vertical, 3_i32),
+    ///     Coordinate::new(3_i32, 0_i32)
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::from_axis(Axis2D::Horizontal, 0.5_f64),
+    ///     Coordinate::new(0_f64, 0.5_f64)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_axis(axis: Axis2D, value: T) -> Self {
+        Self::zero().replace(axis, value)
+    }
+
+    /// Create a [`Coordinate`] with `value` on the x axis ([`Axis2D::Vertical`]) and zero on
+    /// the y axis.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// assert_eq!(Coordinate::x_axis(4_u8), Coordinate::new(4_u8, 0_u8));
+    /// assert_eq!(
+    ///     Coordinate::x_axis(-2.5_f64),
+    ///     Coordinate::new(-2.5_f64, 0_f64)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn x_axis(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+
+    /// Create a [`Coordinate`] with `value` on the y axis ([`Axis2D::Horizontal`]) and zero on
+    /// the x axis.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// assert_eq!(Coordinate::y_axis(4_u8), Coordinate::new(0_u8, 4_u8));
+    /// assert_eq!(
+    ///     Coordinate::y_axis(-2.5_f64),
+    ///     Coordinate::new(0_f64, -2.5_f64)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn y_axis(value: T) -> Self {
+        Self::new(T::zero(), value)
+    }
 }
 
 // ~const Drop
 /// Const conversion function using [`Copy`] as a bound on `T`.
+impl<T> Coordinate<T> {
+    /// Convert both components with [`TryFrom`], like `Coordinate<f64>` into
+    /// `Coordinate<PositiveFloat>`. Both components are always converted so that the error
+    /// reports every invalid one.
+    ///
+    /// # Errors
+    /// Returns a [`PerAxisError`] with the axis that failed and the conversion errors.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{error::PerAxisError, Coordinate, PositiveFloat};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(
+    ///     Coordinate::new(1_f64, 2_f64).try_map_validate::<PositiveFloat>()?,
+    ///     Coordinate::new(PositiveFloat::new(1_f64)?, PositiveFloat::new(2_f64)?)
+    /// );
+    /// assert!(matches!(
+    ///     Coordinate::new(-1_f64, f64::NAN).try_map_validate::<PositiveFloat>(),
+    ///     Err(PerAxisError::Both { .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_map_validate<W: TryFrom<T>>(self) -> Result<Coordinate<W>, PerAxisError<W::Error>> {
+        match (W::try_from(self.x), W::try_from(self.y)) {
+            (Ok(x), Ok(y)) => Ok(Coordinate::new(x, y)),
+            (Err(x), Ok(_)) => Err(PerAxisError::X(x)),
+            (Ok(_), Err(y)) => Err(PerAxisError::Y(y)),
+            (Err(x), Err(y)) => Err(PerAxisError::Both { x, y }),
+        }
+    }
+}
+
 impl<T: Copy> Coordinate<T> {
     /// Get the [`Coordinate`] as a tuple.
     /// This is a const function.
@@ -183,6 +363,107 @@ impl<T: Copy> Coordinate<T> {
     pub const fn into_array_const(self) -> [T; 2] {
         [self.x, self.y]
     }
+
+    /// Create a [`Coordinate`] from an array `[x, y]`.
+    /// This is a const function, see also the [`From`] implementation.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// const COORD: Coordinate<u8> = Coordinate::from_array([1, 2]);
+    /// assert_eq!(COORD, Coordinate::new(1, 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_array(value: [T; 2]) -> Self {
+        let [x, y] = value;
+        Self::new(x, y)
+    }
+
+    /// Create a [`Coordinate`] from a tuple `(x, y)`.
+    /// This is a const function, see also the [`From`] implementation.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// const COORD: Coordinate<u8> = Coordinate::from_tuple((1, 2));
+    /// assert_eq!(COORD, Coordinate::new(1, 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_tuple(value: (T, T)) -> Self {
+        let (x, y) = value;
+        Self::new(x, y)
+    }
+}
+
+impl<T: Clone> Coordinate<T> {
+    /// Create a [`Coordinate`] from a slice `[x, y]` of length exactly 2.
+    ///
+    /// It is the strict counterpart of [`Self::from_slice_defaulting`] and of the
+    /// implementation of [`From<&[T]>`] which fill the missing components with the default
+    /// value and ignore the extra ones. [`TryFrom<&[T]>`] cannot be implemented as it is
+    /// already implemented through [`From<&[T]>`].
+    ///
+    /// # Errors
+    /// Returns a [`WrongLengthError`] if the length of the slice is not 2.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{error::WrongLengthError, Coordinate};
+    ///
+    /// assert_eq!(
+    ///     Coordinate::try_from_slice(&[1, 2]),
+    ///     Ok(Coordinate::new(1, 2))
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::try_from_slice(&[1]),
+    ///     Err(WrongLengthError::new(2, 1))
+    /// );
+    /// ```
+    #[cfg_attr(feature = "borsh", allow(clippy::same_name_method))] // reason = "BorshDeserialize::try_from_slice decodes bytes, this one takes the values"
+    #[inline]
+    pub fn try_from_slice(value: &[T]) -> Result<Self, WrongLengthError> {
+        match value {
+            [x, y] => Ok(Self::new(x.clone(), y.clone())),
+            _ => Err(WrongLengthError::new(2, value.len())),
+        }
+    }
+
+    /// Create a [`Coordinate`] from the first two elements of a slice, filling the missing
+    /// components with [`Default::default`] and ignoring the extra elements.
+    ///
+    /// This conversion is lossy, so `[4]` gives `Coordinate::new(4, 0)`. Prefer
+    /// [`Self::try_from_slice`] unless the defaulting is intended. It is the conversion used
+    /// by the implementations of [`From<&[T]>`] and [`From<Vec<T>>`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// assert_eq!(
+    ///     Coordinate::from_slice_defaulting(&[4]),
+    ///     Coordinate::new(4, 0)
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::from_slice_defaulting(&[4, 5, 6]),
+    ///     Coordinate::new(4, 5)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_slice_defaulting(value: &[T]) -> Self
+    where
+        T: Default,
+    {
+        let mut iter = value.iter();
+        Self::new(
+            iter.next().cloned().unwrap_or_default(),
+            iter.next().cloned().unwrap_or_default(),
+        )
+    }
 }
 
 impl<'a, T> Coordinate<T>
@@ -221,110 +502,1186 @@ where
     }
 }
 
-//----------------------------------
-// index operation
-
-impl<T> Index<Axis2D> for Coordinate<T> {
-    type Output = T;
-
+impl<T> Coordinate<T> {
+    /// Manhattan distances, consuming both coordinates. Unlike [`Self::s1_distance`] it only
+    /// bounds `T` and not references of `T`, so it can be used in generic code and with owned
+    /// values of a type that is not [`Copy`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let coord_1 = Coordinate::new(10_i32, 22_i32);
+    /// let coord_2 = Coordinate::new(13_i32, 21_i32);
+    /// assert_eq!(coord_1.manhattan_distance(coord_2), 4_i32);
+    /// assert_eq!(
+    ///     Coordinate::new(0.5_f64, 2_f64).manhattan_distance(Coordinate::new(1_f64, 0_f64)),
+    ///     2.5_f64
+    /// );
+    /// ```
     #[inline]
-    fn index(&self, index: Axis2D) -> &Self::Output {
-        self.get(index)
+    #[must_use]
+    pub fn manhattan_distance(self, other: Self) -> T::Output
+    where
+        T: Sub + PartialOrd,
+        T::Output: Add<Output = T::Output>,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
     }
-}
 
-impl<T> IndexMut<Axis2D> for Coordinate<T> {
+    /// Manhattan distances of [`Copy`] coordinates, see [`Self::manhattan_distance`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let coord_zero = Coordinate::new(0_u32, 0_u32);
+    /// let coord = Coordinate::new(3_u32, 4_u32);
+    /// assert_eq!(coord.manhattan_distance_copy(&coord_zero), 7_u32);
+    /// assert_eq!(coord_zero.manhattan_distance_copy(&coord), 7_u32);
+    /// ```
     #[inline]
-    fn index_mut(&mut self, index: Axis2D) -> &mut Self::Output {
-        self.get_mut(index)
+    #[must_use]
+    pub fn manhattan_distance_copy(&self, other: &Self) -> T
+    where
+        T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+    {
+        self.manhattan_distance(*other)
     }
 }
 
-impl<T> Index<usize> for Coordinate<T> {
-    type Output = T;
-
+/// Orderings other than the derived [`Ord`], which compares x then y.
+impl<T: Ord> Coordinate<T> {
+    /// Compare two [`Coordinate`] on the `primary` axis first, then on the other axis.
+    /// `cmp_by_axis(other, Axis2D::Vertical)` is the derived [`Ord`].
+    ///
+    /// # Example
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use utils_lib::coordinate::{Axis2D, Coordinate};
+    ///
+    /// let a = Coordinate::new(1_u32, 5_u32);
+    /// let b = Coordinate::new(2_u32, 3_u32);
+    /// assert_eq!(a.cmp_by_axis(&b, Axis2D::Vertical), Ordering::Less);
+    /// assert_eq!(a.cmp_by_axis(&b, Axis2D::Horizontal), Ordering::Greater);
+    /// assert_eq!(a.cmp_by_axis(&b, Axis2D::Vertical), a.cmp(&b));
+    /// ```
     #[inline]
-    fn index(&self, index: usize) -> &Self::Output {
-        self.as_array()[index]
+    #[must_use]
+    pub fn cmp_by_axis(&self, other: &Self, primary: Axis2D) -> Ordering {
+        self.get(primary)
+            .cmp(other.get(primary))
+            .then_with(|| self.get(!primary).cmp(other.get(!primary)))
     }
-}
 
-impl<T> IndexMut<usize> for Coordinate<T> {
+    /// Compare two [`Coordinate`] in reading order, y first (the row) then x (the column).
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let mut coords = vec![
+    ///     Coordinate::new(1_u32, 1_u32),
+    ///     Coordinate::new(2_u32, 0_u32),
+    ///     Coordinate::new(0_u32, 1_u32),
+    /// ];
+    /// coords.sort_by(Coordinate::cmp_reading_order);
+    /// assert_eq!(
+    ///     coords,
+    ///     vec![
+    ///         Coordinate::new(2_u32, 0_u32),
+    ///         Coordinate::new(0_u32, 1_u32),
+    ///         Coordinate::new(1_u32, 1_u32),
+    ///     ]
+    /// );
+    /// ```
     #[inline]
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.as_array_mut()[index]
+    #[must_use]
+    pub fn cmp_reading_order(&self, other: &Self) -> Ordering {
+        self.cmp_by_axis(other, Axis2D::Horizontal)
     }
 }
 
-// impl<T: Clone, I> Index<I> for Coordinate<T>
-// where
-//     [T; 2]: Index<I>,
-// {
-//     type Output = <[T; 2] as Index<I>>::Output;
-
-//     #[inline]
-//     fn index(&self, index: I) -> &Self::Output {
-//         self.into_array().clone().index(index)
-//     }
-// }
-
-//----------------------------------
-// num operation
-
-impl<T: AddAssign<T2>, T2> AddAssign<Coordinate<T2>> for Coordinate<T> {
+impl<T: Copy> Coordinate<T> {
+    /// Key `(y, x)` whose ordering is the reading order, see [`Self::cmp_reading_order`],
+    /// to use with [`slice::sort_by_key`] and similar functions.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let mut coords = vec![
+    ///     Coordinate::new(1_i32, 1_i32),
+    ///     Coordinate::new(2_i32, -1_i32),
+    /// ];
+    /// coords.sort_by_key(Coordinate::reading_order_key);
+    /// assert_eq!(
+    ///     coords,
+    ///     vec![
+    ///         Coordinate::new(2_i32, -1_i32),
+    ///         Coordinate::new(1_i32, 1_i32)
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::new(1_i32, 2_i32).reading_order_key(),
+    ///     (2_i32, 1_i32)
+    /// );
+    /// ```
     #[inline]
-    fn add_assign(&mut self, rhs: Coordinate<T2>) {
-        *self.x_mut() += rhs.x;
-        *self.y_mut() += rhs.y;
+    #[must_use]
+    pub const fn reading_order_key(&self) -> (T, T) {
+        (self.y, self.x)
     }
 }
 
-impl<T: Add<T2>, T2> Add<Coordinate<T2>> for Coordinate<T> {
-    type Output = Coordinate<T::Output>;
-
+/// Signed movement between [`Coordinate<usize>`] for grid walks.
+impl Coordinate<f64> {
+    /// Convert both components into [`ZeroOneBoundedFloat`], clamping them into `[0, 1]`
+    /// with [`ZeroOneBoundedFloat::new_or_bounded`] ([`f64::NAN`] gives zero).
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{Coordinate, ZeroOneBoundedFloat};
+    ///
+    /// assert_eq!(
+    ///     Coordinate::new(-1_f64, 2_f64).clamp_components_to_unit(),
+    ///     Coordinate::new(ZeroOneBoundedFloat::ZERO, ZeroOneBoundedFloat::ONE)
+    /// );
+    /// ```
     #[inline]
-    fn add(self, rhs: Coordinate<T2>) -> Self::Output {
-        Coordinate::new(self.x + rhs.x, self.y + rhs.y)
+    #[must_use]
+    pub const fn clamp_components_to_unit(self) -> Coordinate<ZeroOneBoundedFloat> {
+        Coordinate::new(
+            ZeroOneBoundedFloat::new_or_bounded(self.x),
+            ZeroOneBoundedFloat::new_or_bounded(self.y),
+        )
     }
-}
 
-impl<T: SubAssign<T2>, T2> SubAssign<Coordinate<T2>> for Coordinate<T> {
+    /// Tell if the absolute difference of each component is at most `epsilon`. An `epsilon`
+    /// of zero checks for equality.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{Coordinate, PositiveFloat};
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let coord = Coordinate::new(0.1_f64 + 0.2_f64, -1_f64);
+    /// let epsilon = PositiveFloat::new(1E-15_f64)?;
+    ///
+    /// assert!(coord.approx_eq(Coordinate::new(0.3_f64, -1_f64), epsilon));
+    /// assert!(!coord.approx_eq(Coordinate::new(0.3_f64, -1.1_f64), epsilon));
+    /// assert!(!coord.approx_eq(Coordinate::new(0.3_f64, -1_f64), PositiveFloat::ZERO));
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
-    fn sub_assign(&mut self, rhs: Coordinate<T2>) {
-        *self.x_mut() -= rhs.x;
-        *self.y_mut() -= rhs.y;
+    #[must_use]
+    pub fn approx_eq(self, other: Self, epsilon: PositiveFloat) -> bool {
+        (self.x - other.x).abs() <= epsilon.float() && (self.y - other.y).abs() <= epsilon.float()
     }
-}
-
-impl<T: Sub<T2>, T2> Sub<Coordinate<T2>> for Coordinate<T> {
-    type Output = Coordinate<T::Output>;
 
+    /// Get the largest distance in units in the last place (ULP) of the components, the number
+    /// of representable floats between them. `0` and `-0` are the same, and the result is
+    /// meaningless if a component is [`f64::NAN`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let coord = Coordinate::new(0.1_f64 + 0.2_f64, -0_f64);
+    ///
+    /// assert_eq!(coord.max_ulp_diff(Coordinate::new(0.3_f64, 0_f64)), 1);
+    /// assert_eq!(coord.max_ulp_diff(coord), 0);
+    /// ```
     #[inline]
-    fn sub(self, rhs: Coordinate<T2>) -> Self::Output {
-        Coordinate::new(self.x - rhs.x, self.y - rhs.y)
+    #[must_use]
+    pub const fn max_ulp_diff(self, other: Self) -> u64 {
+        let x = ulp_diff(self.x, other.x);
+        let y = ulp_diff(self.y, other.y);
+        if x > y {
+            x
+        } else {
+            y
+        }
     }
-}
-
-impl<T: Neg<Output = T2>, T2> Neg for Coordinate<T> {
-    type Output = Coordinate<T2>;
 
+    /// The dot product `x * other.x + y * other.y`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// assert_eq!(
+    ///     Coordinate::new(1_f64, 2_f64).dot(&Coordinate::new(3_f64, -1_f64)),
+    ///     1_f64
+    /// );
+    /// ```
     #[inline]
-    fn neg(self) -> Self::Output {
-        Coordinate::new(-self.x, -self.y)
+    #[must_use]
+    pub const fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y
     }
-}
 
-impl<T: Zero> Zero for Coordinate<T> {
+    /// The cross product `x * other.y - y * other.x`, the z component of the three dimensional
+    /// cross product. It is positive if `other` is counterclockwise from `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let x = Coordinate::new(1_f64, 0_f64);
+    /// let y = Coordinate::new(0_f64, 1_f64);
+    /// assert_eq!(x.cross(&y), 1_f64);
+    /// assert_eq!(y.cross(&x), -1_f64);
+    /// ```
     #[inline]
-    fn zero() -> Self {
-        Self::new(T::zero(), T::zero())
+    #[must_use]
+    pub const fn cross(&self, other: &Self) -> f64 {
+        self.x * other.y - self.y * other.x
     }
 
-    #[inline]
-    fn is_zero(&self) -> bool {
-        self.iter().all(Zero::is_zero)
-    }
-}
+    /// The unsigned angle between the two vectors in radian, in `[0, π]`.
+    ///
+    /// It is computed as the `atan2` of the cross and the dot product, which stays precise
+    /// for almost parallel and almost antiparallel vectors where `acos` of the cosine is not.
+    /// The angle is not defined if one of the vectors is zero, zero is returned in that case,
+    /// use [`Self::cosine_similarity`] to get an error instead.
+    ///
+    /// # Example
+    /// ```
+    /// use core::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// use utils_lib::Coordinate;
+    ///
+    /// let v = Coordinate::new(2_f64, 0_f64);
+    /// assert_eq!(v.angle_between(&Coordinate::new(0_f64, 3_f64)), FRAC_PI_2);
+    /// assert_eq!(v.angle_between(&Coordinate::new(0_f64, -3_f64)), FRAC_PI_2);
+    /// assert_eq!(v.angle_between(&Coordinate::new(-1_f64, 0_f64)), PI);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn angle_between(&self, other: &Self) -> f64 {
+        self.cross(other).abs().atan2(self.dot(other))
+    }
+
+    /// The cosine of the angle between the two vectors, in `[-1, 1]`: `1` for parallel vectors,
+    /// `0` for orthogonal ones and `-1` for antiparallel ones.
+    ///
+    /// The vectors are scaled before the dot product so large components do not overflow. A [`f64::NAN`] component gives [`f64::NAN`].
+    ///
+    /// # Errors
+    /// Returns [`ZeroVectorError`] if one of the vectors is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{error::ZeroVectorError, Coordinate};
+    ///
+    /// let v = Coordinate::new(1_f64, 1_f64);
+    /// assert_eq!(
+    ///     v.cosine_similarity(&Coordinate::new(2_f64, 2_f64)),
+    ///     Ok(1_f64)
+    /// );
+    /// assert_eq!(
+    ///     v.cosine_similarity(&Coordinate::new(1_f64, -1_f64)),
+    ///     Ok(0_f64)
+    /// );
+    /// assert_eq!(
+    ///     v.cosine_similarity(&Coordinate::new(0_f64, 0_f64)),
+    ///     Err(ZeroVectorError)
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn cosine_similarity(&self, other: &Self) -> Result<f64, ZeroVectorError> {
+        // scale the vectors so that the largest component is one, the squared norms do not
+        // overflow and parallel vectors with the same direction give exactly the same vector
+        let scaled = |vector: &Self| {
+            let max = vector.x.abs().max(vector.y.abs());
+            if max == 0_f64 {
+                Err(ZeroVectorError)
+            } else {
+                Ok(Self::new(vector.x / max, vector.y / max))
+            }
+        };
+        let (a, b) = (scaled(self)?, scaled(other)?);
+        // the rounding can give a value slightly out of [-1, 1]
+        Ok((a.dot(&b) / (a.dot(&a) * b.dot(&b)).sqrt()).clamp(-1_f64, 1_f64))
+    }
+
+    /// The [cosine similarity](Self::cosine_similarity) remapped from `[-1, 1]` to `[0, 1]`
+    /// by `(cos + 1) / 2`: `1` for parallel vectors, `0.5` for orthogonal ones and `0` for
+    /// antiparallel ones. A [`f64::NAN`] component gives [`ZeroOneBoundedFloat::ZERO`].
+    ///
+    /// # Errors
+    /// Returns [`ZeroVectorError`] if one of the vectors is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{Coordinate, ZeroOneBoundedFloat};
+    ///
+    /// let v = Coordinate::new(0_f64, 1_f64);
+    /// assert_eq!(
+    ///     v.cosine_similarity_unit(&Coordinate::new(0_f64, -5_f64)),
+    ///     Ok(ZeroOneBoundedFloat::ZERO)
+    /// );
+    /// assert_eq!(
+    ///     v.cosine_similarity_unit(&Coordinate::new(3_f64, 0_f64))
+    ///         .map(ZeroOneBoundedFloat::float),
+    ///     Ok(0.5_f64)
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn cosine_similarity_unit(
+        &self,
+        other: &Self,
+    ) -> Result<ZeroOneBoundedFloat, ZeroVectorError> {
+        self.cosine_similarity(other)
+            .map(|cos| ZeroOneBoundedFloat::from_bounded_operation(cos.midpoint(1_f64)))
+    }
+}
+
+impl Coordinate<usize> {
+    /// Get the signed delta `self - other` per component, or [`None`] if a component does not
+    /// fit in an [`i64`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let a = Coordinate::new(2_usize, 5_usize);
+    /// let b = Coordinate::new(4_usize, 1_usize);
+    /// assert_eq!(a.checked_delta(b), Some(Coordinate::new(-2_i64, 4_i64)));
+    /// assert_eq!(
+    ///     Coordinate::new(usize::MAX, 0_usize).checked_delta(Coordinate::new(0_usize, 0_usize)),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_delta(self, other: Self) -> Option<Coordinate<i64>> {
+        Some(Coordinate::new(
+            component_delta(self.x, other.x)?,
+            component_delta(self.y, other.y)?,
+        ))
+    }
+
+    /// Get the signed delta `self - other` per component, saturating at [`i64::MIN`] and
+    /// [`i64::MAX`] if a component does not fit in an [`i64`]. See [`Self::checked_delta`] for
+    /// a version that reports the overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let a = Coordinate::new(2_usize, 5_usize);
+    /// let b = Coordinate::new(4_usize, 1_usize);
+    /// assert_eq!(a.delta(b), Coordinate::new(-2_i64, 4_i64));
+    /// assert_eq!(b.offset_by(a.delta(b)), Some(a));
+    ///
+    /// let far = Coordinate::new(usize::MAX, 0_usize);
+    /// assert_eq!(
+    ///     far.delta(Coordinate::new(0_usize, 0_usize)),
+    ///     Coordinate::new(i64::MAX, 0_i64)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn delta(self, other: Self) -> Coordinate<i64> {
+        /// saturating delta of one component
+        fn saturating_delta(a: usize, b: usize) -> i64 {
+            component_delta(a, b).unwrap_or(if a >= b { i64::MAX } else { i64::MIN })
+        }
+
+        Coordinate::new(
+            saturating_delta(self.x, other.x),
+            saturating_delta(self.y, other.y),
+        )
+    }
+
+    /// Apply a signed delta to the coordinate. Returns [`None`] if a component would go below
+    /// zero or above [`usize::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let c = Coordinate::new(1_usize, 1_usize);
+    /// assert_eq!(
+    ///     c.offset_by(Coordinate::new(-1_i64, 2_i64)),
+    ///     Some(Coordinate::new(0_usize, 3_usize))
+    /// );
+    /// assert_eq!(c.offset_by(Coordinate::new(-2_i64, 0_i64)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn offset_by(self, delta: Coordinate<i64>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(isize::try_from(delta.x).ok()?)?,
+            self.y.checked_add_signed(isize::try_from(delta.y).ok()?)?,
+        ))
+    }
+
+    /// Get the absolute difference per component, `|self - other|`, using [`abs_diff`].
+    /// It never overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let a = Coordinate::new(2_usize, 5_usize);
+    /// let b = Coordinate::new(4_usize, 1_usize);
+    /// assert_eq!(a.abs_delta(b), Coordinate::new(2_usize, 4_usize));
+    /// assert_eq!(a.abs_delta(b), b.abs_delta(a));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn abs_delta(self, other: Self) -> Self {
+        Self::new(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+
+    /// Wrap the coordinate in `0..bounds` per component, like on a torus.
+    ///
+    /// # Panics
+    /// Panics if a component of `bounds` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let bounds = Coordinate::new(5_usize, 3_usize);
+    /// assert_eq!(
+    ///     Coordinate::new(7_usize, 2_usize).wrap_in(bounds),
+    ///     Coordinate::new(2_usize, 2_usize)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn wrap_in(self, bounds: Self) -> Self {
+        assert!(
+            bounds.x != 0 && bounds.y != 0,
+            "the bounds must not be zero"
+        );
+        Self::new(self.x % bounds.x, self.y % bounds.y)
+    }
+
+    /// Reflect the coordinate in `0..bounds` per component, mirroring it at the walls `0` and
+    /// `bounds - 1` as many times as needed, like a bouncing particle.
+    ///
+    /// # Panics
+    /// Panics if a component of `bounds` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let bounds = Coordinate::new(5_usize, 3_usize);
+    /// // 5 bounces on the wall 4 and 6 on the wall 2 then on the wall 0
+    /// assert_eq!(
+    ///     Coordinate::new(5_usize, 6_usize).reflect_in(bounds),
+    ///     Coordinate::new(3_usize, 2_usize)
+    /// );
+    /// ```
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // reason = "the result is in 0..bounds"
+    #[inline]
+    #[must_use]
+    pub fn reflect_in(self, bounds: Self) -> Self {
+        assert!(
+            bounds.x != 0 && bounds.y != 0,
+            "the bounds must not be zero"
+        );
+        Self::new(
+            reflect_component(self.x as i128, bounds.x as i128) as usize,
+            reflect_component(self.y as i128, bounds.y as i128) as usize,
+        )
+    }
+}
+
+impl Coordinate<i64> {
+    /// Wrap the coordinate in `0..bounds` per component, like on a torus. It uses the
+    /// euclidean modulo so negative components wrap from the end, `-1` wraps to `bounds - 1`.
+    ///
+    /// # Panics
+    /// Panics if a component of `bounds` is not strictly positive.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let bounds = Coordinate::new(5_i64, 3_i64);
+    /// assert_eq!(
+    ///     Coordinate::new(-1_i64, 7_i64).wrap_in(bounds),
+    ///     Coordinate::new(4_i64, 1_i64)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn wrap_in(self, bounds: Self) -> Self {
+        assert!(
+            bounds.x > 0 && bounds.y > 0,
+            "the bounds must be strictly positive"
+        );
+        Self::new(self.x.rem_euclid(bounds.x), self.y.rem_euclid(bounds.y))
+    }
+
+    /// Reflect the coordinate in `0..bounds` per component, mirroring it at the walls `0` and
+    /// `bounds - 1` as many times as needed, like a bouncing particle. A negative component
+    /// is mirrored at `0`, so `-1` gives `1`.
+    ///
+    /// # Panics
+    /// Panics if a component of `bounds` is not strictly positive.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let bounds = Coordinate::new(5_i64, 3_i64);
+    /// assert_eq!(
+    ///     Coordinate::new(-1_i64, 3_i64).reflect_in(bounds),
+    ///     Coordinate::new(1_i64, 1_i64)
+    /// );
+    /// ```
+    #[allow(clippy::cast_possible_truncation)] // reason = "the result is in 0..bounds"
+    #[inline]
+    #[must_use]
+    pub fn reflect_in(self, bounds: Self) -> Self {
+        assert!(
+            bounds.x > 0 && bounds.y > 0,
+            "the bounds must be strictly positive"
+        );
+        Self::new(
+            reflect_component(i128::from(self.x), i128::from(bounds.x)) as i64,
+            reflect_component(i128::from(self.y), i128::from(bounds.y)) as i64,
+        )
+    }
+
+    /// Get the primitive lattice vector in the direction of `self`, i.e. `self` divided by the
+    /// greatest common divider of the absolute values of its components, alongside this
+    /// divider. `self` is the returned vector multiplied by the returned factor.
+    ///
+    /// It is useful to cast rays on an integer grid as consecutive multiples of the
+    /// direction are the only grid points on the segment.
+    ///
+    /// The zero vector has no direction, it is returned as is with a factor of `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// assert_eq!(
+    ///     Coordinate::new(-6_i64, 4_i64).primitive_direction(),
+    ///     (Coordinate::new(-3_i64, 2_i64), 2_u64)
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::new(0_i64, -5_i64).primitive_direction(),
+    ///     (Coordinate::new(0_i64, -1_i64), 5_u64)
+    /// );
+    /// assert_eq!(
+    ///     Coordinate::new(0_i64, 0_i64).primitive_direction(),
+    ///     (Coordinate::new(0_i64, 0_i64), 0_u64)
+    /// );
+    /// ```
+    #[allow(clippy::cast_possible_truncation)] // reason = "the quotients are bounded by the components"
+    #[inline]
+    #[must_use]
+    pub fn primitive_direction(self) -> (Self, u64) {
+        let (x_abs, y_abs) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+        // `gcd` returns 0 if one of the value is 0
+        let factor = match (x_abs, y_abs) {
+            (0, 0) => return (self, 0),
+            (0, other) | (other, 0) => other,
+            (x_abs, y_abs) => gcd(x_abs, y_abs),
+        };
+        // the factor might not fit in an i64 if a component is i64::MIN
+        let divide = |component: i64| (i128::from(component) / i128::from(factor)) as i64;
+        (Self::new(divide(self.x), divide(self.y)), factor)
+    }
+
+    /// Returns `true` if `self` and `other` lie on the same line through the origin, i.e. if
+    /// their cross product is zero. The zero vector is collinear with every vector.
+    ///
+    /// The cross product is computed on [`i128`] so it cannot overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let direction = Coordinate::new(2_i64, -3_i64);
+    /// assert!(direction.collinear_with(Coordinate::new(-4_i64, 6_i64)));
+    /// assert!(!direction.collinear_with(Coordinate::new(4_i64, 6_i64)));
+    /// assert!(direction.collinear_with(Coordinate::new(0_i64, 0_i64)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn collinear_with(self, other: Self) -> bool {
+        i128::from(self.x) * i128::from(other.y) == i128::from(self.y) * i128::from(other.x)
+    }
+}
+
+/// Metrics with periodic boundaries, as on a torus where leaving the grid on one side enters
+/// it on the other side. The components are taken modulo `period`, so they do not need to be
+/// in `0..period`.
+impl<T: PrimInt> Coordinate<T> {
+    /// Manhattan distance on a torus of size `period`: per component the shortest of the direct
+    /// distance `|d|` and the distance going around `period - |d|`.
+    ///
+    /// The components are reduced in `0..period` before any subtraction, so it cannot
+    /// overflow or underflow for unsigned components near `0` and for any signed components.
+    /// The sum of the two components can still overflow if the period is close to the maximum
+    /// of `T`.
+    ///
+    /// # Panics
+    /// Panics if a component of `period` is not strictly positive.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let period = Coordinate::new(10_u32, 10_u32);
+    /// let a = Coordinate::new(1_u32, 2_u32);
+    /// let b = Coordinate::new(9_u32, 3_u32);
+    /// assert_eq!(a.s1_distance(&b), 9_u32);
+    /// // going left from 1 to 9 only takes 2 steps
+    /// assert_eq!(a.periodic_s1_distance(&b, &period), 3_u32);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn periodic_s1_distance(&self, other: &Self, period: &Self) -> T {
+        assert!(
+            period.x > T::zero() && period.y > T::zero(),
+            "the period must be strictly positive"
+        );
+        let distance = |a: T, b: T, period: T| {
+            let (a, b) = (periodic_component(a, period), periodic_component(b, period));
+            let direct = if a >= b { a - b } else { b - a };
+            direct.min(period - direct)
+        };
+        distance(self.x, other.x, period.x) + distance(self.y, other.y, period.y)
+    }
+
+    /// Get the displacement `self - other` on a torus of size `period` with the smallest
+    /// magnitude per component, in `-period / 2..=period / 2`. If both directions have the
+    /// same length, for an even period, the positive displacement `period / 2` is returned.
+    ///
+    /// Like [`Self::periodic_s1_distance`] the components are reduced in `0..period` first so
+    /// the computation cannot overflow. The sum of the absolute values of the components of
+    /// the displacement is the periodic distance.
+    ///
+    /// # Panics
+    /// Panics if a component of `period` is not strictly positive.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// let period = Coordinate::new(10_i32, 10_i32);
+    /// let a = Coordinate::new(1_i32, 2_i32);
+    /// let b = Coordinate::new(9_i32, -7_i32);
+    /// assert_eq!(a - b, Coordinate::new(-8_i32, 9_i32));
+    /// assert_eq!(
+    ///     a.periodic_delta(&b, &period),
+    ///     Coordinate::new(2_i32, -1_i32)
+    /// );
+    /// assert_eq!(
+    ///     b.periodic_delta(&a, &period),
+    ///     Coordinate::new(-2_i32, 1_i32)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn periodic_delta(&self, other: &Self, period: &Self) -> Self
+    where
+        T: Signed,
+    {
+        assert!(
+            period.x > T::zero() && period.y > T::zero(),
+            "the period must be strictly positive"
+        );
+        let delta = |a: T, b: T, period: T| {
+            // in -period + 1..period so neither `period - delta` nor `period + delta` overflow
+            let delta = periodic_component(a, period) - periodic_component(b, period);
+            if delta > T::zero() && delta > period - delta {
+                delta - period
+            } else if delta < T::zero() && -delta >= period + delta {
+                delta + period
+            } else {
+                delta
+            }
+        };
+        Self::new(
+            delta(self.x, other.x, period.x),
+            delta(self.y, other.y, period.y),
+        )
+    }
+}
+
+/// 2×2 matrices as a [`Coordinate`] of rows: `m.x` is the first row `(m.x.x, m.x.y)` and
+/// `m.y` the second row `(m.y.x, m.y.y)`.
+impl<T> Coordinate<Coordinate<T>> {
+    /// Get the identity matrix, one on the diagonal and zero elsewhere.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let v = Coordinate::new(3_i32, -4_i32);
+    /// assert_eq!(Coordinate::identity().matmul_vec(v), v);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Self::new(
+            Coordinate::new(T::one(), T::zero()),
+            Coordinate::new(T::zero(), T::one()),
+        )
+    }
+
+    /// Get the transposed matrix, the rows become the columns.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let m = Coordinate::new(Coordinate::new(1_i32, 2_i32), Coordinate::new(3_i32, 4_i32));
+    /// assert_eq!(
+    ///     m.transpose(),
+    ///     Coordinate::new(Coordinate::new(1_i32, 3_i32), Coordinate::new(2_i32, 4_i32))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        Self::new(
+            Coordinate::new(self.x.x, self.y.x),
+            Coordinate::new(self.x.y, self.y.y),
+        )
+    }
+
+    /// Multiply the matrix by the column vector `v`, i.e. the dot product of each row with
+    /// `v`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// // rotation by a quarter turn
+    /// let rotation = Coordinate::new(
+    ///     Coordinate::new(0_i32, -1_i32),
+    ///     Coordinate::new(1_i32, 0_i32),
+    /// );
+    /// assert_eq!(
+    ///     rotation.matmul_vec(Coordinate::new(2_i32, 1_i32)),
+    ///     Coordinate::new(-1_i32, 2_i32)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn matmul_vec(self, v: Coordinate<T>) -> Coordinate<T>
+    where
+        T: Clone + Add<Output = T> + Mul<Output = T>,
+    {
+        Coordinate::new(
+            self.x.x * v.x.clone() + self.x.y * v.y.clone(),
+            self.y.x * v.x + self.y.y * v.y,
+        )
+    }
+
+    /// Get the determinant of the matrix, `m.x.x * m.y.y - m.x.y * m.y.x`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let m = Coordinate::new(Coordinate::new(1_i32, 2_i32), Coordinate::new(3_i32, 4_i32));
+    /// assert_eq!(m.determinant(), -2_i32);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn determinant(self) -> T
+    where
+        T: Mul<Output = T> + Sub<Output = T>,
+    {
+        self.x.x * self.y.y - self.x.y * self.y.x
+    }
+}
+
+/// Reduce `value` in `0..period` with the euclidean modulo, `-1` gives `period - 1`. `period`
+/// must be strictly positive.
+fn periodic_component<T: PrimInt>(value: T, period: T) -> T {
+    let value = value % period;
+    // the remainder of a negative value is in -period + 1..0
+    if value < T::zero() {
+        value + period
+    } else {
+        value
+    }
+}
+
+/// Get `a - b` as an [`i64`] without overflowing the intermediate computation, or [`None`]
+/// if the result does not fit.
+fn component_delta(a: usize, b: usize) -> Option<i64> {
+    let diff = u64::try_from(abs_diff(a, b)).ok()?;
+    if a >= b {
+        i64::try_from(diff).ok()
+    } else {
+        0_i64.checked_sub_unsigned(diff)
+    }
+}
+
+/// Reflect `value` in `0..bound` at the walls `0` and `bound - 1`. The reflection has a
+/// period of `2 * (bound - 1)`, the computation is done in [`i128`] so it cannot overflow for
+/// [`i64`] and [`usize`] inputs and the result is in `0..bound`. `bound` must be strictly
+/// positive.
+const fn reflect_component(value: i128, bound: i128) -> i128 {
+    let period = 2 * (bound - 1);
+    if period == 0 {
+        return 0;
+    }
+    let value = value.rem_euclid(period);
+    if value < bound {
+        value
+    } else {
+        period - value
+    }
+}
+
+/// Create a [`CoordinateRange`] containing all the coordinates from `start` (included) to `end`
+/// (excluded) on both axis, iterated in row-major order.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{range, Coordinate};
+///
+/// let r = range(Coordinate::new(1, 1), Coordinate::new(3, 3));
+/// assert_eq!(
+///     r.into_iter().collect::<Vec<_>>(),
+///     vec![
+///         Coordinate::new(1, 1),
+///         Coordinate::new(1, 2),
+///         Coordinate::new(2, 1),
+///         Coordinate::new(2, 2),
+///     ]
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub const fn range(start: Coordinate<usize>, end: Coordinate<usize>) -> CoordinateRange {
+    CoordinateRange::new(start, end)
+}
+
+/// Create a [`CoordinateRange`] containing all the coordinates from `start` to `end`, both
+/// included, on both axis. It is the equivalent of `start..=end`.
+///
+/// # Panics
+/// Panics if a component of `end` is [`usize::MAX`].
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{range_inclusive, Coordinate};
+///
+/// let r = range_inclusive(Coordinate::new(0, 0), Coordinate::new(1, 2));
+/// assert_eq!(r.len(), 6);
+/// assert!(r.contains(&Coordinate::new(1, 2)));
+/// ```
+#[inline]
+#[must_use]
+pub const fn range_inclusive(start: Coordinate<usize>, end: Coordinate<usize>) -> CoordinateRange {
+    range(
+        start,
+        Coordinate::new(
+            end.x
+                .checked_add(1)
+                .expect("end of inclusive range overflowed"),
+            end.y
+                .checked_add(1)
+                .expect("end of inclusive range overflowed"),
+        ),
+    )
+}
+
+/// Iterator over the coordinates of the row `row` with the columns in `columns`, i.e. the
+/// coordinates `(row, y)` for `y` in `columns`. As for [`CoordinateRange`], `x` is the row
+/// index and `y` the column index.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{row_coords, Coordinate};
+///
+/// let row = row_coords(2, 1..4);
+/// assert_eq!(row.len(), 3);
+/// assert_eq!(
+///     row.rev().collect::<Vec<_>>(),
+///     vec![
+///         Coordinate::new(2, 3),
+///         Coordinate::new(2, 2),
+///         Coordinate::new(2, 1)
+///     ]
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn row_coords(
+    row: usize,
+    columns: Range<usize>,
+) -> impl DoubleEndedIterator<Item = Coordinate<usize>> + ExactSizeIterator + FusedIterator {
+    columns.map(move |y| Coordinate::new(row, y))
+}
+
+/// Iterator over the coordinates of the column `column` with the rows in `rows`, i.e. the
+/// coordinates `(x, column)` for `x` in `rows`. As for [`CoordinateRange`], `x` is the row
+/// index and `y` the column index.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{col_coords, Coordinate};
+///
+/// let column = col_coords(2, 0..2);
+/// assert_eq!(column.len(), 2);
+/// assert_eq!(
+///     column.collect::<Vec<_>>(),
+///     vec![Coordinate::new(0, 2), Coordinate::new(1, 2)]
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn col_coords(
+    column: usize,
+    rows: Range<usize>,
+) -> impl DoubleEndedIterator<Item = Coordinate<usize>> + ExactSizeIterator + FusedIterator {
+    rows.map(move |x| Coordinate::new(x, column))
+}
+
+//----------------------------------
+// index operation
+
+impl<T> Index<Axis2D> for Coordinate<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Axis2D) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<T> IndexMut<Axis2D> for Coordinate<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Axis2D) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<T> Index<usize> for Coordinate<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        self.as_array()[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Coordinate<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.as_array_mut()[index]
+    }
+}
+
+// impl<T: Clone, I> Index<I> for Coordinate<T>
+// where
+//     [T; 2]: Index<I>,
+// {
+//     type Output = <[T; 2] as Index<I>>::Output;
+
+//     #[inline]
+//     fn index(&self, index: I) -> &Self::Output {
+//         self.into_array().clone().index(index)
+//     }
+// }
+
+//----------------------------------
+// num operation
+
+impl<T: AddAssign<T2>, T2> AddAssign<Coordinate<T2>> for Coordinate<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Coordinate<T2>) {
+        *self.x_mut() += rhs.x;
+        *self.y_mut() += rhs.y;
+    }
+}
+
+impl<T: Add<T2>, T2> Add<Coordinate<T2>> for Coordinate<T> {
+    type Output = Coordinate<T::Output>;
+
+    #[inline]
+    fn add(self, rhs: Coordinate<T2>) -> Self::Output {
+        Coordinate::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: SubAssign<T2>, T2> SubAssign<Coordinate<T2>> for Coordinate<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Coordinate<T2>) {
+        *self.x_mut() -= rhs.x;
+        *self.y_mut() -= rhs.y;
+    }
+}
+
+impl<T: Sub<T2>, T2> Sub<Coordinate<T2>> for Coordinate<T> {
+    type Output = Coordinate<T::Output>;
+
+    #[inline]
+    fn sub(self, rhs: Coordinate<T2>) -> Self::Output {
+        Coordinate::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Neg<Output = T2>, T2> Neg for Coordinate<T> {
+    type Output = Coordinate<T2>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Coordinate::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Zero> Zero for Coordinate<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.iter().all(Zero::is_zero)
+    }
+}
+
+/// Implement a component wise checked or saturating operation on [`Coordinate`] with the
+/// given [`num_traits`] trait. The right hand side of `x` and `y` is given after the `=>`,
+/// either the components of an other coordinate or a scalar.
+macro_rules! impl_integer_op {
+    (
+        $(#[$attr:meta])*
+        checked $trait:ident::$method:ident
+        fn $name:ident($rhs:ident: $rhs_ty:ty) => ($x:expr, $y:expr)
+    ) => {
+        impl<T: $trait> Coordinate<T> {
+            $(#[$attr])*
+            #[inline]
+            #[must_use]
+            pub fn $name(self, $rhs: $rhs_ty) -> Option<Self> {
+                Some(Self::new(self.x.$method(&$x)?, self.y.$method(&$y)?))
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        saturating $trait:ident::$method:ident
+        fn $name:ident($rhs:ident: $rhs_ty:ty) => ($x:expr, $y:expr)
+    ) => {
+        impl<T: $trait> Coordinate<T> {
+            $(#[$attr])*
+            #[inline]
+            #[must_use]
+            pub fn $name(self, $rhs: $rhs_ty) -> Self {
+                Self::new(self.x.$method(&$x), self.y.$method(&$y))
+            }
+        }
+    };
+}
+
+impl_integer_op!(
+    /// Add `other` component wise, [`None`] if a component overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(250_u8, 3_u8);
+    /// assert_eq!(c.checked_add(Coordinate::new(5, 1)), Some(Coordinate::new(255, 4)));
+    /// assert_eq!(c.checked_add(Coordinate::new(6, 1)), None);
+    /// ```
+    checked CheckedAdd::checked_add
+    fn checked_add(other: Self) => (other.x, other.y)
+);
+
+impl_integer_op!(
+    /// Subtract `other` component wise, [`None`] if a component overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(5_u8, 3_u8);
+    /// assert_eq!(c.checked_sub(Coordinate::new(5, 1)), Some(Coordinate::new(0, 2)));
+    /// assert_eq!(c.checked_sub(Coordinate::new(0, 4)), None);
+    /// ```
+    checked CheckedSub::checked_sub
+    fn checked_sub(other: Self) => (other.x, other.y)
+);
+
+impl_integer_op!(
+    /// Multiply both components by `factor`, [`None`] if a component overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(100_u8, 3_u8);
+    /// assert_eq!(c.checked_mul_scalar(2), Some(Coordinate::new(200, 6)));
+    /// assert_eq!(c.checked_mul_scalar(3), None);
+    /// ```
+    checked CheckedMul::checked_mul
+    fn checked_mul_scalar(factor: T) => (factor, factor)
+);
+
+impl_integer_op!(
+    /// Add `other` component wise, each component saturating at the bounds of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(250_u8, 3_u8);
+    /// assert_eq!(c.saturating_add(Coordinate::new(10, 1)), Coordinate::new(255, 4));
+    /// ```
+    saturating SaturatingAdd::saturating_add
+    fn saturating_add(other: Self) => (other.x, other.y)
+);
+
+impl_integer_op!(
+    /// Subtract `other` component wise, each component saturating at the bounds of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(5_u8, 3_u8);
+    /// assert_eq!(c.saturating_sub(Coordinate::new(1, 4)), Coordinate::new(4, 0));
+    /// ```
+    saturating SaturatingSub::saturating_sub
+    fn saturating_sub(other: Self) => (other.x, other.y)
+);
+
+impl_integer_op!(
+    /// Multiply both components by `factor`, each component saturating at the bounds of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::Coordinate;
+    ///
+    /// let c = Coordinate::new(100_u8, 3_u8);
+    /// assert_eq!(c.saturating_mul_scalar(3), Coordinate::new(255, 9));
+    /// ```
+    saturating SaturatingMul::saturating_mul
+    fn saturating_mul_scalar(factor: T) => (factor, factor)
+);
 
 //----------------------------------
 // conversion
@@ -350,29 +1707,29 @@ impl<T> From<Coordinate<T>> for [T; 2] {
     }
 }
 
-#[allow(clippy::fallible_impl_from)] // reason = "the conversion actually never panic"
 impl<T> From<[T; 2]> for Coordinate<T> {
     #[inline]
     fn from(value: [T; 2]) -> Self {
-        let mut iter = value.into_iter();
-        Self::new(
-            iter.next().expect("never none"),
-            iter.next().expect("never none"),
-        )
+        let [x, y] = value;
+        Self::new(x, y)
     }
 }
 
+/// **Lossy conversion**: the missing components are filled with [`Default::default`] and the
+/// extra elements are ignored, so `[4]` gives `Coordinate::new(4, 0)`. It is kept for
+/// compatibility, prefer the explicit [`Coordinate::try_from_slice`] or
+/// [`Coordinate::from_slice_defaulting`].
 impl<T: Clone + Default> From<&[T]> for Coordinate<T> {
     #[inline]
     fn from(value: &[T]) -> Self {
-        let mut iter = value.iter();
-        Self::new(
-            iter.next().cloned().unwrap_or_default(),
-            iter.next().cloned().unwrap_or_default(),
-        )
+        Self::from_slice_defaulting(value)
     }
 }
 
+/// **Lossy conversion**: the missing components are filled with [`Default::default`] and the
+/// extra elements are ignored, see [`Coordinate::from_slice_defaulting`]. It is kept for
+/// compatibility, prefer [`Coordinate::try_from_slice`].
+#[cfg(feature = "alloc")]
 impl<T: Default> From<Vec<T>> for Coordinate<T> {
     #[inline]
     fn from(value: Vec<T>) -> Self {
@@ -387,7 +1744,38 @@ impl<T: Default> From<Vec<T>> for Coordinate<T> {
 //----------------------------------
 // format
 
-/// implement a [`fmt`] trait for [`Coordinate`]
+impl<T: Display> Coordinate<T> {
+    /// Display the coordinate with each component right-aligned in a field of `width`
+    /// characters, for printing a table of coordinates. A component longer than `width` is
+    /// not truncated. The precision flag is forwarded to the components.
+    ///
+    /// The format flags of [`Display`] are forwarded to each component, so `{:5}` already
+    /// pads the components, but the alignment has to be given with the flags at each use.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::Coordinate;
+    ///
+    /// assert_eq!(
+    ///     Coordinate::new(4_u32, 105_u32)
+    ///         .display_padded(3)
+    ///         .to_string(),
+    ///     "[  4, 105]"
+    /// );
+    /// assert_eq!(
+    ///     format!("{:.1}", Coordinate::new(1.25_f64, -3_f64).display_padded(5)),
+    ///     "[  1.2,  -3.0]"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_padded(&self, width: usize) -> impl Display + '_ {
+        display::PaddedDisplay::new(self, width)
+    }
+}
+
+/// implement a [`fmt`] trait for [`Coordinate`]. The format flags, like the width or the
+/// precision, are forwarded to each component, so `{:3}` gives `[  4, 105]`.
 macro_rules! impl_fmt_coord {
     ($trait:path) => {
         impl<T: $trait> $trait for Coordinate<T> {
@@ -403,6 +1791,59 @@ macro_rules! impl_fmt_coord {
     };
 }
 
+/// Compact form `Coordinate(1, 2)` and the pretty struct form under the alternate flag.
+impl<T: Debug> Debug for Coordinate<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Coordinate")
+                .field("x", self.x())
+                .field("y", self.y())
+                .finish()
+        } else {
+            f.debug_tuple("Coordinate")
+                .field(self.x())
+                .field(self.y())
+                .finish()
+        }
+    }
+}
+
+/// Parse the compact form `"3,-4"` or the [`Display`] form `"[3, -4]"`, the whitespace around
+/// the components is ignored.
+///
+/// # Example
+/// ```
+/// use utils_lib::Coordinate;
+///
+/// assert_eq!("3,-4".parse(), Ok(Coordinate::new(3_i64, -4_i64)));
+/// assert_eq!("[1.5, 2]".parse(), Ok(Coordinate::new(1.5_f64, 2_f64)));
+///
+/// let coordinate = Coordinate::new(-7_i32, 12_i32);
+/// assert_eq!(coordinate.to_string().parse(), Ok(coordinate));
+/// ```
+impl<T: FromStr> FromStr for Coordinate<T> {
+    type Err = ParseCoordinateError<T::Err>;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('[')
+            .and_then(|inner| inner.strip_suffix(']'))
+            .unwrap_or(s);
+        let (x, y) = s
+            .split_once(',')
+            .ok_or(ParseCoordinateError::MissingSeparator)?;
+        match (x.trim().parse(), y.trim().parse()) {
+            (Ok(x), Ok(y)) => Ok(Self::new(x, y)),
+            (Err(x), Ok(_)) => Err(PerAxisError::X(x).into()),
+            (Ok(_), Err(y)) => Err(PerAxisError::Y(y).into()),
+            (Err(x), Err(y)) => Err(PerAxisError::Both { x, y }.into()),
+        }
+    }
+}
+
 impl_fmt_coord!(Display);
 impl_fmt_coord!(Octal);
 impl_fmt_coord!(LowerHex);
@@ -415,10 +1856,72 @@ impl_fmt_coord!(UpperExp);
 #[cfg(test)]
 mod test {
 
+    use core::{
+        f64::consts::FRAC_PI_6,
+        ops::{Add, Sub},
+    };
+
     use num_traits::Zero;
 
     use super::{Axis2D, Coordinate};
-    use crate::{error::NoneError, PositiveFloat};
+    use crate::{
+        error::{NoneError, ParseCoordinateError, PerAxisError, WrongLengthError},
+        number::PositiveFloatConversionError,
+        PositiveFloat,
+    };
+
+    #[test]
+    fn ordering() {
+        let coords = [
+            Coordinate::new(2_u32, 0_u32),
+            Coordinate::new(0_u32, 1_u32),
+            Coordinate::new(1_u32, 0_u32),
+            Coordinate::new(0_u32, 0_u32),
+            Coordinate::new(1_u32, 2_u32),
+        ];
+        let column_order = vec![
+            Coordinate::new(0_u32, 0_u32),
+            Coordinate::new(0_u32, 1_u32),
+            Coordinate::new(1_u32, 0_u32),
+            Coordinate::new(1_u32, 2_u32),
+            Coordinate::new(2_u32, 0_u32),
+        ];
+        let reading_order = vec![
+            Coordinate::new(0_u32, 0_u32),
+            Coordinate::new(1_u32, 0_u32),
+            Coordinate::new(2_u32, 0_u32),
+            Coordinate::new(0_u32, 1_u32),
+            Coordinate::new(1_u32, 2_u32),
+        ];
+        assert_ne!(column_order, reading_order);
+
+        let mut sorted = coords.to_vec();
+        sorted.sort();
+        assert_eq!(sorted, column_order);
+        let mut sorted = coords.to_vec();
+        sorted.sort_by(|a, b| a.cmp_by_axis(b, Axis2D::Vertical));
+        assert_eq!(sorted, column_order);
+
+        let mut sorted = coords.to_vec();
+        sorted.sort_by(Coordinate::cmp_reading_order);
+        assert_eq!(sorted, reading_order);
+        let mut sorted = coords.to_vec();
+        sorted.sort_by(|a, b| a.cmp_by_axis(b, Axis2D::Horizontal));
+        assert_eq!(sorted, reading_order);
+        let mut sorted = coords.to_vec();
+        sorted.sort_by_key(Coordinate::reading_order_key);
+        assert_eq!(sorted, reading_order);
+
+        for a in coords {
+            for b in coords {
+                assert_eq!(a.cmp_by_axis(&b, Axis2D::Vertical), a.cmp(&b));
+                assert_eq!(
+                    a.cmp_reading_order(&b),
+                    a.reading_order_key().cmp(&b.reading_order_key())
+                );
+            }
+        }
+    }
 
     #[test]
     fn axis_2d() {
@@ -439,6 +1942,77 @@ mod test {
         assert_eq!(Axis2D::try_from(1_usize), Ok(Axis2D::Horizontal));
     }
 
+    #[test]
+    fn delta() {
+        let zero = Coordinate::new(0_usize, 0_usize);
+        let one = Coordinate::new(1_usize, 1_usize);
+        let max = Coordinate::new(usize::MAX, usize::MAX);
+
+        assert_eq!(zero.delta(zero), Coordinate::new(0_i64, 0_i64));
+        assert_eq!(zero.delta(one), Coordinate::new(-1_i64, -1_i64));
+        assert_eq!(one.delta(zero), Coordinate::new(1_i64, 1_i64));
+        assert_eq!(
+            zero.checked_delta(one),
+            Some(Coordinate::new(-1_i64, -1_i64))
+        );
+
+        // out of the i64 range
+        assert_eq!(max.checked_delta(zero), None);
+        assert_eq!(zero.checked_delta(max), None);
+        assert_eq!(max.delta(zero), Coordinate::new(i64::MAX, i64::MAX));
+        assert_eq!(zero.delta(max), Coordinate::new(i64::MIN, i64::MIN));
+        let big = Coordinate::new(usize::MAX - 1_usize, usize::MAX);
+        assert_eq!(max.delta(big), Coordinate::new(1_i64, 0_i64));
+        assert_eq!(big.delta(max), Coordinate::new(-1_i64, 0_i64));
+
+        // boundary of the i64 range
+        let half = Coordinate::new(1_usize << 63_u32, 0_usize);
+        assert_eq!(
+            zero.checked_delta(half),
+            Some(Coordinate::new(i64::MIN, 0_i64))
+        );
+        assert_eq!(half.checked_delta(zero), None);
+
+        assert_eq!(zero.offset_by(Coordinate::new(0_i64, 0_i64)), Some(zero));
+        assert_eq!(zero.offset_by(Coordinate::new(-1_i64, 0_i64)), None);
+        assert_eq!(zero.offset_by(Coordinate::new(0_i64, -1_i64)), None);
+        assert_eq!(one.offset_by(Coordinate::new(-1_i64, -1_i64)), Some(zero));
+        assert_eq!(max.offset_by(Coordinate::new(1_i64, 0_i64)), None);
+        assert_eq!(max.offset_by(Coordinate::new(-1_i64, 0_i64)), Some(big));
+        assert_eq!(big.offset_by(-big.delta(max)), Some(max));
+        assert_eq!(
+            zero.offset_by(Coordinate::new(i64::MAX, 0_i64)),
+            Some(Coordinate::new(usize::MAX >> 1_u32, 0_usize))
+        );
+
+        assert_eq!(zero.abs_delta(max), max);
+        assert_eq!(max.abs_delta(zero), max);
+        assert_eq!(max.abs_delta(big), Coordinate::new(1_usize, 0_usize));
+        assert_eq!(one.abs_delta(one), zero);
+    }
+
+    #[test]
+    fn debug() {
+        let coord = Coordinate::new(1_i32, -2_i32);
+        assert_eq!(format!("{coord:?}"), "Coordinate(1, -2)");
+        assert_eq!(
+            format!("{coord:#?}"),
+            "Coordinate {\n    x: 1,\n    y: -2,\n}"
+        );
+        assert_eq!(
+            format!("{:?}", Coordinate::new("a", "b")),
+            "Coordinate(\"a\", \"b\")"
+        );
+        assert_eq!(
+            format!("{:?}", vec![coord, Coordinate::new(0_i32, 0_i32)]),
+            "[Coordinate(1, -2), Coordinate(0, 0)]"
+        );
+        assert_eq!(
+            format!("{:.1?}", Coordinate::new(0.25_f64, 1_f64)),
+            "Coordinate(0.2, 1.0)"
+        );
+    }
+
     #[test]
     fn coord() {
         let mut coord = Coordinate::new(0_usize, 1_usize);
@@ -469,6 +2043,7 @@ mod test {
         assert_eq!(coord[Axis2D::Horizontal], 6_usize);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn coord_conversion() {
         let coord = Coordinate::new(0_usize, 1_usize);
@@ -484,14 +2059,253 @@ mod test {
         let array = [0_usize, 1_usize];
         assert_eq!(<Coordinate<usize> as From<&[usize]>>::from(&array), coord);
         assert_eq!(Coordinate::from(array.to_vec()), coord);
+        assert_eq!(Coordinate::try_from_slice(&array), Ok(coord));
+
+        // a short slice is an error with the strict conversion
         let array = [4_usize];
         assert_eq!(
-            <Coordinate<usize> as From<&[usize]>>::from(&array),
+            Coordinate::try_from_slice(&array),
+            Err(WrongLengthError::new(2, 1))
+        );
+        // the defaulting has to be explicitly requested
+        assert_eq!(
+            Coordinate::from_slice_defaulting(&array),
             Coordinate::new(4_usize, 0_usize)
         );
+        // the From implementations are lossy, they are kept for compatibility
+        assert_eq!(
+            <Coordinate<usize> as From<&[usize]>>::from(&array),
+            Coordinate::from_slice_defaulting(&array)
+        );
         assert_eq!(
             Coordinate::from(array.to_vec()),
-            Coordinate::new(4_usize, 0_usize)
+            Coordinate::from_slice_defaulting(&array)
+        );
+    }
+
+    #[test]
+    fn coord_strict_conversion() {
+        const ARRAY: Coordinate<i8> = Coordinate::from_array([-1, 2]);
+        const TUPLE: Coordinate<i8> = Coordinate::from_tuple((-1, 2));
+
+        assert_eq!(
+            Coordinate::try_from_slice(&[0_usize, 1_usize]),
+            Ok(Coordinate::new(0, 1))
+        );
+        assert_eq!(
+            Coordinate::try_from_slice(&[4_usize]),
+            Err(WrongLengthError::new(2, 1))
+        );
+        let err =
+            Coordinate::try_from_slice(&[4_usize, 5_usize, 6_usize]).expect_err("the length is 3");
+        assert_eq!(err.expected(), 2);
+        assert_eq!(err.actual(), 3);
+        assert_eq!(
+            Coordinate::<usize>::try_from_slice(&[]),
+            Err(WrongLengthError::new(2, 0))
+        );
+
+        assert_eq!(ARRAY, TUPLE);
+        assert_eq!(ARRAY, Coordinate::from([-1_i8, 2_i8]));
+        assert_eq!(
+            Coordinate::from([String::from("x"), String::from("y")]),
+            Coordinate::new(String::from("x"), String::from("y"))
+        );
+    }
+
+    #[test]
+    fn coord_validated_conversion() {
+        use crate::{
+            error::PerAxisError,
+            number::{PositiveFloatConversionError, ZeroOneBoundedFloatConversionError},
+            ZeroOneBoundedFloat,
+        };
+
+        let half = ZeroOneBoundedFloat::new(0.5_f64).expect("valid");
+        assert_eq!(
+            Coordinate::new(0.5_f64, 1_f64).try_map_validate(),
+            Ok(Coordinate::new(half, ZeroOneBoundedFloat::ONE))
+        );
+
+        let err = Coordinate::new(-1_f64, 0.5_f64)
+            .try_map_validate::<ZeroOneBoundedFloat>()
+            .expect_err("x is invalid");
+        assert_eq!(
+            err,
+            PerAxisError::X(ZeroOneBoundedFloatConversionError::TooLow)
+        );
+        assert_eq!(err.x(), Some(&ZeroOneBoundedFloatConversionError::TooLow));
+        assert_eq!(err.y(), None);
+        assert_eq!(
+            err.to_string(),
+            "invalid x component: the float is below zero"
+        );
+
+        let err = Coordinate::new(1_f64, f64::NAN)
+            .try_map_validate::<PositiveFloat>()
+            .expect_err("y is invalid");
+        assert_eq!(err, PerAxisError::Y(PositiveFloatConversionError::Nan));
+        assert_eq!(err.x(), None);
+        assert_eq!(err.y(), Some(&PositiveFloatConversionError::Nan));
+        assert!(err.to_string().starts_with("invalid y component: "));
+
+        let err = Coordinate::new(2_f64, -2_f64)
+            .try_map_validate::<ZeroOneBoundedFloat>()
+            .expect_err("both are invalid");
+        assert_eq!(
+            err,
+            PerAxisError::Both {
+                x: ZeroOneBoundedFloatConversionError::TooBig,
+                y: ZeroOneBoundedFloatConversionError::TooLow,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid x and y components: x: the float is above one, y: the float is below zero"
+        );
+
+        assert_eq!(
+            Coordinate::new(-1_f64, 2_f64).clamp_components_to_unit(),
+            Coordinate::new(ZeroOneBoundedFloat::ZERO, ZeroOneBoundedFloat::ONE)
+        );
+        assert_eq!(
+            Coordinate::new(f64::NAN, f64::INFINITY).clamp_components_to_unit(),
+            Coordinate::new(ZeroOneBoundedFloat::ZERO, ZeroOneBoundedFloat::ONE)
+        );
+        assert_eq!(
+            Coordinate::new(0.5_f64, f64::NEG_INFINITY).clamp_components_to_unit(),
+            Coordinate::new(half, ZeroOneBoundedFloat::ZERO)
+        );
+    }
+
+    #[test]
+    fn primitive_direction() {
+        for (x, y) in [(6_i64, 4_i64), (-6, 4), (-6, -4), (6, -4)] {
+            let vector = Coordinate::new(x, y);
+            let (direction, factor) = vector.primitive_direction();
+            assert_eq!(direction, Coordinate::new(x / 2_i64, y / 2_i64));
+            assert_eq!(factor, 2_u64);
+            assert!(vector.collinear_with(direction));
+        }
+        assert_eq!(
+            Coordinate::new(7_i64, -3_i64).primitive_direction(),
+            (Coordinate::new(7_i64, -3_i64), 1_u64)
+        );
+
+        // axis aligned
+        assert_eq!(
+            Coordinate::new(-4_i64, 0_i64).primitive_direction(),
+            (Coordinate::new(-1_i64, 0_i64), 4_u64)
+        );
+        assert_eq!(
+            Coordinate::new(0_i64, 9_i64).primitive_direction(),
+            (Coordinate::new(0_i64, 1_i64), 9_u64)
+        );
+        assert_eq!(
+            Coordinate::new(i64::MIN, 0_i64).primitive_direction(),
+            (Coordinate::new(-1_i64, 0_i64), i64::MIN.unsigned_abs())
+        );
+        assert_eq!(
+            Coordinate::new(i64::MIN, i64::MIN).primitive_direction(),
+            (Coordinate::new(-1_i64, -1_i64), i64::MIN.unsigned_abs())
+        );
+        assert_eq!(
+            Coordinate::new(1_000_000_000_000_i64, 3_i64).primitive_direction(),
+            (Coordinate::new(1_000_000_000_000_i64, 3_i64), 1_u64)
+        );
+
+        // zero vector
+        let zero = Coordinate::new(0_i64, 0_i64);
+        assert_eq!(zero.primitive_direction(), (zero, 0_u64));
+
+        let direction = Coordinate::new(1_i64, 2_i64);
+        assert!(direction.collinear_with(Coordinate::new(-3_i64, -6_i64)));
+        assert!(!direction.collinear_with(Coordinate::new(2_i64, 1_i64)));
+        assert!(!direction.collinear_with(Coordinate::new(1_i64, 0_i64)));
+        assert!(zero.collinear_with(direction));
+        assert!(direction.collinear_with(zero));
+        assert!(
+            Coordinate::new(i64::MAX, i64::MIN).collinear_with(Coordinate::new(i64::MAX, i64::MIN))
+        );
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh() {
+        use borsh::io::ErrorKind;
+
+        let coord = Coordinate::new(-3_i32, 7_i32);
+        let bytes = borsh::to_vec(&coord).expect("serializable");
+        assert_eq!(
+            bytes,
+            borsh::to_vec(&(-3_i32, 7_i32)).expect("serializable")
+        );
+        assert_eq!(
+            borsh::from_slice::<Coordinate<i32>>(&bytes).expect("valid bytes"),
+            coord
+        );
+
+        let coord = Coordinate::new(PositiveFloat::ONE, PositiveFloat::ZERO);
+        let bytes = borsh::to_vec(&coord).expect("serializable");
+        assert_eq!(
+            borsh::from_slice::<Coordinate<PositiveFloat>>(&bytes).expect("valid bytes"),
+            coord
+        );
+
+        let bytes = borsh::to_vec(&Coordinate::new(1_f64, -1_f64)).expect("serializable");
+        let err = borsh::from_slice::<Coordinate<PositiveFloat>>(&bytes)
+            .expect_err("the y component is negative");
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        /// A number that is not [`Copy`].
+        #[derive(Debug, PartialEq, PartialOrd)]
+        struct Owned(i64);
+
+        impl Sub for Owned {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl Add for Owned {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        /// Generic code only bounding `T`, it does not compile with [`Coordinate::s1_distance`].
+        fn distance<T>(a: Coordinate<T>, b: Coordinate<T>) -> T
+        where
+            T: Sub<Output = T> + Add<Output = T> + PartialOrd,
+        {
+            a.manhattan_distance(b)
+        }
+
+        let coord_1 = Coordinate::new(10_i32, 22_i32);
+        let coord_2 = Coordinate::new(13_i32, 21_i32);
+        assert_eq!(distance(coord_1, coord_2), 4_i32);
+        assert_eq!(coord_1.manhattan_distance_copy(&coord_2), 4_i32);
+        assert_eq!(
+            coord_1.manhattan_distance_copy(&coord_2),
+            coord_1.s1_distance(&coord_2)
+        );
+        assert_eq!(
+            Coordinate::new(3_u8, 1_u8).manhattan_distance(Coordinate::new(1_u8, 5_u8)),
+            6_u8
+        );
+        assert_eq!(
+            distance(
+                Coordinate::new(Owned(-2_i64), Owned(5_i64)),
+                Coordinate::new(Owned(1_i64), Owned(1_i64))
+            ),
+            Owned(7_i64)
         );
     }
 
@@ -517,6 +2331,236 @@ mod test {
         assert!(Coordinate::<PositiveFloat>::zero().is_zero());
     }
 
+    #[test]
+    fn checked_saturating() {
+        let zero = Coordinate::new(0_u8, 0_u8);
+        let max = Coordinate::new(u8::MAX, u8::MAX);
+        let one = Coordinate::new(1_u8, 1_u8);
+
+        assert_eq!(max.checked_add(zero), Some(max));
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(Coordinate::new(u8::MAX, 0_u8).checked_add(one), None);
+        assert_eq!(Coordinate::new(0_u8, u8::MAX).checked_add(one), None);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(
+            Coordinate::new(u8::MAX, 0_u8).saturating_add(one),
+            Coordinate::new(u8::MAX, 1_u8)
+        );
+
+        assert_eq!(zero.checked_sub(zero), Some(zero));
+        assert_eq!(zero.checked_sub(one), None);
+        assert_eq!(Coordinate::new(1_u8, 0_u8).checked_sub(one), None);
+        assert_eq!(max.checked_sub(max), Some(zero));
+        assert_eq!(zero.saturating_sub(one), zero);
+        assert_eq!(
+            Coordinate::new(0_u8, u8::MAX).saturating_sub(one),
+            Coordinate::new(0_u8, u8::MAX - 1_u8)
+        );
+
+        assert_eq!(max.checked_mul_scalar(1_u8), Some(max));
+        assert_eq!(max.checked_mul_scalar(0_u8), Some(zero));
+        assert_eq!(max.checked_mul_scalar(2_u8), None);
+        assert_eq!(Coordinate::new(1_u8, 128_u8).checked_mul_scalar(2_u8), None);
+        assert_eq!(max.saturating_mul_scalar(2_u8), max);
+        assert_eq!(
+            Coordinate::new(1_u8, 128_u8).saturating_mul_scalar(2_u8),
+            Coordinate::new(2_u8, u8::MAX)
+        );
+        assert_eq!(zero.saturating_mul_scalar(u8::MAX), zero);
+
+        let max = Coordinate::new(u16::MAX, 0_u16);
+        assert_eq!(
+            max.saturating_add(Coordinate::new(1_u16, 1_u16)),
+            Coordinate::new(u16::MAX, 1_u16)
+        );
+        assert_eq!(
+            Coordinate::new(-1_i8, i8::MIN).saturating_mul_scalar(i8::MAX),
+            Coordinate::new(-i8::MAX, i8::MIN)
+        );
+    }
+
+    #[test]
+    fn wrap_reflect() {
+        let bounds = Coordinate::new(5_i64, 3_i64);
+        // euclidean modulo for negative components
+        assert_eq!(
+            Coordinate::new(-1_i64, -1_i64).wrap_in(bounds),
+            Coordinate::new(4_i64, 2_i64)
+        );
+        assert_eq!(
+            Coordinate::new(-5_i64, -3_i64).wrap_in(bounds),
+            Coordinate::new(0_i64, 0_i64)
+        );
+        // several bounds lengths away
+        assert_eq!(
+            Coordinate::new(-11_i64, 3_i64 * 7_i64 + 1_i64).wrap_in(bounds),
+            Coordinate::new(4_i64, 1_i64)
+        );
+        assert_eq!(
+            Coordinate::new(4_i64, 2_i64).wrap_in(bounds),
+            Coordinate::new(4_i64, 2_i64)
+        );
+        assert_eq!(
+            Coordinate::new(i64::MIN, i64::MAX).wrap_in(Coordinate::new(5_i64, 3_i64)),
+            Coordinate::new(i64::MIN.rem_euclid(5_i64), i64::MAX.rem_euclid(3_i64))
+        );
+
+        // reflection with a period of 8 on the x axis (0 1 2 3 4 3 2 1 0 ...)
+        let expected_x = [0_i64, 1, 2, 3, 4, 3, 2, 1];
+        for x in -24_i64..24_i64 {
+            let reflected = Coordinate::new(x, 0_i64).reflect_in(bounds);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // reason = "in 0..8"
+            let index = x.rem_euclid(8_i64) as usize;
+            assert_eq!(reflected, Coordinate::new(expected_x[index], 0_i64));
+        }
+        assert_eq!(
+            Coordinate::new(-1_i64, -1_i64).reflect_in(bounds),
+            Coordinate::new(1_i64, 1_i64)
+        );
+        assert_eq!(
+            Coordinate::new(5_i64 + 8_i64 * 3_i64, 3_i64).reflect_in(bounds),
+            Coordinate::new(3_i64, 1_i64)
+        );
+        // a bound of 1 always gives 0
+        assert_eq!(
+            Coordinate::new(-7_i64, 9_i64).reflect_in(Coordinate::new(1_i64, 1_i64)),
+            Coordinate::new(0_i64, 0_i64)
+        );
+        assert_eq!(
+            Coordinate::new(i64::MIN, i64::MAX).reflect_in(Coordinate::new(i64::MAX, i64::MAX)),
+            Coordinate::new(i64::MAX - 3_i64, i64::MAX - 2_i64)
+        );
+
+        let bounds = Coordinate::new(5_usize, 3_usize);
+        assert_eq!(
+            Coordinate::new(12_usize, 3_usize).wrap_in(bounds),
+            Coordinate::new(2_usize, 0_usize)
+        );
+        assert_eq!(
+            Coordinate::new(5_usize, 6_usize).reflect_in(bounds),
+            Coordinate::new(3_usize, 2_usize)
+        );
+        assert_eq!(
+            Coordinate::new(4_usize + 8_usize * 5_usize, 2_usize + 4_usize * 9_usize)
+                .reflect_in(bounds),
+            Coordinate::new(4_usize, 2_usize)
+        );
+        assert_eq!(
+            Coordinate::new(usize::MAX, usize::MAX)
+                .reflect_in(Coordinate::new(usize::MAX, 2_usize)),
+            Coordinate::new(usize::MAX - 2_usize, 1_usize)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the bounds must be strictly positive")]
+    fn wrap_negative_bounds() {
+        let _wrapped = Coordinate::new(1_i64, 1_i64).wrap_in(Coordinate::new(-5_i64, 3_i64));
+    }
+
+    #[test]
+    fn periodic_metrics() {
+        let period = Coordinate::new(10_i32, 10_i32);
+        let unsigned_period = Coordinate::new(10_u32, 10_u32);
+        // brute force on a 10x10 torus: the shortest of the images of `b` shifted by a period
+        let component_distance = |a: i32, b: i32| {
+            [-10_i32, 0_i32, 10_i32]
+                .into_iter()
+                .map(|shift| (a - b + shift).abs())
+                .min()
+                .expect("not empty")
+        };
+        let mut differ = 0_u32;
+        for a in super::range(Coordinate::new(0, 0), Coordinate::new(10, 10)) {
+            for b in super::range(Coordinate::new(0, 0), Coordinate::new(10, 10)) {
+                let convert = |c: Coordinate<usize>| {
+                    (
+                        c.try_map_validate::<i32>().expect("small component"),
+                        c.try_map_validate::<u32>().expect("small component"),
+                    )
+                };
+                let ((signed_a, unsigned_a), (signed_b, unsigned_b)) = (convert(a), convert(b));
+                let expected = component_distance(signed_a.x, signed_b.x)
+                    + component_distance(signed_a.y, signed_b.y);
+                let distance = signed_a.periodic_s1_distance(&signed_b, &period);
+                assert_eq!(distance, expected);
+                assert_eq!(
+                    unsigned_a.periodic_s1_distance(&unsigned_b, &unsigned_period),
+                    expected.unsigned_abs()
+                );
+                assert_eq!(signed_b.periodic_s1_distance(&signed_a, &period), distance);
+                if distance != signed_a.s1_distance(&signed_b) {
+                    differ += 1;
+                }
+
+                let delta = signed_a.periodic_delta(&signed_b, &period);
+                assert_eq!(delta.x.abs() + delta.y.abs(), distance);
+                assert!(delta.x > -5_i32 && delta.x <= 5_i32);
+                assert!(delta.y > -5_i32 && delta.y <= 5_i32);
+                assert_eq!((signed_a - signed_b - delta).x.rem_euclid(10_i32), 0_i32);
+                assert_eq!((signed_a - signed_b - delta).y.rem_euclid(10_i32), 0_i32);
+            }
+        }
+        assert!(differ > 0_u32);
+
+        // the half period is positive in both directions
+        let a = Coordinate::new(0_i32, 7_i32);
+        let b = Coordinate::new(5_i32, 2_i32);
+        assert_eq!(a.periodic_delta(&b, &period), Coordinate::new(5_i32, 5_i32));
+        assert_eq!(b.periodic_delta(&a, &period), Coordinate::new(5_i32, 5_i32));
+
+        // components outside of 0..period
+        assert_eq!(
+            Coordinate::new(-1_i32, 23_i32)
+                .periodic_s1_distance(&Coordinate::new(0_i32, -8_i32), &period),
+            2_i32
+        );
+        assert_eq!(
+            Coordinate::new(-1_i32, 23_i32)
+                .periodic_delta(&Coordinate::new(0_i32, -8_i32), &period),
+            Coordinate::new(-1_i32, 1_i32)
+        );
+
+        // near 0 and near the period, the naive subtraction would underflow or overflow
+        let byte_period = Coordinate::new(u8::MAX, 200_u8);
+        assert_eq!(
+            Coordinate::new(0_u8, 0_u8)
+                .periodic_s1_distance(&Coordinate::new(254_u8, 199_u8), &byte_period),
+            2_u8
+        );
+        assert_eq!(
+            Coordinate::new(255_u8, 250_u8)
+                .periodic_s1_distance(&Coordinate::new(1_u8, 49_u8), &byte_period),
+            2_u8
+        );
+        let signed_period = Coordinate::new(i8::MAX, i8::MAX);
+        let min = Coordinate::new(i8::MIN, i8::MAX);
+        let max = Coordinate::new(i8::MAX, i8::MIN);
+        assert_eq!(min.periodic_s1_distance(&max, &signed_period), 2_i8);
+        assert_eq!(
+            min.periodic_delta(&max, &signed_period),
+            Coordinate::new(-1_i8, 1_i8)
+        );
+        assert_eq!(
+            max.periodic_delta(&min, &signed_period),
+            Coordinate::new(1_i8, -1_i8)
+        );
+        assert_eq!(
+            Coordinate::new(0_i8, 126_i8)
+                .periodic_delta(&Coordinate::new(126_i8, 0_i8), &signed_period),
+            Coordinate::new(1_i8, -1_i8)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the period must be strictly positive")]
+    fn periodic_zero_period() {
+        let _distance = Coordinate::new(1_u32, 1_u32).periodic_s1_distance(
+            &Coordinate::new(0_u32, 0_u32),
+            &Coordinate::new(0_u32, 3_u32),
+        );
+    }
+
     #[test]
     fn fmt() {
         assert_eq!(Coordinate::new(4_u32, 1053_u32).to_string(), "[4, 1053]");
@@ -555,4 +2599,232 @@ mod test {
             "[1.4, 6.8]"
         );
     }
+
+    #[test]
+    fn fmt_flags() {
+        // the flags are applied on each component
+        assert_eq!(
+            format!("{:3}", Coordinate::new(4_u32, 105_u32)),
+            "[  4, 105]"
+        );
+        assert_eq!(
+            format!("{:<3}", Coordinate::new(4_u32, 105_u32)),
+            "[4  , 105]"
+        );
+        assert_eq!(
+            format!("{:03}", Coordinate::new(4_i32, -5_i32)),
+            "[004, -05]"
+        );
+        assert_eq!(
+            format!("{:6.2}", Coordinate::new(1.5_f64, -2_f64)),
+            "[  1.50,  -2.00]"
+        );
+        assert_eq!(
+            format!("{:>4x}", Coordinate::new(0xf_u8, 0xff_u8)),
+            "[   f,   ff]"
+        );
+        assert_eq!(
+            format!("{:^5}", Coordinate::new(1_u8, 22_u8)),
+            "[  1  ,  22  ]"
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("3,-4".parse(), Ok(Coordinate::new(3_i64, -4_i64)));
+        assert_eq!(" 3 , -4 ".parse(), Ok(Coordinate::new(3_i64, -4_i64)));
+        assert_eq!("[3, -4]".parse(), Ok(Coordinate::new(3_i64, -4_i64)));
+        assert_eq!("-0.5,1e3".parse(), Ok(Coordinate::new(-0.5_f64, 1000_f64)));
+        for coordinate in [
+            Coordinate::new(0_i32, 0_i32),
+            Coordinate::new(-7_i32, 12_i32),
+            Coordinate::new(i32::MIN, i32::MAX),
+        ] {
+            assert_eq!(coordinate.to_string().parse(), Ok(coordinate));
+        }
+
+        for s in ["", "3", "3 -4", "[3]"] {
+            assert_eq!(
+                s.parse::<Coordinate<i64>>(),
+                Err(ParseCoordinateError::MissingSeparator),
+                "{s:?}"
+            );
+        }
+        assert!(matches!(
+            "x,-4".parse::<Coordinate<i64>>(),
+            Err(ParseCoordinateError::Component(PerAxisError::X(_)))
+        ));
+        assert!(matches!(
+            "3,".parse::<Coordinate<i64>>(),
+            Err(ParseCoordinateError::Component(PerAxisError::Y(_)))
+        ));
+        assert!(matches!(
+            "3,-4,5".parse::<Coordinate<i64>>(),
+            Err(ParseCoordinateError::Component(PerAxisError::Y(_)))
+        ));
+        assert!(matches!(
+            "-1,2".parse::<Coordinate<u8>>(),
+            Err(ParseCoordinateError::Component(PerAxisError::X(_)))
+        ));
+        assert!(matches!(
+            "a,b".parse::<Coordinate<i64>>(),
+            Err(ParseCoordinateError::Component(PerAxisError::Both { .. }))
+        ));
+    }
+
+    #[test]
+    fn display_padded() {
+        let c = Coordinate::new(4_u32, 105_u32);
+        assert_eq!(c.display_padded(3).to_string(), "[  4, 105]");
+        assert_eq!(c.display_padded(5).to_string(), "[    4,   105]");
+        // no truncation
+        assert_eq!(c.display_padded(1).to_string(), "[4, 105]");
+        assert_eq!(c.display_padded(0).to_string(), "[4, 105]");
+        // the width flag is ignored, the precision is forwarded
+        assert_eq!(format!("{:10}", c.display_padded(3)), "[  4, 105]");
+        assert_eq!(
+            format!("{:.2}", Coordinate::new(1.5_f64, -2_f64).display_padded(6)),
+            "[  1.50,  -2.00]"
+        );
+        assert_eq!(
+            Coordinate::new("ab", "c").display_padded(3).to_string(),
+            "[ ab,   c]"
+        );
+    }
+
+    #[test]
+    fn approx_eq() -> Result<(), PositiveFloatConversionError> {
+        let coord = Coordinate::new(1_f64, -2_f64);
+        let next = Coordinate::new(1_f64.next_up(), -2_f64);
+        let epsilon = PositiveFloat::new(f64::EPSILON)?;
+
+        assert_eq!(coord.max_ulp_diff(coord), 0);
+        assert_eq!(coord.max_ulp_diff(next), 1);
+        assert_eq!(
+            coord.max_ulp_diff(Coordinate::new(
+                1_f64.next_up(),
+                (-2_f64).next_up().next_up()
+            )),
+            2
+        );
+        assert_eq!(
+            Coordinate::new(0_f64, -0_f64).max_ulp_diff(Coordinate::new(-0_f64, 0_f64)),
+            0
+        );
+        assert!(coord.approx_eq(coord, PositiveFloat::ZERO));
+        assert!(!coord.approx_eq(next, PositiveFloat::ZERO));
+        assert!(coord.approx_eq(next, epsilon));
+        assert!(!coord.approx_eq(Coordinate::new(1_f64, -2.5_f64), epsilon));
+        assert!(!coord.approx_eq(Coordinate::new(1.5_f64, -2_f64), epsilon));
+        assert!(!coord.approx_eq(Coordinate::new(f64::NAN, -2_f64), PositiveFloat::MAX));
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "exact values"
+    fn angle_and_cosine_similarity() {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        use crate::{error::ZeroVectorError, ZeroOneBoundedFloat};
+
+        let x = Coordinate::new(3_f64, 0_f64);
+        let y = Coordinate::new(0_f64, 0.5_f64);
+        let diagonal = Coordinate::new(1_f64, 1_f64);
+        let zero = Coordinate::new(0_f64, 0_f64);
+
+        // orthogonal
+        assert_eq!(x.dot(&y), 0_f64);
+        assert_eq!(x.angle_between(&y), FRAC_PI_2);
+        assert_eq!(y.angle_between(&x), FRAC_PI_2);
+        assert_eq!(x.cosine_similarity(&y), Ok(0_f64));
+        assert_eq!(
+            x.cosine_similarity_unit(&y).map(ZeroOneBoundedFloat::float),
+            Ok(0.5_f64)
+        );
+
+        // parallel
+        assert_eq!(x.angle_between(&(x + x)), 0_f64);
+        assert_eq!(
+            diagonal.cosine_similarity(&(diagonal + diagonal)),
+            Ok(1_f64)
+        );
+        assert_eq!(x.cosine_similarity_unit(&x), Ok(ZeroOneBoundedFloat::ONE));
+        assert!((x.angle_between(&diagonal) - FRAC_PI_4).abs() < 1E-15_f64);
+
+        // antiparallel, including almost antiparallel vectors where acos loses precision
+        assert_eq!(x.angle_between(&-x), PI);
+        assert_eq!(diagonal.cosine_similarity(&-diagonal), Ok(-1_f64));
+        assert_eq!(
+            diagonal.cosine_similarity_unit(&-diagonal),
+            Ok(ZeroOneBoundedFloat::ZERO)
+        );
+        let almost = Coordinate::new(-1_f64, 1E-10_f64);
+        assert!(
+            (Coordinate::new(1_f64, 0_f64).angle_between(&almost) - (PI - 1E-10_f64)).abs()
+                < 1E-15_f64
+        );
+
+        // large components do not overflow
+        let large = Coordinate::new(1E300_f64, 1E300_f64);
+        assert_eq!(large.cosine_similarity(&large), Ok(1_f64));
+        assert_eq!(large.cosine_similarity(&-large), Ok(-1_f64));
+
+        // zero vectors
+        assert_eq!(zero.angle_between(&x), 0_f64);
+        assert_eq!(zero.cosine_similarity(&x), Err(ZeroVectorError));
+        assert_eq!(x.cosine_similarity(&zero), Err(ZeroVectorError));
+        assert_eq!(zero.cosine_similarity_unit(&zero), Err(ZeroVectorError));
+
+        // NaN
+        let nan = Coordinate::new(f64::NAN, 1_f64);
+        assert!(nan.cosine_similarity(&x).expect("not zero").is_nan());
+        assert_eq!(
+            nan.cosine_similarity_unit(&x),
+            Ok(ZeroOneBoundedFloat::ZERO)
+        );
+    }
+
+    #[test]
+    fn matrix() {
+        let m = Coordinate::new(
+            Coordinate::new(2_i32, -1_i32),
+            Coordinate::new(5_i32, 3_i32),
+        );
+        let v = Coordinate::new(4_i32, 7_i32);
+
+        assert_eq!(m.matmul_vec(v), Coordinate::new(1_i32, 41_i32));
+        assert_eq!(m.transpose().matmul_vec(v), Coordinate::new(43_i32, 17_i32));
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(m.determinant(), 11_i32);
+        assert_eq!(m.transpose().determinant(), m.determinant());
+
+        let identity = Coordinate::<Coordinate<i32>>::identity();
+        assert_eq!(
+            identity,
+            Coordinate::new(Coordinate::new(1_i32, 0_i32), Coordinate::new(0_i32, 1_i32))
+        );
+        assert_eq!(identity.matmul_vec(v), v);
+        assert_eq!(identity.transpose(), identity);
+        assert_eq!(identity.determinant(), 1_i32);
+
+        // singular matrix
+        let singular = Coordinate::new(Coordinate::new(1_u8, 2_u8), Coordinate::new(2_u8, 4_u8));
+        assert_eq!(singular.determinant(), 0_u8);
+
+        // rotation by pi / 6, the determinant is one and the norm is kept
+        let (sin, cos) = FRAC_PI_6.sin_cos();
+        let rotation = Coordinate::new(Coordinate::new(cos, -sin), Coordinate::new(sin, cos));
+        let rotated = rotation.matmul_vec(Coordinate::new(2_f64, 0_f64));
+        assert!((rotated.x - 3_f64.sqrt()).abs() < 1E-12_f64);
+        assert!((rotated.y - 1_f64).abs() < 1E-12_f64);
+        assert!((rotation.determinant() - 1_f64).abs() < 1E-12_f64);
+        // the inverse of a rotation is its transpose
+        let back = rotation.transpose().matmul_vec(rotated);
+        assert!((back.x - 2_f64).abs() < 1E-12_f64);
+        assert!(back.y.abs() < 1E-12_f64);
+
+        let identity = Coordinate::<Coordinate<f64>>::identity();
+        assert_eq!(identity.matmul_vec(rotated), rotated);
+    }
 }
@@ -0,0 +1,187 @@
+//! Contains the [`rand`] sampling of [`Axis2D`] and [`Coordinate`], with
+//! [`Coordinate::random_in_rect`] and [`Coordinate::random_in`] to draw coordinates in a
+//! rectangle, e.g. for property tests and procedural generation.
+//!
+//! The module requires the `rand` feature.
+
+use rand::{
+    distributions::{uniform::SampleUniform, Distribution, Standard},
+    Rng,
+};
+
+use super::{Axis2D, Coordinate, CoordinateRange};
+
+/// Sample the two axis with the same probability.
+impl Distribution<Axis2D> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Axis2D {
+        if rng.gen::<bool>() {
+            Axis2D::Horizontal
+        } else {
+            Axis2D::Vertical
+        }
+    }
+}
+
+/// Sample both components independently with [`Standard`], e.g. in `[0, 1)` for floats and
+/// in the full range for integers.
+impl<T> Distribution<Coordinate<T>> for Standard
+where
+    Self: Distribution<T>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Coordinate<T> {
+        Coordinate::new(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T: SampleUniform + PartialOrd> Coordinate<T> {
+    /// Get a uniformly random coordinate `c` in the rectangle with `min.x <= c.x < max.x` and
+    /// `min.y <= c.y < max.y`, for integer and float components alike.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle is empty, i.e. if `min.x >= max.x` or `min.y >= max.y`.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    /// use utils_lib::Coordinate;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let (min, max) = (Coordinate::new(-2, 3), Coordinate::new(2, 5));
+    /// let c = Coordinate::random_in_rect(&mut rng, min, max);
+    /// assert!((-2..2).contains(&c.x));
+    /// assert!((3..5).contains(&c.y));
+    ///
+    /// let c = Coordinate::random_in_rect(
+    ///     &mut rng,
+    ///     Coordinate::new(0_f64, 0_f64),
+    ///     Coordinate::new(1_f64, 0.5_f64),
+    /// );
+    /// assert!(c.x < 1_f64 && c.y < 0.5_f64);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn random_in_rect<R: Rng + ?Sized>(rng: &mut R, min: Self, max: Self) -> Self {
+        Self::new(rng.gen_range(min.x..max.x), rng.gen_range(min.y..max.y))
+    }
+}
+
+impl Coordinate<usize> {
+    /// Get a uniformly random coordinate in the range, see [`Self::random_in_rect`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    /// use utils_lib::coordinate::{range, Coordinate};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let grid = range(Coordinate::new(0, 0), Coordinate::new(10, 20));
+    /// assert!(grid.contains(&Coordinate::random_in(&mut rng, &grid)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn random_in<R: Rng + ?Sized>(rng: &mut R, range: &CoordinateRange) -> Self {
+        Self::random_in_rect(rng, range.start(), range.end())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use super::{Axis2D, Coordinate};
+    use crate::coordinate::range;
+
+    /// Number of samples drawn by the tests
+    const SAMPLES: usize = 1_000;
+
+    #[test]
+    fn seeded_determinism() {
+        let sample = |seed: u64| {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            (0..SAMPLES)
+                .map(|_| {
+                    (
+                        rng.gen::<Axis2D>(),
+                        rng.gen::<Coordinate<u8>>(),
+                        Coordinate::random_in_rect(
+                            &mut rng,
+                            Coordinate::new(-5_i32, 0_i32),
+                            Coordinate::new(5_i32, 3_i32),
+                        ),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sample(7_u64), sample(7_u64));
+        assert_ne!(sample(7_u64), sample(8_u64));
+    }
+
+    #[test]
+    fn axis() {
+        let mut rng = SmallRng::seed_from_u64(1_u64);
+        let axis = (0..SAMPLES)
+            .map(|_| rng.gen::<Axis2D>())
+            .collect::<Vec<_>>();
+        for variant in Axis2D::AXIS {
+            assert!(axis.contains(&variant), "{variant} never sampled");
+        }
+    }
+
+    #[test]
+    fn bounds_respected() {
+        let mut rng = SmallRng::seed_from_u64(2_u64);
+
+        let min = Coordinate::new(-3_i32, 10_i32);
+        let max = Coordinate::new(2_i32, 12_i32);
+        let samples = (0..SAMPLES)
+            .map(|_| Coordinate::random_in_rect(&mut rng, min, max))
+            .collect::<Vec<_>>();
+        for c in &samples {
+            assert!((min.x..max.x).contains(&c.x), "{c} out of bounds");
+            assert!((min.y..max.y).contains(&c.y), "{c} out of bounds");
+        }
+        // every one of the 10 coordinates is drawn
+        for x in min.x..max.x {
+            for y in min.y..max.y {
+                assert!(samples.contains(&Coordinate::new(x, y)));
+            }
+        }
+
+        let min = Coordinate::new(-1_f64, 0.25_f64);
+        let max = Coordinate::new(1E-3_f64, 0.5_f64);
+        for _ in 0..SAMPLES {
+            let c = Coordinate::random_in_rect(&mut rng, min, max);
+            assert!((min.x..max.x).contains(&c.x), "{c} out of bounds");
+            assert!((min.y..max.y).contains(&c.y), "{c} out of bounds");
+        }
+
+        let grid = range(Coordinate::new(4, 0), Coordinate::new(6, 100));
+        for _ in 0..SAMPLES {
+            let c = Coordinate::random_in(&mut rng, &grid);
+            assert!(grid.contains(&c), "{c} out of bounds");
+        }
+
+        for _ in 0..SAMPLES {
+            let c = rng.gen::<Coordinate<f64>>();
+            assert!((0_f64..1_f64).contains(&c.x), "{c} out of bounds");
+            assert!((0_f64..1_f64).contains(&c.y), "{c} out of bounds");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn empty_rect() {
+        let mut rng = SmallRng::seed_from_u64(3_u64);
+        let _coord = Coordinate::random_in(
+            &mut rng,
+            &range(Coordinate::new(0, 0), Coordinate::new(0, 5)),
+        );
+    }
+}
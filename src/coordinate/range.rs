@@ -0,0 +1,467 @@
+//! Contains [`CoordinateRange`] a rectangular range of [`Coordinate<usize>`] and its iterator
+//! [`CoordinateRangeIterator`]. Ranges are created with [`super::range`] and
+//! [`super::range_inclusive`].
+
+use core::{iter::FusedIterator, ops::Range};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::Coordinate;
+
+/// A rectangular range of [`Coordinate<usize>`], the equivalent of `start..end` on both axis.
+///
+/// It contains all the coordinates `c` such that `start.x <= c.x < end.x` and
+/// `start.y <= c.y < end.y`. The coordinates are iterated in row-major order, i.e. `x` is the
+/// row index (the [`super::Axis2D::Vertical`] axis) and `y` the column index, which is the
+/// same order as [`Ord`] on [`Coordinate`].
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{range, Coordinate};
+///
+/// let r = range(Coordinate::new(0, 0), Coordinate::new(2, 3));
+/// assert_eq!(r.len(), 6);
+/// assert!(r.contains(&Coordinate::new(1, 2)));
+/// assert!(!r.contains(&Coordinate::new(2, 0)));
+///
+/// let vec = r.into_iter().collect::<Vec<_>>();
+/// assert_eq!(vec[0], Coordinate::new(0, 0));
+/// assert_eq!(vec[1], Coordinate::new(0, 1));
+/// assert_eq!(vec[3], Coordinate::new(1, 0));
+/// assert_eq!(vec.len(), 6);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoordinateRange {
+    /// range on the x axis (rows)
+    x: Range<usize>,
+    /// range on the y axis (columns)
+    y: Range<usize>,
+}
+
+impl CoordinateRange {
+    /// Create a new range from `start` (included) to `end` (excluded) on both axis.
+    /// See [`super::range`].
+    #[inline]
+    #[must_use]
+    pub const fn new(start: Coordinate<usize>, end: Coordinate<usize>) -> Self {
+        Self {
+            x: start.x..end.x,
+            y: start.y..end.y,
+        }
+    }
+
+    /// The first coordinate of the range (included). Note that if the range is empty
+    /// this coordinate is not in the range.
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> Coordinate<usize> {
+        Coordinate::new(self.x.start, self.y.start)
+    }
+
+    /// The end of the range (excluded).
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> Coordinate<usize> {
+        Coordinate::new(self.x.end, self.y.end)
+    }
+
+    /// The range on the x axis (rows).
+    #[inline]
+    #[must_use]
+    pub const fn x_range(&self) -> &Range<usize> {
+        &self.x
+    }
+
+    /// The range on the y axis (columns).
+    #[inline]
+    #[must_use]
+    pub const fn y_range(&self) -> &Range<usize> {
+        &self.y
+    }
+
+    /// Returns `true` if the coordinate is in the range.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, coord: &Coordinate<usize>) -> bool {
+        self.x.contains(&coord.x) && self.y.contains(&coord.y)
+    }
+
+    /// Returns `true` if the range contains no coordinate, i.e. if it is empty on one of the axis.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::{range, Coordinate};
+    ///
+    /// assert!(range(Coordinate::new(0, 0), Coordinate::new(0, 3)).is_empty());
+    /// assert!(range(Coordinate::new(2, 0), Coordinate::new(1, 3)).is_empty());
+    /// assert!(!range(Coordinate::new(0, 0), Coordinate::new(1, 1)).is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() || self.y.is_empty()
+    }
+
+    /// Number of coordinates in the range.
+    ///
+    /// # Panics
+    /// Panics in debug if the number of coordinates overflows an [`usize`].
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.x.len() * self.y.len()
+    }
+
+    /// Get an iterator over the coordinates of the range in row-major order.
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> CoordinateRangeIterator {
+        self.clone().into_iter()
+    }
+
+    /// Get an iterator over the rows of the range, each row being an iterator over its
+    /// coordinates, see [`super::row_coords`]. Flattening it gives the same order as
+    /// [`Self::iter`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::{range, Coordinate};
+    ///
+    /// let r = range(Coordinate::new(0, 0), Coordinate::new(2, 3));
+    /// let mut rows = r.rows();
+    /// assert_eq!(rows.len(), 2);
+    /// let last = rows.next_back().expect("two rows");
+    /// assert_eq!(last.len(), 3);
+    /// assert_eq!(
+    ///     last.collect::<Vec<_>>(),
+    ///     vec![
+    ///         Coordinate::new(1, 0),
+    ///         Coordinate::new(1, 1),
+    ///         Coordinate::new(1, 2)
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rows(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = Coordinate<usize>> + ExactSizeIterator + FusedIterator,
+    > + ExactSizeIterator
+           + FusedIterator {
+        let columns = self.y.clone();
+        // an empty range has no row, even if the row range is not empty
+        let rows = if columns.is_empty() {
+            0..0
+        } else {
+            self.x.clone()
+        };
+        rows.map(move |x| super::row_coords(x, columns.clone()))
+    }
+
+    /// Get an iterator over the columns of the range, each column being an iterator over
+    /// its coordinates, see [`super::col_coords`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::coordinate::{range, Coordinate};
+    ///
+    /// let r = range(Coordinate::new(0, 0), Coordinate::new(2, 3));
+    /// assert_eq!(r.columns().len(), 3);
+    /// assert_eq!(
+    ///     r.columns().flatten().take(3).collect::<Vec<_>>(),
+    ///     vec![
+    ///         Coordinate::new(0, 0),
+    ///         Coordinate::new(1, 0),
+    ///         Coordinate::new(0, 1)
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = Coordinate<usize>> + ExactSizeIterator + FusedIterator,
+    > + ExactSizeIterator
+           + FusedIterator {
+        let rows = self.x.clone();
+        // an empty range has no column, even if the column range is not empty
+        let columns = if rows.is_empty() {
+            0..0
+        } else {
+            self.y.clone()
+        };
+        columns.map(move |y| super::col_coords(y, rows.clone()))
+    }
+
+    /// Get the coordinate at the given linear index in row-major order.
+    /// The index must be smaller than [`Self::len`].
+    const fn coordinate_at(&self, index: usize) -> Coordinate<usize> {
+        let width = self.y.end - self.y.start;
+        Coordinate::new(self.x.start + index / width, self.y.start + index % width)
+    }
+}
+
+impl From<(Range<usize>, Range<usize>)> for CoordinateRange {
+    #[inline]
+    fn from((x, y): (Range<usize>, Range<usize>)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<CoordinateRange> for (Range<usize>, Range<usize>) {
+    #[inline]
+    fn from(value: CoordinateRange) -> Self {
+        (value.x, value.y)
+    }
+}
+
+impl From<Range<Coordinate<usize>>> for CoordinateRange {
+    #[inline]
+    fn from(value: Range<Coordinate<usize>>) -> Self {
+        Self::new(value.start, value.end)
+    }
+}
+
+impl IntoIterator for CoordinateRange {
+    type IntoIter = CoordinateRangeIterator;
+    type Item = Coordinate<usize>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        CoordinateRangeIterator::new(self)
+    }
+}
+
+impl IntoIterator for &CoordinateRange {
+    type IntoIter = CoordinateRangeIterator;
+    type Item = Coordinate<usize>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// [`Iterator`] over a [`CoordinateRange`] in row-major order. It is the type returned by
+/// [`CoordinateRange::into_iter`] and [`CoordinateRange::iter`].
+///
+/// Also implement [`DoubleEndedIterator`], [`FusedIterator`] and [`ExactSizeIterator`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)] // it should not be copy as it is an iterator (clippy::copy_iterator)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoordinateRangeIterator {
+    /// the iterated range
+    range: CoordinateRange,
+    /// linear index of the front of the iterator
+    front: usize,
+    /// linear index of the back of the iterator (excluded)
+    back: usize,
+}
+
+impl CoordinateRangeIterator {
+    /// Create a new iterator over the given range.
+    #[inline]
+    #[must_use]
+    pub fn new(range: CoordinateRange) -> Self {
+        let back = if range.is_empty() { 0 } else { range.len() };
+        Self {
+            range,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl Iterator for CoordinateRangeIterator {
+    type Item = Coordinate<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            let coord = self.range.coordinate_at(self.front);
+            self.front += 1;
+            coord
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let val = self.back - self.front;
+        (val, Some(val))
+    }
+}
+
+impl DoubleEndedIterator for CoordinateRangeIterator {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            self.range.coordinate_at(self.back)
+        })
+    }
+}
+
+impl FusedIterator for CoordinateRangeIterator {}
+
+impl ExactSizeIterator for CoordinateRangeIterator {}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        super::{col_coords, range, range_inclusive, row_coords},
+        Coordinate, CoordinateRange,
+    };
+
+    #[test]
+    fn empty() {
+        let ranges = [
+            range(Coordinate::new(0, 0), Coordinate::new(0, 0)),
+            range(Coordinate::new(0, 0), Coordinate::new(0, 5)),
+            range(Coordinate::new(0, 0), Coordinate::new(5, 0)),
+            range(Coordinate::new(3, 3), Coordinate::new(1, 5)),
+            range(Coordinate::new(3, 3), Coordinate::new(5, 1)),
+            CoordinateRange::default(),
+        ];
+        for r in ranges {
+            assert!(r.is_empty());
+            assert_eq!(r.len(), 0);
+            assert_eq!(r.iter().len(), 0);
+            assert_eq!(r.iter().next(), None);
+            assert_eq!(r.iter().next_back(), None);
+            assert!(!r.contains(&r.start()));
+        }
+    }
+
+    #[test]
+    fn single_row_column() {
+        let row = range(Coordinate::new(2, 0), Coordinate::new(3, 4));
+        assert!(!row.is_empty());
+        assert_eq!(row.len(), 4);
+        assert_eq!(
+            row.iter().collect::<Vec<_>>(),
+            vec![
+                Coordinate::new(2, 0),
+                Coordinate::new(2, 1),
+                Coordinate::new(2, 2),
+                Coordinate::new(2, 3)
+            ]
+        );
+
+        let column = range(Coordinate::new(0, 5), Coordinate::new(3, 6));
+        assert_eq!(column.len(), 3);
+        assert_eq!(
+            column.iter().collect::<Vec<_>>(),
+            vec![
+                Coordinate::new(0, 5),
+                Coordinate::new(1, 5),
+                Coordinate::new(2, 5)
+            ]
+        );
+
+        let single = range_inclusive(Coordinate::new(4, 4), Coordinate::new(4, 4));
+        assert_eq!(single.len(), 1);
+        assert_eq!(
+            single.iter().collect::<Vec<_>>(),
+            vec![Coordinate::new(4, 4)]
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let r = range(Coordinate::new(1, 2), Coordinate::new(4, 5));
+        assert_eq!(r.len(), 9);
+        assert_eq!(r.iter().count(), r.len());
+        assert_eq!(r.iter().rev().count(), r.len());
+
+        let mut previous = None;
+        for c in &r {
+            assert!(r.contains(&c));
+            if let Some(previous) = previous {
+                assert!(previous < c);
+            }
+            previous = Some(c);
+        }
+
+        let mut iter = r.iter();
+        assert_eq!(iter.size_hint(), (9, Some(9)));
+        assert_eq!(iter.next(), Some(Coordinate::new(1, 2)));
+        assert_eq!(iter.next_back(), Some(Coordinate::new(3, 4)));
+        assert_eq!(iter.next_back(), Some(Coordinate::new(3, 3)));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.by_ref().count(), 6);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(
+            range_inclusive(Coordinate::new(0, 0), Coordinate::new(2, 2)),
+            range(Coordinate::new(0, 0), Coordinate::new(3, 3))
+        );
+    }
+
+    #[test]
+    fn rows_columns() {
+        let r = range(Coordinate::new(1, 2), Coordinate::new(3, 5));
+        assert_eq!(r.rows().len(), 2);
+        assert_eq!(r.columns().len(), 3);
+        for row in r.rows() {
+            assert_eq!(row.len(), 3);
+        }
+        for column in r.columns() {
+            assert_eq!(column.len(), 2);
+        }
+        assert_eq!(
+            r.rows().flatten().collect::<Vec<_>>(),
+            r.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            r.rows().rev().flat_map(Iterator::rev).collect::<Vec<_>>(),
+            r.iter().rev().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            r.columns().flatten().collect::<Vec<_>>(),
+            vec![
+                Coordinate::new(1, 2),
+                Coordinate::new(2, 2),
+                Coordinate::new(1, 3),
+                Coordinate::new(2, 3),
+                Coordinate::new(1, 4),
+                Coordinate::new(2, 4)
+            ]
+        );
+        let mut last_column = r.columns().next_back().expect("three columns");
+        assert_eq!(last_column.next_back(), Some(Coordinate::new(2, 4)));
+        assert_eq!(last_column.len(), 1);
+
+        assert_eq!(
+            row_coords(1, 2..5).collect::<Vec<_>>(),
+            r.rows().next().expect("two rows").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            col_coords(4, 1..3).rev().collect::<Vec<_>>(),
+            vec![Coordinate::new(2, 4), Coordinate::new(1, 4)]
+        );
+        assert_eq!(row_coords(0, 3..3).len(), 0);
+
+        let empty = range(Coordinate::new(0, 0), Coordinate::new(3, 0));
+        assert_eq!(empty.rows().len(), 0);
+        assert_eq!(empty.columns().len(), 0);
+    }
+
+    #[test]
+    fn conversion() {
+        let r = CoordinateRange::from((1..3, 0..2));
+        assert_eq!(r, range(Coordinate::new(1, 0), Coordinate::new(3, 2)));
+        assert_eq!(<(_, _)>::from(r.clone()), (1..3, 0..2));
+        assert_eq!(
+            CoordinateRange::from(Coordinate::new(1, 0)..Coordinate::new(3, 2)),
+            r
+        );
+        assert_eq!(r.x_range(), &(1..3));
+        assert_eq!(r.y_range(), &(0..2));
+        assert_eq!(r.start(), Coordinate::new(1, 0));
+        assert_eq!(r.end(), Coordinate::new(3, 2));
+    }
+}
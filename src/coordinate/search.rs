@@ -0,0 +1,644 @@
+//! Path finding and region filling on a grid of [`Coordinate<usize>`].
+//!
+//! The grid is given by its `bounds`: a coordinate `c` is on the grid if `c.x < bounds.x` and
+//! `c.y < bounds.y`. From a coordinate, one can move to its four direct neighbors that are on
+//! the grid and passable, and also to the four diagonal ones for [`flood_fill`] and
+//! [`connected_components`] if `diagonal` is `true`. The neighbors are explored in a fixed
+//! order so the results are deterministic. The searches use a queue instead of recursion so
+//! they do not overflow the stack on large grids.
+//!
+//! # Example
+//! ```
+//! use utils_lib::coordinate::{search::bfs_shortest_path, Coordinate};
+//!
+//! // a 3x3 grid with a wall in the middle
+//! let wall = Coordinate::new(1, 1);
+//! let path = bfs_shortest_path(
+//!     Coordinate::new(0, 1),
+//!     Coordinate::new(2, 1),
+//!     |c| c != wall,
+//!     Coordinate::new(3, 3),
+//! )
+//! .expect("the goal is reachable");
+//! assert_eq!(path.len(), 5);
+//! assert_eq!(path.first(), Some(&Coordinate::new(0, 1)));
+//! assert_eq!(path.last(), Some(&Coordinate::new(2, 1)));
+//! ```
+
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+
+use super::{range, Coordinate};
+use crate::number::{MinFloatHeap, PositiveFloat};
+
+/// Returns `true` if the coordinate is on the grid of the given bounds.
+const fn in_bounds(coord: Coordinate<usize>, bounds: Coordinate<usize>) -> bool {
+    coord.x < bounds.x && coord.y < bounds.y
+}
+
+/// Iterator over the direct neighbors of a coordinate that are on the grid.
+fn neighbors(
+    coord: Coordinate<usize>,
+    bounds: Coordinate<usize>,
+) -> impl Iterator<Item = Coordinate<usize>> {
+    [
+        coord.x.checked_sub(1).map(|x| Coordinate::new(x, coord.y)),
+        coord.x.checked_add(1).map(|x| Coordinate::new(x, coord.y)),
+        coord.y.checked_sub(1).map(|y| Coordinate::new(coord.x, y)),
+        coord.y.checked_add(1).map(|y| Coordinate::new(coord.x, y)),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(move |neighbor| in_bounds(*neighbor, bounds))
+}
+
+/// Iterator over the direct and the diagonal neighbors of a coordinate that are on the grid.
+fn neighbors_with_diagonals(
+    coord: Coordinate<usize>,
+    bounds: Coordinate<usize>,
+) -> impl Iterator<Item = Coordinate<usize>> {
+    [-1_i64, 0_i64, 1_i64]
+        .into_iter()
+        .flat_map(|x| [-1_i64, 0_i64, 1_i64].map(|y| Coordinate::new(x, y)))
+        .filter(|delta| *delta != Coordinate::new(0_i64, 0_i64))
+        .filter_map(move |delta| coord.offset_by(delta))
+        .filter(move |neighbor| in_bounds(*neighbor, bounds))
+}
+
+/// Rebuild the path from `start` to `goal` following the predecessor of each coordinate.
+fn rebuild_path(
+    predecessors: &HashMap<Coordinate<usize>, Coordinate<usize>>,
+    start: Coordinate<usize>,
+    goal: Coordinate<usize>,
+) -> Vec<Coordinate<usize>> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while current != start {
+        current = predecessors[&current];
+        path.push(current);
+    }
+    path.reverse();
+    path
+}
+
+/// Find a shortest path, in number of steps, from `start` to `goal` using a breadth first
+/// search. The path contains both `start` and `goal`.
+///
+/// Returns [`None`] if the goal is not reachable, including if `start` or `goal` is not
+/// passable or is out of the bounds.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{search::bfs_shortest_path, Coordinate};
+///
+/// let bounds = Coordinate::new(2, 3);
+/// assert_eq!(
+///     bfs_shortest_path(
+///         Coordinate::new(0, 0),
+///         Coordinate::new(0, 2),
+///         |_| true,
+///         bounds
+///     ),
+///     Some(vec![
+///         Coordinate::new(0, 0),
+///         Coordinate::new(0, 1),
+///         Coordinate::new(0, 2)
+///     ])
+/// );
+/// // the column y = 1 is a wall
+/// assert_eq!(
+///     bfs_shortest_path(
+///         Coordinate::new(0, 0),
+///         Coordinate::new(0, 2),
+///         |c| c.y != 1,
+///         bounds
+///     ),
+///     None
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn bfs_shortest_path<F>(
+    start: Coordinate<usize>,
+    goal: Coordinate<usize>,
+    passable: F,
+    bounds: Coordinate<usize>,
+) -> Option<Vec<Coordinate<usize>>>
+where
+    F: Fn(Coordinate<usize>) -> bool,
+{
+    if !in_bounds(goal, bounds) || !passable(goal) {
+        return None;
+    }
+    bfs(start, passable, bounds, |coord| coord == goal)
+        .map(|predecessors| rebuild_path(&predecessors, start, goal))
+}
+
+/// Get the distance, in number of steps, from `start` to every reachable coordinate
+/// using a breadth first search. `start` is at distance 0.
+///
+/// The map is empty if `start` is not passable or is out of the bounds.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{search::bfs_distances, Coordinate};
+///
+/// let distances = bfs_distances(
+///     Coordinate::new(0, 0),
+///     |c| c != Coordinate::new(0, 1),
+///     Coordinate::new(2, 2),
+/// );
+/// assert_eq!(distances.len(), 3);
+/// assert_eq!(distances[&Coordinate::new(0, 0)], 0);
+/// assert_eq!(distances[&Coordinate::new(1, 1)], 2);
+/// assert!(!distances.contains_key(&Coordinate::new(0, 1)));
+/// ```
+#[inline]
+#[must_use]
+pub fn bfs_distances<F>(
+    start: Coordinate<usize>,
+    passable: F,
+    bounds: Coordinate<usize>,
+) -> HashMap<Coordinate<usize>, usize>
+where
+    F: Fn(Coordinate<usize>) -> bool,
+{
+    let mut distances = HashMap::new();
+    if !in_bounds(start, bounds) || !passable(start) {
+        return distances;
+    }
+    distances.insert(start, 0_usize);
+    let mut queue = VecDeque::from([start]);
+    while let Some(coord) = queue.pop_front() {
+        let distance = distances[&coord] + 1;
+        for neighbor in neighbors(coord, bounds) {
+            if passable(neighbor) {
+                if let Entry::Vacant(entry) = distances.entry(neighbor) {
+                    entry.insert(distance);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+    distances
+}
+
+/// Get the region of passable coordinates connected to `start`, including `start`, using a
+/// breadth first search. Two coordinates are connected if they are direct neighbors, or also
+/// diagonal neighbors if `diagonal` is `true`.
+///
+/// The set is empty if `start` is not passable or is out of the bounds.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{search::flood_fill, Coordinate};
+///
+/// // the diagonal x = y is a wall
+/// let passable = |c: Coordinate<usize>| c.x != c.y;
+/// let bounds = Coordinate::new(3, 3);
+///
+/// let region = flood_fill(Coordinate::new(0, 1), passable, bounds, false);
+/// assert_eq!(region.len(), 3);
+/// assert!(region.contains(&Coordinate::new(1, 2)));
+/// assert!(!region.contains(&Coordinate::new(1, 0)));
+///
+/// // the two triangles touch by their corners
+/// assert_eq!(
+///     flood_fill(Coordinate::new(0, 1), passable, bounds, true).len(),
+///     6
+/// );
+/// assert!(flood_fill(Coordinate::new(1, 1), passable, bounds, false).is_empty());
+/// ```
+#[inline]
+#[must_use]
+pub fn flood_fill<F>(
+    start: Coordinate<usize>,
+    passable: F,
+    bounds: Coordinate<usize>,
+    diagonal: bool,
+) -> HashSet<Coordinate<usize>>
+where
+    F: Fn(Coordinate<usize>) -> bool,
+{
+    let mut region = HashSet::new();
+    if !in_bounds(start, bounds) || !passable(start) {
+        return region;
+    }
+    region.insert(start);
+    let mut queue = VecDeque::from([start]);
+    while let Some(coord) = queue.pop_front() {
+        let mut visit = |neighbor| {
+            if passable(neighbor) && region.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        };
+        if diagonal {
+            neighbors_with_diagonals(coord, bounds).for_each(&mut visit);
+        } else {
+            neighbors(coord, bounds).for_each(&mut visit);
+        }
+    }
+    region
+}
+
+/// Split the passable coordinates of the grid into connected regions, see [`flood_fill`].
+///
+/// The regions are ordered by their smallest coordinate in the order of [`Ord`] for
+/// [`Coordinate`], i.e. by row then by column. The vector is empty if no coordinate is
+/// passable.
+///
+/// # Example
+/// ```
+/// use utils_lib::coordinate::{search::connected_components, Coordinate};
+///
+/// // the column y = 1 is a wall
+/// let components = connected_components(|c| c.y != 1, Coordinate::new(2, 3), false);
+/// assert_eq!(components.len(), 2);
+/// assert!(components[0].contains(&Coordinate::new(1, 0)));
+/// assert!(components[1].contains(&Coordinate::new(0, 2)));
+/// ```
+#[inline]
+#[must_use]
+pub fn connected_components<F>(
+    passable: F,
+    bounds: Coordinate<usize>,
+    diagonal: bool,
+) -> Vec<HashSet<Coordinate<usize>>>
+where
+    F: Fn(Coordinate<usize>) -> bool,
+{
+    let mut visited = HashSet::new();
+    let mut components = Vec::new();
+    for coord in range(Coordinate::new(0, 0), bounds) {
+        if !visited.contains(&coord) && passable(coord) {
+            let component = flood_fill(coord, &passable, bounds, diagonal);
+            visited.extend(component.iter().copied());
+            components.push(component);
+        }
+    }
+    components
+}
+
+/// Breadth first search from `start` until `is_goal` returns `true`. Returns the predecessor
+/// of each visited coordinate, or [`None`] if no goal was found.
+fn bfs<F, G>(
+    start: Coordinate<usize>,
+    passable: F,
+    bounds: Coordinate<usize>,
+    is_goal: G,
+) -> Option<HashMap<Coordinate<usize>, Coordinate<usize>>>
+where
+    F: Fn(Coordinate<usize>) -> bool,
+    G: Fn(Coordinate<usize>) -> bool,
+{
+    if !in_bounds(start, bounds) || !passable(start) {
+        return None;
+    }
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(coord) = queue.pop_front() {
+        if is_goal(coord) {
+            return Some(predecessors);
+        }
+        for neighbor in neighbors(coord, bounds) {
+            if neighbor != start && passable(neighbor) {
+                if let Entry::Vacant(entry) = predecessors.entry(neighbor) {
+                    entry.insert(coord);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Find a path of minimal cost from `start` to `goal` using Dijkstra's algorithm.
+/// `cost(from, to)` is the cost of the step from a coordinate to one of its neighbors.
+/// The path contains both `start` and `goal` and it is returned with its total cost.
+///
+/// Returns [`None`] if the goal is not reachable, including if `start` or `goal` is not
+/// passable or is out of the bounds.
+///
+/// # Example
+/// ```
+/// use utils_lib::{
+///     coordinate::{search::dijkstra_shortest_path, Coordinate},
+///     PositiveFloat,
+/// };
+///
+/// // moving on the row x = 0 is expensive
+/// let cost = |_from: Coordinate<usize>, to: Coordinate<usize>| {
+///     if to.x == 0 {
+///         PositiveFloat::new(10_f64).unwrap()
+///     } else {
+///         PositiveFloat::ONE
+///     }
+/// };
+/// let (path, total) = dijkstra_shortest_path(
+///     Coordinate::new(0, 0),
+///     Coordinate::new(0, 2),
+///     |_| true,
+///     cost,
+///     Coordinate::new(2, 3),
+/// )
+/// .expect("the goal is reachable");
+/// assert_eq!(path.len(), 5);
+/// assert_eq!(total, PositiveFloat::new(13_f64).unwrap());
+/// ```
+#[inline]
+#[must_use]
+pub fn dijkstra_shortest_path<F, C>(
+    start: Coordinate<usize>,
+    goal: Coordinate<usize>,
+    passable: F,
+    cost: C,
+    bounds: Coordinate<usize>,
+) -> Option<(Vec<Coordinate<usize>>, PositiveFloat)>
+where
+    F: Fn(Coordinate<usize>) -> bool,
+    C: Fn(Coordinate<usize>, Coordinate<usize>) -> PositiveFloat,
+{
+    if !in_bounds(start, bounds) || !passable(start) || !in_bounds(goal, bounds) || !passable(goal)
+    {
+        return None;
+    }
+    let mut costs = HashMap::from([(start, PositiveFloat::ZERO)]);
+    let mut predecessors = HashMap::new();
+    let mut heap = MinFloatHeap::new();
+    heap.push(PositiveFloat::ZERO, start);
+    while let Some((current_cost, coord)) = heap.pop() {
+        if coord == goal {
+            return Some((rebuild_path(&predecessors, start, goal), current_cost));
+        }
+        if costs.get(&coord).is_some_and(|best| *best < current_cost) {
+            // outdated entry
+            continue;
+        }
+        for neighbor in neighbors(coord, bounds) {
+            if !passable(neighbor) {
+                continue;
+            }
+            let new_cost = current_cost + cost(coord, neighbor);
+            if costs.get(&neighbor).is_none_or(|best| new_cost < *best) {
+                costs.insert(neighbor, new_cost);
+                predecessors.insert(neighbor, coord);
+                heap.push(new_cost, neighbor);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{
+        bfs_distances, bfs_shortest_path, connected_components, dijkstra_shortest_path, flood_fill,
+        Coordinate,
+    };
+    use crate::{coordinate::range, number::PositiveFloat};
+
+    /// A small maze, `#` are walls.
+    const MAZE: [&str; 5] = [
+        ".#...", //
+        ".#.#.", //
+        ".#.#.", //
+        "...#.", //
+        "##...", //
+    ];
+
+    /// Bounds of [`MAZE`]
+    const MAZE_BOUNDS: Coordinate<usize> = Coordinate::new(5, 5);
+
+    fn maze_passable(c: Coordinate<usize>) -> bool {
+        MAZE[c.x].as_bytes()[c.y] == b'.'
+    }
+
+    /// Assert that each step of the path is a move to a passable direct neighbor.
+    fn assert_valid_path(path: &[Coordinate<usize>]) {
+        for c in path {
+            assert!(maze_passable(*c), "{c} is a wall");
+        }
+        for step in path.windows(2) {
+            let delta = step[0].abs_delta(step[1]);
+            assert_eq!(
+                delta.x + delta.y,
+                1,
+                "{} -> {} is not a step",
+                step[0],
+                step[1]
+            );
+        }
+    }
+
+    #[test]
+    fn bfs_maze() {
+        let start = Coordinate::new(0, 0);
+        let goal = Coordinate::new(0, 4);
+        let path = bfs_shortest_path(start, goal, maze_passable, MAZE_BOUNDS).expect("reachable");
+        assert_valid_path(&path);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        // down 3, right 2, up 3, right 2
+        assert_eq!(path.len(), 11);
+
+        let distances = bfs_distances(start, maze_passable, MAZE_BOUNDS);
+        assert_eq!(distances[&goal], path.len() - 1);
+        assert_eq!(distances[&start], 0);
+        assert_eq!(distances[&Coordinate::new(3, 2)], 5);
+        assert_eq!(distances.len(), 17);
+        assert!(!distances.contains_key(&Coordinate::new(0, 1)));
+
+        assert_eq!(
+            bfs_shortest_path(start, start, maze_passable, MAZE_BOUNDS),
+            Some(vec![start])
+        );
+    }
+
+    #[test]
+    fn bfs_unreachable() {
+        let start = Coordinate::new(0, 0);
+        // the wall (0, 1) and a goal out of bounds
+        assert_eq!(
+            bfs_shortest_path(start, Coordinate::new(0, 1), maze_passable, MAZE_BOUNDS),
+            None
+        );
+        assert_eq!(
+            bfs_shortest_path(start, Coordinate::new(5, 0), |_| true, MAZE_BOUNDS),
+            None
+        );
+        // closed by walls
+        let closed = |c: Coordinate<usize>| maze_passable(c) && c != Coordinate::new(3, 2);
+        assert_eq!(
+            bfs_shortest_path(start, Coordinate::new(0, 4), closed, MAZE_BOUNDS),
+            None
+        );
+        assert_eq!(bfs_distances(start, closed, MAZE_BOUNDS).len(), 5);
+        assert!(bfs_distances(Coordinate::new(0, 1), maze_passable, MAZE_BOUNDS).is_empty());
+        assert_eq!(
+            dijkstra_shortest_path(
+                start,
+                Coordinate::new(0, 4),
+                closed,
+                |_, _| PositiveFloat::ONE,
+                MAZE_BOUNDS
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn dijkstra_cost() {
+        let start = Coordinate::new(0, 0);
+        let goal = Coordinate::new(0, 2);
+        let bounds = Coordinate::new(3, 3);
+        // the middle cell is very expensive to enter
+        let cost = |_: Coordinate<usize>, to: Coordinate<usize>| {
+            if to == Coordinate::new(0, 1) {
+                PositiveFloat::new(10_f64).expect("valid")
+            } else {
+                PositiveFloat::ONE
+            }
+        };
+
+        let bfs_path = bfs_shortest_path(start, goal, |_| true, bounds).expect("reachable");
+        assert_eq!(bfs_path, vec![start, Coordinate::new(0, 1), goal]);
+
+        let (path, total) =
+            dijkstra_shortest_path(start, goal, |_| true, cost, bounds).expect("reachable");
+        assert_eq!(
+            path,
+            vec![
+                start,
+                Coordinate::new(1, 0),
+                Coordinate::new(1, 1),
+                Coordinate::new(1, 2),
+                goal
+            ]
+        );
+        assert_eq!(total, PositiveFloat::new(4_f64).expect("valid"));
+        assert_ne!(path, bfs_path);
+
+        // uniform cost agrees with the bfs on the length
+        let (path, total) = dijkstra_shortest_path(
+            start,
+            Coordinate::new(0, 4),
+            maze_passable,
+            |_, _| PositiveFloat::ONE,
+            MAZE_BOUNDS,
+        )
+        .expect("reachable");
+        assert_valid_path(&path);
+        assert_eq!(path.len(), 11);
+        assert_eq!(total, PositiveFloat::new(10_f64).expect("valid"));
+    }
+
+    /// A donut, `#` is the ring, with a hole in the middle and a corner touching the ring
+    /// only diagonally.
+    const DONUT: [&str; 6] = [
+        "......", //
+        ".####.", //
+        ".#..#.", //
+        ".#..#.", //
+        ".####.", //
+        ".....#", //
+    ];
+
+    /// Bounds of [`DONUT`]
+    const DONUT_BOUNDS: Coordinate<usize> = Coordinate::new(6, 6);
+
+    fn donut_ring(c: Coordinate<usize>) -> bool {
+        DONUT[c.x].as_bytes()[c.y] == b'#'
+    }
+
+    #[test]
+    fn flood_fill_donut() {
+        let ring = flood_fill(Coordinate::new(1, 1), donut_ring, DONUT_BOUNDS, false);
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|c| donut_ring(*c)));
+        // the hole is not filled
+        assert!(!ring.contains(&Coordinate::new(2, 2)));
+        assert!(!ring.contains(&Coordinate::new(5, 5)));
+
+        let with_diagonals = flood_fill(Coordinate::new(4, 4), donut_ring, DONUT_BOUNDS, true);
+        assert_eq!(with_diagonals.len(), 13);
+        assert!(with_diagonals.contains(&Coordinate::new(5, 5)));
+        assert!(with_diagonals.is_superset(&ring));
+
+        let hole = flood_fill(
+            Coordinate::new(2, 3),
+            |c| !donut_ring(c),
+            DONUT_BOUNDS,
+            false,
+        );
+        assert_eq!(
+            hole,
+            HashSet::from([
+                Coordinate::new(2, 2),
+                Coordinate::new(2, 3),
+                Coordinate::new(3, 2),
+                Coordinate::new(3, 3),
+            ])
+        );
+        // the ring is closed, even on the corners, so the hole is not reached by the diagonals
+        let outside = flood_fill(
+            Coordinate::new(0, 0),
+            |c| !donut_ring(c),
+            DONUT_BOUNDS,
+            true,
+        );
+        assert_eq!(outside.len(), 36 - 13 - 4);
+        assert!(outside.is_disjoint(&hole));
+
+        let components = connected_components(|c| !donut_ring(c), DONUT_BOUNDS, false);
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0], outside);
+        assert_eq!(components[1], hole);
+        let components = connected_components(donut_ring, DONUT_BOUNDS, false);
+        assert_eq!(
+            components,
+            vec![ring, HashSet::from([Coordinate::new(5, 5)])]
+        );
+        assert_eq!(
+            connected_components(donut_ring, DONUT_BOUNDS, true),
+            vec![with_diagonals]
+        );
+    }
+
+    #[test]
+    fn flood_fill_empty_and_full() {
+        let bounds = Coordinate::new(4, 3);
+        // empty region
+        assert!(flood_fill(Coordinate::new(0, 0), |_| false, bounds, false).is_empty());
+        assert!(flood_fill(Coordinate::new(4, 0), |_| true, bounds, true).is_empty());
+        assert!(connected_components(|_| false, bounds, true).is_empty());
+        assert!(connected_components(|_| true, Coordinate::new(0, 3), false).is_empty());
+
+        // full grid
+        let full = range(Coordinate::new(0, 0), bounds)
+            .into_iter()
+            .collect::<HashSet<_>>();
+        for diagonal in [false, true] {
+            assert_eq!(
+                flood_fill(Coordinate::new(3, 2), |_| true, bounds, diagonal),
+                full
+            );
+            assert_eq!(
+                connected_components(|_| true, bounds, diagonal),
+                vec![full.clone()]
+            );
+        }
+
+        // a checkerboard is only connected by the diagonals
+        let checkerboard = |c: Coordinate<usize>| (c.x + c.y).is_multiple_of(2);
+        assert_eq!(connected_components(checkerboard, bounds, false).len(), 6);
+        assert_eq!(connected_components(checkerboard, bounds, true).len(), 1);
+    }
+
+    #[test]
+    fn flood_fill_large_grid() {
+        let bounds = Coordinate::new(1000, 1000);
+        let region = flood_fill(Coordinate::new(500, 500), |_| true, bounds, false);
+        assert_eq!(region.len(), 1_000_000);
+    }
+}
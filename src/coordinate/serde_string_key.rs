@@ -0,0 +1,312 @@
+//! Serde helper to use [`Coordinate`] as the keys of a map in formats where the keys are
+//! strings, like JSON objects. The keys are written in the compact form `"3,-4"` and read
+//! back with the [`FromStr`] implementation of [`Coordinate`].
+//!
+//! Use the module with `#[serde(with = "utils_lib::coordinate::serde_string_key")]` on any map
+//! iterating over `(&Coordinate<T>, &V)` and collected from `(Coordinate<T>, V)`, like a
+//! [`HashMap`](std::collections::HashMap) or a [`BTreeMap`](std::collections::BTreeMap), or
+//! use the key wrapper [`CoordinateKey`] directly.
+//!
+//! The module requires the `serde` feature.
+//!
+//! # Example
+//! ```
+//! use std::collections::HashMap;
+//!
+//! use serde::{Deserialize, Serialize};
+//! use utils_lib::Coordinate;
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Level {
+//!     #[serde(with = "utils_lib::coordinate::serde_string_key")]
+//!     walls: HashMap<Coordinate<i64>, u8>,
+//! }
+//!
+//! let level = Level {
+//!     walls: HashMap::from([(Coordinate::new(3, -4), 1)]),
+//! };
+//! let json = serde_json::to_string(&level)?;
+//! assert_eq!(json, r#"{"walls":{"3,-4":1}}"#);
+//! assert_eq!(serde_json::from_str::<Level>(&json)?, level);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::{
+    fmt::{self, Display},
+    iter,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::Coordinate;
+
+/// Wrapper of a [`Coordinate`] serialized as the string `"x,y"`, for the keys of a map in
+/// formats like JSON, see the [module](self) for the `#[serde(with = ...)]` helper.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use utils_lib::{coordinate::CoordinateKey, Coordinate};
+///
+/// let map = BTreeMap::from([(CoordinateKey(Coordinate::new(-1, 2)), "a")]);
+/// let json = serde_json::to_string(&map)?;
+/// assert_eq!(json, r#"{"-1,2":"a"}"#);
+/// assert_eq!(
+///     serde_json::from_str::<BTreeMap<CoordinateKey<i32>, &str>>(&json)?,
+///     map
+/// );
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::exhaustive_structs)] // reason = "transparent wrapper"
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub struct CoordinateKey<T>(pub Coordinate<T>);
+
+impl<T> From<Coordinate<T>> for CoordinateKey<T> {
+    #[inline]
+    fn from(value: Coordinate<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<CoordinateKey<T>> for Coordinate<T> {
+    #[inline]
+    fn from(value: CoordinateKey<T>) -> Self {
+        value.0
+    }
+}
+
+/// Display the compact form `"3,-4"`, without the brackets and the space of the [`Display`]
+/// implementation of [`Coordinate`].
+impl<T: Display> Display for CoordinateKey<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.0.x(), self.0.y())
+    }
+}
+
+impl<T: FromStr> FromStr for CoordinateKey<T> {
+    type Err = <Coordinate<T> as FromStr>::Err;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl<T: Display> Serialize for CoordinateKey<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for CoordinateKey<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(KeyVisitor(PhantomData))
+    }
+}
+
+/// Visitor parsing a [`CoordinateKey`] from a string.
+struct KeyVisitor<T>(PhantomData<fn() -> T>);
+
+impl<T> Visitor<'_> for KeyVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = CoordinateKey<T>;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a coordinate as a string like \"3,-4\"")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Borrowed key serialized like a [`CoordinateKey`].
+struct KeyRef<'a, T>(&'a Coordinate<T>);
+
+impl<T: Display> Serialize for KeyRef<'_, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{},{}", self.0.x(), self.0.y()))
+    }
+}
+
+/// Serialize a map with [`Coordinate`] keys, each key as the string `"x,y"`.
+///
+/// # Errors
+/// Returns the error of the serializer.
+#[inline]
+pub fn serialize<'a, M, T, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a Coordinate<T>, &'a V)>,
+    T: Display + 'a,
+    V: Serialize + 'a,
+    S: Serializer,
+{
+    serializer.collect_map(map.into_iter().map(|(key, value)| (KeyRef(key), value)))
+}
+
+/// Deserialize a map with [`Coordinate`] keys written as the string `"x,y"`.
+///
+/// # Errors
+/// Returns the error of the deserializer, or a custom error if a key is not a coordinate.
+#[inline]
+pub fn deserialize<'de, M, T, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: FromIterator<(Coordinate<T>, V)>,
+    T: FromStr,
+    T::Err: Display,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor(PhantomData, PhantomData))
+}
+
+/// Visitor collecting a map with [`CoordinateKey`] keys.
+struct MapVisitor<M, T, V>(PhantomData<fn() -> M>, PhantomData<fn() -> (T, V)>);
+
+impl<'de, M, T, V> Visitor<'de> for MapVisitor<M, T, V>
+where
+    M: FromIterator<(Coordinate<T>, V)>,
+    T: FromStr,
+    T::Err: Display,
+    V: Deserialize<'de>,
+{
+    type Value = M;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a map with coordinate keys like \"3,-4\"")
+    }
+
+    #[inline]
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut error = None;
+        let map = iter::from_fn(|| match access.next_entry::<CoordinateKey<T>, V>() {
+            Ok(entry) => entry.map(|(key, value)| (key.0, value)),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        })
+        .collect();
+        error.map_or(Ok(map), Err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, HashMap};
+
+    use serde::{Deserialize, Serialize};
+
+    use super::CoordinateKey;
+    use crate::Coordinate;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Maps {
+        #[serde(with = "super")]
+        hash: HashMap<Coordinate<i64>, String>,
+        #[serde(with = "super")]
+        tree: BTreeMap<Coordinate<i64>, Vec<u8>>,
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), serde_json::Error> {
+        let maps = Maps {
+            hash: HashMap::from([
+                (Coordinate::new(3, -4), "a".to_owned()),
+                (Coordinate::new(-1, -1), "b".to_owned()),
+                (Coordinate::new(i64::MIN, i64::MAX), "c".to_owned()),
+            ]),
+            tree: BTreeMap::from([
+                (Coordinate::new(0, 0), vec![]),
+                (Coordinate::new(-10, 20), vec![1, 2]),
+            ]),
+        };
+        let json = serde_json::to_string(&maps)?;
+        assert!(json.contains(r#""3,-4":"a""#), "{json}");
+        assert!(json.contains(r#""-9223372036854775808,9223372036854775807":"c""#));
+        assert!(
+            json.contains(r#""tree":{"-10,20":[1,2],"0,0":[]}"#),
+            "{json}"
+        );
+        assert_eq!(serde_json::from_str::<Maps>(&json)?, maps);
+
+        let empty = r#"{"hash":{},"tree":{}}"#;
+        let maps = serde_json::from_str::<Maps>(empty)?;
+        assert!(maps.hash.is_empty() && maps.tree.is_empty());
+        assert_eq!(serde_json::to_string(&maps)?, empty);
+
+        // the display form and the whitespace are accepted
+        let maps =
+            serde_json::from_str::<Maps>(r#"{"hash":{"[3, -4]":"a"},"tree":{" 1 , 2 ":[]}}"#)?;
+        assert_eq!(maps.hash[&Coordinate::new(3, -4)], "a");
+        assert!(maps.tree.contains_key(&Coordinate::new(1, 2)));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_key() {
+        for json in [
+            r#"{"hash":{"3":"a"},"tree":{}}"#,
+            r#"{"hash":{"3,x":"a"},"tree":{}}"#,
+            r#"{"hash":{"":"a"},"tree":{}}"#,
+            r#"{"hash":{},"tree":{"1.5,2":[]}}"#,
+        ] {
+            assert!(serde_json::from_str::<Maps>(json).is_err(), "{json}");
+        }
+        let error = serde_json::from_str::<Maps>(r#"{"hash":{"3 -4":"a"},"tree":{}}"#)
+            .expect_err("no separator");
+        assert!(
+            error.to_string().contains("separated by a comma"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn coordinate_key() -> Result<(), serde_json::Error> {
+        let key = CoordinateKey(Coordinate::new(-3_i32, 4_i32));
+        assert_eq!(key.to_string(), "-3,4");
+        assert_eq!("-3,4".parse(), Ok(key));
+        assert_eq!(serde_json::to_string(&key)?, r#""-3,4""#);
+        assert_eq!(
+            serde_json::from_str::<CoordinateKey<i32>>(r#""-3,4""#)?,
+            key
+        );
+        let _error = serde_json::from_str::<CoordinateKey<i32>>("[-3,4]")
+            .expect_err("a key is a string, not an array");
+
+        let float = CoordinateKey(Coordinate::new(-0.5_f64, 1E-10_f64));
+        let json = serde_json::to_string(&float)?;
+        assert_eq!(serde_json::from_str::<CoordinateKey<f64>>(&json)?, float);
+
+        let map = HashMap::from([(key, 1_u8), (CoordinateKey::default(), 2_u8)]);
+        let json = serde_json::to_string(&map)?;
+        assert_eq!(
+            serde_json::from_str::<HashMap<CoordinateKey<i32>, u8>>(&json)?,
+            map
+        );
+        assert_eq!(Coordinate::from(key), Coordinate::new(-3_i32, 4_i32));
+        assert_eq!(CoordinateKey::from(Coordinate::new(-3_i32, 4_i32)), key);
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 //! Contains the errors definitions.
 
-use std::{
+use core::{
     error::Error,
     fmt::{self, Display},
 };
@@ -34,3 +34,248 @@ impl From<NoneError> for () {
 }
 
 impl Error for NoneError {}
+
+/// Error when a collection, like a slice, does not have the expected length.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WrongLengthError {
+    /// the expected length
+    expected: usize,
+    /// the actual length
+    actual: usize,
+}
+
+impl WrongLengthError {
+    /// Create a new error from the expected and the actual length.
+    #[inline]
+    #[must_use]
+    pub const fn new(expected: usize, actual: usize) -> Self {
+        Self { expected, actual }
+    }
+
+    /// The expected length.
+    #[inline]
+    #[must_use]
+    pub const fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// The actual length.
+    #[inline]
+    #[must_use]
+    pub const fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl Display for WrongLengthError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a length of {} but the length is {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl Error for WrongLengthError {}
+
+/// Error when a vector of length zero is given to an operation that needs a direction, like
+/// [`Coordinate::cosine_similarity`](crate::Coordinate::cosine_similarity).
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZeroVectorError;
+
+impl Display for ZeroVectorError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the vector has a length of zero and no direction")
+    }
+}
+
+impl Error for ZeroVectorError {}
+
+/// Error when parsing an [`Axis2D`](crate::Axis2D) from a string that is not the name of an
+/// axis.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParseAxisError;
+
+impl Display for ParseAxisError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an axis among \"x\", \"y\", \"vertical\" or \"horizontal\""
+        )
+    }
+}
+
+impl Error for ParseAxisError {}
+
+/// Error of a component-wise conversion of a [`Coordinate`](crate::Coordinate), like
+/// [`Coordinate::try_map_validate`](crate::Coordinate::try_map_validate). It records which
+/// axis failed with its error, or the two errors if both failed.
+///
+/// # Example
+/// ```
+/// use utils_lib::{
+///     error::PerAxisError, number::ZeroOneBoundedFloatConversionError, Coordinate,
+///     ZeroOneBoundedFloat,
+/// };
+///
+/// let error = Coordinate::new(0.5_f64, 2_f64)
+///     .try_map_validate::<ZeroOneBoundedFloat>()
+///     .unwrap_err();
+/// assert_eq!(
+///     error,
+///     PerAxisError::Y(ZeroOneBoundedFloatConversionError::TooBig)
+/// );
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid y component: the float is above one"
+/// );
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::exhaustive_enums)] // reason = "no more variant possible"
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PerAxisError<E> {
+    /// Only the x component is invalid
+    X(E),
+    /// Only the y component is invalid
+    Y(E),
+    /// Both component are invalid
+    Both {
+        /// the error of the x component
+        x: E,
+        /// the error of the y component
+        y: E,
+    },
+}
+
+impl<E> PerAxisError<E> {
+    /// Create the error from the error of each component, returns [`None`] if there is none.
+    #[inline]
+    #[must_use]
+    pub fn from_components(x: Option<E>, y: Option<E>) -> Option<Self> {
+        match (x, y) {
+            (None, None) => None,
+            (Some(x), None) => Some(Self::X(x)),
+            (None, Some(y)) => Some(Self::Y(y)),
+            (Some(x), Some(y)) => Some(Self::Both { x, y }),
+        }
+    }
+
+    /// The error of the x component, if it is invalid.
+    #[inline]
+    #[must_use]
+    pub const fn x(&self) -> Option<&E> {
+        match self {
+            Self::X(x) | Self::Both { x, .. } => Some(x),
+            Self::Y(_) => None,
+        }
+    }
+
+    /// The error of the y component, if it is invalid.
+    #[inline]
+    #[must_use]
+    pub const fn y(&self) -> Option<&E> {
+        match self {
+            Self::Y(y) | Self::Both { y, .. } => Some(y),
+            Self::X(_) => None,
+        }
+    }
+}
+
+impl<E: Display> Display for PerAxisError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::X(x) => write!(f, "invalid x component: {x}"),
+            Self::Y(y) => write!(f, "invalid y component: {y}"),
+            Self::Both { x, y } => write!(f, "invalid x and y components: x: {x}, y: {y}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for PerAxisError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::X(x) | Self::Both { x, .. } => Some(x),
+            Self::Y(y) => Some(y),
+        }
+    }
+}
+
+/// Error when parsing a [`Coordinate`](crate::Coordinate) from a string, like `"3,-4"` or
+/// `"[3, -4]"`. It is either a missing comma or the errors of the components.
+///
+/// # Example
+/// ```
+/// use core::str::FromStr;
+///
+/// use utils_lib::{
+///     error::{ParseCoordinateError, PerAxisError},
+///     Coordinate,
+/// };
+///
+/// assert_eq!(
+///     Coordinate::<i64>::from_str("3 -4"),
+///     Err(ParseCoordinateError::MissingSeparator)
+/// );
+/// let error = Coordinate::<i64>::from_str("3,a").unwrap_err();
+/// assert!(matches!(
+///     error,
+///     ParseCoordinateError::Component(PerAxisError::Y(_))
+/// ));
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid y component: invalid digit found in string"
+/// );
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::exhaustive_enums)] // reason = "no more variant possible"
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParseCoordinateError<E> {
+    /// There is no comma between the components
+    MissingSeparator,
+    /// At least one component cannot be parsed
+    Component(PerAxisError<E>),
+}
+
+impl<E> From<PerAxisError<E>> for ParseCoordinateError<E> {
+    #[inline]
+    fn from(value: PerAxisError<E>) -> Self {
+        Self::Component(value)
+    }
+}
+
+impl<E: Display> Display for ParseCoordinateError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSeparator => write!(
+                f,
+                "expected two components separated by a comma, like \"3,-4\""
+            ),
+            Self::Component(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseCoordinateError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::MissingSeparator => None,
+            Self::Component(err) => Some(err),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![doc(html_root_url = "https://docs.rs/utils-lib/0.1.0")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 //------
 // main lints
 //------
@@ -131,6 +132,9 @@
 //#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
 //#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod macro_def;
 
@@ -142,7 +146,7 @@ pub mod number;
 mod test;
 
 #[doc(inline)]
-pub use utils_lib_derive::{trait_sealed, Getter, Sealed};
+pub use utils_lib_derive::{trait_sealed, Getter, New, Sealed, Variants};
 
 pub use self::coordinate::{Axis2D, Coordinate};
 pub use self::number::{abs_diff, PositiveFloat, ValidationGuard, ZeroOneBoundedFloat};
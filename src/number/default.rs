@@ -0,0 +1,180 @@
+//! Contains [`OneDefault`], the "neutral element of multiplication" default of
+//! [`ZeroOneBoundedFloat`] and [`PositiveFloat`], with its serde helpers and the
+//! [`OptionDefaultExt`] extension trait.
+//!
+//! [`Default`] stays zero for both types, these are opt-in alternatives.
+
+use super::{PositiveFloat, ZeroOneBoundedFloat};
+
+/// Types whose [`Default`] is zero that also have a one default.
+pub trait OneDefault: Default {
+    /// Get the value one.
+    #[must_use]
+    fn default_one() -> Self;
+}
+
+impl ZeroOneBoundedFloat {
+    /// Get the value one, the neutral element of the multiplication, whereas
+    /// [`Default::default`] gives zero.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// assert_eq!(ZeroOneBoundedFloat::one_default(), ZeroOneBoundedFloat::ONE);
+    /// assert_eq!(ZeroOneBoundedFloat::default(), ZeroOneBoundedFloat::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn one_default() -> Self {
+        Self::ONE
+    }
+}
+
+impl OneDefault for ZeroOneBoundedFloat {
+    #[inline]
+    fn default_one() -> Self {
+        Self::one_default()
+    }
+}
+
+impl PositiveFloat {
+    /// Get the value one, the neutral element of the multiplication, whereas
+    /// [`Default::default`] gives zero.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// assert_eq!(PositiveFloat::one_default(), PositiveFloat::ONE);
+    /// assert_eq!(PositiveFloat::default(), PositiveFloat::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn one_default() -> Self {
+        Self::ONE
+    }
+}
+
+impl OneDefault for PositiveFloat {
+    #[inline]
+    fn default_one() -> Self {
+        Self::one_default()
+    }
+}
+
+/// Default one for a missing field, to use as
+/// `#[serde(default = "utils_lib::number::serde_default_one")]`.
+///
+/// # Example
+/// ```
+/// use serde::Deserialize;
+/// use utils_lib::ZeroOneBoundedFloat;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(default = "utils_lib::number::serde_default_one")]
+///     opacity: ZeroOneBoundedFloat,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+#[inline]
+#[must_use]
+pub fn serde_default_one<T: OneDefault>() -> T {
+    T::default_one()
+}
+
+/// Default zero for a missing field, to use as
+/// `#[serde(default = "utils_lib::number::serde_default_zero")]`. It is the same as
+/// `#[serde(default)]` but states the value explicitly.
+#[cfg(feature = "serde")]
+#[inline]
+#[must_use]
+pub fn serde_default_zero<T: OneDefault>() -> T {
+    T::default()
+}
+
+/// Extension trait on [`Option`] of a [`OneDefault`] type to unwrap it with either default.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::OptionDefaultExt, PositiveFloat};
+///
+/// let scale: Option<PositiveFloat> = None;
+/// assert_eq!(scale.unwrap_or_one(), PositiveFloat::ONE);
+/// assert_eq!(scale.unwrap_or_zero(), PositiveFloat::ZERO);
+/// ```
+pub trait OptionDefaultExt<T> {
+    /// Get the contained value or zero if it is [`None`].
+    #[must_use]
+    fn unwrap_or_zero(self) -> T;
+
+    /// Get the contained value or one if it is [`None`].
+    #[must_use]
+    fn unwrap_or_one(self) -> T;
+}
+
+impl<T: OneDefault> OptionDefaultExt<T> for Option<T> {
+    #[inline]
+    fn unwrap_or_zero(self) -> T {
+        self.unwrap_or_default()
+    }
+
+    #[inline]
+    fn unwrap_or_one(self) -> T {
+        self.unwrap_or_else(T::default_one)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::OptionDefaultExt;
+    use crate::{PositiveFloat, ZeroOneBoundedFloat};
+
+    #[test]
+    fn option_default() {
+        let half = ZeroOneBoundedFloat::new(0.5_f64).expect("valid");
+        assert_eq!(Some(half).unwrap_or_one(), half);
+        assert_eq!(Some(half).unwrap_or_zero(), half);
+        let none: Option<ZeroOneBoundedFloat> = None;
+        assert_eq!(none.unwrap_or_one(), ZeroOneBoundedFloat::ONE);
+        assert_eq!(none.unwrap_or_zero(), ZeroOneBoundedFloat::ZERO);
+        let none: Option<PositiveFloat> = None;
+        assert_eq!(none.unwrap_or_one(), PositiveFloat::ONE);
+        assert_eq!(none.unwrap_or_zero(), PositiveFloat::ZERO);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_default() {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct Config {
+            #[serde(default = "super::serde_default_one")]
+            factor: ZeroOneBoundedFloat,
+            #[serde(default = "super::serde_default_zero")]
+            offset: ZeroOneBoundedFloat,
+            #[serde(default = "super::serde_default_one")]
+            scale: PositiveFloat,
+            #[serde(default = "super::serde_default_zero")]
+            shift: PositiveFloat,
+        }
+
+        let config: Config = serde_json::from_str("{}").expect("valid json");
+        assert_eq!(config.factor, ZeroOneBoundedFloat::ONE);
+        assert_eq!(config.offset, ZeroOneBoundedFloat::ZERO);
+        assert_eq!(config.scale, PositiveFloat::ONE);
+        assert_eq!(config.shift, PositiveFloat::ZERO);
+
+        let config: Config =
+            serde_json::from_str(r#"{"factor": 0.5, "shift": 2.0}"#).expect("valid json");
+        assert_eq!(
+            config.factor,
+            ZeroOneBoundedFloat::new(0.5_f64).expect("valid")
+        );
+        assert_eq!(config.offset, ZeroOneBoundedFloat::ZERO);
+        assert_eq!(config.scale, PositiveFloat::ONE);
+        assert_eq!(config.shift, PositiveFloat::new(2_f64).expect("valid"));
+    }
+}
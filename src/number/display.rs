@@ -0,0 +1,99 @@
+//! Contains the display helpers returned by [`super::ZeroOneBoundedFloat::display_percent`]
+//! and [`super::PositiveFloat::display_ratio`], and the debug helper of the float wrappers.
+
+use core::{
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+};
+
+use super::{PositiveFloat, Validation, ZeroOneBoundedFloat};
+
+/// Display a [`ZeroOneBoundedFloat`] as a percentage, like `"42.0%"`.
+///
+/// The precision flag is applied on the percentage and it defaults to one decimal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PercentDisplay(ZeroOneBoundedFloat);
+
+impl PercentDisplay {
+    /// Create the display helper.
+    pub const fn new(float: ZeroOneBoundedFloat) -> Self {
+        Self(float)
+    }
+}
+
+impl Display for PercentDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(1);
+        write!(f, "{:.*}%", precision, self.0.float() * 100_f64)
+    }
+}
+
+/// Display a ratio of two [`PositiveFloat`] as the fraction and its percentage,
+/// like `"3/4 (75%)"`.
+///
+/// The precision flag is only applied on the percentage, by default the shortest
+/// representation is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RatioDisplay {
+    /// numerator of the ratio
+    numerator: PositiveFloat,
+    /// denominator of the ratio
+    denominator: PositiveFloat,
+}
+
+impl RatioDisplay {
+    /// Create the display helper.
+    pub const fn new(numerator: PositiveFloat, denominator: PositiveFloat) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl Display for RatioDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = self.numerator.float() / self.denominator.float() * 100_f64;
+        write!(f, "{}/{} (", self.numerator, self.denominator)?;
+        if let Some(precision) = f.precision() {
+            write!(f, "{percent:.precision$}")?;
+        } else {
+            write!(f, "{percent}")?;
+        }
+        write!(f, "%)")
+    }
+}
+
+/// Debug the float of a wrapper `T`, followed by ` [INVALID]` if it breaks the invariant of
+/// `T`, see [`Validation::validate_data`]. It is used in the [`Debug`] implementations of the
+/// wrappers and of [`super::ValidationGuard`].
+pub(super) struct FloatDebug<T: ?Sized> {
+    /// the float
+    float: f64,
+    /// the wrapper whose invariant is checked
+    wrapper: PhantomData<T>,
+}
+
+impl<T: Validation + ?Sized> FloatDebug<T> {
+    /// Create the debug helper.
+    pub(super) const fn new(float: f64) -> Self {
+        Self {
+            float,
+            wrapper: PhantomData,
+        }
+    }
+}
+
+impl<T: Validation + ?Sized> Debug for FloatDebug<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <f64 as Debug>::fmt(&self.float, f)?;
+        if T::validate_data(self.float) {
+            Ok(())
+        } else {
+            write!(f, " [INVALID]")
+        }
+    }
+}
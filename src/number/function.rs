@@ -1,12 +1,11 @@
 //! Contain useful numerical function
 
-use std::{
-    cmp::Ordering,
-    ops::{Div, Mul, Sub},
-};
+use core::ops::{Div, Mul, Sub};
 
 use num_traits::{One, Unsigned, Zero};
 
+use super::mul_add;
+
 /// Find the greater common divider
 ///
 /// # Example
@@ -18,6 +17,7 @@ use num_traits::{One, Unsigned, Zero};
 /// assert_eq!(gcd(120_u16, 7_u16), 1_u16);
 /// assert_eq!(gcd(0_u16, 7_u16), 0_u16);
 /// assert_eq!(gcd(32_u64, 24_u64), 8_u64);
+/// assert_eq!(gcd(1_000_000_000_000_u64, 3_u64), 1_u64);
 /// ```
 #[must_use]
 #[inline]
@@ -26,16 +26,16 @@ where
     Number: Sub<Output = Number> + Ord + Zero + One + Clone + Unsigned,
 {
     if n1 == Number::zero() || n2 == Number::zero() {
-        Number::zero()
-    } else if n1 == Number::one() || n2 == Number::one() {
-        Number::one()
-    } else {
-        match n1.cmp(&n2) {
-            Ordering::Equal => n1,
-            Ordering::Greater => gcd(n1 - n2.clone(), n2),
-            Ordering::Less => gcd(n1.clone(), n2 - n1),
-        }
+        return Number::zero();
+    }
+    // Euclid's algorithm, the remainder is available as `Unsigned` implies `Num`
+    let (mut n1, mut n2) = (n1, n2);
+    while n2 != Number::zero() {
+        let remainder = n1 % n2.clone();
+        n1 = n2;
+        n2 = remainder;
     }
+    n1
 }
 
 /// Find the lowest common multiplier
@@ -97,3 +97,36 @@ where
         n2 - n1
     }
 }
+
+/// Affinely map `x` from the range `[from_low, from_high]` to the range `[to_low, to_high]`,
+/// i.e. `(x - from_low) / (from_high - from_low) * (to_high - to_low) + to_low`. `from_low`
+/// goes to `to_low` and `from_high` to `to_high`, the values out of the source range are
+/// extrapolated and the ranges can be decreasing.
+///
+/// If the source range is degenerate, `from_low == from_high`, every value is mapped to
+/// `to_low`.
+///
+/// # Example
+/// ```
+/// use utils_lib::number::rescale;
+///
+/// assert_eq!(rescale(5_f64, 0_f64, 10_f64, 0_f64, 1_f64), 0.5_f64);
+/// assert_eq!(rescale(20_f64, 0_f64, 10_f64, 0_f64, 1_f64), 2_f64);
+/// // decreasing destination
+/// assert_eq!(rescale(2_f64, 0_f64, 8_f64, 1_f64, -1_f64), 0.5_f64);
+/// // degenerate source
+/// assert_eq!(rescale(3_f64, 1_f64, 1_f64, 4_f64, 5_f64), 4_f64);
+/// ```
+#[must_use]
+#[inline]
+pub fn rescale(x: f64, from_low: f64, from_high: f64, to_low: f64, to_high: f64) -> f64 {
+    #[allow(clippy::float_cmp)] // reason = "only an exactly degenerate range divides by zero"
+    if from_low == from_high {
+        return to_low;
+    }
+    mul_add(
+        (x - from_low) / (from_high - from_low),
+        to_high - to_low,
+        to_low,
+    )
+}
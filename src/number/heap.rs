@@ -0,0 +1,522 @@
+//! Contains [`MinFloatHeap`] and [`MaxFloatHeap`], priority queues of values keyed by a
+//! [`PositiveFloat`], e.g. for Dijkstra's algorithm or event scheduling.
+//!
+//! The module requires the `alloc` feature.
+
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::{
+    cmp::{Ordering, Reverse},
+    fmt::{self, Debug},
+};
+
+use super::PositiveFloat;
+
+/// Key of the priority in the [`BinaryHeap`]: [`PositiveFloat`] for a max heap and
+/// `Reverse<PositiveFloat>` for a min heap.
+trait PriorityKey: Ord + Copy {
+    /// Get the key of the priority.
+    fn from_priority(priority: PositiveFloat) -> Self;
+
+    /// Get the priority back.
+    fn priority(self) -> PositiveFloat;
+}
+
+impl PriorityKey for PositiveFloat {
+    #[inline]
+    fn from_priority(priority: PositiveFloat) -> Self {
+        priority
+    }
+
+    #[inline]
+    fn priority(self) -> PositiveFloat {
+        self
+    }
+}
+
+impl PriorityKey for Reverse<PositiveFloat> {
+    #[inline]
+    fn from_priority(priority: PositiveFloat) -> Self {
+        Self(priority)
+    }
+
+    #[inline]
+    fn priority(self) -> PositiveFloat {
+        self.0
+    }
+}
+
+/// Element of the heap, ordered by its key then by its insertion order, the oldest first.
+/// The value is not compared.
+#[derive(Clone)]
+struct Entry<K, T> {
+    /// key of the priority
+    key: K,
+    /// insertion order of the element
+    sequence: Reverse<u64>,
+    /// payload
+    value: T,
+}
+
+impl<K: PriorityKey, T> Entry<K, T> {
+    /// Get the priority and the value.
+    #[inline]
+    fn into_pair(self) -> (PositiveFloat, T) {
+        (self.key.priority(), self.value)
+    }
+}
+
+impl<K: Ord, T> PartialEq for Entry<K, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Ord, T> Eq for Entry<K, T> {}
+
+impl<K: Ord, T> PartialOrd for Entry<K, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for Entry<K, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .cmp(&other.key)
+            .then_with(|| self.sequence.cmp(&other.sequence))
+    }
+}
+
+/// Heap shared by [`MinFloatHeap`] and [`MaxFloatHeap`], the greatest key is popped first.
+#[derive(Clone)]
+struct FloatHeap<K, T> {
+    /// the elements
+    heap: BinaryHeap<Entry<K, T>>,
+    /// insertion order of the next element
+    sequence: u64,
+}
+
+impl<K: PriorityKey, T> FloatHeap<K, T> {
+    /// Create an empty heap.
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            sequence: 0,
+        }
+    }
+
+    /// Push a value with its priority.
+    #[inline]
+    fn push(&mut self, priority: PositiveFloat, value: T) {
+        self.heap.push(Entry {
+            key: K::from_priority(priority),
+            sequence: Reverse(self.sequence),
+            value,
+        });
+        self.sequence = self.sequence.wrapping_add(1);
+    }
+
+    /// Remove the element with the greatest key.
+    #[inline]
+    fn pop(&mut self) -> Option<(PositiveFloat, T)> {
+        self.heap.pop().map(Entry::into_pair)
+    }
+
+    /// Get the element with the greatest key.
+    #[inline]
+    fn peek(&self) -> Option<(PositiveFloat, &T)> {
+        self.heap
+            .peek()
+            .map(|entry| (entry.key.priority(), &entry.value))
+    }
+
+    /// Get the elements in the order they would be popped.
+    #[inline]
+    fn into_sorted_vec(self) -> Vec<(PositiveFloat, T)> {
+        // the sorted vector of the binary heap is in ascending order
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .rev()
+            .map(Entry::into_pair)
+            .collect()
+    }
+
+    /// Write the elements in the order of the heap, not the order they would be popped.
+    fn fmt_entries(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        T: Debug,
+    {
+        f.debug_struct(name)
+            .field(
+                "heap",
+                &self
+                    .heap
+                    .iter()
+                    .map(|entry| (entry.key.priority(), &entry.value))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Priority queue popping the value with the smallest [`PositiveFloat`] priority first, a
+/// [`BinaryHeap`] without the `Reverse` wrapper and without requiring [`Ord`] on the values.
+///
+/// Values pushed with equal priorities are popped in the order they were pushed.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::MinFloatHeap, PositiveFloat};
+///
+/// # fn main() -> Result<(), utils_lib::number::PositiveFloatConversionError> {
+/// let mut heap = MinFloatHeap::new();
+/// heap.push(PositiveFloat::new(2_f64)?, "b");
+/// heap.push(PositiveFloat::new(1_f64)?, "a");
+/// heap.push(PositiveFloat::new(2_f64)?, "c");
+///
+/// assert_eq!(heap.len(), 3);
+/// assert_eq!(heap.peek(), Some((PositiveFloat::ONE, &"a")));
+/// assert_eq!(heap.pop(), Some((PositiveFloat::ONE, "a")));
+/// assert_eq!(heap.pop(), Some((PositiveFloat::new(2_f64)?, "b")));
+/// assert_eq!(heap.pop(), Some((PositiveFloat::new(2_f64)?, "c")));
+/// assert_eq!(heap.pop(), None);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MinFloatHeap<T>(FloatHeap<Reverse<PositiveFloat>, T>);
+
+impl<T> MinFloatHeap<T> {
+    /// Create an empty heap.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(FloatHeap::new())
+    }
+
+    /// Push a value with its priority.
+    #[inline]
+    pub fn push(&mut self, priority: PositiveFloat, value: T) {
+        self.0.push(priority, value);
+    }
+
+    /// Remove the value with the smallest priority, the oldest one if several have the same
+    /// priority. Returns [`None`] if the heap is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<(PositiveFloat, T)> {
+        self.0.pop()
+    }
+
+    /// Get the value that [`Self::pop`] would remove, without removing it.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> Option<(PositiveFloat, &T)> {
+        self.0.peek()
+    }
+
+    /// Get the number of values in the heap.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.heap.len()
+    }
+
+    /// Returns `true` if the heap is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.heap.is_empty()
+    }
+
+    /// Get the values in the order they would be popped, i.e. by ascending priority.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::MinFloatHeap, PositiveFloat};
+    ///
+    /// let heap = [(PositiveFloat::MAX, 'c'), (PositiveFloat::ZERO, 'a')]
+    ///     .into_iter()
+    ///     .collect::<MinFloatHeap<_>>();
+    /// assert_eq!(
+    ///     heap.into_sorted_vec(),
+    ///     vec![(PositiveFloat::ZERO, 'a'), (PositiveFloat::MAX, 'c')]
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_sorted_vec(self) -> Vec<(PositiveFloat, T)> {
+        self.0.into_sorted_vec()
+    }
+}
+
+impl<T> Default for MinFloatHeap<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The values are given in the order of the heap, not the order they would be popped.
+impl<T: Debug> Debug for MinFloatHeap<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_entries("MinFloatHeap", f)
+    }
+}
+
+impl<T> Extend<(PositiveFloat, T)> for MinFloatHeap<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (PositiveFloat, T)>>(&mut self, iter: I) {
+        for (priority, value) in iter {
+            self.push(priority, value);
+        }
+    }
+}
+
+impl<T> FromIterator<(PositiveFloat, T)> for MinFloatHeap<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (PositiveFloat, T)>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+/// Priority queue popping the value with the greatest [`PositiveFloat`] priority first, a
+/// [`BinaryHeap`] without requiring [`Ord`] on the values, see [`MinFloatHeap`].
+///
+/// Values pushed with equal priorities are popped in the order they were pushed.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::MaxFloatHeap, PositiveFloat};
+///
+/// # fn main() -> Result<(), utils_lib::number::PositiveFloatConversionError> {
+/// let mut heap = MaxFloatHeap::new();
+/// heap.push(PositiveFloat::new(2_f64)?, "b");
+/// heap.push(PositiveFloat::new(1_f64)?, "a");
+///
+/// assert_eq!(heap.peek(), Some((PositiveFloat::new(2_f64)?, &"b")));
+/// assert_eq!(heap.pop(), Some((PositiveFloat::new(2_f64)?, "b")));
+/// assert_eq!(heap.pop(), Some((PositiveFloat::ONE, "a")));
+/// assert!(heap.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MaxFloatHeap<T>(FloatHeap<PositiveFloat, T>);
+
+impl<T> MaxFloatHeap<T> {
+    /// Create an empty heap.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(FloatHeap::new())
+    }
+
+    /// Push a value with its priority.
+    #[inline]
+    pub fn push(&mut self, priority: PositiveFloat, value: T) {
+        self.0.push(priority, value);
+    }
+
+    /// Remove the value with the greatest priority, the oldest one if several have the same
+    /// priority. Returns [`None`] if the heap is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<(PositiveFloat, T)> {
+        self.0.pop()
+    }
+
+    /// Get the value that [`Self::pop`] would remove, without removing it.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> Option<(PositiveFloat, &T)> {
+        self.0.peek()
+    }
+
+    /// Get the number of values in the heap.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.heap.len()
+    }
+
+    /// Returns `true` if the heap is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.heap.is_empty()
+    }
+
+    /// Get the values in the order they would be popped, i.e. by descending priority.
+    #[inline]
+    #[must_use]
+    pub fn into_sorted_vec(self) -> Vec<(PositiveFloat, T)> {
+        self.0.into_sorted_vec()
+    }
+}
+
+impl<T> Default for MaxFloatHeap<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The values are given in the order of the heap, not the order they would be popped.
+impl<T: Debug> Debug for MaxFloatHeap<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_entries("MaxFloatHeap", f)
+    }
+}
+
+impl<T> Extend<(PositiveFloat, T)> for MaxFloatHeap<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (PositiveFloat, T)>>(&mut self, iter: I) {
+        for (priority, value) in iter {
+            self.push(priority, value);
+        }
+    }
+}
+
+impl<T> FromIterator<(PositiveFloat, T)> for MaxFloatHeap<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (PositiveFloat, T)>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MaxFloatHeap, MinFloatHeap};
+    use crate::number::{PositiveFloat, PositiveFloatConversionError};
+
+    #[test]
+    fn empty() {
+        let mut min = MinFloatHeap::<u8>::new();
+        assert!(min.is_empty());
+        assert_eq!(min.len(), 0);
+        assert_eq!(min.peek(), None);
+        assert_eq!(min.pop(), None);
+        assert_eq!(min.pop(), None);
+        assert!(min.into_sorted_vec().is_empty());
+
+        let mut max = MaxFloatHeap::<u8>::default();
+        assert!(max.is_empty());
+        assert_eq!(max.peek(), None);
+        assert_eq!(max.pop(), None);
+
+        max.push(PositiveFloat::ONE, 1_u8);
+        assert_eq!(max.pop(), Some((PositiveFloat::ONE, 1_u8)));
+        assert_eq!(max.pop(), None);
+        assert!(max.is_empty());
+    }
+
+    #[test]
+    fn ordering() -> Result<(), PositiveFloatConversionError> {
+        let priorities = [3_f64, 0.5_f64, 7_f64, 0_f64, 2_f64, 1E10_f64, 2.5_f64];
+        let pairs = priorities
+            .iter()
+            .enumerate()
+            .map(|(index, float)| Ok((PositiveFloat::new(*float)?, index)))
+            .collect::<Result<Vec<_>, PositiveFloatConversionError>>()?;
+
+        let mut min = pairs.iter().copied().collect::<MinFloatHeap<_>>();
+        let mut max = pairs.iter().copied().collect::<MaxFloatHeap<_>>();
+        assert_eq!(min.len(), priorities.len());
+        assert_eq!(max.len(), priorities.len());
+
+        let mut ascending = pairs;
+        ascending.sort_by_key(|(priority, _)| *priority);
+        assert_eq!(min.clone().into_sorted_vec(), ascending);
+        let mut descending = ascending.clone();
+        descending.reverse();
+        assert_eq!(max.clone().into_sorted_vec(), descending);
+
+        for expected in ascending {
+            assert_eq!(min.peek(), Some((expected.0, &expected.1)));
+            assert_eq!(min.pop(), Some(expected));
+        }
+        for expected in descending {
+            assert_eq!(max.peek(), Some((expected.0, &expected.1)));
+            assert_eq!(max.pop(), Some(expected));
+        }
+        assert!(min.is_empty() && max.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn equal_priorities() -> Result<(), PositiveFloatConversionError> {
+        let two = PositiveFloat::new(2_f64)?;
+        let mut min = MinFloatHeap::new();
+        let mut max = MaxFloatHeap::new();
+        for (priority, value) in [
+            (two, 'a'),
+            (PositiveFloat::ONE, 'b'),
+            (two, 'c'),
+            (PositiveFloat::ONE, 'd'),
+            (two, 'e'),
+        ] {
+            min.push(priority, value);
+            max.push(priority, value);
+        }
+        let values = |pairs: Vec<(PositiveFloat, char)>| {
+            pairs
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect::<String>()
+        };
+        // the values with the same priority in insertion order
+        assert_eq!(values(min.clone().into_sorted_vec()), "bdace");
+        assert_eq!(values(max.clone().into_sorted_vec()), "acebd");
+
+        let mut popped = String::new();
+        while let Some((_, value)) = min.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, "bdace");
+        Ok(())
+    }
+
+    #[test]
+    fn interleaved() -> Result<(), PositiveFloatConversionError> {
+        let mut heap = MinFloatHeap::new();
+        heap.push(PositiveFloat::new(5_f64)?, 5_u32);
+        heap.push(PositiveFloat::new(3_f64)?, 3_u32);
+        assert_eq!(heap.pop(), Some((PositiveFloat::new(3_f64)?, 3_u32)));
+
+        heap.push(PositiveFloat::new(4_f64)?, 4_u32);
+        heap.push(PositiveFloat::new(6_f64)?, 6_u32);
+        assert_eq!(heap.peek(), Some((PositiveFloat::new(4_f64)?, &4_u32)));
+        assert_eq!(heap.pop(), Some((PositiveFloat::new(4_f64)?, 4_u32)));
+
+        heap.push(PositiveFloat::ONE, 1_u32);
+        heap.extend([(PositiveFloat::new(5_f64)?, 50_u32)]);
+        assert_eq!(heap.len(), 4);
+        assert_eq!(heap.pop(), Some((PositiveFloat::ONE, 1_u32)));
+        assert_eq!(heap.pop(), Some((PositiveFloat::new(5_f64)?, 5_u32)));
+        assert_eq!(heap.pop(), Some((PositiveFloat::new(5_f64)?, 50_u32)));
+        assert_eq!(heap.pop(), Some((PositiveFloat::new(6_f64)?, 6_u32)));
+        assert_eq!(heap.pop(), None);
+
+        // the values do not need to be comparable
+        let mut heap = MaxFloatHeap::new();
+        heap.push(PositiveFloat::ONE, vec![1_f64]);
+        heap.push(PositiveFloat::MAX, vec![f64::NAN]);
+        assert_eq!(heap.pop().map(|(_, value)| value.len()), Some(1));
+        assert_eq!(heap.pop(), Some((PositiveFloat::ONE, vec![1_f64])));
+        assert_eq!(
+            format!("{:?}", MinFloatHeap::<u8>::new()),
+            "MinFloatHeap { heap: [] }"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,532 @@
+//! Contains [`Histogram`], counting values in bins, and its error [`HistogramError`].
+//!
+//! The module requires the `alloc` feature.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::ZeroOneBoundedFloat;
+
+/// Counts of values in consecutive bins given by their edges.
+///
+/// The bin `i` is the interval `[edges[i], edges[i + 1])`, the lower edge is included and
+/// the upper edge is excluded, except for the last bin which includes the last edge so that
+/// the maximum is counted. The values below the first edge and above the last edge are
+/// counted by [`Self::underflow`] and [`Self::overflow`], and [`f64::NAN`] is ignored.
+///
+/// # Example
+/// ```
+/// use utils_lib::number::histogram::Histogram;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut histogram = Histogram::new(0_f64, 4_f64, 4)?;
+/// histogram.extend([0.5_f64, 1_f64, 1.5_f64, 4_f64, 5_f64]);
+/// assert_eq!(histogram.counts(), &[1_u64, 2_u64, 0_u64, 1_u64]);
+/// assert_eq!(histogram.bin_of(1_f64), Some(1));
+/// assert_eq!(histogram.overflow(), 1_u64);
+/// assert_eq!(
+///     histogram.to_string(),
+///     "[0, 1) 1 ####################\n\
+///      [1, 2) 2 ########################################\n\
+///      [2, 3) 0 \n\
+///      [3, 4] 1 ####################\n\
+///      overflow 1\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "HistogramFields"))]
+pub struct Histogram {
+    /// the edges of the bins, finite and strictly increasing
+    edges: Vec<f64>,
+    /// the number of values in each bin, one less than the edges
+    counts: Vec<u64>,
+    /// the number of values below the first edge
+    underflow: u64,
+    /// the number of values above the last edge
+    overflow: u64,
+}
+
+impl Histogram {
+    /// Width in characters of the longest bar of the [`Display`] implementation.
+    const BAR_WIDTH: u64 = 40;
+
+    /// Create an empty histogram with `bins` bins of the same width between `min` and `max`.
+    ///
+    /// # Errors
+    /// - [`HistogramError::NoBin`] if `bins` is zero,
+    /// - [`HistogramError::InvalidEdges`] if `min` or `max` is not finite or `min >= max`.
+    #[inline]
+    pub fn new(min: f64, max: f64, bins: usize) -> Result<Self, HistogramError> {
+        if bins == 0 {
+            return Err(HistogramError::NoBin);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        // reason = "the number of bins is never close to 2^52"
+        let width = (max - min) / bins as f64;
+        #[allow(clippy::cast_precision_loss)]
+        // reason = "the number of bins is never close to 2^52"
+        let edges = (0..bins)
+            .map(|index| super::mul_add(index as f64, width, min))
+            .chain([max])
+            .collect();
+        Self::from_edges(edges)
+    }
+
+    /// Create an empty histogram with the given edges, `edges.len() - 1` bins.
+    ///
+    /// # Errors
+    /// - [`HistogramError::NoBin`] if there are less than two edges,
+    /// - [`HistogramError::InvalidEdges`] if an edge is not finite or the edges are not
+    ///   strictly increasing.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::histogram::{Histogram, HistogramError};
+    ///
+    /// # fn main() -> Result<(), HistogramError> {
+    /// let histogram = Histogram::from_edges(vec![0_f64, 1_f64, 10_f64, 100_f64])?;
+    /// assert_eq!(histogram.bin_of(50_f64), Some(2));
+    ///
+    /// assert_eq!(
+    ///     Histogram::from_edges(vec![0_f64, 1_f64, 1_f64]),
+    ///     Err(HistogramError::InvalidEdges)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_edges(edges: Vec<f64>) -> Result<Self, HistogramError> {
+        if edges.len() < 2 {
+            return Err(HistogramError::NoBin);
+        }
+        if !edges.iter().all(|edge| edge.is_finite())
+            || !edges.windows(2).all(|window| window[0] < window[1])
+        {
+            return Err(HistogramError::InvalidEdges);
+        }
+        Ok(Self {
+            counts: alloc::vec![0; edges.len() - 1],
+            edges,
+            underflow: 0,
+            overflow: 0,
+        })
+    }
+
+    /// The edges of the bins, one more than the bins.
+    #[inline]
+    #[must_use]
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// The number of values in each bin.
+    #[inline]
+    #[must_use]
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// The number of bins.
+    #[inline]
+    #[must_use]
+    pub const fn bins(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The number of values below the first edge.
+    #[inline]
+    #[must_use]
+    pub const fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// The number of values above the last edge.
+    #[inline]
+    #[must_use]
+    pub const fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// The number of values in the bins, without the underflow and the overflow.
+    #[inline]
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// The index of the bin containing `value`, [`None`] if it is outside of the edges or
+    /// [`f64::NAN`]. A value on an edge is in the bin above it, except for the last edge
+    /// which is in the last bin.
+    #[inline]
+    #[must_use]
+    pub fn bin_of(&self, value: f64) -> Option<usize> {
+        let last = self.edges.len() - 1;
+        // the number of edges below or equal to the value, zero for NaN
+        match self.edges.partition_point(|edge| *edge <= value) {
+            0 => None,
+            index if index <= last => Some(index - 1),
+            #[allow(clippy::float_cmp)] // reason = "the last edge is included"
+            _ if value == self.edges[last] => Some(last - 1),
+            _ => None,
+        }
+    }
+
+    /// Count a value, returns the index of its bin, see [`Self::bin_of`].
+    #[inline]
+    pub fn push(&mut self, value: f64) -> Option<usize> {
+        let bin = self.bin_of(value);
+        if let Some(bin) = bin {
+            self.counts[bin] += 1;
+        } else if value < self.edges[0] {
+            self.underflow += 1;
+        } else if value > self.edges[self.edges.len() - 1] {
+            self.overflow += 1;
+        }
+        bin
+    }
+
+    /// Add the counts of `other` to the counts of `self`, as if the values of `other` were
+    /// pushed in `self`.
+    ///
+    /// # Errors
+    /// Returns [`HistogramError::EdgesMismatch`] if the edges are not the same.
+    #[inline]
+    pub fn merge(&mut self, other: &Self) -> Result<(), HistogramError> {
+        if self.edges != other.edges {
+            return Err(HistogramError::EdgesMismatch);
+        }
+        for (count, other) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other;
+        }
+        self.underflow += other.underflow;
+        self.overflow += other.overflow;
+        Ok(())
+    }
+
+    /// The fraction of the values of each bin over the [`Self::total`], without the
+    /// underflow and the overflow. All the fractions are zero if the bins are empty.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::histogram::Histogram, ZeroOneBoundedFloat};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut histogram = Histogram::new(0_f64, 2_f64, 2)?;
+    /// histogram.extend([0_f64, 1_f64, 1.5_f64, 1.7_f64, -1_f64]);
+    /// assert_eq!(
+    ///     histogram.normalized(),
+    ///     vec![
+    ///         ZeroOneBoundedFloat::new(0.25_f64)?,
+    ///         ZeroOneBoundedFloat::new(0.75_f64)?
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn normalized(&self) -> Vec<ZeroOneBoundedFloat> {
+        let total = self.total();
+        self.counts
+            .iter()
+            .map(|count| ZeroOneBoundedFloat::from_ratio(*count, total).unwrap_or_default())
+            .collect()
+    }
+}
+
+impl Extend<f64> for Histogram {
+    #[inline]
+    fn extend<T: IntoIterator<Item = f64>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// Render an ASCII bar chart, one line per bin with the bin, its count and a bar
+/// proportional to the count. The underflow and the overflow are given on a last line if
+/// they are not zero.
+impl Display for Histogram {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let last = self.counts.len() - 1;
+        let labels = self
+            .edges
+            .windows(2)
+            .enumerate()
+            .map(|(index, window)| {
+                let close = if index == last { ']' } else { ')' };
+                alloc::format!("[{}, {}{close}", window[0], window[1])
+            })
+            .collect::<Vec<_>>();
+        let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+        let count_width = self
+            .counts
+            .iter()
+            .map(|count| count.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let max = self.counts.iter().copied().max().unwrap_or(0);
+
+        for (label, count) in labels.iter().zip(&self.counts) {
+            let bar = (count * Self::BAR_WIDTH).checked_div(max).unwrap_or(0);
+            writeln!(
+                f,
+                "{label:label_width$} {count:>count_width$} {}",
+                "#".repeat(usize::try_from(bar).unwrap_or(0))
+            )?;
+        }
+
+        match (self.underflow, self.overflow) {
+            (0, 0) => Ok(()),
+            (underflow, 0) => writeln!(f, "underflow {underflow}"),
+            (0, overflow) => writeln!(f, "overflow {overflow}"),
+            (underflow, overflow) => writeln!(f, "underflow {underflow}, overflow {overflow}"),
+        }
+    }
+}
+
+/// Unvalidated fields of a [`Histogram`], used to validate the deserialized value.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct HistogramFields {
+    /// the edges of the bins
+    edges: Vec<f64>,
+    /// the number of values in each bin
+    counts: Vec<u64>,
+    /// the number of values below the first edge
+    underflow: u64,
+    /// the number of values above the last edge
+    overflow: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<HistogramFields> for Histogram {
+    type Error = HistogramError;
+
+    #[inline]
+    fn try_from(fields: HistogramFields) -> Result<Self, Self::Error> {
+        let mut histogram = Self::from_edges(fields.edges)?;
+        if histogram.counts.len() != fields.counts.len() {
+            return Err(HistogramError::EdgesMismatch);
+        }
+        histogram.counts = fields.counts;
+        histogram.underflow = fields.underflow;
+        histogram.overflow = fields.overflow;
+        Ok(histogram)
+    }
+}
+
+/// Error returned when creating or merging a [`Histogram`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum HistogramError {
+    /// There is no bin, i.e. zero bins or less than two edges
+    NoBin,
+    /// An edge is not finite or the edges are not strictly increasing
+    InvalidEdges,
+    /// The edges of the histograms are not the same, or the number of counts does not match
+    /// the edges
+    EdgesMismatch,
+}
+
+impl Display for HistogramError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoBin => write!(f, "the histogram has no bin"),
+            Self::InvalidEdges => write!(
+                f,
+                "the edges of the histogram are not finite and strictly increasing"
+            ),
+            Self::EdgesMismatch => write!(f, "the edges of the histograms are not the same"),
+        }
+    }
+}
+
+impl Error for HistogramError {}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "serde")]
+    use std::error::Error;
+
+    use super::{Histogram, HistogramError};
+
+    #[test]
+    fn new() -> Result<(), HistogramError> {
+        let histogram = Histogram::new(0_f64, 1_f64, 4)?;
+        assert_eq!(
+            histogram.edges(),
+            &[0_f64, 0.25_f64, 0.5_f64, 0.75_f64, 1_f64]
+        );
+        assert_eq!(histogram.bins(), 4);
+        assert_eq!(histogram.counts(), &[0_u64; 4]);
+
+        let histogram = Histogram::new(-1_f64, 2_f64, 3)?;
+        assert_eq!(histogram.edges(), &[-1_f64, 0_f64, 1_f64, 2_f64]);
+
+        assert_eq!(Histogram::new(0_f64, 1_f64, 0), Err(HistogramError::NoBin));
+        assert_eq!(
+            Histogram::new(1_f64, 1_f64, 2),
+            Err(HistogramError::InvalidEdges)
+        );
+        assert_eq!(
+            Histogram::new(1_f64, 0_f64, 2),
+            Err(HistogramError::InvalidEdges)
+        );
+        assert_eq!(
+            Histogram::new(0_f64, f64::INFINITY, 2),
+            Err(HistogramError::InvalidEdges)
+        );
+        assert_eq!(
+            Histogram::new(f64::NAN, 1_f64, 2),
+            Err(HistogramError::InvalidEdges)
+        );
+        assert_eq!(
+            Histogram::from_edges(vec![1_f64]),
+            Err(HistogramError::NoBin)
+        );
+        assert_eq!(
+            Histogram::from_edges(vec![0_f64, 2_f64, 1_f64]),
+            Err(HistogramError::InvalidEdges)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn edges() -> Result<(), HistogramError> {
+        let mut histogram = Histogram::new(0_f64, 1_f64, 4)?;
+        // the values on an edge are in the bin above, the last edge is in the last bin
+        for (value, bin) in [
+            (0_f64, Some(0)),
+            (0.1_f64, Some(0)),
+            (0.25_f64, Some(1)),
+            (0.5_f64, Some(2)),
+            (0.75_f64, Some(3)),
+            (0.9_f64, Some(3)),
+            (1_f64, Some(3)),
+            (-0.1_f64, None),
+            (1.1_f64, None),
+            (f64::NEG_INFINITY, None),
+            (f64::INFINITY, None),
+            (f64::NAN, None),
+        ] {
+            assert_eq!(histogram.bin_of(value), bin, "{value}");
+            assert_eq!(histogram.push(value), bin, "{value}");
+        }
+        assert_eq!(histogram.counts(), &[2_u64, 1_u64, 1_u64, 3_u64]);
+        assert_eq!(histogram.total(), 7_u64);
+        assert_eq!(histogram.underflow(), 2_u64);
+        assert_eq!(histogram.overflow(), 2_u64);
+
+        let histogram = Histogram::from_edges(vec![-5_f64, 0_f64, 0.5_f64])?;
+        assert_eq!(histogram.bin_of(-5_f64), Some(0));
+        assert_eq!(histogram.bin_of(-0_f64), Some(1));
+        assert_eq!(histogram.bin_of(0.5_f64), Some(1));
+        assert_eq!(histogram.bin_of(-5.1_f64), None);
+        Ok(())
+    }
+
+    #[test]
+    fn normalized() -> Result<(), HistogramError> {
+        let mut histogram = Histogram::new(0_f64, 10_f64, 7)?;
+        assert!(histogram
+            .normalized()
+            .iter()
+            .all(|fraction| fraction.float() == 0_f64));
+
+        histogram.extend((0_u32..1000_u32).map(|i| f64::from(i * 7 % 1000) / 97_f64));
+        let normalized = histogram.normalized();
+        assert_eq!(normalized.len(), 7);
+        let sum = normalized
+            .iter()
+            .map(|fraction| fraction.float())
+            .sum::<f64>();
+        assert!((sum - 1_f64).abs() < 1E-12_f64, "{sum}");
+        assert!(histogram.overflow() > 0_u64);
+        Ok(())
+    }
+
+    #[test]
+    fn merge() -> Result<(), HistogramError> {
+        let values = (0_u32..200_u32)
+            .map(|i| f64::from(i * 13 % 200) / 10_f64 - 5_f64)
+            .collect::<Vec<_>>();
+        let (first, second) = values.split_at(77);
+
+        let mut sequential = Histogram::new(-2_f64, 8_f64, 5)?;
+        sequential.extend(values.iter().copied());
+
+        let mut merged = Histogram::new(-2_f64, 8_f64, 5)?;
+        merged.extend(first.iter().copied());
+        let mut other = Histogram::new(-2_f64, 8_f64, 5)?;
+        other.extend(second.iter().copied());
+        merged.merge(&other)?;
+
+        assert_eq!(merged, sequential);
+        assert!(merged.underflow() > 0_u64);
+        assert!(merged.overflow() > 0_u64);
+
+        let different = Histogram::new(-2_f64, 8_f64, 4)?;
+        assert_eq!(merged.merge(&different), Err(HistogramError::EdgesMismatch));
+        assert_eq!(merged, sequential);
+        Ok(())
+    }
+
+    #[test]
+    fn display() -> Result<(), HistogramError> {
+        let mut histogram = Histogram::from_edges(vec![0_f64, 0.5_f64, 10_f64, 100_f64])?;
+        histogram.extend([
+            0_f64, 0.2_f64, 1_f64, 50_f64, 100_f64, 100_f64, 100_f64, -1_f64,
+        ]);
+        assert_eq!(
+            histogram.to_string(),
+            "[0, 0.5)  2 ####################\n\
+             [0.5, 10) 1 ##########\n\
+             [10, 100] 4 ########################################\n\
+             underflow 1\n"
+        );
+
+        let histogram = Histogram::new(0_f64, 1_f64, 2)?;
+        assert_eq!(histogram.to_string(), "[0, 0.5) 0 \n[0.5, 1] 0 \n");
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), Box<dyn Error>> {
+        let mut histogram = Histogram::new(0_f64, 2_f64, 2)?;
+        histogram.extend([0.5_f64, 1.5_f64, 1.7_f64, 3_f64]);
+        let json = serde_json::to_string(&histogram)?;
+        assert_eq!(
+            json,
+            r#"{"edges":[0.0,1.0,2.0],"counts":[1,2],"underflow":0,"overflow":1}"#
+        );
+        assert_eq!(serde_json::from_str::<Histogram>(&json)?, histogram);
+
+        serde_json::from_str::<Histogram>(
+            r#"{"edges":[0.0,1.0],"counts":[1,2],"underflow":0,"overflow":1}"#,
+        )
+        .expect_err("the counts do not match the edges");
+        serde_json::from_str::<Histogram>(
+            r#"{"edges":[1.0,0.0],"counts":[1],"underflow":0,"overflow":1}"#,
+        )
+        .expect_err("the edges are not increasing");
+        Ok(())
+    }
+}
@@ -0,0 +1,425 @@
+//! Contains [`Interval`], a closed interval of [`PositiveFloat`], and its error
+//! [`IntervalError`].
+
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{mul_add, PositiveFloat, ZeroOneBoundedFloat};
+
+/// A closed interval `[low, high]` of [`PositiveFloat`], with `low <= high`.
+///
+/// A degenerate interval with `low == high` is valid and contains one value.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::Interval, PositiveFloat, ZeroOneBoundedFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let interval = Interval::new(PositiveFloat::new(1_f64)?, PositiveFloat::new(3_f64)?)?;
+/// assert!(interval.contains(2_f64));
+/// assert!(!interval.contains(PositiveFloat::new(4_f64)?));
+/// assert_eq!(interval.length(), PositiveFloat::new(2_f64)?);
+/// assert_eq!(interval.midpoint(), PositiveFloat::new(2_f64)?);
+/// assert_eq!(
+///     interval.sample(ZeroOneBoundedFloat::new(0.25_f64)?),
+///     PositiveFloat::new(1.5_f64)?
+/// );
+/// assert_eq!(interval.to_string(), "[1, 3]");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "IntervalFields"))]
+pub struct Interval {
+    /// the lower bound, included
+    low: PositiveFloat,
+    /// the upper bound, included
+    high: PositiveFloat,
+}
+
+impl Interval {
+    /// Create the interval `[low, high]`.
+    ///
+    /// # Errors
+    /// Returns an [`IntervalError`] if `low > high`, see [`Self::new_sorted`] to order the
+    /// bounds instead.
+    #[inline]
+    pub fn new(low: PositiveFloat, high: PositiveFloat) -> Result<Self, IntervalError> {
+        if low <= high {
+            Ok(Self { low, high })
+        } else {
+            Err(IntervalError::new(low, high))
+        }
+    }
+
+    /// Create the interval between the two values whatever their order. It is the conversion
+    /// used by [`From<(PositiveFloat, PositiveFloat)>`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Interval, PositiveFloat};
+    ///
+    /// assert_eq!(
+    ///     Interval::new_sorted(PositiveFloat::ONE, PositiveFloat::ZERO).low(),
+    ///     PositiveFloat::ZERO
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_sorted(a: PositiveFloat, b: PositiveFloat) -> Self {
+        Self {
+            low: a.min(b),
+            high: a.max(b),
+        }
+    }
+
+    /// The lower bound.
+    #[inline]
+    #[must_use]
+    pub const fn low(&self) -> PositiveFloat {
+        self.low
+    }
+
+    /// The upper bound.
+    #[inline]
+    #[must_use]
+    pub const fn high(&self) -> PositiveFloat {
+        self.high
+    }
+
+    /// Returns `true` if the value is in the interval, bounds included. It accepts a [`f64`]
+    /// or a [`PositiveFloat`], [`f64::NAN`] is never contained.
+    #[inline]
+    #[must_use]
+    pub fn contains<T: Into<f64>>(&self, value: T) -> bool {
+        let value = value.into();
+        self.low.float() <= value && value <= self.high.float()
+    }
+
+    /// Get the closest value in the interval.
+    #[inline]
+    #[must_use]
+    pub fn clamp(&self, value: PositiveFloat) -> PositiveFloat {
+        value.clamp(self.low, self.high)
+    }
+
+    /// The length `high - low` of the interval, zero for a degenerate interval.
+    #[inline]
+    #[must_use]
+    pub fn length(&self) -> PositiveFloat {
+        self.high.saturating_sub(self.low)
+    }
+
+    /// The middle of the interval.
+    #[inline]
+    #[must_use]
+    pub fn midpoint(&self) -> PositiveFloat {
+        self.sample(ZeroOneBoundedFloat::new_or_bounded(0.5_f64))
+    }
+
+    /// The linear interpolation between the bounds, `low` for `t = 0` and `high` for `t = 1`.
+    #[inline]
+    #[must_use]
+    pub fn sample(&self, t: ZeroOneBoundedFloat) -> PositiveFloat {
+        // the length is computed first so that it does not overflow
+        let value = mul_add(t.float(), self.length().float(), self.low.float());
+        // rounding can get the value slightly out of the interval
+        self.clamp(PositiveFloat::new_or_bounded(value))
+    }
+
+    /// The intersection of the two intervals, or [`None`] if they are disjoint.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Interval, PositiveFloat};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let a = Interval::new(PositiveFloat::new(1_f64)?, PositiveFloat::new(3_f64)?)?;
+    /// let b = Interval::new(PositiveFloat::new(2_f64)?, PositiveFloat::new(5_f64)?)?;
+    /// let c = Interval::new(PositiveFloat::new(4_f64)?, PositiveFloat::new(5_f64)?)?;
+    /// assert_eq!(
+    ///     a.intersect(&b),
+    ///     Some(Interval::new(
+    ///         PositiveFloat::new(2_f64)?,
+    ///         PositiveFloat::new(3_f64)?
+    ///     )?)
+    /// );
+    /// assert_eq!(a.intersect(&c), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        Self::new(self.low.max(other.low), self.high.min(other.high)).ok()
+    }
+
+    /// The smallest interval containing both intervals. It also contains the gap between
+    /// them if they are disjoint.
+    #[inline]
+    #[must_use]
+    pub fn union_hull(&self, other: &Self) -> Self {
+        Self {
+            low: self.low.min(other.low),
+            high: self.high.max(other.high),
+        }
+    }
+}
+
+impl Display for Interval {
+    /// Display the interval as `[low, high]`, the formatting options are applied on
+    /// both bounds.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        Display::fmt(&self.low, f)?;
+        write!(f, ", ")?;
+        Display::fmt(&self.high, f)?;
+        write!(f, "]")
+    }
+}
+
+impl From<(PositiveFloat, PositiveFloat)> for Interval {
+    /// Create the interval with [`Interval::new_sorted`], the bounds are ordered.
+    #[inline]
+    fn from((a, b): (PositiveFloat, PositiveFloat)) -> Self {
+        Self::new_sorted(a, b)
+    }
+}
+
+impl From<Interval> for (PositiveFloat, PositiveFloat) {
+    #[inline]
+    fn from(interval: Interval) -> Self {
+        (interval.low, interval.high)
+    }
+}
+
+/// Unvalidated fields of an [`Interval`], used to validate the deserialized value.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct IntervalFields {
+    /// the lower bound
+    low: PositiveFloat,
+    /// the upper bound
+    high: PositiveFloat,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<IntervalFields> for Interval {
+    type Error = IntervalError;
+
+    #[inline]
+    fn try_from(fields: IntervalFields) -> Result<Self, Self::Error> {
+        Self::new(fields.low, fields.high)
+    }
+}
+
+/// Error when creating an [`Interval`] whose lower bound is above its upper bound.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntervalError {
+    /// the given lower bound
+    low: PositiveFloat,
+    /// the given upper bound
+    high: PositiveFloat,
+}
+
+impl IntervalError {
+    /// Create the error from the given bounds.
+    #[inline]
+    #[must_use]
+    pub const fn new(low: PositiveFloat, high: PositiveFloat) -> Self {
+        Self { low, high }
+    }
+
+    /// The given lower bound.
+    #[inline]
+    #[must_use]
+    pub const fn low(&self) -> PositiveFloat {
+        self.low
+    }
+
+    /// The given upper bound.
+    #[inline]
+    #[must_use]
+    pub const fn high(&self) -> PositiveFloat {
+        self.high
+    }
+}
+
+impl Display for IntervalError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the lower bound {} is above the upper bound {}",
+            self.low, self.high
+        )
+    }
+}
+
+impl Error for IntervalError {}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use super::{Interval, IntervalError};
+    use crate::{PositiveFloat, ZeroOneBoundedFloat};
+
+    #[test]
+    fn interval() -> Result<(), Box<dyn Error>> {
+        let two = PositiveFloat::new(2_f64)?;
+        let six = PositiveFloat::new(6_f64)?;
+        let interval = Interval::new(two, six)?;
+        assert_eq!(interval.low(), two);
+        assert_eq!(interval.high(), six);
+        assert!(interval.contains(2_f64));
+        assert!(interval.contains(six));
+        assert!(!interval.contains(1.9_f64));
+        assert!(!interval.contains(f64::NAN));
+        assert_eq!(interval.clamp(PositiveFloat::new(1_f64)?), two);
+        assert_eq!(
+            interval.clamp(PositiveFloat::new(3_f64)?),
+            PositiveFloat::new(3_f64)?
+        );
+        assert_eq!(interval.clamp(PositiveFloat::MAX), six);
+        assert_eq!(interval.length(), PositiveFloat::new(4_f64)?);
+        assert_eq!(interval.midpoint(), PositiveFloat::new(4_f64)?);
+        assert_eq!(interval.sample(ZeroOneBoundedFloat::ZERO), two);
+        assert_eq!(interval.sample(ZeroOneBoundedFloat::ONE), six);
+        assert_eq!(format!("{interval:.1}"), "[2.0, 6.0]");
+
+        assert_eq!(Interval::new(six, two), Err(IntervalError::new(six, two)));
+        assert_eq!(
+            IntervalError::new(six, two).to_string(),
+            "the lower bound 6 is above the upper bound 2"
+        );
+        assert_eq!(Interval::from((six, two)), interval);
+        assert_eq!(<(_, _)>::from(interval), (two, six));
+
+        let large = Interval::new(PositiveFloat::ZERO, PositiveFloat::MAX)?;
+        assert_eq!(large.length(), PositiveFloat::MAX);
+        assert!(large.contains(large.midpoint()));
+        Ok(())
+    }
+
+    #[test]
+    fn degenerate() -> Result<(), Box<dyn Error>> {
+        let point = Interval::new(PositiveFloat::new(3_f64)?, PositiveFloat::new(3_f64)?)?;
+        assert_eq!(point.length(), PositiveFloat::ZERO);
+        assert_eq!(point.midpoint(), PositiveFloat::new(3_f64)?);
+        assert_eq!(
+            point.sample(ZeroOneBoundedFloat::ONE),
+            PositiveFloat::new(3_f64)?
+        );
+        assert!(point.contains(3_f64));
+        assert!(!point.contains(3.1_f64));
+        assert_eq!(point.clamp(PositiveFloat::ZERO), PositiveFloat::new(3_f64)?);
+        assert_eq!(point.to_string(), "[3, 3]");
+
+        let max = Interval::new(PositiveFloat::MAX, PositiveFloat::MAX)?;
+        assert_eq!(max.midpoint(), PositiveFloat::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn rescale() -> Result<(), Box<dyn Error>> {
+        let from = Interval::new(PositiveFloat::new(0_f64)?, PositiveFloat::new(10_f64)?)?;
+        let to = Interval::new(PositiveFloat::new(0_f64)?, PositiveFloat::new(1_f64)?)?;
+        for (value, expected) in [
+            (0_f64, 0_f64),
+            (2.5_f64, 0.25_f64),
+            (5_f64, 0.5_f64),
+            (10_f64, 1_f64),
+            // clamped
+            (20_f64, 1_f64),
+        ] {
+            assert_eq!(
+                PositiveFloat::new(value)?.rescale(from, to),
+                PositiveFloat::new(expected)?,
+                "{value}"
+            );
+        }
+
+        // inverse round trip
+        let to = Interval::new(PositiveFloat::new(3_f64)?, PositiveFloat::new(7_f64)?)?;
+        for value in [0_f64, 1_f64, 3.3_f64, 9.75_f64, 10_f64] {
+            let back = PositiveFloat::new(value)?
+                .rescale(from, to)
+                .rescale(to, from);
+            assert!((back.float() - value).abs() < 1E-12_f64, "{value} {back}");
+        }
+        assert_eq!(
+            PositiveFloat::new(1_f64)?.rescale(to, from),
+            PositiveFloat::new(0_f64)?
+        );
+
+        // degenerate source
+        let degenerate = Interval::new(PositiveFloat::new(2_f64)?, PositiveFloat::new(2_f64)?)?;
+        assert_eq!(
+            PositiveFloat::new(2_f64)?.rescale(degenerate, to),
+            PositiveFloat::new(3_f64)?
+        );
+        assert_eq!(
+            PositiveFloat::new(5_f64)?.rescale(degenerate, to),
+            PositiveFloat::new(3_f64)?
+        );
+        assert_eq!(
+            PositiveFloat::new(5_f64)?.rescale(to, degenerate),
+            PositiveFloat::new(2_f64)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_union() -> Result<(), Box<dyn Error>> {
+        let a = Interval::new(PositiveFloat::new(1_f64)?, PositiveFloat::new(3_f64)?)?;
+        let b = Interval::new(PositiveFloat::new(2_f64)?, PositiveFloat::new(5_f64)?)?;
+        let c = Interval::new(PositiveFloat::new(4_f64)?, PositiveFloat::new(5_f64)?)?;
+        let d = Interval::new(PositiveFloat::new(3_f64)?, PositiveFloat::new(4_f64)?)?;
+
+        assert_eq!(
+            a.intersect(&b),
+            Interval::new(PositiveFloat::new(2_f64)?, PositiveFloat::new(3_f64)?).ok()
+        );
+        assert_eq!(a.intersect(&b), b.intersect(&a));
+        // disjoint
+        assert_eq!(a.intersect(&c), None);
+        assert_eq!(c.intersect(&a), None);
+        // touching in one point
+        assert_eq!(
+            a.intersect(&d),
+            Interval::new(PositiveFloat::new(3_f64)?, PositiveFloat::new(3_f64)?).ok()
+        );
+        assert_eq!(a.intersect(&a), Some(a));
+
+        assert_eq!(
+            a.union_hull(&c),
+            Interval::new(PositiveFloat::new(1_f64)?, PositiveFloat::new(5_f64)?)?
+        );
+        assert_eq!(a.union_hull(&b), b.union_hull(&a));
+        assert_eq!(a.union_hull(&a), a);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), Box<dyn Error>> {
+        let interval = Interval::new(PositiveFloat::new(1_f64)?, PositiveFloat::new(2.5_f64)?)?;
+        let json = serde_json::to_string(&interval)?;
+        assert_eq!(json, r#"{"low":1.0,"high":2.5}"#);
+        assert_eq!(serde_json::from_str::<Interval>(&json)?, interval);
+        let err = serde_json::from_str::<Interval>(r#"{"low":3.0,"high":2.5}"#)
+            .expect_err("the bounds are not ordered");
+        assert!(err.to_string().contains("above the upper bound"));
+        Ok(())
+    }
+}
@@ -1,14 +1,31 @@
 //! Contains number and math utilities.
 
+mod default;
+mod display;
 mod function;
+#[cfg(feature = "alloc")]
+pub mod heap;
+#[cfg(feature = "alloc")]
+pub mod histogram;
+pub mod interval;
+pub mod moving_average;
+#[cfg(feature = "alloc")]
+pub mod normalize;
 mod num_op_traits;
+pub mod parse;
 pub mod positive_float;
+mod quantize;
+#[cfg(feature = "rand")]
+mod rand;
+pub mod rate;
+pub mod ratio;
 pub mod sign;
+pub mod statistics;
 pub mod zero_one_bounded_float;
 
-use std::{
+use core::{
     cmp::Ordering,
-    fmt::{self, Display, LowerExp, UpperExp},
+    fmt::{self, Debug, Display, LowerExp, UpperExp},
     num::FpCategory,
     ops::{Deref, DerefMut},
 };
@@ -18,9 +35,34 @@ use serde::Serialize;
 
 // TODO conversion
 // TODO num traits
-pub use self::function::{abs_diff, gcd, lcm};
+#[cfg(feature = "serde")]
+pub use self::default::{serde_default_one, serde_default_zero};
+pub use self::default::{OneDefault, OptionDefaultExt};
+use self::display::FloatDebug;
+pub use self::function::{abs_diff, gcd, lcm, rescale};
+#[cfg(feature = "alloc")]
+pub use self::heap::{MaxFloatHeap, MinFloatHeap};
+pub use self::interval::{Interval, IntervalError};
+pub use self::moving_average::Ewma;
+#[cfg(feature = "alloc")]
+pub use self::moving_average::MovingAverage;
+#[cfg(feature = "std")]
+pub use self::normalize::softmax;
+#[cfg(feature = "alloc")]
+pub use self::normalize::{cumulative, normalize, NormalizeError};
+pub use self::parse::ParseFlexibleError;
 pub use self::positive_float::{ConversionError as PositiveFloatConversionError, PositiveFloat};
+pub use self::quantize::{ceil_to_step, floor_to_step, round_to_step, snap};
+#[cfg(feature = "rand")]
+pub use self::rand::pick_index;
+pub use self::rate::Rate;
+pub use self::ratio::{Ratio, ZeroDenominatorError};
+#[cfg(feature = "alloc")]
+pub use self::sign::sort_by_f64_key;
 pub use self::sign::Sign;
+pub use self::statistics::harmonic_mean;
+#[cfg(feature = "std")]
+pub use self::statistics::{geometric_mean, log_sum_exp};
 pub use self::zero_one_bounded_float::{
     ConversionError as ZeroOneBoundedFloatConversionError, ZeroOneBoundedFloat,
 };
@@ -33,6 +75,40 @@ pub trait Validation {
 
     /// to set a float if it is valid, or the default value if it is not
     fn set_float(&mut self, float: f64);
+
+    /// Set a float if it is valid, or the closest valid value if it is not.
+    /// Used by [`InvalidPolicy::Clamp`].
+    ///
+    /// The default implementation uses [`Self::set_float`].
+    #[inline]
+    fn set_float_clamped(&mut self, float: f64) {
+        self.set_float(float);
+    }
+
+    /// Set a float if it is valid, or the default value of the type if it is not.
+    /// Used by [`InvalidPolicy::Default`].
+    ///
+    /// The default implementation uses [`Self::set_float`].
+    #[inline]
+    fn set_float_default(&mut self, float: f64) {
+        self.set_float(float);
+    }
+}
+
+/// What a [`ValidationGuard`] does on [`Drop`] if the value is not valid,
+/// see [`ValidationGuard::on_invalid`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
+pub enum InvalidPolicy {
+    /// Set the default value of the type, see [`Validation::set_float_default`].
+    Default,
+    /// Set the closest valid value, see [`Validation::set_float_clamped`].
+    Clamp,
+    /// Keep the value from before the guard was created.
+    Keep,
+    /// Panic with the invalid value in the message.
+    Panic,
 }
 
 //-----------------------------------
@@ -42,14 +118,17 @@ pub trait Validation {
 /// It ensure data validation on [`Drop`]. If the data is not valid it is set to 0.
 ///
 /// We voluntarily do not have a new function. The guard is build by the wrapper.
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ValidationGuard<'a, T: Validation + ?Sized> {
     /// the mut ref in order to "lock" the PositiveFloat and mutated on [`Drop`].
-    #[serde(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     reference: &'a mut T,
     /// The new value
     float: f64,
+    /// The action on drop if the value is not valid, [`Validation::set_float`] if [`None`]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    policy: Option<InvalidPolicy>,
 }
 
 impl<'a, T> ValidationGuard<'a, T>
@@ -63,11 +142,41 @@ where
         Self {
             float: *reference.as_ref(),
             reference,
+            policy: None,
         }
     }
 }
 
 impl<'a, T: Validation + ?Sized> ValidationGuard<'a, T> {
+    /// Override what the guard does on [`Drop`] if the value is not valid. By default
+    /// it uses [`Validation::set_float`], which is specific to each type.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::InvalidPolicy;
+    /// use utils_lib::ZeroOneBoundedFloat;
+    /// # use utils_lib::number::ZeroOneBoundedFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), ZeroOneBoundedFloatConversionError> {
+    /// let mut p = ZeroOneBoundedFloat::new(0.5_f64)?;
+    /// *p.float_mut().on_invalid(InvalidPolicy::Keep) += 1_f64;
+    /// assert_eq!(p, ZeroOneBoundedFloat::new(0.5_f64)?);
+    ///
+    /// *p.float_mut().on_invalid(InvalidPolicy::Default) += 1_f64;
+    /// assert_eq!(p, ZeroOneBoundedFloat::ZERO);
+    ///
+    /// *p.float_mut().on_invalid(InvalidPolicy::Clamp) += 2_f64;
+    /// assert_eq!(p, ZeroOneBoundedFloat::ONE);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn on_invalid(mut self, policy: InvalidPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
     /// a mut getter on the float
     #[inline]
     #[must_use]
@@ -111,7 +220,32 @@ impl<'a, T: Validation + ?Sized> DerefMut for ValidationGuard<'a, T> {
 impl<'a, T: Validation + ?Sized> Drop for ValidationGuard<'a, T> {
     #[inline]
     fn drop(&mut self) {
-        self.reference.set_float(self.float);
+        if T::validate_data(self.float) {
+            self.reference.set_float(self.float);
+            return;
+        }
+        match self.policy {
+            None => self.reference.set_float(self.float),
+            Some(InvalidPolicy::Default) => self.reference.set_float_default(self.float),
+            Some(InvalidPolicy::Clamp) => self.reference.set_float_clamped(self.float),
+            Some(InvalidPolicy::Keep) => {}
+            Some(InvalidPolicy::Panic) => {
+                panic!("the value {} is not valid", self.float)
+            }
+        }
+    }
+}
+
+/// Debug the guard, the new value is marked with `[INVALID]` if it is not valid, i.e. if the
+/// guard does not set it as is on [`Drop`].
+impl<T: Validation + Debug + ?Sized> Debug for ValidationGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidationGuard")
+            .field("reference", &self.reference)
+            .field("float", &FloatDebug::<T>::new(self.float))
+            .field("policy", &self.policy)
+            .finish()
     }
 }
 
@@ -186,6 +320,80 @@ impl<'a, 'b: 'a, T: Validation + ?Sized> From<&'a mut ValidationGuard<'b, T>> fo
     }
 }
 
+/// Raise a float to an unsigned integer power. It uses [`f64::powi`] and falls back to
+/// [`f64::powf`] if the exponent does not fit in an [`i32`].
+///
+/// It is used internally for the integer [`num_traits::Pow`] implementations of
+/// [`ZeroOneBoundedFloat`] and [`PositiveFloat`].
+#[cfg(feature = "std")]
+fn pow_unsigned<T: Into<u32>>(float: f64, exponent: T) -> f64 {
+    let exponent = exponent.into();
+    i32::try_from(exponent).map_or_else(
+        |_| float.powf(f64::from(exponent)),
+        |exponent| float.powi(exponent),
+    )
+}
+
+/// Compute `a * b + c`. With the `std` feature it uses [`f64::mul_add`] with only one
+/// rounding error, otherwise the operations are done separately as [`f64::mul_add`] is not
+/// available in `core`.
+#[cfg(feature = "std")]
+#[inline]
+const fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    a.mul_add(b, c)
+}
+
+/// Compute `a * b + c`. With the `std` feature it uses [`f64::mul_add`] with only one
+/// rounding error, otherwise the operations are done separately as [`f64::mul_add`] is not
+/// available in `core`.
+#[cfg(not(feature = "std"))]
+#[allow(clippy::suboptimal_flops)] // reason = "f64::mul_add is not available without std"
+#[inline]
+const fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    a * b + c
+}
+
+/// Raise a float to an unsigned integer power by repeated squaring, using only
+/// multiplications so that it is available in `core` and in const context. `float^0` is `1`.
+///
+/// For a float in `[0, 1]` every intermediate value stays in `[0, 1]`. For a positive finite
+/// float the result is positive and either finite or [`f64::INFINITY`], never [`f64::NAN`]:
+/// the base only overflows when it is greater than one, in which case the accumulator is at
+/// least one.
+const fn pow_by_squaring(float: f64, exponent: u32) -> f64 {
+    let mut result = 1_f64;
+    let mut base = float;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result *= base;
+        }
+        exponent >>= 1_u32;
+        if exponent > 0 {
+            base *= base;
+        }
+    }
+    result
+}
+
+/// Get the number of representable [`f64`] between `a` and `b`, the distance in units in the
+/// last place. `0` and `-0` are the same float, and [`f64::INFINITY`] is one ULP after
+/// [`f64::MAX`]. The result is meaningless if one of the value is [`f64::NAN`].
+pub(crate) const fn ulp_diff(a: f64, b: f64) -> u64 {
+    /// Map the floats to integers in the same order, with `0` and `-0` mapped to the same key.
+    const fn key(float: f64) -> u64 {
+        const SIGN: u64 = 1_u64 << 63_u32;
+        let bits = float.to_bits();
+        let magnitude = bits & !SIGN;
+        if bits & SIGN == 0 {
+            SIGN + magnitude
+        } else {
+            SIGN - magnitude
+        }
+    }
+    key(a).abs_diff(key(b))
+}
+
 /// Do an ordering operation on two [`f64`].
 /// It is used internally for [`Ord`] and [`PartialOrd`] implementation of
 /// [`ZeroOneBoundedFloat`] and [`PositiveFloat`]
@@ -232,11 +440,57 @@ fn compare_f64(first: f64, other: f64) -> Ordering {
 
 #[cfg(test)]
 mod test {
-    use std::cmp::Ordering;
+    use std::{cmp::Ordering, error::Error};
 
-    use super::{compare_f64, PositiveFloatConversionError};
+    use super::{
+        compare_f64, ulp_diff, InvalidPolicy, PositiveFloatConversionError,
+        ZeroOneBoundedFloatConversionError,
+    };
     use crate::{PositiveFloat, ZeroOneBoundedFloat};
 
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "the values are exact"
+    fn rescale() {
+        for (value, expected) in [
+            (0_f64, 0_f64),
+            (1_f64, 0.1_f64),
+            (5_f64, 0.5_f64),
+            (10_f64, 1_f64),
+            (-10_f64, -1_f64),
+            (15_f64, 1.5_f64),
+        ] {
+            assert_eq!(
+                super::rescale(value, 0_f64, 10_f64, 0_f64, 1_f64),
+                expected,
+                "{value}"
+            );
+        }
+
+        // inverse round trip
+        for value in [-4_f64, 0_f64, 0.3_f64, 7.7_f64, 123.456_f64] {
+            let scaled = super::rescale(value, -5_f64, 3_f64, 100_f64, -20_f64);
+            let back = super::rescale(scaled, 100_f64, -20_f64, -5_f64, 3_f64);
+            assert!((back - value).abs() < 1E-12_f64, "{value} {back}");
+        }
+
+        // degenerate source
+        assert_eq!(super::rescale(1_f64, 1_f64, 1_f64, 2_f64, 3_f64), 2_f64);
+        assert_eq!(super::rescale(7_f64, 1_f64, 1_f64, 2_f64, 3_f64), 2_f64);
+        // degenerate destination
+        assert_eq!(super::rescale(7_f64, 0_f64, 10_f64, 2_f64, 2_f64), 2_f64);
+        assert!(super::rescale(f64::NAN, 0_f64, 1_f64, 0_f64, 1_f64).is_nan());
+
+        let t = ZeroOneBoundedFloat::new_or_bounded(0.75_f64);
+        assert_eq!(t.rescale_to(0_f64, 100_f64), 75_f64);
+        assert_eq!(t.rescale_to(-2_f64, 2_f64), 1_f64);
+        assert_eq!(ZeroOneBoundedFloat::ZERO.rescale_to(-2_f64, 2_f64), -2_f64);
+        assert_eq!(ZeroOneBoundedFloat::ONE.rescale_to(-2_f64, 2_f64), 2_f64);
+        assert_eq!(
+            super::rescale(t.rescale_to(4_f64, 8_f64), 4_f64, 8_f64, 0_f64, 1_f64),
+            t.float()
+        );
+    }
+
     #[test]
     fn cmp_f64() {
         // cmp number number
@@ -351,4 +605,220 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn validation_guard_debug() -> Result<(), PositiveFloatConversionError> {
+        let mut p = PositiveFloat::new(2.5_f64)?;
+        let mut guard = p.float_mut();
+        assert_eq!(
+            format!("{guard:?}"),
+            "ValidationGuard { reference: PositiveFloat(2.5), float: 2.5, policy: None }"
+        );
+        *guard = -1_f64;
+        assert_eq!(
+            format!("{guard:?}"),
+            "ValidationGuard { reference: PositiveFloat(2.5), float: -1.0 [INVALID], \
+            policy: None }"
+        );
+        drop(guard);
+        assert_eq!(p, PositiveFloat::ZERO);
+
+        let mut z = ZeroOneBoundedFloat::ONE;
+        let mut guard = z.float_mut().on_invalid(InvalidPolicy::Keep);
+        *guard += 1_f64;
+        assert_eq!(
+            format!("{guard:?}"),
+            "ValidationGuard { reference: ZeroOneBoundedFloat(1.0), float: 2.0 [INVALID], \
+            policy: Some(Keep) }"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_policy_positive_float() -> Result<(), PositiveFloatConversionError> {
+        let initial = PositiveFloat::new(2_f64)?;
+        for (policy, float, expected) in [
+            (None, -1_f64, PositiveFloat::ZERO),
+            (None, f64::INFINITY, PositiveFloat::new(f64::MAX)?),
+            (None, f64::NAN, PositiveFloat::ZERO),
+            (Some(InvalidPolicy::Default), -1_f64, PositiveFloat::ZERO),
+            (
+                Some(InvalidPolicy::Default),
+                f64::INFINITY,
+                PositiveFloat::ZERO,
+            ),
+            (Some(InvalidPolicy::Default), f64::NAN, PositiveFloat::ZERO),
+            (Some(InvalidPolicy::Clamp), -1_f64, PositiveFloat::ZERO),
+            (
+                Some(InvalidPolicy::Clamp),
+                f64::INFINITY,
+                PositiveFloat::new(f64::MAX)?,
+            ),
+            (Some(InvalidPolicy::Clamp), f64::NAN, PositiveFloat::ZERO),
+            (Some(InvalidPolicy::Keep), -1_f64, initial),
+            (Some(InvalidPolicy::Keep), f64::INFINITY, initial),
+            (Some(InvalidPolicy::Keep), f64::NAN, initial),
+            // valid values are always set
+            (
+                Some(InvalidPolicy::Default),
+                3_f64,
+                PositiveFloat::new(3_f64)?,
+            ),
+            (
+                Some(InvalidPolicy::Clamp),
+                3_f64,
+                PositiveFloat::new(3_f64)?,
+            ),
+            (Some(InvalidPolicy::Keep), 3_f64, PositiveFloat::new(3_f64)?),
+            (
+                Some(InvalidPolicy::Panic),
+                3_f64,
+                PositiveFloat::new(3_f64)?,
+            ),
+        ] {
+            let mut p = initial;
+            let mut guard = p.float_mut();
+            if let Some(policy) = policy {
+                guard = guard.on_invalid(policy);
+            }
+            *guard = float;
+            drop(guard);
+            assert_eq!(p, expected, "{policy:?} {float}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_policy_zero_one_bounded_float() -> Result<(), ZeroOneBoundedFloatConversionError> {
+        let initial = ZeroOneBoundedFloat::new(0.5_f64)?;
+        for (policy, float, expected) in [
+            (None, -1_f64, ZeroOneBoundedFloat::ZERO),
+            (None, 2_f64, ZeroOneBoundedFloat::ONE),
+            (None, f64::NAN, ZeroOneBoundedFloat::ZERO),
+            (
+                Some(InvalidPolicy::Default),
+                -1_f64,
+                ZeroOneBoundedFloat::ZERO,
+            ),
+            (
+                Some(InvalidPolicy::Default),
+                2_f64,
+                ZeroOneBoundedFloat::ZERO,
+            ),
+            (
+                Some(InvalidPolicy::Default),
+                f64::NAN,
+                ZeroOneBoundedFloat::ZERO,
+            ),
+            (
+                Some(InvalidPolicy::Clamp),
+                -1_f64,
+                ZeroOneBoundedFloat::ZERO,
+            ),
+            (Some(InvalidPolicy::Clamp), 2_f64, ZeroOneBoundedFloat::ONE),
+            (
+                Some(InvalidPolicy::Clamp),
+                f64::NAN,
+                ZeroOneBoundedFloat::ZERO,
+            ),
+            (Some(InvalidPolicy::Keep), -1_f64, initial),
+            (Some(InvalidPolicy::Keep), 2_f64, initial),
+            (Some(InvalidPolicy::Keep), f64::NAN, initial),
+            // valid values are always set
+            (
+                Some(InvalidPolicy::Default),
+                0.25_f64,
+                ZeroOneBoundedFloat::new(0.25_f64)?,
+            ),
+            (
+                Some(InvalidPolicy::Clamp),
+                0.25_f64,
+                ZeroOneBoundedFloat::new(0.25_f64)?,
+            ),
+            (
+                Some(InvalidPolicy::Keep),
+                0.25_f64,
+                ZeroOneBoundedFloat::new(0.25_f64)?,
+            ),
+            (
+                Some(InvalidPolicy::Panic),
+                0.25_f64,
+                ZeroOneBoundedFloat::new(0.25_f64)?,
+            ),
+        ] {
+            let mut p = initial;
+            let mut guard = p.float_mut();
+            if let Some(policy) = policy {
+                guard = guard.on_invalid(policy);
+            }
+            *guard = float;
+            drop(guard);
+            assert_eq!(p, expected, "{policy:?} {float}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "the value -1 is not valid")]
+    fn invalid_policy_panic_positive_float() {
+        let mut p = PositiveFloat::ONE;
+        *p.float_mut().on_invalid(InvalidPolicy::Panic) = -1_f64;
+    }
+
+    #[test]
+    #[should_panic(expected = "the value 1.5 is not valid")]
+    fn invalid_policy_panic_zero_one_bounded_float() {
+        let mut p = ZeroOneBoundedFloat::ONE;
+        *p.float_mut().on_invalid(InvalidPolicy::Panic) += 0.5_f64;
+    }
+
+    #[test]
+    fn ulp_distance() {
+        assert_eq!(ulp_diff(1_f64, 1_f64), 0);
+        assert_eq!(ulp_diff(0_f64, -0_f64), 0);
+        assert_eq!(ulp_diff(1_f64, 1_f64.next_up()), 1);
+        assert_eq!(ulp_diff(1_f64.next_down(), 1_f64.next_up()), 2);
+        assert_eq!(ulp_diff(-0_f64, f64::from_bits(1)), 1);
+        assert_eq!(ulp_diff(-f64::from_bits(1), f64::from_bits(1)), 2);
+        assert_eq!(ulp_diff(f64::MAX, f64::INFINITY), 1);
+        assert_eq!(ulp_diff(-1_f64, 1_f64), 2 * ulp_diff(0_f64, 1_f64));
+    }
+
+    #[test]
+    fn positive_float_approx_eq() -> Result<(), PositiveFloatConversionError> {
+        let zero = PositiveFloat::ZERO;
+        let min = PositiveFloat::new(f64::from_bits(1))?;
+        assert_eq!(zero.max_ulp_diff(PositiveFloat::new(-0_f64)?), 0);
+        assert_eq!(zero.max_ulp_diff(min), 1);
+        assert_eq!(min.max_ulp_diff(zero), 1);
+        assert!(zero.approx_eq(min, min));
+        assert!(!zero.approx_eq(min, PositiveFloat::ZERO));
+        let below_max = PositiveFloat::new(f64::MAX.next_down())?;
+        assert_eq!(PositiveFloat::MAX.max_ulp_diff(below_max), 1);
+        assert!(PositiveFloat::MAX.approx_eq(PositiveFloat::MAX, PositiveFloat::ZERO));
+        assert!(!PositiveFloat::MAX.approx_eq(below_max, PositiveFloat::ZERO));
+        assert!(PositiveFloat::MAX.approx_eq(zero, PositiveFloat::MAX));
+        Ok(())
+    }
+
+    #[test]
+    fn zero_one_bounded_float_approx_eq() -> Result<(), Box<dyn Error>> {
+        let zero = ZeroOneBoundedFloat::ZERO;
+        let one = ZeroOneBoundedFloat::ONE;
+        let below_one = ZeroOneBoundedFloat::new(1_f64.next_down())?;
+        assert_eq!(one.max_ulp_diff(below_one), 1);
+        assert_eq!(zero.max_ulp_diff(ZeroOneBoundedFloat::new(-0_f64)?), 0);
+        assert_eq!(
+            zero.max_ulp_diff(ZeroOneBoundedFloat::new(f64::from_bits(1))?),
+            1
+        );
+        assert!(one.approx_eq(one, PositiveFloat::ZERO));
+        assert!(!one.approx_eq(below_one, PositiveFloat::ZERO));
+        assert!(one.approx_eq(below_one, PositiveFloat::new(f64::EPSILON)?));
+        assert!(zero.approx_eq(one, PositiveFloat::new(1_f64)?));
+        assert!(!zero.approx_eq(one, PositiveFloat::new(1_f64.next_down())?));
+        Ok(())
+    }
 }
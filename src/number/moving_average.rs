@@ -0,0 +1,288 @@
+//! Contains [`MovingAverage`], a windowed mean over the last values of a series of
+//! [`PositiveFloat`], and [`Ewma`], its exponentially weighted counterpart.
+//! [`MovingAverage`] requires the `alloc` feature.
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{mul_add, PositiveFloat, ZeroOneBoundedFloat};
+
+/// Mean of the last `window` values pushed into it.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::MovingAverage, PositiveFloat};
+///
+/// # fn main() -> Result<(), utils_lib::number::PositiveFloatConversionError> {
+/// let mut average = MovingAverage::new(2);
+/// assert_eq!(average.current(), None);
+/// assert_eq!(
+///     average.push(PositiveFloat::new(1_f64)?),
+///     PositiveFloat::new(1_f64)?
+/// );
+/// assert_eq!(
+///     average.push(PositiveFloat::new(3_f64)?),
+///     PositiveFloat::new(2_f64)?
+/// );
+/// // the first value leaves the window
+/// assert_eq!(
+///     average.push(PositiveFloat::new(5_f64)?),
+///     PositiveFloat::new(4_f64)?
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MovingAverage {
+    /// maximum number of values in the mean
+    window: usize,
+    /// the last values, the oldest at the front
+    values: VecDeque<PositiveFloat>,
+}
+
+#[cfg(feature = "alloc")]
+impl MovingAverage {
+    /// Create an empty moving average over the last `window` values. A window of `0` is
+    /// treated as a window of `1`.
+    #[inline]
+    #[must_use]
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            window,
+            values: VecDeque::with_capacity(window),
+        }
+    }
+
+    /// The maximum number of values in the mean.
+    #[inline]
+    #[must_use]
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Number of values currently in the window, at most [`Self::window`].
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no value has been pushed since the creation or the last
+    /// [`Self::reset`].
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Add a value, dropping the oldest one if the window is full, and return the new mean.
+    #[inline]
+    pub fn push(&mut self, value: PositiveFloat) -> PositiveFloat {
+        if self.values.len() == self.window {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+        self.mean()
+    }
+
+    /// The mean of the values in the window or [`None`] if it is empty.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<PositiveFloat> {
+        (!self.values.is_empty()).then(|| self.mean())
+    }
+
+    /// Remove all the values, the window is kept.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.values.clear();
+    }
+
+    /// Mean of the values, the window must not be empty.
+    fn mean(&self) -> PositiveFloat {
+        #[allow(clippy::cast_precision_loss)] // reason = "the window is never close to 2^52"
+        let len = self.values.len() as f64;
+        let sum = self.values.iter().map(|value| value.float()).sum::<f64>();
+        // the sum can overflow to infinity
+        PositiveFloat::new_or_bounded(sum / len)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<PositiveFloat> for MovingAverage {
+    #[inline]
+    fn extend<I: IntoIterator<Item = PositiveFloat>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// Exponentially weighted moving average.
+///
+/// The first value initializes the average and then each new value `x` updates it to
+/// `alpha * x + (1 - alpha) * average`. The smoothing factor `alpha` is a
+/// [`ZeroOneBoundedFloat`] so it is always valid: `0` keeps the first value forever and `1`
+/// follows the last value.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::Ewma, PositiveFloat, ZeroOneBoundedFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut average = Ewma::new(ZeroOneBoundedFloat::new(0.5_f64)?);
+/// assert_eq!(
+///     average.push(PositiveFloat::new(4_f64)?),
+///     PositiveFloat::new(4_f64)?
+/// );
+/// assert_eq!(
+///     average.push(PositiveFloat::new(2_f64)?),
+///     PositiveFloat::new(3_f64)?
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ewma {
+    /// smoothing factor, the weight of a new value
+    alpha: ZeroOneBoundedFloat,
+    /// current average, [`None`] if no value was pushed
+    value: Option<PositiveFloat>,
+}
+
+impl Ewma {
+    /// Create an empty exponentially weighted moving average with the smoothing factor `alpha`.
+    #[inline]
+    #[must_use]
+    pub const fn new(alpha: ZeroOneBoundedFloat) -> Self {
+        Self { alpha, value: None }
+    }
+
+    /// The smoothing factor.
+    #[inline]
+    #[must_use]
+    pub const fn alpha(&self) -> ZeroOneBoundedFloat {
+        self.alpha
+    }
+
+    /// Add a value and return the new average.
+    #[inline]
+    pub fn push(&mut self, value: PositiveFloat) -> PositiveFloat {
+        let new_value = self.value.map_or(value, |previous| {
+            // previous + alpha * (value - previous) is between previous and value
+            PositiveFloat::new_or_bounded(mul_add(
+                self.alpha.float(),
+                value.float() - previous.float(),
+                previous.float(),
+            ))
+        });
+        self.value = Some(new_value);
+        new_value
+    }
+
+    /// The current average or [`None`] if no value has been pushed.
+    #[inline]
+    #[must_use]
+    pub const fn current(&self) -> Option<PositiveFloat> {
+        self.value
+    }
+
+    /// Forget the average, the smoothing factor is kept.
+    #[inline]
+    pub const fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+impl Extend<PositiveFloat> for Ewma {
+    #[inline]
+    fn extend<I: IntoIterator<Item = PositiveFloat>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use super::Ewma;
+    #[cfg(feature = "alloc")]
+    use super::MovingAverage;
+    #[cfg(feature = "alloc")]
+    use crate::number::PositiveFloatConversionError;
+    use crate::{PositiveFloat, ZeroOneBoundedFloat};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn moving_average() -> Result<(), PositiveFloatConversionError> {
+        const EPSILON: f64 = 1E-12_f64;
+
+        let constant = PositiveFloat::new(0.1_f64)?;
+        let mut average = MovingAverage::new(3);
+        assert!(average.is_empty());
+        for _ in 0_usize..10_usize {
+            let mean = average.push(constant);
+            assert!((mean.float() - constant.float()).abs() < EPSILON);
+        }
+        assert_eq!(average.len(), 3);
+
+        // step from 0 to 1
+        average.reset();
+        assert_eq!(average.current(), None);
+        assert_eq!(average.window(), 3);
+        average.extend([PositiveFloat::ZERO; 5]);
+        assert_eq!(average.current(), Some(PositiveFloat::ZERO));
+        let expected = [1_f64 / 3_f64, 2_f64 / 3_f64, 1_f64, 1_f64];
+        for expected in expected {
+            let mean = average.push(PositiveFloat::ONE);
+            assert!((mean.float() - expected).abs() < EPSILON);
+        }
+
+        let mut average = MovingAverage::new(0);
+        assert_eq!(average.window(), 1);
+        average.extend([PositiveFloat::ZERO, PositiveFloat::ONE]);
+        assert_eq!(average.current(), Some(PositiveFloat::ONE));
+        Ok(())
+    }
+
+    #[test]
+    fn ewma() -> Result<(), Box<dyn Error>> {
+        const EPSILON: f64 = 1E-12_f64;
+
+        let step = [PositiveFloat::ZERO, PositiveFloat::ONE, PositiveFloat::ONE];
+
+        let mut average = Ewma::new(ZeroOneBoundedFloat::ZERO);
+        average.extend(step);
+        assert_eq!(average.current(), Some(PositiveFloat::ZERO));
+
+        let mut average = Ewma::new(ZeroOneBoundedFloat::ONE);
+        assert_eq!(average.current(), None);
+        for value in step {
+            assert_eq!(average.push(value), value);
+        }
+        average.reset();
+        assert_eq!(average.current(), None);
+
+        let mut average = Ewma::new(ZeroOneBoundedFloat::new(0.5_f64)?);
+        let expected = [0_f64, 0.5_f64, 0.75_f64];
+        for (value, expected) in step.into_iter().zip(expected) {
+            assert!((average.push(value).float() - expected).abs() < EPSILON);
+        }
+
+        let constant = PositiveFloat::new(0.3_f64)?;
+        let mut average = Ewma::new(ZeroOneBoundedFloat::new(0.2_f64)?);
+        for _ in 0_usize..10_usize {
+            assert!((average.push(constant).float() - constant.float()).abs() < EPSILON);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+//! Contains functions converting slices of numbers into distributions of
+//! [`ZeroOneBoundedFloat`]: [`normalize`], [`softmax`] and [`cumulative`], and the error
+//! [`NormalizeError`].
+//!
+//! The module requires the `alloc` feature, and [`softmax`] the `std` feature.
+
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{PositiveFloat, ZeroOneBoundedFloat};
+
+/// Divide each value by the sum of the values, so that the result sums to one (up to the
+/// rounding errors).
+///
+/// The values are first scaled by their maximum so that the sum cannot overflow, even for
+/// values close to [`PositiveFloat::MAX`].
+///
+/// # Errors
+/// - [`NormalizeError::Empty`] if `values` is empty,
+/// - [`NormalizeError::ZeroSum`] if all the values are zero.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::normalize, PositiveFloat, ZeroOneBoundedFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let values = [PositiveFloat::new(1_f64)?, PositiveFloat::new(3_f64)?];
+/// assert_eq!(
+///     normalize(&values)?,
+///     vec![
+///         ZeroOneBoundedFloat::new(0.25_f64)?,
+///         ZeroOneBoundedFloat::new(0.75_f64)?
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn normalize(values: &[PositiveFloat]) -> Result<Vec<ZeroOneBoundedFloat>, NormalizeError> {
+    let max = values
+        .iter()
+        .copied()
+        .max()
+        .ok_or(NormalizeError::Empty)?
+        .float();
+    if max == 0_f64 {
+        return Err(NormalizeError::ZeroSum);
+    }
+    // each scaled value is in [0, 1] and the sum is in [1, values.len()]
+    let sum: f64 = values.iter().map(|value| value.float() / max).sum();
+    Ok(values
+        .iter()
+        .map(|value| {
+            // SAFETY: the scaled value is not above the sum as the sum contains it and all the
+            // values are positive, and the sum is finite and not zero.
+            unsafe { ZeroOneBoundedFloat::new_unchecked(value.float() / max / sum) }
+        })
+        .collect())
+}
+
+/// The softmax function, the exponential of each value divided by the sum of the
+/// exponentials, so that the result sums to one (up to the rounding errors).
+///
+/// The maximum is subtracted from the values before taking the exponential, so large values
+/// do not overflow. An empty slice gives an empty vector. If a value is [`f64::NAN`], or the
+/// maximum is infinite, the result is not defined and all the values are
+/// [`ZeroOneBoundedFloat::ZERO`].
+///
+/// # Example
+/// ```
+/// use utils_lib::number::softmax;
+///
+/// let result = softmax(&[1000_f64, 1000_f64]);
+/// assert_eq!(result[0].float(), 0.5_f64);
+/// assert_eq!(result[1].float(), 0.5_f64);
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+#[must_use]
+pub fn softmax(values: &[f64]) -> Vec<ZeroOneBoundedFloat> {
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let exp = values
+        .iter()
+        .map(|value| (value - max).exp())
+        .collect::<Vec<_>>();
+    // the maximum gives e^0 = 1 so the sum is at least 1, each term is in [0, 1]
+    let sum: f64 = exp.iter().sum();
+    exp.into_iter()
+        .map(|exp| ZeroOneBoundedFloat::from_bounded_operation(exp / sum))
+        .collect()
+}
+
+/// Running sum of the values, saturating at one, see [`ZeroOneBoundedFloat::saturating_add`].
+///
+/// It is the cumulative distribution of a distribution given by [`normalize`] or [`softmax`],
+/// useful to sample from it. The result has the same length as `values`.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::cumulative, ZeroOneBoundedFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let values = [
+///     ZeroOneBoundedFloat::new(0.25_f64)?,
+///     ZeroOneBoundedFloat::new(0.5_f64)?,
+///     ZeroOneBoundedFloat::new(0.5_f64)?,
+/// ];
+/// assert_eq!(
+///     cumulative(&values),
+///     vec![
+///         ZeroOneBoundedFloat::new(0.25_f64)?,
+///         ZeroOneBoundedFloat::new(0.75_f64)?,
+///         ZeroOneBoundedFloat::ONE,
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+#[must_use]
+pub fn cumulative(values: &[ZeroOneBoundedFloat]) -> Vec<ZeroOneBoundedFloat> {
+    values
+        .iter()
+        .scan(ZeroOneBoundedFloat::ZERO, |sum, value| {
+            *sum = sum.saturating_add(*value);
+            Some(*sum)
+        })
+        .collect()
+}
+
+/// Error returned by [`normalize`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum NormalizeError {
+    /// There is no value to normalize
+    Empty,
+    /// All the values are zero
+    ZeroSum,
+}
+
+impl Display for NormalizeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "there is no value to normalize"),
+            Self::ZeroSum => write!(f, "the values sum to zero"),
+        }
+    }
+}
+
+impl Error for NormalizeError {}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, vec::Vec};
+    use core::error::Error;
+
+    use super::{cumulative, normalize, NormalizeError};
+    use crate::{PositiveFloat, ZeroOneBoundedFloat};
+
+    /// Sum the values as [`f64`].
+    fn sum(values: &[ZeroOneBoundedFloat]) -> f64 {
+        values.iter().map(|value| value.float()).sum()
+    }
+
+    /// Get the values as [`f64`].
+    fn floats(values: &[ZeroOneBoundedFloat]) -> Vec<f64> {
+        values.iter().map(|value| value.float()).collect()
+    }
+
+    #[test]
+    fn normalize_values() -> Result<(), Box<dyn Error>> {
+        let result = normalize(&[
+            PositiveFloat::new(2_f64)?,
+            PositiveFloat::ZERO,
+            PositiveFloat::new(6_f64)?,
+        ])?;
+        assert_eq!(floats(&result), [0.25_f64, 0_f64, 0.75_f64]);
+
+        let result = normalize(&[PositiveFloat::MAX; 3])?;
+        assert!(result
+            .iter()
+            .all(|value| (value.float() - 1_f64 / 3_f64).abs() < 1E-15_f64));
+
+        let values = (1_u32..=100_u32)
+            .map(|i| PositiveFloat::new(f64::from(i).sqrt()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let result = normalize(&values)?;
+        assert_eq!(result.len(), 100);
+        assert!((sum(&result) - 1_f64).abs() < 1E-12_f64);
+
+        assert_eq!(
+            normalize(&[PositiveFloat::new(f64::MIN_POSITIVE / 4_f64)?]),
+            Ok(vec![ZeroOneBoundedFloat::ONE])
+        );
+
+        assert_eq!(normalize(&[]), Err(NormalizeError::Empty));
+        assert_eq!(
+            normalize(&[PositiveFloat::ZERO; 2]),
+            Err(NormalizeError::ZeroSum)
+        );
+        assert_eq!(
+            NormalizeError::ZeroSum.to_string(),
+            "the values sum to zero"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn softmax() {
+        use super::softmax;
+
+        assert!(softmax(&[]).is_empty());
+        assert_eq!(softmax(&[-3_f64]), vec![ZeroOneBoundedFloat::ONE]);
+
+        let result = softmax(&[1_f64, 2_f64, 3_f64]);
+        assert!((sum(&result) - 1_f64).abs() < 1E-15_f64);
+        assert!(result[0] < result[1] && result[1] < result[2]);
+        assert!((result[0].float() - 0.090_030_573_170_380_46_f64).abs() < 1E-15_f64);
+
+        // without the max subtraction e^1000 overflows
+        let large = softmax(&[1000_f64, 1001_f64, 1002_f64]);
+        assert_eq!(large, result);
+        let small = softmax(&[-1000_f64, -999_f64, -998_f64]);
+        assert_eq!(small, result);
+
+        let result = softmax(&[0_f64, 1000_f64]);
+        assert_eq!(result[0], ZeroOneBoundedFloat::ZERO);
+        assert_eq!(result[1], ZeroOneBoundedFloat::ONE);
+
+        assert_eq!(
+            softmax(&[0_f64, f64::NAN]),
+            vec![ZeroOneBoundedFloat::ZERO; 2]
+        );
+        assert_eq!(
+            softmax(&[0_f64, f64::INFINITY]),
+            vec![ZeroOneBoundedFloat::ZERO; 2]
+        );
+    }
+
+    #[test]
+    fn cumulative_sum() -> Result<(), Box<dyn Error>> {
+        assert!(cumulative(&[]).is_empty());
+
+        let values = normalize(&[
+            PositiveFloat::ONE,
+            PositiveFloat::ONE,
+            PositiveFloat::new(2_f64)?,
+        ])?;
+        let result = cumulative(&values);
+        assert_eq!(floats(&result), [0.25_f64, 0.5_f64, 1_f64]);
+        assert!(result.windows(2).all(|window| window[0] <= window[1]));
+
+        assert_eq!(
+            cumulative(&[ZeroOneBoundedFloat::ONE; 3]),
+            vec![ZeroOneBoundedFloat::ONE; 3]
+        );
+        Ok(())
+    }
+}
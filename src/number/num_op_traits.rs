@@ -1,22 +1,97 @@
 //! Implementation of some [`std::ops`] trait for [`PositiveFloat`].
 //!
 //! more precisely [`std::ops::Add`], [`std::ops::AddAssign`], [`std::ops::Div`],
-//! [`std::ops::DivAssign`], [`std::ops::Mul`] and [`std::ops::MulAssign`].
+//! [`std::ops::DivAssign`], [`std::ops::Mul`], [`std::ops::MulAssign`], [`std::ops::Rem`]
+//! and [`std::ops::RemAssign`].
 
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign};
 
 use super::{PositiveFloat, ZeroOneBoundedFloat};
 
+// The sum and the product saturate at `PositiveFloat::MAX`, with the feature
+// `strict-arithmetic` they panic instead.
+#[cfg(not(feature = "strict-arithmetic"))]
 impl_op_trait!(PositiveFloat, float_mut, Add);
+#[cfg(not(feature = "strict-arithmetic"))]
 impl_op_trait!(PositiveFloat, float_mut, Mul);
+#[cfg(feature = "strict-arithmetic")]
+impl_op_trait!(PositiveFloat, float_mut_strict, Add);
+#[cfg(feature = "strict-arithmetic")]
+impl_op_trait!(PositiveFloat, float_mut_strict, Mul);
 impl_op_trait!(PositiveFloat, float_mut, Div);
 impl_op_trait!(PositiveFloat, float_mut, Rem);
 
 impl_op_trait!(ZeroOneBoundedFloat, float_mut, Mul);
-impl_op_trait!(ZeroOneBoundedFloat, float_mut, Rem);
 
 // TODO macro and ref trait
 
+/// The remainder is the one of [`f64`], it has the sign of the dividend so the remainder of two
+/// [`ZeroOneBoundedFloat`] is always in `[0, rhs)` and is valid, e.g. `0.3 % 0.2` is
+/// `0.09999999999999998` as for [`f64`].
+///
+/// The remainder by zero panics in debug mode and is zero in release mode,
+/// use [`ZeroOneBoundedFloat::checked_rem`] to handle it.
+impl RemAssign for ZeroOneBoundedFloat {
+    #[cfg(debug_assertions)]
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.checked_rem(rhs).expect("remainder error");
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        // the remainder by zero is NaN which is set to zero by the guard
+        *self.float_mut() %= rhs.float();
+    }
+}
+
+impl<'a> RemAssign<&'a Self> for ZeroOneBoundedFloat {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &'a Self) {
+        *self %= *rhs;
+    }
+}
+
+impl Rem for ZeroOneBoundedFloat {
+    type Output = Self;
+
+    #[inline]
+    fn rem(mut self, rhs: Self) -> Self::Output {
+        self %= rhs;
+        self
+    }
+}
+
+impl<'a> Rem<&'a Self> for ZeroOneBoundedFloat {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: &'a Self) -> Self::Output {
+        self % *rhs
+    }
+}
+
+impl Rem<ZeroOneBoundedFloat> for &ZeroOneBoundedFloat {
+    type Output = ZeroOneBoundedFloat;
+
+    #[inline]
+    fn rem(self, rhs: ZeroOneBoundedFloat) -> Self::Output {
+        *self % rhs
+    }
+}
+
+impl<'a> Rem<&'a ZeroOneBoundedFloat> for &ZeroOneBoundedFloat {
+    type Output = ZeroOneBoundedFloat;
+
+    #[inline]
+    fn rem(self, rhs: &'a ZeroOneBoundedFloat) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+//----------------------
+
 impl MulAssign<ZeroOneBoundedFloat> for PositiveFloat {
     #[cfg(debug_assertions)]
     #[inline]
@@ -95,6 +170,74 @@ impl Div<PositiveFloat> for ZeroOneBoundedFloat {
     }
 }
 
+/// The quotient of two [`ZeroOneBoundedFloat`] can be greater than one, it is a
+/// [`PositiveFloat`].
+///
+/// The division by zero panics in debug mode, in release mode it is [`PositiveFloat::MAX`]
+/// or zero for `0 / 0`, use [`ZeroOneBoundedFloat::checked_div_positive`] to handle it.
+impl Div for ZeroOneBoundedFloat {
+    type Output = PositiveFloat;
+
+    #[cfg(debug_assertions)]
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div_positive(rhs).expect("division error")
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        PositiveFloat::new_or_bounded(self.float() / rhs.float())
+    }
+}
+
+impl<'a> Div<&'a Self> for ZeroOneBoundedFloat {
+    type Output = PositiveFloat;
+
+    #[inline]
+    fn div(self, rhs: &'a Self) -> Self::Output {
+        self / *rhs
+    }
+}
+
+impl Div<ZeroOneBoundedFloat> for &ZeroOneBoundedFloat {
+    type Output = PositiveFloat;
+
+    #[inline]
+    fn div(self, rhs: ZeroOneBoundedFloat) -> Self::Output {
+        *self / rhs
+    }
+}
+
+impl<'a> Div<&'a ZeroOneBoundedFloat> for &ZeroOneBoundedFloat {
+    type Output = PositiveFloat;
+
+    #[inline]
+    fn div(self, rhs: &'a ZeroOneBoundedFloat) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+/// Raw float division, it is the division of [`f64`] with no check.
+impl Div<ZeroOneBoundedFloat> for f64 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: ZeroOneBoundedFloat) -> Self::Output {
+        self / rhs.float()
+    }
+}
+
+/// Raw float division, it is the division of [`f64`] with no check.
+impl Div<f64> for ZeroOneBoundedFloat {
+    type Output = f64;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        self.float() / rhs
+    }
+}
+
 //----------------------
 
 impl AddAssign<ZeroOneBoundedFloat> for PositiveFloat {
@@ -135,7 +278,10 @@ impl Add<PositiveFloat> for ZeroOneBoundedFloat {
 mod test {
     use std::error::Error;
 
-    use crate::{PositiveFloat, ZeroOneBoundedFloat};
+    use crate::{
+        number::{PositiveFloatConversionError, Validation},
+        PositiveFloat, ZeroOneBoundedFloat,
+    };
 
     #[test]
     fn hybrid_mul() -> Result<(), Box<dyn Error>> {
@@ -252,6 +398,133 @@ mod test {
         let _: PositiveFloat = ZeroOneBoundedFloat::ONE / PositiveFloat::ZERO;
     }
 
+    #[test]
+    fn zero_one_div() -> Result<(), Box<dyn Error>> {
+        let p = ZeroOneBoundedFloat::new(0.5_f64)?;
+        let q = ZeroOneBoundedFloat::new(0.25_f64)?;
+        // p / q > 1
+        assert_eq!(p / q, PositiveFloat::new(2_f64)?);
+        // p / q <= 1
+        assert_eq!(q / p, PositiveFloat::new(0.5_f64)?);
+        assert_eq!(p / p, PositiveFloat::ONE);
+        assert_eq!(ZeroOneBoundedFloat::ZERO / p, PositiveFloat::ZERO);
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.3_f64)? / ZeroOneBoundedFloat::new(0.6_f64)?,
+            PositiveFloat::new(0.5_f64)?
+        );
+        // q = 0
+        assert_eq!(
+            p.checked_div_positive(ZeroOneBoundedFloat::ZERO),
+            Err(PositiveFloatConversionError::Infinity)
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO.checked_div_positive(ZeroOneBoundedFloat::ZERO),
+            Err(PositiveFloatConversionError::Nan)
+        );
+        // overflow
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE
+                .checked_div_positive(ZeroOneBoundedFloat::new(f64::MIN_POSITIVE / 4_f64)?),
+            Err(PositiveFloatConversionError::Infinity)
+        );
+
+        // raw float
+        assert!((3_f64 / p - 6_f64).abs() < f64::EPSILON);
+        assert!((p / 4_f64 - 0.125_f64).abs() < f64::EPSILON);
+        assert!((p / 0_f64).is_infinite());
+
+        Ok(())
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "division error")]
+    fn zero_one_div_zero() {
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE / ZeroOneBoundedFloat::ZERO,
+            PositiveFloat::MAX,
+            "not reached"
+        );
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn zero_one_div_zero() {
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE / ZeroOneBoundedFloat::ZERO,
+            PositiveFloat::MAX
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO / ZeroOneBoundedFloat::ZERO,
+            PositiveFloat::ZERO
+        );
+    }
+
+    #[test]
+    fn overflowing() -> Result<(), Box<dyn Error>> {
+        let max = PositiveFloat::MAX;
+        let two = PositiveFloat::new(2_f64)?;
+
+        assert_eq!(max.overflowing_add(max), (max, true));
+        assert_eq!(max.overflowing_add(two), (max, false));
+        assert_eq!(
+            two.overflowing_add(two),
+            (PositiveFloat::new(4_f64)?, false)
+        );
+        assert_eq!(max.overflowing_mul(two), (max, true));
+        assert_eq!(two.overflowing_mul(max), (max, true));
+        assert_eq!(max.overflowing_mul(PositiveFloat::ONE), (max, false));
+        assert_eq!(
+            max.overflowing_mul(PositiveFloat::ZERO),
+            (PositiveFloat::ZERO, false)
+        );
+        Ok(())
+    }
+
+    #[cfg(not(feature = "strict-arithmetic"))]
+    #[test]
+    fn saturating_operators() -> Result<(), Box<dyn Error>> {
+        let max = PositiveFloat::MAX;
+        assert_eq!(max + max, max);
+        assert_eq!(max * PositiveFloat::new(2_f64)?, max);
+        let mut p = max;
+        p += max;
+        assert_eq!(p, max);
+        p *= max;
+        assert_eq!(p, max);
+        Ok(())
+    }
+
+    #[cfg(feature = "strict-arithmetic")]
+    #[test]
+    fn strict_operators() -> Result<(), Box<dyn Error>> {
+        let two = PositiveFloat::new(2_f64)?;
+        assert_eq!(two + two, PositiveFloat::new(4_f64)?);
+        assert_eq!(two * two, PositiveFloat::new(4_f64)?);
+        assert_eq!(PositiveFloat::MAX + two, PositiveFloat::MAX);
+        Ok(())
+    }
+
+    #[cfg(feature = "strict-arithmetic")]
+    #[test]
+    #[should_panic(expected = "is not valid")]
+    fn strict_add_overflow() {
+        assert_eq!(
+            PositiveFloat::MAX + PositiveFloat::MAX,
+            PositiveFloat::MAX,
+            "not reached"
+        );
+    }
+
+    #[cfg(feature = "strict-arithmetic")]
+    #[test]
+    #[should_panic(expected = "is not valid")]
+    fn strict_mul_overflow() {
+        let mut p = PositiveFloat::MAX;
+        p *= PositiveFloat::MAX;
+        assert_eq!(p, PositiveFloat::MAX, "not reached");
+    }
+
     #[test]
     fn hybrid_add() -> Result<(), Box<dyn Error>> {
         let mut p = PositiveFloat::new(5_f64)?;
@@ -284,4 +557,112 @@ mod test {
 
         Ok(())
     }
+
+    #[allow(clippy::float_cmp)] // reason = "the remainder is compared to the one of f64"
+    #[allow(clippy::op_ref)] // reason = "test the implementations on references"
+    #[test]
+    fn zero_one_bounded_float_rem() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.5_f64)? % ZeroOneBoundedFloat::new(0.2_f64)?,
+            ZeroOneBoundedFloat::new(0.5_f64 % 0.2_f64)?
+        );
+        assert_eq!(
+            (ZeroOneBoundedFloat::new(0.3_f64)? % ZeroOneBoundedFloat::new(0.2_f64)?).float(),
+            0.099_999_999_999_999_98_f64
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.75_f64)? % ZeroOneBoundedFloat::new(0.25_f64)?,
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.2_f64)? % ZeroOneBoundedFloat::new(0.5_f64)?,
+            ZeroOneBoundedFloat::new(0.2_f64)?
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE % ZeroOneBoundedFloat::ONE,
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO % ZeroOneBoundedFloat::new(0.3_f64)?,
+            ZeroOneBoundedFloat::ZERO
+        );
+
+        let p1 = ZeroOneBoundedFloat::new(0.9_f64)?;
+        let p2 = ZeroOneBoundedFloat::new(0.4_f64)?;
+        let expected = ZeroOneBoundedFloat::new(0.9_f64 % 0.4_f64)?;
+        assert_eq!(p1 % p2, expected);
+        assert_eq!(p1 % &p2, expected);
+        assert_eq!(&p1 % p2, expected);
+        assert_eq!(&p1 % &p2, expected);
+        let mut p = p1;
+        p %= p2;
+        assert_eq!(p, expected);
+        let mut p = p1;
+        p %= &p2;
+        assert_eq!(p, expected);
+
+        Ok(())
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the remainder is compared to the one of f64"
+    #[test]
+    fn zero_one_bounded_float_rem_guard() -> Result<(), Box<dyn Error>> {
+        // the remainder of two valid values is valid so the guard never changes it
+        let values = [
+            0_f64, 1E-10_f64, 0.1_f64, 0.2_f64, 0.3_f64, 0.5_f64, 0.7_f64, 1_f64,
+        ];
+        for lhs in values {
+            for rhs in values.into_iter().filter(|rhs| *rhs != 0_f64) {
+                let float = lhs % rhs;
+                assert!(ZeroOneBoundedFloat::validate_data(float), "{lhs} % {rhs}");
+
+                let mut p = ZeroOneBoundedFloat::new(lhs)?;
+                *p.float_mut() %= rhs;
+                assert_eq!(p.float(), float, "{lhs} % {rhs}");
+
+                let p1 = ZeroOneBoundedFloat::new(lhs)?;
+                let p2 = ZeroOneBoundedFloat::new(rhs)?;
+                assert_eq!((p1 % p2).float(), float, "{lhs} % {rhs}");
+                assert_eq!(p1.checked_rem(p2), Ok(p1 % p2), "{lhs} % {rhs}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_one_bounded_float_checked_rem() -> Result<(), Box<dyn Error>> {
+        use crate::number::ZeroOneBoundedFloatConversionError;
+
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.5_f64)?.checked_rem(ZeroOneBoundedFloat::ZERO),
+            Err(ZeroOneBoundedFloatConversionError::Nan)
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO.checked_rem(ZeroOneBoundedFloat::ZERO),
+            Err(ZeroOneBoundedFloatConversionError::Nan)
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.5_f64)?.checked_rem(ZeroOneBoundedFloat::new(0.4_f64)?),
+            Ok(ZeroOneBoundedFloat::new(0.5_f64 % 0.4_f64)?)
+        );
+
+        Ok(())
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "remainder error")]
+    fn zero_one_bounded_float_rem_zero() {
+        let _: ZeroOneBoundedFloat = ZeroOneBoundedFloat::ONE % ZeroOneBoundedFloat::ZERO;
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn zero_one_bounded_float_rem_zero() {
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE % ZeroOneBoundedFloat::ZERO,
+            ZeroOneBoundedFloat::ZERO
+        );
+    }
 }
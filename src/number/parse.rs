@@ -0,0 +1,249 @@
+//! Contains [`ParseFlexibleError`], the error of [`ZeroOneBoundedFloat::parse_flexible`] and
+//! [`PositiveFloat::parse_flexible`], and the parsing functions behind them.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{
+    error::Error,
+    fmt::{self, Display},
+    num::ParseFloatError,
+};
+
+#[cfg(doc)]
+use super::{PositiveFloat, ZeroOneBoundedFloat};
+
+/// Error returned by [`ZeroOneBoundedFloat::parse_flexible`] and
+/// [`PositiveFloat::parse_flexible`].
+///
+/// `E` is the conversion error of the type, returned when the string is a valid number
+/// outside of the range of the type.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseFlexibleError<E> {
+    /// A number of the string is not a valid float
+    Syntax(ParseFloatError),
+    /// An underscore separator is not between two digits
+    Separator,
+    /// The value is not in the range of the type
+    Range(E),
+}
+
+impl<E> From<ParseFloatError> for ParseFlexibleError<E> {
+    #[inline]
+    fn from(value: ParseFloatError) -> Self {
+        Self::Syntax(value)
+    }
+}
+
+impl<E: Display> Display for ParseFlexibleError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(err) => write!(f, "the number cannot be parsed: {err}"),
+            Self::Separator => write!(f, "an underscore separator is not between two digits"),
+            Self::Range(err) => write!(f, "the number is out of range: {err}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseFlexibleError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Syntax(err) => Some(err),
+            Self::Separator => None,
+            Self::Range(err) => Some(err),
+        }
+    }
+}
+
+/// Parse a ratio given as a float `"0.75"`, a percentage `"75%"` or a fraction `"3/4"`.
+/// The string and the numbers around the `%` and `/` are trimmed.
+pub(crate) fn parse_ratio(s: &str) -> Result<f64, ParseFloatError> {
+    let s = s.trim();
+    if let Some(percent) = s.strip_suffix('%') {
+        Ok(percent.trim_end().parse::<f64>()? / 100_f64)
+    } else if let Some((numerator, denominator)) = s.split_once('/') {
+        Ok(numerator.trim_end().parse::<f64>()? / denominator.trim_start().parse::<f64>()?)
+    } else {
+        s.parse()
+    }
+}
+
+/// Parse a trimmed float whose digits can be grouped with underscores, like `"1_000.5"`.
+///
+/// # Errors
+/// Returns [`ParseFlexibleError::Separator`] if an underscore is not between two digits.
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_with_separators<E>(s: &str) -> Result<f64, ParseFlexibleError<E>> {
+    let s = s.trim();
+    let bytes = s.as_bytes();
+    let separator_valid = |index: usize| {
+        index
+            .checked_sub(1)
+            .and_then(|before| bytes.get(before))
+            .is_some_and(u8::is_ascii_digit)
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+    };
+    if !bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, byte)| **byte == b'_')
+        .all(|(index, _)| separator_valid(index))
+    {
+        return Err(ParseFlexibleError::Separator);
+    }
+    Ok(s.chars()
+        .filter(|c| *c != '_')
+        .collect::<String>()
+        .parse()?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::ParseFlexibleError;
+    #[cfg(feature = "alloc")]
+    use crate::{number::PositiveFloatConversionError, PositiveFloat};
+    use crate::{number::ZeroOneBoundedFloatConversionError, ZeroOneBoundedFloat};
+
+    #[test]
+    fn zero_one_bounded_float() {
+        for (string, expected) in [
+            ("0.75", 0.75_f64),
+            ("  0.75\n", 0.75_f64),
+            ("1", 1_f64),
+            ("0", 0_f64),
+            ("2.5e-1", 0.25_f64),
+            ("75%", 0.75_f64),
+            ("12.5 %", 0.125_f64),
+            ("100%", 1_f64),
+            ("3/4", 0.75_f64),
+            (" 1 / 8 ", 0.125_f64),
+            ("0/5", 0_f64),
+            ("4/4", 1_f64),
+        ] {
+            assert_eq!(
+                ZeroOneBoundedFloat::parse_flexible(string).map(ZeroOneBoundedFloat::float),
+                Ok(expected),
+                "{string}"
+            );
+        }
+
+        for (string, expected) in [
+            ("1.5", ZeroOneBoundedFloatConversionError::TooBig),
+            ("-0.1", ZeroOneBoundedFloatConversionError::TooLow),
+            ("150%", ZeroOneBoundedFloatConversionError::TooBig),
+            ("5/4", ZeroOneBoundedFloatConversionError::TooBig),
+            ("-1/4", ZeroOneBoundedFloatConversionError::TooLow),
+            ("1/0", ZeroOneBoundedFloatConversionError::TooBig),
+            ("0/0", ZeroOneBoundedFloatConversionError::Nan),
+            ("NaN", ZeroOneBoundedFloatConversionError::Nan),
+        ] {
+            assert_eq!(
+                ZeroOneBoundedFloat::parse_flexible(string),
+                Err(ParseFlexibleError::Range(expected)),
+                "{string}"
+            );
+        }
+
+        for string in ["", "%", "abc", "0.5.5", "75%%", "1/2/3", "/4", "3/", "0_5"] {
+            assert!(
+                matches!(
+                    ZeroOneBoundedFloat::parse_flexible(string),
+                    Err(ParseFlexibleError::Syntax(_))
+                ),
+                "{string}"
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn positive_float() {
+        for (string, expected) in [
+            ("0", 0_f64),
+            ("1.5", 1.5_f64),
+            (" 42 ", 42_f64),
+            ("1e3", 1000_f64),
+            ("2.5E-2", 0.025_f64),
+            ("1_000.5", 1000.5_f64),
+            ("1_000_000", 1_000_000_f64),
+            ("0.000_1", 0.000_1_f64),
+            ("1e1_0", 1E10_f64),
+            ("+3", 3_f64),
+        ] {
+            assert_eq!(
+                PositiveFloat::parse_flexible(string).map(PositiveFloat::float),
+                Ok(expected),
+                "{string}"
+            );
+        }
+
+        for (string, expected) in [
+            (
+                "-1",
+                ParseFlexibleError::Range(PositiveFloatConversionError::TooLow),
+            ),
+            (
+                "inf",
+                ParseFlexibleError::Range(PositiveFloatConversionError::Infinity),
+            ),
+            (
+                "1e400",
+                ParseFlexibleError::Range(PositiveFloatConversionError::Infinity),
+            ),
+            (
+                "NaN",
+                ParseFlexibleError::Range(PositiveFloatConversionError::Nan),
+            ),
+            ("_1", ParseFlexibleError::Separator),
+            ("1_", ParseFlexibleError::Separator),
+            ("1__000", ParseFlexibleError::Separator),
+            ("1_.5", ParseFlexibleError::Separator),
+            ("1._5", ParseFlexibleError::Separator),
+        ] {
+            assert_eq!(
+                PositiveFloat::parse_flexible(string),
+                Err(expected),
+                "{string}"
+            );
+        }
+
+        for string in ["", "abc", "1.2.3", "1 000", "50%", "3/4"] {
+            assert!(
+                matches!(
+                    PositiveFloat::parse_flexible(string),
+                    Err(ParseFlexibleError::Syntax(_))
+                ),
+                "{string}"
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn error() {
+        use std::error::Error;
+
+        let err = PositiveFloat::parse_flexible("x").expect_err("invalid float");
+        assert_eq!(
+            err.to_string(),
+            "the number cannot be parsed: invalid float literal"
+        );
+        assert!(err.source().is_some());
+
+        let err = ZeroOneBoundedFloat::parse_flexible("2").expect_err("out of range");
+        assert_eq!(
+            err.to_string(),
+            "the number is out of range: the float is above one"
+        );
+        assert!(err.source().is_some());
+
+        assert!(
+            ParseFlexibleError::<PositiveFloatConversionError>::Separator
+                .source()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! mod to separate the implementation of [`borsh`] traits for [`PositiveFloat`]
+
+use alloc::string::ToString;
+
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use super::PositiveFloat;
+
+impl BorshSerialize for PositiveFloat {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.float().serialize(writer)
+    }
+}
+
+/// The float is validated, an invalid value gives an error of kind [`ErrorKind::InvalidData`].
+impl BorshDeserialize for PositiveFloat {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Self::new(f64::deserialize_reader(reader)?)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use borsh::io::ErrorKind;
+
+    use super::PositiveFloat;
+
+    #[test]
+    fn borsh_round_trip() {
+        for float in [
+            PositiveFloat::ZERO,
+            PositiveFloat::ONE,
+            PositiveFloat::new(2.5_f64).expect("valid float"),
+        ] {
+            let bytes = borsh::to_vec(&float).expect("serializable");
+            assert_eq!(bytes, borsh::to_vec(&float.float()).expect("serializable"));
+            assert_eq!(
+                borsh::from_slice::<PositiveFloat>(&bytes).expect("valid bytes"),
+                float
+            );
+        }
+    }
+
+    #[test]
+    fn borsh_invalid() {
+        for float in [-1_f64, f64::INFINITY] {
+            let bytes = borsh::to_vec(&float).expect("serializable");
+            let err = borsh::from_slice::<PositiveFloat>(&bytes).expect_err("out of range float");
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+        borsh::from_slice::<PositiveFloat>(&[0_u8; 4]).expect_err("not enough bytes");
+    }
+}
@@ -2,28 +2,40 @@
 //!
 //! The module exits in order to compartmentalize code.
 
+#[cfg(feature = "borsh")]
+mod borsh_impl;
 mod num_traits_impl;
 
-use std::{
+use core::{
     cmp::Ordering,
     error::Error,
-    fmt::{self, Display, LowerExp, UpperExp},
+    fmt::{self, Debug, Display, LowerExp, UpperExp},
     hash::{Hash, Hasher},
     num::FpCategory,
     ops::Deref,
+    time::{Duration, TryFromFloatSecsError},
 };
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::{compare_f64, Validation, ValidationGuard};
+#[cfg(feature = "alloc")]
+use super::parse::{parse_with_separators, ParseFlexibleError};
+#[cfg(feature = "strict-arithmetic")]
+use super::InvalidPolicy;
+use super::{
+    compare_f64,
+    display::{FloatDebug, RatioDisplay},
+    pow_by_squaring, ulp_diff, Interval, Validation, ValidationGuard,
+};
 use crate::ZeroOneBoundedFloat;
 
 // TODO see if it is possible to use a trait to merge code of PositiveFloat and ZeroOneBoundedFloats.
 
 /// A float that is `>= 0` and is not [`f64::NAN`] or [`f64::INFINITY`].
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Clone, Copy, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::unsafe_derive_deserialize)] // reason = "the unsafe constructor is only unsafe in regard to the invariant of the type, not memory"
 pub struct PositiveFloat(f64);
 
 impl Eq for PositiveFloat {}
@@ -42,6 +54,18 @@ impl PartialOrd for PositiveFloat {
     }
 }
 
+/// Debug the wrapped float, like `PositiveFloat(2.5)`. If the invariant of the type is broken, which
+/// should not happen without an unsafe constructor, the float is marked like
+/// `PositiveFloat(-1.0 [INVALID])`.
+impl Debug for PositiveFloat {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PositiveFloat")
+            .field(&FloatDebug::<Self>::new(self.float()))
+            .finish()
+    }
+}
+
 impl Display for PositiveFloat {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -105,9 +129,18 @@ impl PositiveFloat {
     /// Maximum value
     pub const MAX: Self = Self(f64::MAX);
 
+    /// Returns `true` if the float is valid, i.e. it is `>= 0`, finite and not [`f64::NAN`], see
+    /// [`Validation::validate_data`]. It is const unlike the trait method.
+    const fn is_valid(t: f64) -> bool {
+        matches!(
+            t.classify(),
+            FpCategory::Normal | FpCategory::Subnormal | FpCategory::Zero
+        ) && t >= 0_f64
+    }
+
     /// determine under which bound the given float is
-    fn float_range(float: f64) -> BoundRange {
-        if Self::validate_data(float) {
+    const fn float_range(float: f64) -> BoundRange {
+        if Self::is_valid(float) {
             BoundRange::InRange
         } else if float.is_nan() {
             BoundRange::Nan
@@ -118,42 +151,56 @@ impl PositiveFloat {
         }
     }
 
-    // /// Create a wrapped value skipping the validity check
-    // ///
-    // /// # Safety
-    // /// make sure that the float is valid
-    // //# [cfg(any(not(debug_assertions), doc, test))]
-    // // #[cfg_attr(debug_assertions, allow(dead_code))] // it is used by new_partially_check
-    // #[must_use]
-    // #[inline]
-    // const unsafe fn new_unchecked(float: f64) -> Self {
-    //     Self(float)
-    // }
-
-    // /// see the other [`Self::new_partially_check`]
-    // // It is the other one which is documented
-    // #[allow(clippy::missing_const_for_fn)] // it has to keep the same signature, the other fn can't be const
-    // #[cfg(not(debug_assertions))]
-    // #[must_use]
-    // #[inline]
-    // unsafe fn new_partially_check(float: f64) -> Self {
-    //     Self::new_unchecked(float)
-    // }
-
-    // /// Create a wrapped value doing the validity check only when [`debug_assertions`]
-    // /// and panics if the value is not valid. Otherwise it wraps the value even if it is not valid
-    // ///
-    // /// # Panic
-    // /// Panics if the value if not valid and [`debug_assertions`] is on
-    // ///
-    // /// # Safety
-    // /// make sure that the float is valid
-    // #[cfg(debug_assertions)]
-    // #[must_use]
-    // #[inline]
-    // unsafe fn new_partially_check(float: f64) -> Self {
-    //     Self::new(float).expect("invalid value")
-    // }
+    /// Create a wrapped value skipping the validity check.
+    ///
+    /// It is meant for performance sensitive code where the value is already known to be
+    /// valid, e.g. the output of an operation that is always in range. Otherwise prefer the
+    /// safe [`Self::new`], or [`Self::new_saturating`] which only adds a cheap range check.
+    ///
+    /// # Safety
+    /// The float must be valid, i.e. it is `>= 0`, finite and not [`f64::NAN`]. An invalid value breaks the invariant
+    /// of the type that other functions rely on.
+    ///
+    /// # Panics
+    /// Panics if the float is not valid and `debug_assertions` are enabled, so that a misuse
+    /// is caught in debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// // SAFETY: 0.5_f64 is valid
+    /// let float = unsafe { PositiveFloat::new_unchecked(0.5_f64) };
+    /// assert_eq!(float.float(), 0.5_f64);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(float: f64) -> Self {
+        debug_assert!(
+            float >= 0_f64 && float <= f64::MAX,
+            "the float is not valid"
+        );
+        Self(float)
+    }
+
+    /// Create a new Self from a [`f64`], saturating at the bounds. It is an alias of
+    /// [`Self::new_or_bounded`] and the safe alternative to [`Self::new_unchecked`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// assert_eq!(
+    ///     PositiveFloat::new_saturating(1E300_f64),
+    ///     PositiveFloat::new_or_bounded(1E300_f64)
+    /// );
+    /// assert_eq!(PositiveFloat::new_saturating(-1_f64), PositiveFloat::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new_saturating(float: f64) -> Self {
+        Self::new_or_bounded(float)
+    }
 
     /// Create a new Self from a [`f64`]. It returns [`Some`] only if the float is valid ([`Self::validate_data`]), i.e.
     /// it is >= 0 it is not [`f64::NAN`] and not [`f64::INFINITY`].
@@ -198,7 +245,7 @@ impl PositiveFloat {
     /// # }
     /// ```
     #[inline]
-    pub fn new(float: f64) -> Result<Self, ConversionError> {
+    pub const fn new(float: f64) -> Result<Self, ConversionError> {
         match Self::float_range(float) {
             BoundRange::InRange => Ok(Self(float)),
             BoundRange::LowerBound => Err(ConversionError::TooLow),
@@ -207,6 +254,49 @@ impl PositiveFloat {
         }
     }
 
+    /// Create a new Self from a [`f64`], returning [`None`] if it is not valid. It is
+    /// [`Self::new`] discarding the error and mirrors [`num_traits::FromPrimitive::from_f64`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// assert_eq!(
+    ///     PositiveFloat::from_f64(2_f64).map(PositiveFloat::float),
+    ///     Some(2_f64)
+    /// );
+    /// assert_eq!(PositiveFloat::from_f64(-1_f64), None);
+    /// assert_eq!(PositiveFloat::from_f64(f64::INFINITY), None);
+    /// ```
+    #[allow(clippy::same_name_method)] // reason = "it mirrors the FromPrimitive method"
+    #[inline]
+    #[must_use]
+    pub const fn from_f64(float: f64) -> Option<Self> {
+        Self::new_ok(float)
+    }
+
+    /// Create a new Self from a [`f64`], returning [`None`] if it is not valid. It is
+    /// [`Self::new`] discarding the error, usable in a const context where
+    /// [`Result::ok`] is not.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// const HALF: Option<PositiveFloat> = PositiveFloat::new_ok(0.5_f64);
+    /// assert_eq!(HALF.map(PositiveFloat::float), Some(0.5_f64));
+    /// assert_eq!(PositiveFloat::new_ok(f64::NAN), None);
+    /// assert_eq!(PositiveFloat::new_ok(-1_f64), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new_ok(float: f64) -> Option<Self> {
+        match Self::float_range(float) {
+            BoundRange::InRange => Some(Self(float)),
+            BoundRange::LowerBound | BoundRange::UpperBound | BoundRange::Nan => None,
+        }
+    }
+
     /// Create a new Self with the float as value if it is valid ( `>= 0` finite and not [`f64::NAN`])
     /// or return the default value (0) instead.
     ///
@@ -254,8 +344,11 @@ impl PositiveFloat {
     /// ```
     #[inline]
     #[must_use]
-    pub fn new_or_default(float: f64) -> Self {
-        Self::new(float).unwrap_or_default()
+    pub const fn new_or_default(float: f64) -> Self {
+        match Self::new_ok(float) {
+            Some(value) => value,
+            None => Self::ZERO,
+        }
     }
 
     // Create a new Self with the float as value if it is valid ( `>= 0` finite and not [`f64::NAN`])
@@ -282,9 +375,23 @@ impl PositiveFloat {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// It is `const`, like [`Self::new`], [`Self::new_ok`] and [`Self::new_or_default`], so
+    /// constants can be declared without unwrapping at run time:
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// const SCALES: [PositiveFloat; 3] = [
+    ///     PositiveFloat::new_or_bounded(0.5_f64),
+    ///     PositiveFloat::new_or_bounded(2_f64),
+    ///     PositiveFloat::new_or_bounded(f64::INFINITY),
+    /// ];
+    /// assert_eq!(SCALES[1].float(), 2_f64);
+    /// assert_eq!(SCALES[2], PositiveFloat::MAX);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn new_or_bounded(float: f64) -> Self {
+    pub const fn new_or_bounded(float: f64) -> Self {
         match Self::float_range(float) {
             BoundRange::InRange => Self(float),
             BoundRange::UpperBound => Self::MAX,
@@ -292,6 +399,36 @@ impl PositiveFloat {
         }
     }
 
+    /// Parse a float like [`f64::from_str`](core::str::FromStr::from_str), with an exponent
+    /// like `"1e3"`, and whose digits can be grouped with underscores like `"1_000.5"`.
+    /// Surrounding whitespaces are ignored.
+    ///
+    /// # Errors
+    /// - [`ParseFlexibleError::Syntax`] if the number cannot be parsed,
+    /// - [`ParseFlexibleError::Separator`] if an underscore is not between two digits,
+    /// - [`ParseFlexibleError::Range`] if the value is not valid, see [`Self::new`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(PositiveFloat::parse_flexible("1e3")?.float(), 1000_f64);
+    /// assert_eq!(
+    ///     PositiveFloat::parse_flexible("1_000.5")?.float(),
+    ///     1000.5_f64
+    /// );
+    /// assert!(PositiveFloat::parse_flexible("-1").is_err());
+    /// assert!(PositiveFloat::parse_flexible("1__0").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn parse_flexible(s: &str) -> Result<Self, ParseFlexibleError<ConversionError>> {
+        Self::new(parse_with_separators(s)?).map_err(ParseFlexibleError::Range)
+    }
+
     /// Get the underling float. It could also be accessed by using [`Deref`],
     /// note that [`std::ops::DerefMut`] is not implemented.
     #[inline]
@@ -308,6 +445,15 @@ impl PositiveFloat {
         ValidationGuard::new(self)
     }
 
+    /// Same as [`Self::float_mut`] but the guard panics if the final value is not valid, used
+    /// by the operators with the feature `strict-arithmetic`.
+    #[cfg(feature = "strict-arithmetic")]
+    #[inline]
+    #[must_use]
+    pub(crate) fn float_mut_strict(&'_ mut self) -> ValidationGuard<'_, Self> {
+        self.float_mut().on_invalid(InvalidPolicy::Panic)
+    }
+
     /// Returns the value of the subtraction of two numbers if it doesn't underflow.
     /// It works in the same spirit as [`usize::checked_sub`].
     ///
@@ -360,6 +506,458 @@ impl PositiveFloat {
     pub fn saturating_sub(self, other: Self) -> Self {
         self.checked_sub(other).unwrap_or_default()
     }
+
+    /// Add two [`PositiveFloat`] saturating at [`Self::MAX`], with a flag telling if the sum
+    /// overflowed and was saturated. It works in the same spirit as [`usize::overflowing_add`].
+    ///
+    /// Note that the sum is rounded, so adding a small value to [`Self::MAX`] gives
+    /// [`Self::MAX`] without overflowing.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let p = PositiveFloat::new(1_f64)?;
+    ///
+    /// assert_eq!(p.overflowing_add(p), (PositiveFloat::new(2_f64)?, false));
+    /// assert_eq!(
+    ///     PositiveFloat::MAX.overflowing_add(PositiveFloat::MAX),
+    ///     (PositiveFloat::MAX, true)
+    /// );
+    /// assert_eq!(
+    ///     PositiveFloat::MAX.overflowing_add(p),
+    ///     (PositiveFloat::MAX, false)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let sum = self.float() + other.float();
+        (Self::new_or_bounded(sum), sum.is_infinite())
+    }
+
+    /// Multiply two [`PositiveFloat`] saturating at [`Self::MAX`], with a flag telling if the
+    /// product overflowed and was saturated. It works in the same spirit as
+    /// [`usize::overflowing_mul`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let p = PositiveFloat::new(2_f64)?;
+    ///
+    /// assert_eq!(p.overflowing_mul(p), (PositiveFloat::new(4_f64)?, false));
+    /// assert_eq!(
+    ///     PositiveFloat::MAX.overflowing_mul(p),
+    ///     (PositiveFloat::MAX, true)
+    /// );
+    /// assert_eq!(
+    ///     PositiveFloat::MAX.overflowing_mul(PositiveFloat::ZERO),
+    ///     (PositiveFloat::ZERO, false)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let product = self.float() * other.float();
+        (Self::new_or_bounded(product), product.is_infinite())
+    }
+
+    /// Tell if the absolute difference of two [`PositiveFloat`] is at most `epsilon`. An
+    /// `epsilon` of zero checks for equality.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let sum = PositiveFloat::new(0.1_f64 + 0.2_f64)?;
+    /// let expected = PositiveFloat::new(0.3_f64)?;
+    ///
+    /// assert_ne!(sum, expected);
+    /// assert!(sum.approx_eq(expected, PositiveFloat::new(1E-15_f64)?));
+    /// assert!(!sum.approx_eq(expected, PositiveFloat::ZERO));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn approx_eq(self, other: Self, epsilon: Self) -> bool {
+        (self.float() - other.float()).abs() <= epsilon.float()
+    }
+
+    /// Get the number of representable floats between two [`PositiveFloat`], the distance in
+    /// units in the last place (ULP). It is zero only for equal values.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let sum = PositiveFloat::new(0.1_f64 + 0.2_f64)?;
+    ///
+    /// assert_eq!(sum.max_ulp_diff(PositiveFloat::new(0.3_f64)?), 1);
+    /// assert_eq!(sum.max_ulp_diff(sum), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_ulp_diff(self, other: Self) -> u64 {
+        ulp_diff(self.float(), other.float())
+    }
+
+    /// Affinely map the value from the interval `from` to the interval `to`, like
+    /// [`super::rescale`]. The values out of `from` are clamped, so the result is always in
+    /// `to`.
+    ///
+    /// If `from` is degenerate, with a length of zero, every value is mapped to `to.low()`.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Interval, PositiveFloat};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let from = Interval::new(PositiveFloat::ZERO, PositiveFloat::new(10_f64)?)?;
+    /// let to = Interval::new(PositiveFloat::new(2_f64)?, PositiveFloat::new(4_f64)?)?;
+    /// assert_eq!(
+    ///     PositiveFloat::new(5_f64)?.rescale(from, to),
+    ///     PositiveFloat::new(3_f64)?
+    /// );
+    /// assert_eq!(
+    ///     PositiveFloat::new(20_f64)?.rescale(from, to),
+    ///     PositiveFloat::new(4_f64)?
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rescale(self, from: Interval, to: Interval) -> Self {
+        let length = from.length();
+        if length == Self::ZERO {
+            return to.low();
+        }
+        let t = (self.float() - from.low().float()) / length.float();
+        to.sample(ZeroOneBoundedFloat::new_or_bounded(t))
+    }
+
+    /// Raise the float to an integer power, see [`f64::powi`].
+    ///
+    /// The result can overflow to infinity, for example with a negative exponent of zero.
+    /// In that case it panics in debug and gives [`Self::MAX`] in release.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// let two = PositiveFloat::new(2_f64)?;
+    /// assert_eq!(two.powi(3), PositiveFloat::new(8_f64)?);
+    /// assert_eq!(two.powi(-2), PositiveFloat::new(0.25_f64)?);
+    /// assert_eq!(PositiveFloat::ZERO.powi(0), PositiveFloat::ONE);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "std", debug_assertions))]
+    #[inline]
+    #[must_use]
+    pub fn powi(self, n: i32) -> Self {
+        Self::new(self.float().powi(n)).expect("value not valid")
+    }
+
+    /// Raise the float to an integer power, see [`f64::powi`].
+    ///
+    /// The result can overflow to infinity, for example with a negative exponent of zero.
+    /// In that case it panics in debug and gives [`Self::MAX`] in release.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// let two = PositiveFloat::new(2_f64)?;
+    /// assert_eq!(two.powi(3), PositiveFloat::new(8_f64)?);
+    /// assert_eq!(two.powi(-2), PositiveFloat::new(0.25_f64)?);
+    /// assert_eq!(PositiveFloat::ZERO.powi(0), PositiveFloat::ONE);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "std", not(debug_assertions)))]
+    #[inline]
+    #[must_use]
+    pub fn powi(self, n: i32) -> Self {
+        Self::new_or_bounded(self.float().powi(n))
+    }
+
+    /// Get the square of the float, `self * self`, saturating at [`Self::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// assert_eq!(
+    ///     PositiveFloat::new(3_f64)?.squared(),
+    ///     PositiveFloat::new(9_f64)?
+    /// );
+    /// assert_eq!(PositiveFloat::MAX.squared(), PositiveFloat::MAX);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn squared(self) -> Self {
+        Self::new_or_bounded(self.float() * self.float())
+    }
+
+    /// Get the cube of the float, `self * self * self`, saturating at [`Self::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// assert_eq!(
+    ///     PositiveFloat::new(2_f64)?.cubed(),
+    ///     PositiveFloat::new(8_f64)?
+    /// );
+    /// assert_eq!(PositiveFloat::new(1E200_f64)?.cubed(), PositiveFloat::MAX);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cubed(self) -> Self {
+        Self::new_or_bounded(self.float() * self.float() * self.float())
+    }
+
+    /// Raise the float to an unsigned integer power by repeated multiplication, saturating
+    /// at [`Self::MAX`]. Unlike [`Self::powi`] it is available without the `std` feature, in
+    /// const context and never panics. `x.powi_fast(0)` is [`Self::ONE`], including for zero.
+    ///
+    /// It uses exponentiation by squaring, the rounding errors add up so that the result can
+    /// differ by up to about `n` ULPs from [`f64::powf`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// const EIGHT: PositiveFloat = PositiveFloat::new_or_bounded(2_f64).powi_fast(3);
+    /// assert_eq!(EIGHT, PositiveFloat::new(8_f64)?);
+    ///
+    /// let two = PositiveFloat::new(2_f64)?;
+    /// assert_eq!(two.powi_fast(1024), PositiveFloat::MAX);
+    /// assert_eq!(PositiveFloat::ZERO.powi_fast(0), PositiveFloat::ONE);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn powi_fast(self, n: u32) -> Self {
+        Self::new_or_bounded(pow_by_squaring(self.float(), n))
+    }
+
+    /// Restrict the value to the interval `[min, max]`, like [`Ord::clamp`] but callable without
+    /// the trait in scope. The result is `min` if `min == max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, see [`Self::checked_clamp`] for a non panicking version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// let min = PositiveFloat::new(1_f64)?;
+    /// let max = PositiveFloat::new(2.5_f64)?;
+    /// assert_eq!(PositiveFloat::new(0.5_f64)?.clamp(min, max), min);
+    /// assert_eq!(
+    ///     PositiveFloat::new(1.5_f64)?.clamp(min, max).float(),
+    ///     1.5_f64
+    /// );
+    /// assert_eq!(PositiveFloat::new(3_f64)?.clamp(min, max), max);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::same_name_method)] // reason = "it mirrors Ord::clamp"
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+
+    /// Restrict the value to the interval `[min, max]`, see [`Self::clamp`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::InvertedBounds`] if `min > max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// let min = PositiveFloat::new(1_f64)?;
+    /// let max = PositiveFloat::new(2.5_f64)?;
+    /// assert_eq!(PositiveFloat::new(3_f64)?.checked_clamp(min, max), Ok(max));
+    /// assert_eq!(
+    ///     PositiveFloat::new(3_f64)?.checked_clamp(max, min),
+    ///     Err(ConversionError::InvertedBounds)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn checked_clamp(self, min: Self, max: Self) -> Result<Self, ConversionError> {
+        if min > max {
+            Err(ConversionError::InvertedBounds)
+        } else {
+            Ok(Ord::clamp(self, min, max))
+        }
+    }
+
+    /// Get a value implementing [`Display`] rendering the ratio `self / denominator` as the
+    /// fraction and its percentage, like `"3/4 (75%)"`.
+    /// The precision flag is only applied on the percentage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// let num = PositiveFloat::new(3_f64)?;
+    /// let den = PositiveFloat::new(4_f64)?;
+    /// assert_eq!(num.display_ratio(den).to_string(), "3/4 (75%)");
+    ///
+    /// let den = PositiveFloat::new(9_f64)?;
+    /// assert_eq!(format!("{:.1}", num.display_ratio(den)), "3/9 (33.3%)");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_ratio(self, denominator: Self) -> impl Display {
+        RatioDisplay::new(self, denominator)
+    }
+
+    /// Convert a number of seconds to a [`Duration`], saturating at [`Duration::MAX`].
+    ///
+    /// The precision of a [`Duration`] is the nanosecond, the value is rounded to the nearest
+    /// nanosecond, like [`Duration::from_secs_f64`].
+    /// Use [`Duration::try_from`] to get an error instead of saturating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// assert_eq!(
+    ///     PositiveFloat::new(1.5_f64)?.to_duration(),
+    ///     Duration::from_millis(1500)
+    /// );
+    /// assert_eq!(
+    ///     PositiveFloat::new(1.2E-9_f64)?.to_duration(),
+    ///     Duration::from_nanos(1)
+    /// );
+    /// assert_eq!(PositiveFloat::MAX.to_duration(), Duration::MAX);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_duration(self) -> Duration {
+        Duration::try_from_secs_f64(self.float()).unwrap_or(Duration::MAX)
+    }
+
+    /// Get the number of seconds of a [`Duration`]. The result is always valid but it is
+    /// rounded to the nearest [`f64`] for long durations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use utils_lib::PositiveFloat;
+    ///
+    /// assert_eq!(
+    ///     PositiveFloat::from_duration(Duration::from_millis(250)).float(),
+    ///     0.25_f64
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_duration(duration: Duration) -> Self {
+        Self(duration.as_secs_f64())
+    }
+
+    /// Get the reciprocal `1 / self`, to convert a period in seconds into a rate per second
+    /// and vice versa.
+    ///
+    /// A zero value (or a value so small that its reciprocal overflows) saturates at
+    /// [`Self::MAX`] as an infinite rate is not valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::PositiveFloat;
+    /// # use utils_lib::number::positive_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// // a period of 0.25 s is a rate of 4 per second
+    /// assert_eq!(
+    ///     PositiveFloat::new(0.25_f64)?.per_second(),
+    ///     PositiveFloat::new(4_f64)?
+    /// );
+    /// assert_eq!(PositiveFloat::ZERO.per_second(), PositiveFloat::MAX);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn per_second(self) -> Self {
+        Self::new_or_bounded(self.float().recip())
+    }
 }
 
 impl AsRef<f64> for PositiveFloat {
@@ -380,6 +978,8 @@ pub enum ConversionError {
     Nan,
     /// The float is too big, i.e. [`f64::INFINITY`]
     Infinity,
+    /// The lower bound of a range is above its upper bound, see [`PositiveFloat::checked_clamp`]
+    InvertedBounds,
 }
 
 impl Display for ConversionError {
@@ -389,6 +989,7 @@ impl Display for ConversionError {
             Self::Infinity => write!(f, "the float is infinity"),
             Self::Nan => write!(f, "the float is not a number"),
             Self::TooLow => write!(f, "the float is below zero"),
+            Self::InvertedBounds => write!(f, "the minimum is above the maximum"),
         }
     }
 }
@@ -397,23 +998,37 @@ impl Error for ConversionError {
     #[inline]
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::Infinity | Self::Nan | Self::TooLow => None,
+            Self::Infinity | Self::Nan | Self::TooLow | Self::InvertedBounds => None,
         }
     }
 }
 
 impl From<ZeroOneBoundedFloat> for PositiveFloat {
-    #[cfg(debug_assertions)]
     #[inline]
     fn from(value: ZeroOneBoundedFloat) -> Self {
-        Self::new(value.float()).expect("the value could not be converted as it is not valid")
+        // SAFETY: a ZeroOneBoundedFloat is in [0, 1] so it is a valid PositiveFloat
+        unsafe { Self::new_unchecked(value.float()) }
     }
+}
 
-    #[cfg(not(debug_assertions))]
+impl From<Duration> for PositiveFloat {
     #[inline]
-    fn from(value: ZeroOneBoundedFloat) -> Self {
-        //unsafe { Self::new_unchecked(value.float()) }
-        Self::new_or_bounded(value.float())
+    fn from(value: Duration) -> Self {
+        Self::from_duration(value)
+    }
+}
+
+impl TryFrom<PositiveFloat> for Duration {
+    type Error = TryFromFloatSecsError;
+
+    /// Convert a number of seconds to a [`Duration`], see [`PositiveFloat::to_duration`]
+    /// for the saturating conversion.
+    ///
+    /// # Errors
+    /// Returns an error if the value is too big to fit in a [`Duration`].
+    #[inline]
+    fn try_from(value: PositiveFloat) -> Result<Self, Self::Error> {
+        Self::try_from_secs_f64(value.float())
     }
 }
 
@@ -450,10 +1065,7 @@ impl<'a> From<&'a mut PositiveFloat> for ValidationGuard<'a, PositiveFloat> {
 impl Validation for PositiveFloat {
     #[inline]
     fn validate_data(t: f64) -> bool {
-        matches!(
-            t.classify(),
-            FpCategory::Normal | FpCategory::Subnormal | FpCategory::Zero
-        ) && t >= 0_f64
+        Self::is_valid(t)
     }
 
     #[inline]
@@ -464,13 +1076,187 @@ impl Validation for PositiveFloat {
             BoundRange::LowerBound | BoundRange::Nan => 0_f64,
         }
     }
+
+    /// Same as [`Self::set_float`], the negative values and [`f64::NAN`] are set to 0 and [`f64::INFINITY`] to [`f64::MAX`].
+    #[inline]
+    fn set_float_clamped(&mut self, float: f64) {
+        self.set_float(float);
+    }
+
+    /// The invalid values are set to 0, the default value.
+    #[inline]
+    fn set_float_default(&mut self, float: f64) {
+        self.0 = if Self::validate_data(float) {
+            float
+        } else {
+            0_f64
+        };
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use super::{ConversionError, PositiveFloat};
     use crate::ValidationGuard;
 
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "exact values"
+    fn const_constructors() {
+        const BOUNDED: [PositiveFloat; 4] = [
+            PositiveFloat::new_or_bounded(-1_f64),
+            PositiveFloat::new_or_bounded(2.5_f64),
+            PositiveFloat::new_or_bounded(f64::INFINITY),
+            PositiveFloat::new_or_bounded(f64::NAN),
+        ];
+        const DEFAULT: PositiveFloat = PositiveFloat::new_or_default(f64::INFINITY);
+        const SATURATING: PositiveFloat = PositiveFloat::new_saturating(-2_f64);
+        const OK: Option<PositiveFloat> = PositiveFloat::new_ok(3_f64);
+        const NONE: Option<PositiveFloat> = PositiveFloat::new_ok(-3_f64);
+        const NEW: Result<PositiveFloat, ConversionError> = PositiveFloat::new(f64::INFINITY);
+        const FROM: Option<PositiveFloat> = PositiveFloat::from_f64(1_f64);
+
+        assert_eq!(
+            BOUNDED.map(PositiveFloat::float),
+            [0_f64, 2.5_f64, f64::MAX, 0_f64]
+        );
+        assert_eq!(DEFAULT, PositiveFloat::ZERO);
+        assert_eq!(SATURATING, PositiveFloat::ZERO);
+        assert_eq!(OK.map(PositiveFloat::float), Some(3_f64));
+        assert_eq!(NONE, None);
+        assert_eq!(NEW, Err(ConversionError::Infinity));
+        assert_eq!(FROM, Some(PositiveFloat::ONE));
+        for float in [
+            -1_f64,
+            -0_f64,
+            0_f64,
+            1.5_f64,
+            f64::MAX,
+            f64::NAN,
+            f64::INFINITY,
+        ] {
+            assert_eq!(PositiveFloat::new_ok(float), PositiveFloat::new(float).ok());
+            assert_eq!(
+                PositiveFloat::new_or_default(float),
+                PositiveFloat::new(float).unwrap_or_default()
+            );
+        }
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the values are exact"
+    #[test]
+    fn new_unchecked() {
+        for float in [0_f64, 0.5_f64, 1E300_f64, f64::MAX, f64::MIN_POSITIVE] {
+            // SAFETY: the values are valid
+            let unchecked = unsafe { PositiveFloat::new_unchecked(float) };
+            assert_eq!(unchecked.float(), float);
+            assert_eq!(PositiveFloat::new_saturating(float), unchecked);
+        }
+        assert_eq!(PositiveFloat::new_saturating(-1_f64), PositiveFloat::ZERO);
+        assert_eq!(
+            PositiveFloat::new_saturating(f64::INFINITY),
+            PositiveFloat::MAX
+        );
+        assert_eq!(PositiveFloat::new_saturating(f64::NAN), PositiveFloat::ZERO);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the float is not valid")]
+    fn new_unchecked_invalid() {
+        // SAFETY: it is not safe, but the debug assertion panics before the value is used
+        let _float = unsafe { PositiveFloat::new_unchecked(f64::INFINITY) };
+    }
+
+    #[test]
+    fn debug() -> Result<(), ConversionError> {
+        assert_eq!(
+            format!("{:?}", PositiveFloat::new(2.5_f64)?),
+            "PositiveFloat(2.5)"
+        );
+        assert_eq!(format!("{:?}", PositiveFloat::ZERO), "PositiveFloat(0.0)");
+        assert_eq!(format!("{:.2?}", PositiveFloat::ONE), "PositiveFloat(1.00)");
+        // invalid values can only be built in this module, without the debug assertion
+        assert_eq!(
+            format!("{:?}", PositiveFloat(-1_f64)),
+            "PositiveFloat(-1.0 [INVALID])"
+        );
+        assert_eq!(
+            format!("{:?}", PositiveFloat(f64::NAN)),
+            "PositiveFloat(NaN [INVALID])"
+        );
+        assert_eq!(
+            format!("{:#?}", PositiveFloat(f64::INFINITY)),
+            "PositiveFloat(\n    inf [INVALID],\n)"
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the values are exact"
+    #[test]
+    fn duration() -> Result<(), ConversionError> {
+        // sub-second
+        let float = PositiveFloat::new(0.125_f64)?;
+        assert_eq!(float.to_duration(), Duration::from_millis(125));
+        assert_eq!(Duration::try_from(float), Ok(Duration::from_millis(125)));
+        assert_eq!(PositiveFloat::from(Duration::from_millis(125)), float);
+        // rounded to the nearest nanosecond
+        assert_eq!(
+            PositiveFloat::new(2.5E-10_f64)?.to_duration(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            PositiveFloat::new(1.6E-9_f64)?.to_duration(),
+            Duration::from_nanos(2)
+        );
+        assert_eq!(PositiveFloat::ZERO.to_duration(), Duration::ZERO);
+        assert_eq!(
+            PositiveFloat::from_duration(Duration::ZERO),
+            PositiveFloat::ZERO
+        );
+
+        // multi-hour
+        let duration = Duration::from_hours(5) + Duration::from_millis(1);
+        let float = PositiveFloat::from_duration(duration);
+        assert_eq!(float.float(), 18_000.001_f64);
+        assert_eq!(float.to_duration().as_millis(), duration.as_millis());
+
+        // huge values
+        assert_eq!(PositiveFloat::MAX.to_duration(), Duration::MAX);
+        Duration::try_from(PositiveFloat::MAX).expect_err("overflow");
+        let float = PositiveFloat::new(1E19_f64)?;
+        assert_eq!(
+            float.to_duration(),
+            Duration::from_secs(10_000_000_000_000_000_000)
+        );
+        let float = PositiveFloat::from_duration(Duration::MAX);
+        // the conversion rounds up above Duration::MAX
+        assert_eq!(float.to_duration(), Duration::MAX);
+        Duration::try_from(float).expect_err("overflow");
+
+        Ok(())
+    }
+
+    #[test]
+    fn per_second() -> Result<(), ConversionError> {
+        assert_eq!(
+            PositiveFloat::new(0.5_f64)?.per_second(),
+            PositiveFloat::new(2_f64)?
+        );
+        assert_eq!(PositiveFloat::ONE.per_second(), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ZERO.per_second(), PositiveFloat::MAX);
+        assert_eq!(
+            PositiveFloat::new(f64::from_bits(1))?.per_second(),
+            PositiveFloat::MAX
+        );
+        assert_eq!(
+            PositiveFloat::MAX.per_second().per_second(),
+            PositiveFloat::MAX
+        );
+        Ok(())
+    }
+
     #[test]
     fn positive_float_const() -> Result<(), ConversionError> {
         assert_eq!(PositiveFloat::default(), PositiveFloat::new(0_f64)?);
@@ -578,6 +1364,116 @@ mod test {
             format!("{:.1e}", PositiveFloat::new(1.234_56e+10_f64)?),
             "1.2e10"
         );
+        assert_eq!(
+            format!("{:>10.3}", PositiveFloat::new(1.234_56_f64)?),
+            "     1.235"
+        );
+        assert_eq!(
+            format!("{:*<9.2}", PositiveFloat::new(1.234_56_f64)?),
+            "1.23*****"
+        );
+        assert_eq!(
+            format!("{:^+9.1}", PositiveFloat::new(1.234_56_f64)?),
+            "  +1.2   "
+        );
+        assert_eq!(
+            format!("{:010.2e}", PositiveFloat::new(1.234_56e+10_f64)?),
+            "0001.23e10"
+        );
+
+        // the flags are applied exactly like on a plain f64
+        for float in [0_f64, 1.234_56_f64, 1_f64, 1.234_56E+10_f64] {
+            let wrapped = PositiveFloat::new(float)?;
+            assert_eq!(format!("{wrapped:>12.4}"), format!("{float:>12.4}"));
+            assert_eq!(format!("{wrapped:#<7}"), format!("{float:#<7}"));
+            assert_eq!(format!("{wrapped:^14.3e}"), format!("{float:^14.3e}"));
+            assert_eq!(format!("{wrapped:+012.1E}"), format!("{float:+012.1E}"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "exact values"
+    fn closed_powers() -> Result<(), ConversionError> {
+        const CUBE: PositiveFloat = PositiveFloat::new_or_bounded(3_f64).cubed();
+        assert_eq!(CUBE.float(), 27_f64);
+
+        for float in [0_f64, 0.3_f64, 1_f64, 1.5_f64, 2_f64, 7.25_f64, 1E10_f64] {
+            let wrapped = PositiveFloat::new(float)?;
+            assert_eq!(wrapped.squared().float(), float * float);
+            assert_eq!(wrapped.cubed().float(), float * float * float);
+            assert_eq!(wrapped.powi_fast(0), PositiveFloat::ONE);
+            assert_eq!(wrapped.powi_fast(1), wrapped);
+            assert_eq!(wrapped.powi_fast(2), wrapped.squared());
+            for n in [3_u32, 5_u32, 10_u32, 17_u32, 30_u32] {
+                let expected = PositiveFloat::new_or_bounded(float.powf(f64::from(n)));
+                assert!(
+                    wrapped.powi_fast(n).max_ulp_diff(expected) <= u64::from(n),
+                    "{float}^{n}: {} != {expected}",
+                    wrapped.powi_fast(n)
+                );
+            }
+        }
+
+        // boundaries
+        assert_eq!(PositiveFloat::MAX.squared(), PositiveFloat::MAX);
+        assert_eq!(PositiveFloat::MAX.cubed(), PositiveFloat::MAX);
+        assert_eq!(PositiveFloat::MAX.powi_fast(1), PositiveFloat::MAX);
+        assert_eq!(PositiveFloat::MAX.powi_fast(u32::MAX), PositiveFloat::MAX);
+        assert_eq!(PositiveFloat::MAX.powi_fast(0), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ONE.squared(), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ONE.powi_fast(u32::MAX), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ZERO.squared(), PositiveFloat::ZERO);
+        assert_eq!(PositiveFloat::ZERO.cubed(), PositiveFloat::ZERO);
+        assert_eq!(PositiveFloat::ZERO.powi_fast(0), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ZERO.powi_fast(u32::MAX), PositiveFloat::ZERO);
+        assert_eq!(
+            PositiveFloat::new(2_f64)?.powi_fast(1023).float(),
+            2_f64.powi(1023)
+        );
+        assert_eq!(
+            PositiveFloat::new(2_f64)?.powi_fast(1024),
+            PositiveFloat::MAX
+        );
+        assert_eq!(
+            PositiveFloat::new(0.5_f64)?.powi_fast(2000),
+            PositiveFloat::ZERO
+        );
         Ok(())
     }
+
+    #[test]
+    fn clamp() -> Result<(), ConversionError> {
+        let min = PositiveFloat::new(1_f64)?;
+        let max = PositiveFloat::new(4_f64)?;
+        let inside = PositiveFloat::new(2.5_f64)?;
+
+        assert_eq!(PositiveFloat::ZERO.clamp(min, max), min);
+        assert_eq!(inside.clamp(min, max), inside);
+        assert_eq!(PositiveFloat::MAX.clamp(min, max), max);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(PositiveFloat::ZERO.clamp(inside, inside), inside);
+        assert_eq!(PositiveFloat::MAX.clamp(inside, inside), inside);
+        assert_eq!(
+            inside.clamp(PositiveFloat::ZERO, PositiveFloat::MAX),
+            inside
+        );
+
+        assert_eq!(PositiveFloat::ZERO.checked_clamp(min, max), Ok(min));
+        assert_eq!(inside.checked_clamp(min, max), Ok(inside));
+        assert_eq!(PositiveFloat::MAX.checked_clamp(min, max), Ok(max));
+        assert_eq!(inside.checked_clamp(inside, inside), Ok(inside));
+        assert_eq!(
+            inside.checked_clamp(max, min),
+            Err(ConversionError::InvertedBounds)
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "min <= max")]
+    fn clamp_inverted_bounds() {
+        let _float = PositiveFloat::ONE.clamp(PositiveFloat::MAX, PositiveFloat::ZERO);
+    }
 }
@@ -1,11 +1,16 @@
 //! mod to separate the implementation of [`num_traits`] traits for [`PositiveFloat`]
 
+#[cfg(feature = "std")]
+use num_traits::Pow;
 use num_traits::{
-    AsPrimitive, Bounded, CheckedAdd, CheckedDiv, CheckedMul, FloatConst, Inv, MulAdd,
-    MulAddAssign, NumCast, One, Pow, SaturatingAdd, SaturatingMul, ToBytes, ToPrimitive, Zero,
+    AsPrimitive, Bounded, CheckedAdd, CheckedDiv, CheckedMul, FloatConst, FromPrimitive, Inv,
+    MulAdd, MulAddAssign, NumCast, One, SaturatingAdd, SaturatingMul, ToBytes, ToPrimitive, Zero,
 };
 
-use super::PositiveFloat;
+#[cfg(feature = "std")]
+use super::super::pow_unsigned;
+use super::{super::mul_add, PositiveFloat};
+#[cfg(feature = "std")]
 use crate::ZeroOneBoundedFloat;
 
 impl Zero for PositiveFloat {
@@ -129,12 +134,48 @@ impl NumCast for PositiveFloat {
     }
 }
 
+/// The conversions are validating and return [`None`] for values out of the range of the
+/// type. The reverse of [`AsPrimitive`], `AsPrimitive<PositiveFloat>` for the primitive types, is
+/// not implemented as [`AsPrimitive::as_`] cannot fail.
+impl FromPrimitive for PositiveFloat {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::new(n.into()).ok()
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::new(n).ok()
+    }
+}
+
 // impl Unsigned for PositiveFloat {}
 
 // impl Num for PositiveFloat {}
 
 // impl NumOps for PositiveFloat {}
 
+#[cfg(feature = "std")]
 impl Pow<Self> for PositiveFloat {
     type Output = Self;
 
@@ -144,6 +185,7 @@ impl Pow<Self> for PositiveFloat {
     }
 }
 
+#[cfg(feature = "std")]
 impl Pow<ZeroOneBoundedFloat> for PositiveFloat {
     type Output = Self;
 
@@ -153,6 +195,7 @@ impl Pow<ZeroOneBoundedFloat> for PositiveFloat {
     }
 }
 
+#[cfg(feature = "std")]
 impl Pow<f64> for PositiveFloat {
     type Output = Self;
 
@@ -170,6 +213,45 @@ impl Pow<f64> for PositiveFloat {
     }
 }
 
+/// Implement [`Pow`] with an unsigned integer exponent for [`PositiveFloat`].
+/// The result can overflow, it panics in debug and gives [`PositiveFloat::MAX`] in release.
+macro_rules! impl_pow_unsigned {
+    ($ty:ty) => {
+        #[cfg(feature = "std")]
+        impl Pow<$ty> for PositiveFloat {
+            type Output = Self;
+
+            #[cfg(debug_assertions)]
+            #[inline]
+            fn pow(self, rhs: $ty) -> Self::Output {
+                Self::new(pow_unsigned(self.float(), rhs)).expect("value not valid")
+            }
+
+            #[cfg(not(debug_assertions))]
+            #[inline]
+            fn pow(self, rhs: $ty) -> Self::Output {
+                Self::new_or_bounded(pow_unsigned(self.float(), rhs))
+            }
+        }
+    };
+}
+
+impl_pow_unsigned!(u8);
+impl_pow_unsigned!(u16);
+impl_pow_unsigned!(u32);
+
+/// A negative exponent of zero gives infinity, it panics in debug and gives
+/// [`PositiveFloat::MAX`] in release, see [`PositiveFloat::powi`].
+#[cfg(feature = "std")]
+impl Pow<i32> for PositiveFloat {
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, rhs: i32) -> Self::Output {
+        self.powi(rhs)
+    }
+}
+
 impl ToBytes for PositiveFloat {
     type Bytes = <f64 as ToBytes>::Bytes;
 
@@ -229,14 +311,14 @@ impl MulAdd for PositiveFloat {
     #[inline]
     #[cfg(debug_assertions)]
     fn mul_add(self, a: Self, b: Self) -> Self::Output {
-        let mul_add = self.float().mul_add(a.float(), b.float());
+        let mul_add = mul_add(self.float(), a.float(), b.float());
         Self::new(mul_add).expect("invalid value")
     }
 
     #[inline]
     #[cfg(not(debug_assertions))]
     fn mul_add(self, a: Self, b: Self) -> Self::Output {
-        let mul_add = self.float().mul_add(a.float(), b.float());
+        let mul_add = mul_add(self.float(), a.float(), b.float());
         //unsafe { Self::new_unchecked(mul_add) }
         Self::new_or_bounded(mul_add)
     }
@@ -279,15 +361,58 @@ impl SaturatingMul for PositiveFloat {
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "std")]
     use std::error::Error;
 
     use num_traits::{
-        Bounded, CheckedAdd, CheckedDiv, CheckedMul, FloatConst, Inv, One, Pow, SaturatingAdd,
-        SaturatingMul, Zero,
+        Bounded, CheckedAdd, CheckedDiv, CheckedMul, FromPrimitive, Inv, One, SaturatingAdd,
+        SaturatingMul, ToPrimitive, Zero,
     };
+    #[cfg(feature = "std")]
+    use num_traits::{FloatConst, Pow};
 
     use super::PositiveFloat;
-    use crate::{number::PositiveFloatConversionError, ZeroOneBoundedFloat};
+    use crate::number::PositiveFloatConversionError;
+    #[cfg(feature = "std")]
+    use crate::ZeroOneBoundedFloat;
+
+    /// Convert a value to `T` and back to a [`f64`] through the [`FromPrimitive`] and
+    /// [`ToPrimitive`] traits.
+    fn round_trip<T: FromPrimitive + ToPrimitive>(value: f64) -> Option<f64> {
+        T::from_f64(value)?.to_f64()
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn from_primitive() {
+        assert_eq!(round_trip::<PositiveFloat>(2.5_f64), Some(2.5_f64));
+        assert_eq!(round_trip::<PositiveFloat>(0_f64), Some(0_f64));
+        assert_eq!(round_trip::<PositiveFloat>(-1_f64), None);
+        assert_eq!(round_trip::<PositiveFloat>(f64::INFINITY), None);
+        assert_eq!(round_trip::<PositiveFloat>(f64::NAN), None);
+
+        assert_eq!(
+            PositiveFloat::from_u32(7_u32).and_then(|f| f.to_u32()),
+            Some(7_u32)
+        );
+        assert_eq!(
+            PositiveFloat::from_i8(-3_i8).map(PositiveFloat::float),
+            None
+        );
+        assert_eq!(
+            PositiveFloat::from_u128(u128::MAX).map(PositiveFloat::float),
+            Some(u128::MAX.to_f64().expect("u128 to f64 always works"))
+        );
+        assert_eq!(
+            PositiveFloat::from_f32(0.5_f32).map(PositiveFloat::float),
+            Some(0.5_f64)
+        );
+        assert_eq!(PositiveFloat::from_f32(f32::NAN), None);
+        assert_eq!(
+            <PositiveFloat as FromPrimitive>::from_f64(1.5_f64),
+            PositiveFloat::from_f64(1.5_f64)
+        );
+    }
 
     #[allow(clippy::float_cmp)]
     #[test]
@@ -301,6 +426,7 @@ mod test {
         assert_eq!(PositiveFloat::one().float(), 1_f64);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn pow() -> Result<(), Box<dyn Error>> {
         assert_eq!(
@@ -343,6 +469,82 @@ mod test {
         Ok(())
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn pow_integer() -> Result<(), Box<dyn Error>> {
+        assert_eq!(PositiveFloat::ONE.pow(32_u32), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ONE.pow(32_u8), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ONE.pow(u32::MAX), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ONE.pow(-32_i32), PositiveFloat::ONE);
+
+        assert_eq!(
+            PositiveFloat::new(2_f64)?.pow(2_u32),
+            PositiveFloat::new(4_f64)?
+        );
+        assert_eq!(
+            PositiveFloat::new(2_f64)?.pow(10_u16),
+            PositiveFloat::new(1024_f64)?
+        );
+        assert_eq!(
+            PositiveFloat::new(2_f64)?.pow(-2_i32),
+            PositiveFloat::new(0.25_f64)?
+        );
+        assert_eq!(
+            PositiveFloat::new(0.5_f64)?.pow(u32::MAX),
+            PositiveFloat::ZERO
+        );
+        assert_eq!(PositiveFloat::ZERO.pow(0_u8), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ZERO.pow(0_i32), PositiveFloat::ONE);
+        assert_eq!(PositiveFloat::ZERO.pow(3_u32), PositiveFloat::ZERO);
+
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.5_f64)?.pow(2_u32),
+            ZeroOneBoundedFloat::new(0.25_f64)?
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.5_f64)?.pow(3_u8),
+            ZeroOneBoundedFloat::new(0.125_f64)?
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.5_f64)?.pow(1_u16),
+            ZeroOneBoundedFloat::new(0.5_f64)?
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new(0.5_f64)?.pow(u32::MAX),
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO.pow(0_u32),
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE.pow(u32::MAX),
+            ZeroOneBoundedFloat::ONE
+        );
+
+        // same result as with a float exponent
+        for i in 0_u8..10_u8 {
+            let p = PositiveFloat::new(1.5_f64)?;
+            assert!((p.pow(i).float() - p.pow(f64::from(i)).float()).abs() < 1E-12_f64);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "value not valid")]
+    fn pow_integer_zero_negative() {
+        let _: PositiveFloat = PositiveFloat::ZERO.pow(-1_i32);
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn pow_integer_zero_negative() {
+        assert_eq!(PositiveFloat::ZERO.pow(-1_i32), PositiveFloat::MAX);
+    }
+
     #[test]
     fn inv() -> Result<(), PositiveFloatConversionError> {
         assert_eq!(PositiveFloat::ONE.inv(), PositiveFloat::ONE);
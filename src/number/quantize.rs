@@ -0,0 +1,205 @@
+//! Contains quantization functions: [`round_to_step`], [`floor_to_step`], [`ceil_to_step`]
+//! and [`snap`].
+
+use num_traits::float::FloatCore;
+
+use super::{compare_f64, PositiveFloat};
+
+/// Apply `rounding` to `value` expressed in units of `step` and convert it back.
+///
+/// # Panics
+/// Panics if `step` is zero and `debug_assertions` are enabled.
+fn to_step(value: f64, step: PositiveFloat, rounding: fn(f64) -> f64) -> f64 {
+    debug_assert!(step != PositiveFloat::ZERO, "the step must not be zero");
+    rounding(value / step.float()) * step.float()
+}
+
+/// Round `value` to the nearest multiple of `step`. The ties are rounded away from zero,
+/// like [`f64::round`], so `0.125` with a step of `0.25` gives `0.25` and `-0.125` gives
+/// `-0.25`.
+///
+/// # Panics
+/// Panics if `step` is zero and `debug_assertions` are enabled, otherwise the result is
+/// [`f64::NAN`].
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::round_to_step, PositiveFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let step = PositiveFloat::new(0.25_f64)?;
+/// assert_eq!(round_to_step(0.3_f64, step), 0.25_f64);
+/// assert_eq!(round_to_step(0.4_f64, step), 0.5_f64);
+/// assert_eq!(round_to_step(-0.4_f64, step), -0.5_f64);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+#[must_use]
+pub fn round_to_step(value: f64, step: PositiveFloat) -> f64 {
+    to_step(value, step, FloatCore::round)
+}
+
+/// Round `value` down to the greatest multiple of `step` that is not above it.
+///
+/// # Panics
+/// Panics if `step` is zero and `debug_assertions` are enabled, otherwise the result is
+/// [`f64::NAN`].
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::floor_to_step, PositiveFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let step = PositiveFloat::new(0.25_f64)?;
+/// assert_eq!(floor_to_step(0.4_f64, step), 0.25_f64);
+/// assert_eq!(floor_to_step(-0.1_f64, step), -0.25_f64);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+#[must_use]
+pub fn floor_to_step(value: f64, step: PositiveFloat) -> f64 {
+    to_step(value, step, FloatCore::floor)
+}
+
+/// Round `value` up to the smallest multiple of `step` that is not below it.
+///
+/// # Panics
+/// Panics if `step` is zero and `debug_assertions` are enabled, otherwise the result is
+/// [`f64::NAN`].
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::ceil_to_step, PositiveFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let step = PositiveFloat::new(0.25_f64)?;
+/// assert_eq!(ceil_to_step(0.1_f64, step), 0.25_f64);
+/// assert_eq!(ceil_to_step(-0.4_f64, step), -0.25_f64);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+#[must_use]
+pub fn ceil_to_step(value: f64, step: PositiveFloat) -> f64 {
+    to_step(value, step, FloatCore::ceil)
+}
+
+/// Returns the candidate the nearest to `value`, or `value` itself if `candidates` is empty.
+/// If two candidates are at the same distance the first one in `candidates` is returned, in
+/// particular if `value` is infinite and the candidates are finite.
+///
+/// # Panics
+/// Panics if the distance to a candidate is [`f64::NAN`] while another one is finite, i.e.
+/// if `value` or a candidate is [`f64::NAN`], or if `value` and a candidate are the same
+/// infinity.
+///
+/// # Example
+/// ```
+/// use utils_lib::number::snap;
+///
+/// let candidates = [0_f64, 0.5_f64, 1_f64];
+/// assert_eq!(snap(0.6_f64, &candidates), 0.5_f64);
+/// assert_eq!(snap(-3_f64, &candidates), 0_f64);
+/// // tie between 0.5 and 1, the first candidate is returned
+/// assert_eq!(snap(0.75_f64, &candidates), 0.5_f64);
+/// assert_eq!(snap(0.75_f64, &[]), 0.75_f64);
+/// ```
+#[inline]
+#[must_use]
+pub fn snap(value: f64, candidates: &[f64]) -> f64 {
+    candidates
+        .iter()
+        .copied()
+        .min_by(|a, b| compare_f64((a - value).abs(), (b - value).abs()))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ceil_to_step, floor_to_step, round_to_step, snap};
+    use crate::PositiveFloat;
+
+    #[allow(clippy::float_cmp)] // reason = "the multiples of 0.25 are exact"
+    #[test]
+    fn step() {
+        let step = PositiveFloat::new(0.25_f64).expect("valid float");
+        for (value, round, floor, ceil) in [
+            (0_f64, 0_f64, 0_f64, 0_f64),
+            (0.25_f64, 0.25_f64, 0.25_f64, 0.25_f64),
+            (0.3_f64, 0.25_f64, 0.25_f64, 0.5_f64),
+            (0.4_f64, 0.5_f64, 0.25_f64, 0.5_f64),
+            (1.1_f64, 1_f64, 1_f64, 1.25_f64),
+            (-0.1_f64, 0_f64, -0.25_f64, 0_f64),
+            (-0.3_f64, -0.25_f64, -0.5_f64, -0.25_f64),
+            (-1_f64, -1_f64, -1_f64, -1_f64),
+            (-2.6_f64, -2.5_f64, -2.75_f64, -2.5_f64),
+            // ties are rounded away from zero
+            (0.125_f64, 0.25_f64, 0_f64, 0.25_f64),
+            (0.375_f64, 0.5_f64, 0.25_f64, 0.5_f64),
+            (-0.125_f64, -0.25_f64, -0.25_f64, 0_f64),
+        ] {
+            assert_eq!(round_to_step(value, step), round, "round {value}");
+            assert_eq!(floor_to_step(value, step), floor, "floor {value}");
+            assert_eq!(ceil_to_step(value, step), ceil, "ceil {value}");
+        }
+
+        assert_eq!(
+            round_to_step(7_f64, PositiveFloat::new(5_f64).expect("valid")),
+            5_f64
+        );
+        assert_eq!(round_to_step(f64::INFINITY, step), f64::INFINITY);
+        assert!(round_to_step(f64::NAN, step).is_nan());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the step must not be zero")]
+    fn step_zero() {
+        assert!(
+            round_to_step(1_f64, PositiveFloat::ZERO).is_nan(),
+            "not reached"
+        );
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn step_zero() {
+        assert!(
+            round_to_step(1_f64, PositiveFloat::ZERO).is_nan(),
+            "zero step"
+        );
+        assert!(
+            floor_to_step(1_f64, PositiveFloat::ZERO).is_nan(),
+            "zero step"
+        );
+        assert!(
+            ceil_to_step(1_f64, PositiveFloat::ZERO).is_nan(),
+            "zero step"
+        );
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the candidates are returned unchanged"
+    #[test]
+    fn snap_values() {
+        let candidates = [-1_f64, 0_f64, 0.5_f64, 2_f64];
+        for (value, expected) in [
+            (0.1_f64, 0_f64),
+            (0.4_f64, 0.5_f64),
+            (-0.6_f64, -1_f64),
+            (-10_f64, -1_f64),
+            (100_f64, 2_f64),
+            // ties give the first candidate
+            (-0.5_f64, -1_f64),
+            (0.25_f64, 0_f64),
+            (1.25_f64, 0.5_f64),
+        ] {
+            assert_eq!(snap(value, &candidates), expected, "{value}");
+        }
+        assert_eq!(snap(1.25_f64, &[2_f64, 0.5_f64]), 2_f64);
+        assert_eq!(snap(3_f64, &[]), 3_f64);
+        // all the candidates are infinitely far
+        assert_eq!(snap(f64::INFINITY, &candidates), -1_f64);
+    }
+}
@@ -0,0 +1,187 @@
+//! Contains the [`rand`] helpers of the number types: the Bernoulli trial
+//! [`ZeroOneBoundedFloat::sample_bool`] and the weighted choice [`pick_index`].
+//!
+//! The module requires the `rand` feature.
+
+use rand::{distributions::Bernoulli, Rng};
+
+use super::{PositiveFloat, ZeroOneBoundedFloat};
+
+impl ZeroOneBoundedFloat {
+    /// Get `true` with probability `self`, a Bernoulli trial using [`Bernoulli`].
+    ///
+    /// Unlike [`Bernoulli::new`] it cannot fail as the value is always a valid probability.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// assert!(ZeroOneBoundedFloat::ONE.sample_bool(&mut rng));
+    /// assert!(!ZeroOneBoundedFloat::ZERO.sample_bool(&mut rng));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sample_bool<R: Rng + ?Sized>(self, rng: &mut R) -> bool {
+        // the error is impossible as the probability is in [0, 1]
+        Bernoulli::new(self.float()).is_ok_and(|bernoulli| rng.sample(bernoulli))
+    }
+}
+
+/// Pick an index of `weights` at random, each index with a probability proportional to its
+/// weight, like `SliceRandom::choose_weighted` of [`rand`] on the indices.
+///
+/// The weights are normalized by the biggest one before the cumulative sum, so that the sum
+/// cannot overflow. An index with a zero weight is never picked.
+///
+/// Returns [`None`] if `weights` is empty or if all the weights are zero.
+///
+/// # Example
+/// ```
+/// use rand::{rngs::SmallRng, SeedableRng};
+/// use utils_lib::{number::pick_index, PositiveFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut rng = SmallRng::seed_from_u64(42);
+/// let weights = [PositiveFloat::ZERO, PositiveFloat::new(2_f64)?];
+/// assert_eq!(pick_index(&weights, &mut rng), Some(1));
+///
+/// assert_eq!(pick_index(&[], &mut rng), None);
+/// assert_eq!(pick_index(&[PositiveFloat::ZERO], &mut rng), None);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+#[must_use]
+pub fn pick_index<R: Rng + ?Sized>(weights: &[PositiveFloat], rng: &mut R) -> Option<usize> {
+    let max = weights.iter().copied().max()?;
+    if max == PositiveFloat::ZERO {
+        return None;
+    }
+    let normalized = |weight: &PositiveFloat| weight.float() / max.float();
+    let total = weights.iter().map(normalized).sum::<f64>();
+    let target = rng.gen::<f64>() * total;
+
+    let mut cumulative = 0_f64;
+    let mut last_positive = None;
+    for (index, weight) in weights.iter().map(normalized).enumerate() {
+        if weight > 0_f64 {
+            cumulative += weight;
+            if target < cumulative {
+                return Some(index);
+            }
+            last_positive = Some(index);
+        }
+    }
+    // the rounding of the sum can leave the target above the last cumulative weight
+    last_positive
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use super::pick_index;
+    use crate::{number::PositiveFloatConversionError, PositiveFloat, ZeroOneBoundedFloat};
+
+    /// Number of samples drawn by the tests
+    const SAMPLES: u32 = 20_000;
+
+    /// Tolerance on the empirical frequencies, more than four standard deviations for
+    /// [`SAMPLES`] draws
+    const TOLERANCE: f64 = 0.015_f64;
+
+    #[test]
+    fn sample_bool() {
+        let mut rng = SmallRng::seed_from_u64(0x5eed_u64);
+        for probability in [0.1_f64, 0.25_f64, 0.5_f64, 0.9_f64] {
+            let float = ZeroOneBoundedFloat::new_or_bounded(probability);
+            let count = (0..SAMPLES).filter(|_| float.sample_bool(&mut rng)).count();
+            let frequency =
+                f64::from(u32::try_from(count).expect("count <= SAMPLES")) / f64::from(SAMPLES);
+            assert!(
+                (frequency - probability).abs() < TOLERANCE,
+                "frequency {frequency} for the probability {probability}"
+            );
+        }
+
+        for _ in 0..SAMPLES {
+            assert!(ZeroOneBoundedFloat::ONE.sample_bool(&mut rng));
+            assert!(!ZeroOneBoundedFloat::ZERO.sample_bool(&mut rng));
+        }
+
+        // seeded determinism
+        let draws = |seed: u64| {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            (0..100_u32)
+                .map(|_| ZeroOneBoundedFloat::new_or_bounded(0.5_f64).sample_bool(&mut rng))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draws(1_u64), draws(1_u64));
+    }
+
+    #[test]
+    fn pick_index_frequencies() -> Result<(), PositiveFloatConversionError> {
+        let mut rng = SmallRng::seed_from_u64(0x5eed_u64);
+        let weights = [
+            PositiveFloat::new(1_f64)?,
+            PositiveFloat::ZERO,
+            PositiveFloat::new(3_f64)?,
+            PositiveFloat::new(6_f64)?,
+        ];
+        let mut counts = [0_u32; 4];
+        for _ in 0..SAMPLES {
+            let index = pick_index(&weights, &mut rng).expect("positive weights");
+            counts[index] += 1;
+        }
+        assert_eq!(counts[1], 0, "zero weight picked");
+        for (count, expected) in counts.into_iter().zip([0.1_f64, 0_f64, 0.3_f64, 0.6_f64]) {
+            let frequency = f64::from(count) / f64::from(SAMPLES);
+            assert!(
+                (frequency - expected).abs() < TOLERANCE,
+                "frequency {frequency} expected {expected}"
+            );
+        }
+
+        // the sum of the weights overflows
+        let weights = [PositiveFloat::MAX, PositiveFloat::MAX];
+        let mut counts = [0_u32; 2];
+        for _ in 0..SAMPLES {
+            counts[pick_index(&weights, &mut rng).expect("positive weights")] += 1;
+        }
+        let frequency = f64::from(counts[0]) / f64::from(SAMPLES);
+        assert!(
+            (frequency - 0.5_f64).abs() < TOLERANCE,
+            "frequency {frequency}"
+        );
+
+        // seeded determinism
+        let draws = |seed: u64| {
+            let mut rng = SmallRng::seed_from_u64(seed);
+            (0..100_u32)
+                .map(|_| pick_index(&weights, &mut rng))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draws(1_u64), draws(1_u64));
+        Ok(())
+    }
+
+    #[test]
+    fn pick_index_none() -> Result<(), PositiveFloatConversionError> {
+        let mut rng = SmallRng::seed_from_u64(0x5eed_u64);
+        assert_eq!(pick_index(&[], &mut rng), None);
+        assert_eq!(pick_index(&[PositiveFloat::ZERO], &mut rng), None);
+        assert_eq!(pick_index(&[PositiveFloat::ZERO; 5], &mut rng), None);
+
+        let weights = [
+            PositiveFloat::ZERO,
+            PositiveFloat::new(1E-300_f64)?,
+            PositiveFloat::ZERO,
+        ];
+        for _ in 0..100_u32 {
+            assert_eq!(pick_index(&weights, &mut rng), Some(1));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+//! Contains [`Rate`], a throughput as a [`PositiveFloat`] count per second, with the
+//! arithmetic between counts, rates and [`Duration`].
+
+use core::{
+    fmt::{self, Display},
+    ops::{Div, Mul},
+    time::Duration,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::PositiveFloat;
+
+/// A rate, or throughput, a [`PositiveFloat`] count of events per second.
+///
+/// The unit conversions and the arithmetic with [`Duration`] saturate at the bounds of
+/// [`PositiveFloat`], i.e. [`Self::MAX`] for a rate and [`PositiveFloat::MAX`] for a count,
+/// instead of panicking:
+/// - `rate * duration` gives the [`PositiveFloat`] count of events during the duration,
+/// - `count / duration` gives the [`Rate`] of a count of events during the duration. A zero
+///   duration saturates at [`Self::MAX`], or gives [`Self::ZERO`] for a zero count.
+///
+/// It is displayed as the count per second followed by `/s`, like `12.5/s`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use utils_lib::{number::Rate, PositiveFloat};
+/// # use utils_lib::number::PositiveFloatConversionError;
+///
+/// # fn main() -> Result<(), PositiveFloatConversionError> {
+/// let rate = Rate::per_minute(PositiveFloat::new(750_f64)?);
+/// assert_eq!(rate.to_string(), "12.5/s");
+/// assert_eq!(rate * Duration::from_secs(4), PositiveFloat::new(50_f64)?);
+///
+/// let count = PositiveFloat::new(30_f64)?;
+/// assert_eq!(
+///     count / Duration::from_mins(1),
+///     Rate::per_second(PositiveFloat::new(0.5_f64)?)
+/// );
+/// assert_eq!(count / Duration::ZERO, Rate::MAX);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Rate(PositiveFloat);
+
+impl Rate {
+    /// No event.
+    pub const ZERO: Self = Self(PositiveFloat::ZERO);
+
+    /// The biggest rate, [`PositiveFloat::MAX`] events per second.
+    pub const MAX: Self = Self(PositiveFloat::MAX);
+
+    /// Number of seconds in a minute
+    const MINUTE: f64 = 60_f64;
+
+    /// Number of seconds in an hour
+    const HOUR: f64 = 3_600_f64;
+
+    /// Create a rate of `count` events per second.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Rate, PositiveFloat};
+    ///
+    /// const RATE: Rate = Rate::per_second(PositiveFloat::ONE);
+    /// assert_eq!(RATE.count_per_second(), PositiveFloat::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn per_second(count: PositiveFloat) -> Self {
+        Self(count)
+    }
+
+    /// Create a rate of `count` events per minute.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Rate, PositiveFloat};
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// assert_eq!(
+    ///     Rate::per_minute(PositiveFloat::new(60_f64)?),
+    ///     Rate::per_second(PositiveFloat::ONE)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn per_minute(count: PositiveFloat) -> Self {
+        Self(PositiveFloat::new_or_bounded(count.float() / Self::MINUTE))
+    }
+
+    /// Create a rate of `count` events per hour.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Rate, PositiveFloat};
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// assert_eq!(
+    ///     Rate::per_hour(PositiveFloat::new(7_200_f64)?),
+    ///     Rate::per_second(PositiveFloat::new(2_f64)?)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn per_hour(count: PositiveFloat) -> Self {
+        Self(PositiveFloat::new_or_bounded(count.float() / Self::HOUR))
+    }
+
+    /// Get the number of events per second.
+    #[inline]
+    #[must_use]
+    pub const fn count_per_second(self) -> PositiveFloat {
+        self.0
+    }
+
+    /// Get the number of events per minute, saturating at [`PositiveFloat::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Rate, PositiveFloat};
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let rate = Rate::per_second(PositiveFloat::new(0.5_f64)?);
+    /// assert_eq!(rate.count_per_minute(), PositiveFloat::new(30_f64)?);
+    /// assert_eq!(Rate::MAX.count_per_minute(), PositiveFloat::MAX);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn count_per_minute(self) -> PositiveFloat {
+        PositiveFloat::new_or_bounded(self.0.float() * Self::MINUTE)
+    }
+
+    /// Get the number of events per hour, saturating at [`PositiveFloat::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{number::Rate, PositiveFloat};
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let rate = Rate::per_second(PositiveFloat::new(0.5_f64)?);
+    /// assert_eq!(rate.count_per_hour(), PositiveFloat::new(1_800_f64)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn count_per_hour(self) -> PositiveFloat {
+        PositiveFloat::new_or_bounded(self.0.float() * Self::HOUR)
+    }
+
+    /// Get the number of events during `duration`, saturating at [`PositiveFloat::MAX`].
+    /// It is the same as `self * duration`.
+    #[inline]
+    #[must_use]
+    pub const fn count_during(self, duration: Duration) -> PositiveFloat {
+        PositiveFloat::new_or_bounded(self.0.float() * duration.as_secs_f64())
+    }
+
+    /// Get the rate of `count` events during `duration`. It is the same as
+    /// `count / duration`.
+    ///
+    /// A zero duration saturates at [`Self::MAX`], except for a zero count which gives
+    /// [`Self::ZERO`] as no event happened.
+    #[inline]
+    #[must_use]
+    pub const fn from_count(count: PositiveFloat, duration: Duration) -> Self {
+        Self(PositiveFloat::new_or_bounded(
+            count.float() / duration.as_secs_f64(),
+        ))
+    }
+}
+
+impl From<Rate> for PositiveFloat {
+    /// Get the number of events per second, see [`Rate::count_per_second`].
+    #[inline]
+    fn from(value: Rate) -> Self {
+        value.count_per_second()
+    }
+}
+
+impl Display for Rate {
+    /// Display the rate as the count per second followed by `/s`, like `12.5/s`. The
+    /// formatting options are applied on the count.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)?;
+        write!(f, "/s")
+    }
+}
+
+impl Mul<Duration> for Rate {
+    type Output = PositiveFloat;
+
+    /// The number of events during the duration, see [`Rate::count_during`].
+    #[inline]
+    fn mul(self, rhs: Duration) -> Self::Output {
+        self.count_during(rhs)
+    }
+}
+
+impl Mul<Rate> for Duration {
+    type Output = PositiveFloat;
+
+    /// The number of events during the duration, see [`Rate::count_during`].
+    #[inline]
+    fn mul(self, rhs: Rate) -> Self::Output {
+        rhs.count_during(self)
+    }
+}
+
+impl Div<Duration> for PositiveFloat {
+    type Output = Rate;
+
+    /// The rate of the count during the duration, see [`Rate::from_count`].
+    #[inline]
+    fn div(self, rhs: Duration) -> Self::Output {
+        Rate::from_count(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::Rate;
+    use crate::number::{PositiveFloat, PositiveFloatConversionError};
+
+    #[test]
+    fn unit_conversion() -> Result<(), PositiveFloatConversionError> {
+        assert_eq!(
+            Rate::per_minute(PositiveFloat::new(60_f64)?),
+            Rate::per_second(PositiveFloat::ONE)
+        );
+        assert_eq!(
+            Rate::per_hour(PositiveFloat::new(3_600_f64)?),
+            Rate::per_second(PositiveFloat::ONE)
+        );
+        assert_eq!(
+            Rate::per_hour(PositiveFloat::new(60_f64)?),
+            Rate::per_minute(PositiveFloat::ONE)
+        );
+
+        let rate = Rate::per_second(PositiveFloat::new(2.5_f64)?);
+        assert_eq!(rate.count_per_second(), PositiveFloat::new(2.5_f64)?);
+        assert_eq!(rate.count_per_minute(), PositiveFloat::new(150_f64)?);
+        assert_eq!(rate.count_per_hour(), PositiveFloat::new(9_000_f64)?);
+        assert_eq!(PositiveFloat::from(rate), PositiveFloat::new(2.5_f64)?);
+        assert_eq!(
+            Rate::per_minute(rate.count_per_minute()),
+            rate,
+            "round trip"
+        );
+
+        // saturation
+        assert_eq!(Rate::MAX.count_per_minute(), PositiveFloat::MAX);
+        assert_eq!(Rate::MAX.count_per_hour(), PositiveFloat::MAX);
+        assert_eq!(Rate::per_second(PositiveFloat::MAX), Rate::MAX);
+        assert!(Rate::per_hour(PositiveFloat::MAX) < Rate::MAX);
+        assert_eq!(Rate::per_minute(PositiveFloat::ZERO), Rate::ZERO);
+        assert_eq!(Rate::default(), Rate::ZERO);
+        Ok(())
+    }
+
+    #[test]
+    fn duration_arithmetic() -> Result<(), PositiveFloatConversionError> {
+        let rate = Rate::per_second(PositiveFloat::new(4_f64)?);
+        assert_eq!(
+            rate * Duration::from_millis(1_500),
+            PositiveFloat::new(6_f64)?
+        );
+        assert_eq!(
+            Duration::from_millis(1_500) * rate,
+            PositiveFloat::new(6_f64)?
+        );
+        assert_eq!(rate * Duration::ZERO, PositiveFloat::ZERO);
+        assert_eq!(Rate::MAX * Duration::from_secs(2), PositiveFloat::MAX);
+        assert_eq!(Rate::MAX * Duration::MAX, PositiveFloat::MAX);
+
+        let count = PositiveFloat::new(6_f64)?;
+        assert_eq!(count / Duration::from_millis(1_500), rate);
+        assert_eq!(count / Duration::from_mins(1), Rate::per_minute(count));
+        assert_eq!(count / Duration::MAX * Duration::MAX, count);
+
+        // zero durations
+        assert_eq!(count / Duration::ZERO, Rate::MAX);
+        assert_eq!(PositiveFloat::ZERO / Duration::ZERO, Rate::ZERO);
+        assert_eq!(PositiveFloat::MAX / Duration::from_nanos(1), Rate::MAX);
+
+        // comparison
+        assert!(Rate::per_minute(count) < Rate::per_second(count));
+        assert!(Rate::ZERO < rate && rate < Rate::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn display() -> Result<(), PositiveFloatConversionError> {
+        assert_eq!(
+            Rate::per_second(PositiveFloat::new(12.5_f64)?).to_string(),
+            "12.5/s"
+        );
+        assert_eq!(Rate::ZERO.to_string(), "0/s");
+        assert_eq!(
+            Rate::per_minute(PositiveFloat::new(20_f64)?).to_string(),
+            format!("{}/s", 20_f64 / 60_f64)
+        );
+        assert_eq!(
+            format!("{:.2}", Rate::per_minute(PositiveFloat::new(20_f64)?)),
+            "0.33/s"
+        );
+        assert_eq!(
+            format!("{:>6.1}", Rate::per_second(PositiveFloat::ONE)),
+            "   1.0/s"
+        );
+        Ok(())
+    }
+}
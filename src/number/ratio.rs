@@ -0,0 +1,568 @@
+//! Contains [`Ratio`], an exact rational number of unsigned integers, and its error
+//! [`ZeroDenominatorError`].
+
+use core::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use num_traits::{PrimInt, Unsigned};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{gcd, ZeroOneBoundedFloat};
+
+/// Exact rational number `numerator / denominator` of unsigned integers.
+///
+/// The ratio is always normalized: the numerator and the denominator are coprime and zero is
+/// `0/1`, so two equal ratios have the same fields. It covers the simple unsigned cases, for
+/// signed or big numbers use the crate `num-rational`.
+///
+/// # Example
+/// ```
+/// use utils_lib::number::Ratio;
+/// # use utils_lib::number::ZeroDenominatorError;
+///
+/// # fn main() -> Result<(), ZeroDenominatorError> {
+/// let half = Ratio::new(2_u32, 4_u32)?;
+/// assert_eq!(half.numerator(), 1_u32);
+/// assert_eq!(half.denominator(), 2_u32);
+///
+/// let third = Ratio::new(1_u32, 3_u32)?;
+/// assert_eq!((half + third).to_string(), "5/6");
+/// assert!(third < half);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RatioFields<T>",
+        bound(deserialize = "T: PrimInt + Unsigned + Deserialize<'de>")
+    )
+)]
+pub struct Ratio<T> {
+    /// numerator, coprime with the denominator
+    numerator: T,
+    /// denominator, never zero
+    denominator: T,
+}
+
+/// Fields of [`Ratio`] as deserialized before validation
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RatioFields<T> {
+    /// numerator
+    numerator: T,
+    /// denominator
+    denominator: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: PrimInt + Unsigned> TryFrom<RatioFields<T>> for Ratio<T> {
+    type Error = ZeroDenominatorError;
+
+    #[inline]
+    fn try_from(fields: RatioFields<T>) -> Result<Self, Self::Error> {
+        Self::new(fields.numerator, fields.denominator)
+    }
+}
+
+impl<T: PrimInt + Unsigned> Ratio<T> {
+    /// Create the ratio `numerator / denominator`, normalized.
+    ///
+    /// # Errors
+    /// Returns [`ZeroDenominatorError`] if the denominator is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::{Ratio, ZeroDenominatorError};
+    ///
+    /// let ratio = Ratio::new(6_u8, 9_u8).expect("non zero denominator");
+    /// assert_eq!((ratio.numerator(), ratio.denominator()), (2_u8, 3_u8));
+    /// assert_eq!(Ratio::new(0_u8, 9_u8), Ratio::new(0_u8, 1_u8));
+    /// assert_eq!(Ratio::new(1_u8, 0_u8), Err(ZeroDenominatorError));
+    /// ```
+    #[inline]
+    pub fn new(numerator: T, denominator: T) -> Result<Self, ZeroDenominatorError> {
+        if denominator.is_zero() {
+            Err(ZeroDenominatorError)
+        } else {
+            Ok(Self::normalized(numerator, denominator))
+        }
+    }
+
+    /// Create the ratio `integer / 1`.
+    #[inline]
+    #[must_use]
+    pub fn from_integer(integer: T) -> Self {
+        Self {
+            numerator: integer,
+            denominator: T::one(),
+        }
+    }
+
+    /// Normalize the ratio, the denominator must not be zero.
+    fn normalized(numerator: T, denominator: T) -> Self {
+        if numerator.is_zero() {
+            return Self::from_integer(T::zero());
+        }
+        let divisor = gcd(numerator, denominator);
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    /// Get the inverse `denominator / numerator`, [`None`] if the ratio is zero.
+    #[inline]
+    #[must_use]
+    pub fn recip(self) -> Option<Self> {
+        (!self.numerator.is_zero()).then_some(Self {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        })
+    }
+
+    /// Add two ratios, [`None`] if an intermediate computation overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Ratio;
+    ///
+    /// let a = Ratio::new(1_u8, 6_u8).expect("non zero denominator");
+    /// let b = Ratio::new(1_u8, 10_u8).expect("non zero denominator");
+    /// assert_eq!(a.checked_add(b), Ratio::new(4_u8, 15_u8).ok());
+    /// assert_eq!(
+    ///     Ratio::from_integer(200_u8).checked_add(Ratio::from_integer(100_u8)),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let divisor = gcd(self.denominator, rhs.denominator);
+        let lhs_factor = rhs.denominator / divisor;
+        let rhs_factor = self.denominator / divisor;
+        let numerator = T::checked_add(
+            &T::checked_mul(&self.numerator, &lhs_factor)?,
+            &T::checked_mul(&rhs.numerator, &rhs_factor)?,
+        )?;
+        let denominator = T::checked_mul(&self.denominator, &lhs_factor)?;
+        Some(Self::normalized(numerator, denominator))
+    }
+
+    /// Subtract two ratios, [`None`] if the result is negative or if an intermediate
+    /// computation overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Ratio;
+    ///
+    /// let a = Ratio::new(1_u8, 2_u8).expect("non zero denominator");
+    /// let b = Ratio::new(1_u8, 3_u8).expect("non zero denominator");
+    /// assert_eq!(a.checked_sub(b), Ratio::new(1_u8, 6_u8).ok());
+    /// assert_eq!(b.checked_sub(a), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let divisor = gcd(self.denominator, rhs.denominator);
+        let lhs_factor = rhs.denominator / divisor;
+        let rhs_factor = self.denominator / divisor;
+        let numerator = T::checked_sub(
+            &T::checked_mul(&self.numerator, &lhs_factor)?,
+            &T::checked_mul(&rhs.numerator, &rhs_factor)?,
+        )?;
+        let denominator = T::checked_mul(&self.denominator, &lhs_factor)?;
+        Some(Self::normalized(numerator, denominator))
+    }
+
+    /// Multiply two ratios, [`None`] if the result overflows. The factors are simplified
+    /// before the multiplication so only a result that is not representable overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Ratio;
+    ///
+    /// let a = Ratio::new(200_u8, 3_u8).expect("non zero denominator");
+    /// let b = Ratio::new(3_u8, 200_u8).expect("non zero denominator");
+    /// assert_eq!(a.checked_mul(b), Some(Ratio::from_integer(1_u8)));
+    /// assert_eq!(a.checked_mul(a), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        if self.numerator.is_zero() || rhs.numerator.is_zero() {
+            return Some(Self::from_integer(T::zero()));
+        }
+        let first = gcd(self.numerator, rhs.denominator);
+        let second = gcd(rhs.numerator, self.denominator);
+        Some(Self {
+            numerator: T::checked_mul(&(self.numerator / first), &(rhs.numerator / second))?,
+            denominator: T::checked_mul(&(self.denominator / second), &(rhs.denominator / first))?,
+        })
+    }
+
+    /// Divide two ratios, [`None`] if `rhs` is zero or if the result overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Ratio;
+    ///
+    /// let a = Ratio::new(1_u8, 2_u8).expect("non zero denominator");
+    /// let b = Ratio::new(3_u8, 4_u8).expect("non zero denominator");
+    /// assert_eq!(a.checked_div(b), Ratio::new(2_u8, 3_u8).ok());
+    /// assert_eq!(a.checked_div(Ratio::from_integer(0_u8)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip()?)
+    }
+
+    /// Convert the ratio to the nearest [`f64`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Ratio;
+    ///
+    /// let ratio = Ratio::new(3_u64, 4_u64).expect("non zero denominator");
+    /// assert!((ratio.to_f64() - 0.75_f64).abs() < f64::EPSILON);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        // the conversion of a primitive unsigned integer to a f64 does not fail
+        match (self.numerator.to_f64(), self.denominator.to_f64()) {
+            (Some(numerator), Some(denominator)) => numerator / denominator,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Convert the ratio to a [`ZeroOneBoundedFloat`], [`None`] if the ratio is greater
+    /// than one.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::{Ratio, ZeroOneBoundedFloat};
+    ///
+    /// let ratio = Ratio::new(1_u32, 4_u32).expect("non zero denominator");
+    /// assert_eq!(
+    ///     ratio.to_zero_one_bounded().map(ZeroOneBoundedFloat::float),
+    ///     Some(0.25_f64)
+    /// );
+    /// assert_eq!(Ratio::from_integer(2_u32).to_zero_one_bounded(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_zero_one_bounded(self) -> Option<ZeroOneBoundedFloat> {
+        (self.numerator <= self.denominator)
+            .then(|| ZeroOneBoundedFloat::new_or_bounded(self.to_f64()))
+    }
+}
+
+impl<T: Copy> Ratio<T> {
+    /// Get the numerator.
+    #[inline]
+    #[must_use]
+    pub const fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    /// Get the denominator, never zero.
+    #[inline]
+    #[must_use]
+    pub const fn denominator(&self) -> T {
+        self.denominator
+    }
+}
+
+/// Compare `a / b` with `c / d` without overflow using their continued fraction expansion.
+/// The denominators `b` and `d` must not be zero.
+fn compare_fraction<T: PrimInt + Unsigned>(a: T, b: T, c: T, d: T) -> Ordering {
+    let (mut a, mut b, mut c, mut d) = (a, b, c, d);
+    loop {
+        match (a / b).cmp(&(c / d)) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+        let (a_remainder, c_remainder) = (a % b, c % d);
+        match (a_remainder.is_zero(), c_remainder.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            // a_remainder / b ? c_remainder / d is d / c_remainder ? b / a_remainder
+            (false, false) => (a, b, c, d) = (d, c_remainder, b, a_remainder),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> PartialOrd for Ratio<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The ratios are compared exactly: by cross-multiplication if it does not overflow,
+/// by their continued fraction expansion otherwise.
+impl<T: PrimInt + Unsigned> Ord for Ratio<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let (Some(lhs), Some(rhs)) = (
+            T::checked_mul(&self.numerator, &other.denominator),
+            T::checked_mul(&other.numerator, &self.denominator),
+        ) {
+            lhs.cmp(&rhs)
+        } else {
+            compare_fraction(
+                self.numerator,
+                self.denominator,
+                other.numerator,
+                other.denominator,
+            )
+        }
+    }
+}
+
+/// # Panics
+/// Panics if the result overflows, see [`Ratio::checked_add`].
+impl<T: PrimInt + Unsigned> Add for Ratio<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding two ratios")
+    }
+}
+
+/// # Panics
+/// Panics if the result is negative or overflows, see [`Ratio::checked_sub`].
+impl<T: PrimInt + Unsigned> Sub for Ratio<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting two ratios")
+    }
+}
+
+/// # Panics
+/// Panics if the result overflows, see [`Ratio::checked_mul`].
+impl<T: PrimInt + Unsigned> Mul for Ratio<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("overflow when multiplying two ratios")
+    }
+}
+
+/// # Panics
+/// Panics if `rhs` is zero or if the result overflows, see [`Ratio::checked_div`].
+impl<T: PrimInt + Unsigned> Div for Ratio<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("division by zero or overflow when dividing two ratios")
+    }
+}
+
+/// Display the ratio as `"numerator/denominator"`, like `"3/4"`.
+impl<T: Display> Display for Ratio<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// Error when creating a [`Ratio`] with a zero denominator.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZeroDenominatorError;
+
+impl Display for ZeroDenominatorError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the denominator of the ratio is zero")
+    }
+}
+
+impl Error for ZeroDenominatorError {}
+
+#[cfg(test)]
+mod test {
+    use core::cmp::Ordering;
+
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use super::{compare_fraction, Ratio, ZeroDenominatorError};
+    use crate::number::{gcd, ZeroOneBoundedFloat};
+
+    /// shorthand to create a ratio
+    fn r(numerator: u64, denominator: u64) -> Ratio<u64> {
+        Ratio::new(numerator, denominator).expect("non zero denominator")
+    }
+
+    /// Pseudo random ratios with the numerators and denominators in `1..=range`.
+    fn ratios(count: usize, range: u64, seed: u64) -> Vec<Ratio<u64>> {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| r(rng.gen_range(1..=range), rng.gen_range(1..=range)))
+            .collect()
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Ratio::new(3_u32, 0_u32), Err(ZeroDenominatorError));
+        assert_eq!(Ratio::new(0_u32, 0_u32), Err(ZeroDenominatorError));
+        let zero = r(0, 7);
+        assert_eq!((zero.numerator(), zero.denominator()), (0, 1));
+        let ratio = r(12, 18);
+        assert_eq!((ratio.numerator(), ratio.denominator()), (2, 3));
+        assert_eq!(Ratio::from_integer(5_u64), r(10, 2));
+        assert_eq!(r(5, 3).recip(), Some(r(3, 5)));
+        assert_eq!(r(0, 3).recip(), None);
+    }
+
+    #[test]
+    fn normalization_idempotence() {
+        for ratio in ratios(200, 10_000, 1) {
+            assert_eq!(r(ratio.numerator(), ratio.denominator()), ratio);
+            assert_eq!(gcd(ratio.numerator(), ratio.denominator()), 1);
+            for factor in [2_u64, 3_u64, 97_u64] {
+                assert_eq!(
+                    r(ratio.numerator() * factor, ratio.denominator() * factor),
+                    ratio
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(1, 2) - r(1, 2), r(0, 1));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) * r(0, 4), r(0, 1));
+        assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+
+        let big = Ratio::from_integer(u64::MAX);
+        assert_eq!(big.checked_add(r(1, 1)), None);
+        assert_eq!(big.checked_mul(r(2, 1)), None);
+        assert_eq!(big.checked_mul(r(1, 3)), Some(r(u64::MAX / 3, 1)));
+        assert_eq!(r(1, 2).checked_sub(r(2, 3)), None);
+        assert_eq!(r(1, 2).checked_div(r(0, 3)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding two ratios")]
+    fn add_overflow() {
+        assert_eq!(
+            Ratio::from_integer(u8::MAX) + Ratio::from_integer(1_u8),
+            Ratio::from_integer(0_u8),
+            "not reached"
+        );
+    }
+
+    #[test]
+    fn add_matches_f64() {
+        let values = ratios(400, 100_000, 2);
+        for pair in values.chunks(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let sum = a + b;
+            let expected = a.to_f64() + b.to_f64();
+            assert!(
+                (sum.to_f64() - expected).abs() <= expected * 1e-12_f64,
+                "{a} + {b} = {sum}"
+            );
+            if a >= b {
+                let difference = a - b;
+                assert!((difference.to_f64() - (a.to_f64() - b.to_f64())).abs() < 1e-9_f64);
+            }
+        }
+    }
+
+    #[test]
+    fn ordering_matches_f64() {
+        let values = ratios(400, 1_000, 3);
+        for pair in values.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let expected = a.to_f64().partial_cmp(&b.to_f64());
+            assert_eq!(Some(a.cmp(&b)), expected, "{a} ? {b}");
+            assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        }
+    }
+
+    #[test]
+    fn ordering_without_overflow() {
+        let max = u64::MAX;
+        // the cross products overflow
+        assert_eq!(r(max - 2, max - 1).cmp(&r(max - 1, max)), Ordering::Less);
+        assert_eq!(r(max - 1, max).cmp(&r(max - 2, max - 1)), Ordering::Greater);
+        assert_eq!(r(max, max - 1).cmp(&r(max - 1, max - 2)), Ordering::Less);
+        assert_eq!(r(max, 3).cmp(&r(max, 3)), Ordering::Equal);
+        assert_eq!(r(max, 2).cmp(&r(max - 1, 2)), Ordering::Greater);
+
+        for pair in ratios(200, 1_000, 4).windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(
+                compare_fraction(
+                    a.numerator(),
+                    a.denominator(),
+                    b.numerator(),
+                    b.denominator()
+                ),
+                a.cmp(&b),
+                "{a} ? {b}"
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "exact conversion"
+    fn conversion() {
+        assert_eq!(r(3, 4).to_f64(), 0.75_f64);
+        assert_eq!(
+            r(3, 4)
+                .to_zero_one_bounded()
+                .map(ZeroOneBoundedFloat::float),
+            Some(0.75_f64)
+        );
+        assert_eq!(
+            r(1, 1)
+                .to_zero_one_bounded()
+                .map(ZeroOneBoundedFloat::float),
+            Some(1_f64)
+        );
+        assert_eq!(r(5, 4).to_zero_one_bounded(), None);
+        assert_eq!(r(3, 4).to_string(), "3/4");
+        assert_eq!(Ratio::from_integer(2_u64).to_string(), "2/1");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let json = serde_json::to_string(&r(6, 4)).expect("serializable");
+        assert_eq!(json, r#"{"numerator":3,"denominator":2}"#);
+        assert_eq!(
+            serde_json::from_str::<Ratio<u64>>(r#"{"numerator":6,"denominator":4}"#)
+                .expect("valid ratio"),
+            r(3, 2)
+        );
+        serde_json::from_str::<Ratio<u64>>(r#"{"numerator":6,"denominator":0}"#)
+            .expect_err("zero denominator");
+    }
+}
@@ -1,19 +1,44 @@
 //! Contains the definition of [`Sign`] and related notions.
 
-use std::{
+use core::{
     cmp::Ordering,
     fmt::{self, Display},
     num::FpCategory,
     ops::{Mul, MulAssign, Neg},
 };
 
+#[cfg(feature = "borsh")]
+use borsh::{
+    io::{self, ErrorKind, Read, Write},
+    BorshDeserialize, BorshSerialize,
+};
+#[cfg(feature = "rand")]
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use utils_lib_derive::Variants;
+
+use super::compare_f64;
 
 // TODO conversion
 
 /// Represent a sign.
+///
+/// The variants are listed in increasing order by [`Sign::VARIANTS`], see the derive
+/// [`Variants`].
+///
+/// # Example
+/// ```
+/// use utils_lib::number::Sign;
+///
+/// assert_eq!(Sign::VARIANTS, [Sign::Negative, Sign::Zero, Sign::Positive]);
+/// assert!(Sign::VARIANTS.is_sorted());
+/// assert_eq!(Sign::Zero.to_index(), 1);
+/// assert_eq!(Sign::try_from(2_usize), Ok(Sign::Positive));
+/// ```
 #[allow(clippy::exhaustive_enums)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default, Variants)]
+#[variants(error = "crate::error::NoneError")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Sign {
     /// Strictly negative number (non zero)
@@ -83,6 +108,223 @@ impl Sign {
         }
     }
 
+    /// Returns the sign of the comparison of two [`f64`], i.e. the sign of `a - b` where the
+    /// infinities are handled like in [`Ord`] for [`super::PositiveFloat`].
+    ///
+    /// # Panics
+    /// Panics if exactly one value is [`f64::NAN`] and the other one is finite.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// assert_eq!(Sign::from_ordering_of(1_f64, 2_f64), Sign::Negative);
+    /// assert_eq!(Sign::from_ordering_of(2_f64, 2_f64), Sign::Zero);
+    /// assert_eq!(Sign::from_ordering_of(f64::INFINITY, 2_f64), Sign::Positive);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_ordering_of(a: f64, b: f64) -> Self {
+        compare_f64(a, b).into()
+    }
+
+    /// Compare two [`f64`], [`Sign::Positive`] if `a > b`. It is the [`Sign`] form of the
+    /// total order used by [`Ord`] for [`super::PositiveFloat`], like
+    /// [`Self::from_ordering_of`].
+    ///
+    /// # Panics
+    /// Panics if exactly one value is [`f64::NAN`] and the other one is finite.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// assert_eq!(Sign::cmp_f64(3_f64, 2_f64), Sign::Positive);
+    /// assert_eq!(Sign::cmp_f64(-0_f64, 0_f64), Sign::Zero);
+    /// assert_eq!(Sign::cmp_f64(f64::NEG_INFINITY, -1E300_f64), Sign::Negative);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn cmp_f64(a: f64, b: f64) -> Self {
+        compare_f64(a, b).into()
+    }
+
+    /// Compare the magnitudes of two [`f64`], [`Sign::Positive`] if `|a| > |b|`, see
+    /// [`Self::cmp_f64`].
+    ///
+    /// # Panics
+    /// Panics if exactly one value is [`f64::NAN`] and the other one is finite.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// assert_eq!(Sign::cmp_abs_f64(-3_f64, 2_f64), Sign::Positive);
+    /// assert_eq!(Sign::cmp_abs_f64(-2_f64, 2_f64), Sign::Zero);
+    /// assert_eq!(Sign::cmp_abs_f64(1_f64, f64::NEG_INFINITY), Sign::Negative);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn cmp_abs_f64(a: f64, b: f64) -> Self {
+        Self::cmp_f64(a.abs(), b.abs())
+    }
+
+    /// Returns the sign of `a - b` without computing the difference, so it is
+    /// [`Sign::Zero`] only if `a == b`. The naive [`Sign::sign_f64`]`(a - b)` gives
+    /// [`Sign::Zero`] for distinct values whose difference is subnormal, and for
+    /// `f64::INFINITY - f64::INFINITY` which is [`f64::NAN`].
+    ///
+    /// If either value is [`f64::NAN`] the sign is [`Sign::Zero`], like [`Sign::sign_f64`]
+    /// of [`f64::NAN`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// let a = 3E-308_f64;
+    /// let b = 2.9E-308_f64;
+    /// // the difference is subnormal
+    /// assert_eq!(Sign::sign_f64(a - b), Sign::Zero);
+    /// assert_eq!(Sign::of_difference(a, b), Sign::Positive);
+    ///
+    /// assert_eq!(
+    ///     Sign::of_difference(f64::INFINITY, f64::INFINITY),
+    ///     Sign::Zero
+    /// );
+    /// assert_eq!(Sign::of_difference(1_f64, f64::NAN), Sign::Zero);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn of_difference(a: f64, b: f64) -> Self {
+        a.partial_cmp(&b).map_or(Self::Zero, Self::from)
+    }
+
+    /// Tri-state "and", [`Sign::Negative`] is false, [`Sign::Zero`] is unknown and
+    /// [`Sign::Positive`] is true. It is the minimum of the two signs.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// assert_eq!(Sign::Positive.and(Sign::Zero), Sign::Zero);
+    /// assert_eq!(Sign::Zero.and(Sign::Negative), Sign::Negative);
+    /// assert_eq!(Sign::Positive.and(Sign::Positive), Sign::Positive);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn and(self, other: Self) -> Self {
+        if self.to_i8() <= other.to_i8() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Tri-state "or", [`Sign::Negative`] is false, [`Sign::Zero`] is unknown and
+    /// [`Sign::Positive`] is true. It is the maximum of the two signs.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// assert_eq!(Sign::Negative.or(Sign::Zero), Sign::Zero);
+    /// assert_eq!(Sign::Zero.or(Sign::Positive), Sign::Positive);
+    /// assert_eq!(Sign::Negative.or(Sign::Negative), Sign::Negative);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn or(self, other: Self) -> Self {
+        if self.to_i8() >= other.to_i8() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Chain two comparison results like [`Ordering::then`]: returns `self` unless it is
+    /// [`Sign::Zero`], in which case `other` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// assert_eq!(Sign::Zero.then(Sign::Negative), Sign::Negative);
+    /// assert_eq!(Sign::Positive.then(Sign::Negative), Sign::Positive);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn then(self, other: Self) -> Self {
+        match self {
+            Self::Zero => other,
+            Self::Negative | Self::Positive => self,
+        }
+    }
+
+    /// Build a sort comparator from a float key, using the same total order as [`Ord`] for
+    /// [`super::PositiveFloat`]. It avoids the `partial_cmp(...).unwrap()` dance when sorting
+    /// by a float field.
+    ///
+    /// # Panics
+    /// The comparator panics if exactly one of the compared keys is [`f64::NAN`] and the
+    /// other one is finite.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// let mut vec = vec![(1_u8, 2.5_f64), (2_u8, -1_f64), (3_u8, 0.5_f64)];
+    /// vec.sort_by(Sign::comparator(|(_, weight): &(u8, f64)| *weight));
+    /// assert_eq!(
+    ///     vec.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+    ///     vec![2, 3, 1]
+    /// );
+    /// ```
+    #[inline]
+    pub fn comparator<T, F: Fn(&T) -> f64>(key: F) -> impl Fn(&T, &T) -> Ordering {
+        move |a, b| compare_f64(key(a), key(b))
+    }
+
+    /// Combine two parities, [`Sign::Positive`] is even and [`Sign::Negative`] is odd: the
+    /// result is odd if exactly one of them is odd. [`Sign::Zero`] is absorbing, like for
+    /// the product of the signs.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::Sign;
+    ///
+    /// assert_eq!(Sign::Negative.xor(Sign::Negative), Sign::Positive);
+    /// assert_eq!(Sign::Positive.xor(Sign::Negative), Sign::Negative);
+    /// assert_eq!(Sign::Negative.xor(Sign::Zero), Sign::Zero);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn xor(self, other: Self) -> Self {
+        Self::sign_i8(self.to_i8() * other.to_i8())
+    }
+
+    /// Get the parity of a permutation made of `count` swaps, [`Sign::Positive`] if `count`
+    /// is even and [`Sign::Negative`] if it is odd. It is the [`levi_civita`] symbol of the
+    /// permutation.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::number::sign::{levi_civita, Sign};
+    ///
+    /// assert_eq!(Sign::parity_of_swaps(0), Sign::Positive);
+    /// assert_eq!(Sign::parity_of_swaps(3), Sign::Negative);
+    /// // one swap from [0, 1, 2]
+    /// assert_eq!(Sign::parity_of_swaps(1), levi_civita(&[1, 0, 2]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn parity_of_swaps(count: usize) -> Self {
+        if count.is_multiple_of(2) {
+            Self::Positive
+        } else {
+            Self::Negative
+        }
+    }
+
     /// Returns the sign of `a - b`, where `a` and `b` are usize
     #[allow(clippy::comparison_chain)]
     #[must_use]
@@ -137,6 +379,64 @@ impl From<i8> for Sign {
     }
 }
 
+/// The sign is encoded as the [`i8`] given by [`Sign::to_i8`].
+#[cfg(feature = "borsh")]
+impl BorshSerialize for Sign {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        BorshSerialize::serialize(&self.to_i8(), writer)
+    }
+}
+
+/// Only `-1`, `0` and `1` are accepted, other values give an error of kind
+/// [`ErrorKind::InvalidData`].
+#[cfg(feature = "borsh")]
+impl BorshDeserialize for Sign {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match i8::deserialize_reader(reader)? {
+            -1_i8 => Ok(Self::Negative),
+            0_i8 => Ok(Self::Zero),
+            1_i8 => Ok(Self::Positive),
+            _ => Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "a sign is encoded as -1, 0 or 1",
+            )),
+        }
+    }
+}
+
+/// Sample the three signs uniformly, [`Sign::Zero`] included.
+#[cfg(feature = "rand")]
+impl Distribution<Sign> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Sign {
+        Sign::VARIANTS[rng.gen_range(0..Sign::VARIANTS.len())]
+    }
+}
+
+impl From<Ordering> for Sign {
+    #[inline]
+    fn from(ordering: Ordering) -> Self {
+        match ordering {
+            Ordering::Less => Self::Negative,
+            Ordering::Equal => Self::Zero,
+            Ordering::Greater => Self::Positive,
+        }
+    }
+}
+
+impl From<Sign> for Ordering {
+    #[inline]
+    fn from(s: Sign) -> Self {
+        match s {
+            Sign::Negative => Self::Less,
+            Sign::Zero => Self::Equal,
+            Sign::Positive => Self::Greater,
+        }
+    }
+}
+
 impl Neg for Sign {
     type Output = Self;
 
@@ -170,6 +470,31 @@ impl MulAssign<Self> for Sign {
     }
 }
 
+/// Implement [`Mul<Sign>`] for a signed number, applying the sign to the number:
+/// [`Sign::Negative`] negates it and [`Sign::Zero`] gives zero.
+macro_rules! impl_mul_sign {
+    ($($ty:ty => $zero:expr),* $(,)?) => {
+        $(
+            /// Apply the sign to the number, [`Sign::Negative`] negates it, with the same
+            #[doc = concat!("overflow as `-", stringify!($ty), "::MIN`, and [`Sign::Zero`] gives zero.")]
+            impl Mul<Sign> for $ty {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: Sign) -> Self {
+                    match rhs {
+                        Sign::Positive => self,
+                        Sign::Zero => $zero,
+                        Sign::Negative => -self,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_mul_sign!(i32 => 0_i32, i64 => 0_i64, f64 => 0_f64);
+
 impl PartialOrd for Sign {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -210,12 +535,92 @@ pub const fn levi_civita(index: &[usize]) -> Sign {
     Sign::sign_i8(prod)
 }
 
+/// Stably sort a slice by a float key, see [`Sign::comparator`].
+///
+/// # Panics
+/// Panics if exactly one of two compared keys is [`f64::NAN`] and the other one is finite.
+/// Keys that are all NaN-free never panic.
+///
+/// # Example
+/// ```
+/// use utils_lib::number::sort_by_f64_key;
+///
+/// let mut vec = vec![3.5_f64, f64::NEG_INFINITY, -2_f64, 0_f64];
+/// sort_by_f64_key(&mut vec, |f| *f);
+/// assert_eq!(vec, vec![f64::NEG_INFINITY, -2_f64, 0_f64, 3.5_f64]);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn sort_by_f64_key<T, F: Fn(&T) -> f64>(slice: &mut [T], key: F) {
+    slice.sort_by(Sign::comparator(key));
+}
+
 #[cfg(test)]
 mod test {
     use std::cmp::Ordering;
 
+    #[cfg(feature = "alloc")]
+    use super::sort_by_f64_key;
     use super::{levi_civita, Sign};
 
+    #[test]
+    fn cmp_f64() {
+        assert_eq!(Sign::cmp_f64(1_f64, 2_f64), Sign::Negative);
+        assert_eq!(Sign::cmp_f64(2_f64, 2_f64), Sign::Zero);
+        assert_eq!(Sign::cmp_f64(2_f64, 1_f64), Sign::Positive);
+        assert_eq!(Sign::cmp_f64(f64::INFINITY, f64::MAX), Sign::Positive);
+        assert_eq!(Sign::cmp_f64(f64::INFINITY, f64::INFINITY), Sign::Zero);
+        assert_eq!(Sign::cmp_f64(f64::NAN, f64::NAN), Sign::Zero);
+
+        assert_eq!(Sign::cmp_abs_f64(-1_f64, 2_f64), Sign::Negative);
+        assert_eq!(Sign::cmp_abs_f64(-2_f64, 2_f64), Sign::Zero);
+        assert_eq!(Sign::cmp_abs_f64(-3_f64, 2_f64), Sign::Positive);
+        assert_eq!(
+            Sign::cmp_abs_f64(f64::NEG_INFINITY, f64::INFINITY),
+            Sign::Zero
+        );
+        assert_eq!(Sign::cmp_abs_f64(-0_f64, 0_f64), Sign::Zero);
+    }
+
+    #[test]
+    #[should_panic(expected = "comparing NaN with 1")]
+    fn cmp_f64_nan() {
+        assert_eq!(Sign::cmp_f64(f64::NAN, 1_f64), Sign::Zero, "not reached");
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "the values are distinct by construction"
+    fn of_difference() {
+        // near equal values whose difference is subnormal
+        for (a, b) in [
+            (3E-308_f64, 2.9E-308_f64),
+            (f64::MIN_POSITIVE, f64::MIN_POSITIVE / 2_f64),
+            (f64::MIN_POSITIVE * 1.5_f64, f64::MIN_POSITIVE),
+            (f64::from_bits(2), f64::from_bits(1)),
+        ] {
+            assert_ne!(a, b);
+            assert_eq!(Sign::sign_f64(a - b), Sign::Zero, "{a} {b}");
+            assert_eq!(Sign::of_difference(a, b), Sign::Positive, "{a} {b}");
+            assert_eq!(Sign::of_difference(b, a), Sign::Negative, "{a} {b}");
+            assert_eq!(Sign::of_difference(-a, -b), Sign::Negative, "{a} {b}");
+            assert_eq!(Sign::of_difference(a, a), Sign::Zero, "{a}");
+        }
+
+        assert_eq!(
+            Sign::of_difference(1_f64, 1_f64 + f64::EPSILON),
+            Sign::Negative
+        );
+        assert_eq!(Sign::of_difference(f64::MAX, -f64::MAX), Sign::Positive);
+        assert_eq!(
+            Sign::of_difference(f64::INFINITY, f64::INFINITY),
+            Sign::Zero
+        );
+        assert_eq!(Sign::of_difference(f64::INFINITY, 0_f64), Sign::Positive);
+        assert_eq!(Sign::of_difference(0_f64, -0_f64), Sign::Zero);
+        assert_eq!(Sign::of_difference(f64::NAN, 1_f64), Sign::Zero);
+        assert_eq!(Sign::of_difference(1_f64, f64::NAN), Sign::Zero);
+    }
+
     #[test]
     fn sign_i8() {
         assert_eq!(Sign::sign_i8(0), Sign::Zero);
@@ -315,4 +720,182 @@ mod test {
         assert_eq!(Sign::Negative.to_string(), "negative");
         assert_eq!(Sign::Zero.to_string(), "zero");
     }
+
+    #[test]
+    fn tri_state() {
+        let signs = Sign::VARIANTS;
+        for a in signs {
+            for b in signs {
+                assert_eq!(a.and(b), a.min(b));
+                assert_eq!(a.or(b), a.max(b));
+                assert_eq!(a.and(b), b.and(a));
+                assert_eq!(-(a.and(b)), (-a).or(-b));
+                assert_eq!(a.then(b), Ordering::from(a).then(Ordering::from(b)).into());
+            }
+            assert_eq!(Sign::from(Ordering::from(a)), a);
+        }
+        assert_eq!(Sign::from_ordering_of(0_f64, -0_f64), Sign::Zero);
+        assert_eq!(
+            Sign::from_ordering_of(f64::NAN, f64::INFINITY),
+            Sign::Negative
+        );
+    }
+
+    #[test]
+    fn parity() {
+        use Sign::{Negative, Positive, Zero};
+
+        for (a, b, xor) in [
+            (Positive, Positive, Positive),
+            (Positive, Negative, Negative),
+            (Negative, Positive, Negative),
+            (Negative, Negative, Positive),
+            (Zero, Positive, Zero),
+            (Zero, Negative, Zero),
+            (Positive, Zero, Zero),
+            (Negative, Zero, Zero),
+            (Zero, Zero, Zero),
+        ] {
+            assert_eq!(a.xor(b), xor, "{a} xor {b}");
+            assert_eq!(a.xor(b), a * b);
+        }
+
+        for count in 0..8_usize {
+            let parity = Sign::parity_of_swaps(count);
+            assert_eq!(
+                parity,
+                if count.is_multiple_of(2) {
+                    Positive
+                } else {
+                    Negative
+                }
+            );
+            assert_eq!(
+                parity.xor(Sign::parity_of_swaps(1)),
+                Sign::parity_of_swaps(count + 1)
+            );
+        }
+        assert_eq!(Sign::parity_of_swaps(usize::MAX), Negative);
+        // the swaps (0 1) then (1 2) of [0, 1, 2] give [1, 2, 0]
+        assert_eq!(Sign::parity_of_swaps(2), levi_civita(&[1, 2, 0]));
+        assert_eq!(Sign::parity_of_swaps(1), levi_civita(&[0, 2, 1]));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "exact values"
+    fn mul_number() {
+        assert_eq!(-3_i32 * Sign::Positive, -3_i32);
+        assert_eq!(-3_i32 * Sign::Negative, 3_i32);
+        assert_eq!(-3_i32 * Sign::Zero, 0_i32);
+        assert_eq!(i32::MAX * Sign::Negative, -i32::MAX);
+
+        assert_eq!(-7_i64 * Sign::Positive, -7_i64);
+        assert_eq!(-7_i64 * Sign::Negative, 7_i64);
+        assert_eq!(-7_i64 * Sign::Zero, 0_i64);
+        assert_eq!(i64::MIN * Sign::Positive, i64::MIN);
+
+        assert_eq!(-2.5_f64 * Sign::Positive, -2.5_f64);
+        assert_eq!(-2.5_f64 * Sign::Negative, 2.5_f64);
+        assert_eq!(-2.5_f64 * Sign::Zero, 0_f64);
+        assert_eq!(f64::NEG_INFINITY * Sign::Negative, f64::INFINITY);
+        assert_eq!(f64::NEG_INFINITY * Sign::Zero, 0_f64);
+        assert!((-0_f64 * Sign::Negative).is_sign_positive());
+        assert!((f64::NAN * Sign::Negative).is_nan());
+
+        for value in [-5_i64, 0_i64, 9_i64] {
+            assert_eq!(value * Sign::sign_i8(-1_i8) * Sign::sign_i8(-1_i8), value);
+        }
+    }
+
+    /// struct sorted by its float field
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Item {
+        /// identifier
+        id: u8,
+        /// sort key
+        weight: f64,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sort_by_key() {
+        let mut items = [
+            Item {
+                id: 0,
+                weight: 1.5_f64,
+            },
+            Item {
+                id: 1,
+                weight: f64::INFINITY,
+            },
+            Item {
+                id: 2,
+                weight: -3_f64,
+            },
+            Item {
+                id: 3,
+                weight: 1.5_f64,
+            },
+            Item {
+                id: 4,
+                weight: 0_f64,
+            },
+        ];
+        sort_by_f64_key(&mut items, |item| item.weight);
+        // the sort is stable
+        assert_eq!(
+            items.iter().map(|item| item.id).collect::<Vec<_>>(),
+            vec![2, 4, 0, 3, 1]
+        );
+
+        items.sort_by(Sign::comparator(|item: &Item| -item.weight));
+        assert_eq!(
+            items.iter().map(|item| item.id).collect::<Vec<_>>(),
+            vec![1, 0, 3, 4, 2]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn sort_by_key_nan() {
+        let mut vec = [1_f64, f64::NAN];
+        sort_by_f64_key(&mut vec, |f| *f);
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh() {
+        use borsh::io::ErrorKind;
+
+        for sign in Sign::VARIANTS {
+            let bytes = borsh::to_vec(&sign).expect("serializable");
+            assert_eq!(bytes, borsh::to_vec(&sign.to_i8()).expect("serializable"));
+            assert_eq!(
+                borsh::from_slice::<Sign>(&bytes).expect("valid bytes"),
+                sign
+            );
+        }
+        let err = borsh::from_slice::<Sign>(&borsh::to_vec(&2_i8).expect("serializable"))
+            .expect_err("invalid sign");
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rand_sample() {
+        use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(0x5eed_u64);
+        let signs = (0_u32..300_u32)
+            .map(|_| rng.gen::<Sign>())
+            .collect::<Vec<_>>();
+        for sign in Sign::VARIANTS {
+            assert!(signs.contains(&sign), "{sign} never sampled");
+        }
+
+        let mut rng = SmallRng::seed_from_u64(0x5eed_u64);
+        assert!((0_u32..300_u32).map(|_| rng.gen::<Sign>()).eq(signs));
+    }
 }
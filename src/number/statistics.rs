@@ -0,0 +1,278 @@
+//! Contains statistics functions on slices of numbers: [`geometric_mean`], [`harmonic_mean`]
+//! and [`log_sum_exp`].
+//!
+//! [`geometric_mean`] and [`log_sum_exp`] require the `std` feature.
+
+use super::PositiveFloat;
+
+/// The geometric mean of the values, the `n`-th root of their product.
+///
+/// It is computed in log-space, as the exponential of the mean of the logarithms, so it
+/// does not overflow or underflow when the product would. Returns [`None`] if `values` is
+/// empty. If a value is zero the product is zero and so is the mean.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::geometric_mean, PositiveFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let values = [
+///     PositiveFloat::new(1E300_f64)?,
+///     PositiveFloat::new(1E300_f64)?,
+/// ];
+/// // the product overflows
+/// assert_eq!(
+///     values[0].overflowing_mul(values[1]),
+///     (PositiveFloat::MAX, true)
+/// );
+/// let mean = geometric_mean(&values).ok_or("empty")?;
+/// assert!((mean.float() / 1E300_f64 - 1_f64).abs() < 1E-12_f64);
+///
+/// assert_eq!(geometric_mean(&[]), None);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+#[must_use]
+pub fn geometric_mean(values: &[PositiveFloat]) -> Option<PositiveFloat> {
+    if values.is_empty() {
+        return None;
+    }
+    if values.contains(&PositiveFloat::ZERO) {
+        return Some(PositiveFloat::ZERO);
+    }
+    #[allow(clippy::cast_precision_loss)] // reason = "the length is never close to 2^52"
+    let len = values.len() as f64;
+    let log_mean = values.iter().map(|value| value.float().ln()).sum::<f64>() / len;
+    // the mean is between the minimum and the maximum, only the rounding can push it
+    // above PositiveFloat::MAX
+    Some(PositiveFloat::new_or_bounded(log_mean.exp()))
+}
+
+/// The harmonic mean of the values, the number of values divided by the sum of their
+/// inverses.
+///
+/// The values are scaled by their minimum before taking the inverse so the sum does not
+/// overflow for values close to zero. Returns [`None`] if `values` is empty or if a value is
+/// zero, as its inverse is not defined.
+///
+/// # Example
+/// ```
+/// use utils_lib::{number::harmonic_mean, PositiveFloat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let values = [
+///     PositiveFloat::new(1_f64)?,
+///     PositiveFloat::new(4_f64)?,
+///     PositiveFloat::new(4_f64)?,
+/// ];
+/// assert_eq!(harmonic_mean(&values), Some(PositiveFloat::new(2_f64)?));
+///
+/// assert_eq!(harmonic_mean(&[PositiveFloat::ZERO]), None);
+/// assert_eq!(harmonic_mean(&[]), None);
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+#[must_use]
+pub fn harmonic_mean(values: &[PositiveFloat]) -> Option<PositiveFloat> {
+    let min = values.iter().copied().min()?;
+    if min == PositiveFloat::ZERO {
+        return None;
+    }
+    // each scaled inverse is in (0, 1] and the sum is in [1, values.len()]
+    let sum = values
+        .iter()
+        .map(|value| min.float() / value.float())
+        .sum::<f64>();
+    #[allow(clippy::cast_precision_loss)] // reason = "the length is never close to 2^52"
+    let len = values.len() as f64;
+    Some(PositiveFloat::new_or_bounded(min.float() * (len / sum)))
+}
+
+/// The logarithm of the sum of the exponentials of the values, `ln(Σ e^x)`.
+///
+/// The maximum is subtracted from the values before taking the exponential, so large values
+/// do not overflow and small values do not all underflow to zero. An empty slice gives
+/// [`f64::NEG_INFINITY`], the logarithm of an empty sum. If a value is [`f64::INFINITY`]
+/// the result is [`f64::INFINITY`] and if a value is [`f64::NAN`] the result is
+/// [`f64::NAN`].
+///
+/// # Example
+/// ```
+/// use utils_lib::number::log_sum_exp;
+///
+/// // e^1000 overflows
+/// assert_eq!(log_sum_exp(&[1000_f64, 1000_f64]), 1000_f64 + 2_f64.ln());
+/// assert_eq!(log_sum_exp(&[]), f64::NEG_INFINITY);
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+#[must_use]
+pub fn log_sum_exp(values: &[f64]) -> f64 {
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if max.is_infinite() && !values.iter().any(|value| value.is_nan()) {
+        return max;
+    }
+    // the maximum gives e^0 = 1 so the sum is at least 1, each term is in [0, 1]
+    let sum = values.iter().map(|value| (value - max).exp()).sum::<f64>();
+    max + sum.ln()
+}
+
+#[cfg(test)]
+mod test {
+    use super::harmonic_mean;
+    #[cfg(feature = "std")]
+    use super::{geometric_mean, log_sum_exp};
+    use crate::{number::PositiveFloatConversionError, PositiveFloat};
+
+    /// Assert that the relative difference of the two floats is below `1E-12`.
+    fn assert_close(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() <= 1E-12_f64 * expected.abs(),
+            "{value} != {expected}"
+        );
+    }
+
+    /// Assert that the mean is defined and close to the expected value, see [`assert_close`].
+    fn assert_mean_close(mean: Option<PositiveFloat>, expected: f64) {
+        assert_close(mean.expect("not empty").float(), expected);
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the overflow and underflow are exact"
+    #[cfg(feature = "std")]
+    #[test]
+    fn geometric_mean_values() -> Result<(), PositiveFloatConversionError> {
+        for values in [
+            vec![4_f64],
+            vec![2_f64, 8_f64],
+            vec![1_f64, 2_f64, 3_f64, 4_f64],
+            vec![0.5_f64, 0.25_f64, 10_f64],
+            vec![1E-3_f64, 7_f64, 12.5_f64, 3_f64, 0.1_f64],
+        ] {
+            #[allow(clippy::cast_precision_loss)] // reason = "small length"
+            let expected = values
+                .iter()
+                .product::<f64>()
+                .powf(1_f64 / values.len() as f64);
+            let values = values
+                .into_iter()
+                .map(PositiveFloat::new)
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_mean_close(geometric_mean(&values), expected);
+        }
+
+        assert_eq!(geometric_mean(&[]), None);
+        assert_eq!(
+            geometric_mean(&[
+                PositiveFloat::new(3_f64)?,
+                PositiveFloat::ZERO,
+                PositiveFloat::new(5_f64)?
+            ]),
+            Some(PositiveFloat::ZERO)
+        );
+        assert_mean_close(geometric_mean(&[PositiveFloat::MAX; 4]), f64::MAX);
+
+        // the naive product overflows or underflows
+        let values = [
+            PositiveFloat::new(1E200_f64)?,
+            PositiveFloat::new(1E250_f64)?,
+            PositiveFloat::new(1E300_f64)?,
+        ];
+        assert_eq!(
+            values.iter().map(|value| value.float()).product::<f64>(),
+            f64::INFINITY
+        );
+        assert_mean_close(geometric_mean(&values), 1E250_f64);
+
+        let values = [
+            PositiveFloat::new(1E-200_f64)?,
+            PositiveFloat::new(1E-250_f64)?,
+            PositiveFloat::new(1E-300_f64)?,
+        ];
+        assert_eq!(
+            values.iter().map(|value| value.float()).product::<f64>(),
+            0_f64
+        );
+        assert_mean_close(geometric_mean(&values), 1E-250_f64);
+        Ok(())
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the overflow is exact"
+    #[test]
+    fn harmonic_mean_values() -> Result<(), PositiveFloatConversionError> {
+        for values in [
+            vec![4_f64],
+            vec![2_f64, 8_f64],
+            vec![1_f64, 2_f64, 3_f64, 4_f64],
+            vec![0.5_f64, 0.25_f64, 10_f64],
+            vec![1E-3_f64, 7_f64, 12.5_f64, 3_f64, 0.1_f64],
+        ] {
+            #[allow(clippy::cast_precision_loss)] // reason = "small length"
+            let expected =
+                values.len() as f64 / values.iter().map(|value| 1_f64 / value).sum::<f64>();
+            let values = values
+                .into_iter()
+                .map(PositiveFloat::new)
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_mean_close(harmonic_mean(&values), expected);
+        }
+
+        assert_eq!(harmonic_mean(&[]), None);
+        assert_eq!(
+            harmonic_mean(&[
+                PositiveFloat::new(3_f64)?,
+                PositiveFloat::ZERO,
+                PositiveFloat::new(5_f64)?
+            ]),
+            None
+        );
+        assert_eq!(
+            harmonic_mean(&[PositiveFloat::MAX; 4]),
+            Some(PositiveFloat::MAX)
+        );
+
+        // the naive inverse of the subnormal value overflows
+        let tiny = f64::MIN_POSITIVE / 1024_f64;
+        assert_eq!(1_f64 / tiny, f64::INFINITY);
+        assert_mean_close(
+            harmonic_mean(&[PositiveFloat::new(tiny)?, PositiveFloat::new(tiny)?]),
+            tiny,
+        );
+        assert_mean_close(
+            harmonic_mean(&[PositiveFloat::new(tiny)?, PositiveFloat::new(1_f64)?]),
+            2_f64 * tiny,
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the infinities and the overflow are exact"
+    #[cfg(feature = "std")]
+    #[test]
+    fn log_sum_exp_values() {
+        for values in [
+            vec![0_f64],
+            vec![1_f64, 2_f64],
+            vec![-1_f64, 0.5_f64, 3_f64, -7_f64],
+            vec![10_f64, 10_f64, 10_f64],
+        ] {
+            let expected = values.iter().map(|value| value.exp()).sum::<f64>().ln();
+            assert_close(log_sum_exp(&values), expected);
+        }
+
+        // the naive sum overflows
+        assert_eq!(1000_f64.exp(), f64::INFINITY);
+        assert_close(log_sum_exp(&[1000_f64, 1000_f64]), 1000_f64 + 2_f64.ln());
+        assert_close(log_sum_exp(&[1000_f64, 0_f64]), 1000_f64);
+        // the naive sum underflows
+        assert_eq!((-1000_f64).exp(), 0_f64);
+        assert_close(log_sum_exp(&[-1000_f64, -1000_f64]), 2_f64.ln() - 1000_f64);
+
+        assert_eq!(log_sum_exp(&[]), f64::NEG_INFINITY);
+        assert_eq!(log_sum_exp(&[f64::NEG_INFINITY]), f64::NEG_INFINITY);
+        assert_eq!(log_sum_exp(&[1_f64, f64::INFINITY]), f64::INFINITY);
+        assert!(log_sum_exp(&[1_f64, f64::NAN]).is_nan());
+        assert!(log_sum_exp(&[f64::INFINITY, f64::NAN]).is_nan());
+    }
+}
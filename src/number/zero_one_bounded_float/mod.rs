@@ -2,12 +2,14 @@
 //!
 //! The module exits in order to compartmentalize code.
 
+#[cfg(feature = "borsh")]
+mod borsh_impl;
 mod num_traits_impl;
 
-use std::{
+use core::{
     cmp::Ordering,
     error::Error,
-    fmt::{self, Display, LowerExp, UpperExp},
+    fmt::{self, Debug, Display, LowerExp, UpperExp},
     hash::{Hash, Hasher},
     num::FpCategory,
     ops::Deref,
@@ -16,12 +18,19 @@ use std::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::{compare_f64, Validation, ValidationGuard};
-use crate::PositiveFloat;
+use super::{
+    compare_f64,
+    display::{FloatDebug, PercentDisplay},
+    mul_add,
+    parse::{parse_ratio, ParseFlexibleError},
+    pow_by_squaring, ulp_diff, Validation, ValidationGuard,
+};
+use crate::{number::PositiveFloatConversionError, PositiveFloat};
 
 /// A float that f is  0 <= f <= 1 and is not NaN.
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Clone, Copy, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::unsafe_derive_deserialize)] // reason = "the unsafe constructor is only unsafe in regard to the invariant of the type, not memory"
 pub struct ZeroOneBoundedFloat(f64);
 
 impl Eq for ZeroOneBoundedFloat {}
@@ -40,6 +49,18 @@ impl PartialOrd for ZeroOneBoundedFloat {
     }
 }
 
+/// Debug the wrapped float, like `ZeroOneBoundedFloat(0.5)`. If the invariant of the type is broken, which
+/// should not happen without an unsafe constructor, the float is marked like
+/// `ZeroOneBoundedFloat(1.5 [INVALID])`.
+impl Debug for ZeroOneBoundedFloat {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ZeroOneBoundedFloat")
+            .field(&FloatDebug::<Self>::new(self.float()))
+            .finish()
+    }
+}
+
 impl Display for ZeroOneBoundedFloat {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -99,9 +120,19 @@ impl ZeroOneBoundedFloat {
     /// Value 1
     pub const ONE: Self = Self(1_f64);
 
+    /// Returns `true` if the float is valid, i.e. it is `>= 0`, `<= 1` and not [`f64::NAN`], see
+    /// [`Validation::validate_data`]. It is const unlike the trait method.
+    const fn is_valid(t: f64) -> bool {
+        matches!(
+            t.classify(),
+            FpCategory::Normal | FpCategory::Subnormal | FpCategory::Zero
+        ) && t >= 0_f64
+            && t <= 1_f64
+    }
+
     /// determine under which bound the given float is
-    fn float_range(float: f64) -> BoundRange {
-        if Self::validate_data(float) {
+    const fn float_range(float: f64) -> BoundRange {
+        if Self::is_valid(float) {
             BoundRange::InRange
         } else if float.is_nan() {
             BoundRange::Nan
@@ -112,42 +143,56 @@ impl ZeroOneBoundedFloat {
         }
     }
 
-    // /// Create a wrapped value skipping the validity check
-    // ///
-    // /// # Safety
-    // /// make sure that the float is valid
-    // //#[cfg(any(not(debug_assertions), doc, test))]
-    // // #[cfg_attr(debug_assertions, allow(dead_code))] // it is used by new_partially_check
-    // #[must_use]
-    // #[inline]
-    // const unsafe fn new_unchecked(float: f64) -> Self {
-    //     Self(float)
-    // }
-
-    // /// see the other [`Self::new_partially_check`]
-    // // It is the other one which is documented
-    // #[allow(clippy::missing_const_for_fn)] // it has to keep the same signature, the other fn can't be const
-    // #[cfg(not(debug_assertions))]
-    // #[must_use]
-    // #[inline]
-    // unsafe fn new_partially_check(float: f64) -> Self {
-    //     Self::new_unchecked(float)
-    // }
-
-    // /// Create a wrapped value doing the validity check only when [`debug_assertions`]
-    // /// and panics if the value is not valid. Otherwise it wraps the value even if it is not valid
-    // ///
-    // /// # Panic
-    // /// Panics if the value if not valid and [`debug_assertions`] is on
-    // ///
-    // /// # Safety
-    // /// make sure that the float is valid
-    // #[cfg(debug_assertions)]
-    // #[must_use]
-    // #[inline]
-    // unsafe fn new_partially_check(float: f64) -> Self {
-    //     Self::new(float).expect("invalid value")
-    // }
+    /// Create a wrapped value skipping the validity check.
+    ///
+    /// It is meant for performance sensitive code where the value is already known to be
+    /// valid, e.g. the output of an operation that is always in range. Otherwise prefer the
+    /// safe [`Self::new`], or [`Self::new_saturating`] which only adds a cheap range check.
+    ///
+    /// # Safety
+    /// The float must be valid, i.e. it is `>= 0`, `<= 1` and not [`f64::NAN`]. An invalid value breaks the invariant
+    /// of the type that other functions rely on.
+    ///
+    /// # Panics
+    /// Panics if the float is not valid and `debug_assertions` are enabled, so that a misuse
+    /// is caught in debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// // SAFETY: 0.5_f64 is valid
+    /// let float = unsafe { ZeroOneBoundedFloat::new_unchecked(0.5_f64) };
+    /// assert_eq!(float.float(), 0.5_f64);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(float: f64) -> Self {
+        debug_assert!(float >= 0_f64 && float <= 1_f64, "the float is not valid");
+        Self(float)
+    }
+
+    /// Create a new Self from a [`f64`], saturating at the bounds. It is an alias of
+    /// [`Self::new_or_bounded`] and the safe alternative to [`Self::new_unchecked`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::new_saturating(2_f64),
+    ///     ZeroOneBoundedFloat::new_or_bounded(2_f64)
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::new_saturating(-1_f64),
+    ///     ZeroOneBoundedFloat::ZERO
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new_saturating(float: f64) -> Self {
+        Self::new_or_bounded(float)
+    }
 
     /// Create a new Self from a [`f64`]. It returns [`Some`] only if the float
     /// is valid ([`Self::validate_data`]), i.e. it is >= 0  and <= 1.
@@ -198,7 +243,7 @@ impl ZeroOneBoundedFloat {
     /// );
     /// ```
     #[inline]
-    pub fn new(float: f64) -> Result<Self, ConversionError> {
+    pub const fn new(float: f64) -> Result<Self, ConversionError> {
         match Self::float_range(float) {
             BoundRange::InRange => Ok(Self(float)),
             BoundRange::LowerBound => Err(ConversionError::TooLow),
@@ -207,6 +252,49 @@ impl ZeroOneBoundedFloat {
         }
     }
 
+    /// Create a new Self from a [`f64`], returning [`None`] if it is not valid. It is
+    /// [`Self::new`] discarding the error and mirrors [`num_traits::FromPrimitive::from_f64`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::from_f64(0.5_f64).map(ZeroOneBoundedFloat::float),
+    ///     Some(0.5_f64)
+    /// );
+    /// assert_eq!(ZeroOneBoundedFloat::from_f64(2_f64), None);
+    /// assert_eq!(ZeroOneBoundedFloat::from_f64(f64::NAN), None);
+    /// ```
+    #[allow(clippy::same_name_method)] // reason = "it mirrors the FromPrimitive method"
+    #[inline]
+    #[must_use]
+    pub const fn from_f64(float: f64) -> Option<Self> {
+        Self::new_ok(float)
+    }
+
+    /// Create a new Self from a [`f64`], returning [`None`] if it is not valid. It is
+    /// [`Self::new`] discarding the error, usable in a const context where
+    /// [`Result::ok`] is not.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// const HALF: Option<ZeroOneBoundedFloat> = ZeroOneBoundedFloat::new_ok(0.5_f64);
+    /// assert_eq!(HALF.map(ZeroOneBoundedFloat::float), Some(0.5_f64));
+    /// assert_eq!(ZeroOneBoundedFloat::new_ok(f64::NAN), None);
+    /// assert_eq!(ZeroOneBoundedFloat::new_ok(-1_f64), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new_ok(float: f64) -> Option<Self> {
+        match Self::float_range(float) {
+            BoundRange::InRange => Some(Self(float)),
+            BoundRange::LowerBound | BoundRange::UpperBound | BoundRange::Nan => None,
+        }
+    }
+
     /// Create a new Self with the float as value if it is valid ( `>= 0` and <= 1)
     /// or return the default value (0) instead.
     ///
@@ -242,8 +330,11 @@ impl ZeroOneBoundedFloat {
     /// ```
     #[inline]
     #[must_use]
-    pub fn new_or_default(float: f64) -> Self {
-        Self::new(float).unwrap_or_default()
+    pub const fn new_or_default(float: f64) -> Self {
+        match Self::new_ok(float) {
+            Some(value) => value,
+            None => Self::ZERO,
+        }
     }
 
     // Create a new Self with the float as value if it is valid (`>= 0` and <= 1)
@@ -275,9 +366,29 @@ impl ZeroOneBoundedFloat {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// It is `const`, like [`Self::new`], [`Self::new_ok`] and [`Self::new_or_default`], so a
+    /// table of probabilities can be built at compile time:
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// const fn table<const N: usize>() -> [ZeroOneBoundedFloat; N] {
+    ///     let mut table = [ZeroOneBoundedFloat::ZERO; N];
+    ///     let mut index = 0;
+    ///     while index < N {
+    ///         table[index] = ZeroOneBoundedFloat::new_or_bounded(index as f64 / 4_f64);
+    ///         index += 1;
+    ///     }
+    ///     table
+    /// }
+    ///
+    /// const TABLE: [ZeroOneBoundedFloat; 6] = table();
+    /// assert_eq!(TABLE[2].float(), 0.5_f64);
+    /// assert_eq!(TABLE[5], ZeroOneBoundedFloat::ONE);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn new_or_bounded(float: f64) -> Self {
+    pub const fn new_or_bounded(float: f64) -> Self {
         match Self::float_range(float) {
             BoundRange::InRange => Self(float),
             BoundRange::LowerBound | BoundRange::Nan => Self::ZERO,
@@ -285,6 +396,31 @@ impl ZeroOneBoundedFloat {
         }
     }
 
+    /// Parse a ratio written as a float `"0.75"`, a percentage `"75%"` or a fraction `"3/4"`,
+    /// as commonly found in configuration files. Surrounding whitespaces are ignored.
+    ///
+    /// # Errors
+    /// - [`ParseFlexibleError::Syntax`] if a number cannot be parsed,
+    /// - [`ParseFlexibleError::Range`] if the value is not in `[0, 1]`, see [`Self::new`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let expected = ZeroOneBoundedFloat::new(0.75_f64)?;
+    /// assert_eq!(ZeroOneBoundedFloat::parse_flexible("0.75")?, expected);
+    /// assert_eq!(ZeroOneBoundedFloat::parse_flexible("75%")?, expected);
+    /// assert_eq!(ZeroOneBoundedFloat::parse_flexible(" 3/4 ")?, expected);
+    /// assert!(ZeroOneBoundedFloat::parse_flexible("5/4").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn parse_flexible(s: &str) -> Result<Self, ParseFlexibleError<ConversionError>> {
+        Self::new(parse_ratio(s)?).map_err(ParseFlexibleError::Range)
+    }
+
     /// Get the underling float. It could also be accessed by using [`Deref`],
     /// note that [`std::ops::DerefMut`] is not implemented.
     #[inline]
@@ -356,6 +492,200 @@ impl ZeroOneBoundedFloat {
         self.checked_sub(other).unwrap_or_default()
     }
 
+    /// Tell if the absolute difference of two [`ZeroOneBoundedFloat`] is at most `epsilon`.
+    /// An `epsilon` of zero checks for equality.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::{PositiveFloat, ZeroOneBoundedFloat};
+    /// # use utils_lib::number::PositiveFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), PositiveFloatConversionError> {
+    /// let sum = ZeroOneBoundedFloat::new_or_bounded(0.1_f64 + 0.2_f64);
+    /// let expected = ZeroOneBoundedFloat::new_or_bounded(0.3_f64);
+    ///
+    /// assert_ne!(sum, expected);
+    /// assert!(sum.approx_eq(expected, PositiveFloat::new(1E-15_f64)?));
+    /// assert!(!sum.approx_eq(expected, PositiveFloat::ZERO));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn approx_eq(self, other: Self, epsilon: PositiveFloat) -> bool {
+        (self.float() - other.float()).abs() <= epsilon.float()
+    }
+
+    /// Get the number of representable floats between two [`ZeroOneBoundedFloat`], the
+    /// distance in units in the last place (ULP). It is zero only for equal values.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// let sum = ZeroOneBoundedFloat::new_or_bounded(0.1_f64 + 0.2_f64);
+    ///
+    /// assert_eq!(
+    ///     sum.max_ulp_diff(ZeroOneBoundedFloat::new_or_bounded(0.3_f64)),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::ONE.max_ulp_diff(ZeroOneBoundedFloat::ONE),
+    ///     0
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_ulp_diff(self, other: Self) -> u64 {
+        ulp_diff(self.float(), other.float())
+    }
+
+    /// Map the value from `[0, 1]` to the range `[low, high]`, zero goes to `low` and one to
+    /// `high`. It is [`super::rescale`] from `[0, 1]`, the range can be decreasing.
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// let t = ZeroOneBoundedFloat::new_or_bounded(0.25_f64);
+    /// assert_eq!(t.rescale_to(10_f64, 20_f64), 12.5_f64);
+    /// assert_eq!(t.rescale_to(1_f64, -1_f64), 0.5_f64);
+    /// assert_eq!(ZeroOneBoundedFloat::ONE.rescale_to(-3_f64, 7_f64), 7_f64);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rescale_to(self, low: f64, high: f64) -> f64 {
+        super::rescale(self.float(), 0_f64, 1_f64, low, high)
+    }
+
+    /// Get the square of the float, `self * self`. It is always in `[0, 1]` so the result
+    /// is not validated, see [`Self::new_unchecked`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// let half = ZeroOneBoundedFloat::new_or_bounded(0.5_f64);
+    /// assert_eq!(half.squared().float(), 0.25_f64);
+    /// assert_eq!(ZeroOneBoundedFloat::ONE.squared(), ZeroOneBoundedFloat::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn squared(self) -> Self {
+        // SAFETY: the product of two floats in [0, 1] is in [0, 1]
+        unsafe { Self::new_unchecked(self.float() * self.float()) }
+    }
+
+    /// Get the cube of the float, `self * self * self`. It is always in `[0, 1]` so the
+    /// result is not validated, see [`Self::new_unchecked`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// let half = ZeroOneBoundedFloat::new_or_bounded(0.5_f64);
+    /// assert_eq!(half.cubed().float(), 0.125_f64);
+    /// assert_eq!(ZeroOneBoundedFloat::ZERO.cubed(), ZeroOneBoundedFloat::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cubed(self) -> Self {
+        // SAFETY: the product of floats in [0, 1] is in [0, 1]
+        unsafe { Self::new_unchecked(self.float() * self.float() * self.float()) }
+    }
+
+    /// Raise the float to an unsigned integer power by repeated multiplication. Every power
+    /// of a value in `[0, 1]` is in `[0, 1]` so the result is not validated, see
+    /// [`Self::new_unchecked`]. `x.powi_fast(0)` is [`Self::ONE`], including for zero.
+    ///
+    /// It uses exponentiation by squaring, the rounding errors add up so that the result can
+    /// differ by up to about `n` ULPs from [`f64::powf`].
+    ///
+    /// # Example
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// const EIGHTH: ZeroOneBoundedFloat = ZeroOneBoundedFloat::new_or_bounded(0.5_f64).powi_fast(3);
+    /// assert_eq!(EIGHTH.float(), 0.125_f64);
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::ZERO.powi_fast(0),
+    ///     ZeroOneBoundedFloat::ONE
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn powi_fast(self, n: u32) -> Self {
+        // SAFETY: the powers of a float in [0, 1] are in [0, 1]
+        unsafe { Self::new_unchecked(pow_by_squaring(self.float(), n)) }
+    }
+
+    /// Restrict the value to the interval `[min, max]`, like [`Ord::clamp`] but callable without
+    /// the trait in scope. The result is `min` if `min == max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, see [`Self::checked_clamp`] for a non panicking version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    /// # use utils_lib::number::ZeroOneBoundedFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), ZeroOneBoundedFloatConversionError> {
+    /// let min = ZeroOneBoundedFloat::new(0.25_f64)?;
+    /// let max = ZeroOneBoundedFloat::new(0.75_f64)?;
+    /// assert_eq!(ZeroOneBoundedFloat::new(0.125_f64)?.clamp(min, max), min);
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::new(0.5_f64)?.clamp(min, max).float(),
+    ///     0.5_f64
+    /// );
+    /// assert_eq!(ZeroOneBoundedFloat::new(1_f64)?.clamp(min, max), max);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::same_name_method)] // reason = "it mirrors Ord::clamp"
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+
+    /// Restrict the value to the interval `[min, max]`, see [`Self::clamp`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZeroOneBoundedFloatConversionError::InvertedBounds`] if `min > max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    /// # use utils_lib::number::ZeroOneBoundedFloatConversionError;
+    ///
+    /// # fn main() -> Result<(), ZeroOneBoundedFloatConversionError> {
+    /// let min = ZeroOneBoundedFloat::new(0.25_f64)?;
+    /// let max = ZeroOneBoundedFloat::new(0.75_f64)?;
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::new(1_f64)?.checked_clamp(min, max),
+    ///     Ok(max)
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::new(1_f64)?.checked_clamp(max, min),
+    ///     Err(ZeroOneBoundedFloatConversionError::InvertedBounds)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn checked_clamp(self, min: Self, max: Self) -> Result<Self, ConversionError> {
+        if min > max {
+            Err(ConversionError::InvertedBounds)
+        } else {
+            Ok(Ord::clamp(self, min, max))
+        }
+    }
+
     /// Returns the value of the addition of two numbers if it doesn't overflow.
     /// It works in the same spirit as [`Self::checked_sub`] but with the upper bound.
     ///
@@ -450,6 +780,52 @@ impl ZeroOneBoundedFloat {
         Self::new(self.float() / other.float())
     }
 
+    /// Returns the value of the division of two numbers as a [`PositiveFloat`], which can be
+    /// greater than one unlike [`Self::checked_div`]. It is the checked version of the
+    /// operator `/` between two [`ZeroOneBoundedFloat`].
+    ///
+    /// # Errors
+    ///
+    /// - [`PositiveFloatConversionError::Infinity`] if `other` is zero and `self` is not, or
+    ///   if the result overflows
+    /// - [`PositiveFloatConversionError::Nan`] if both are zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::number::PositiveFloatConversionError;
+    /// use utils_lib::{PositiveFloat, ZeroOneBoundedFloat};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let p1 = ZeroOneBoundedFloat::new(0.1_f64)?;
+    /// let p2 = ZeroOneBoundedFloat::new(0.5_f64)?;
+    ///
+    /// assert_eq!(
+    ///     p1.checked_div_positive(p2),
+    ///     Ok(PositiveFloat::new(0.2_f64)?)
+    /// );
+    /// assert_eq!(p2.checked_div_positive(p1), Ok(PositiveFloat::new(5_f64)?));
+    ///
+    /// assert_eq!(
+    ///     p1.checked_div_positive(ZeroOneBoundedFloat::ZERO),
+    ///     Err(PositiveFloatConversionError::Infinity)
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::ZERO.checked_div_positive(ZeroOneBoundedFloat::ZERO),
+    ///     Err(PositiveFloatConversionError::Nan)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn checked_div_positive(
+        self,
+        other: Self,
+    ) -> Result<PositiveFloat, PositiveFloatConversionError> {
+        PositiveFloat::new(self.float() / other.float())
+    }
+
     /// Do the division of two [`ZeroOneBoundedFloat`] saturating at 1.
     /// It works in the same spirit as [`Self::saturating_add`].
     ///
@@ -479,27 +855,292 @@ impl ZeroOneBoundedFloat {
     pub fn saturating_div(self, other: Self) -> Self {
         self.checked_div(other).unwrap_or(Self::ONE)
     }
-}
 
-impl AsRef<f64> for ZeroOneBoundedFloat {
+    /// Returns the remainder of the division of two numbers if the divisor is not zero.
+    /// The remainder of two valid numbers is always valid, it is the one of [`f64`]
+    /// and is in `[0, other)`.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Nan`] if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::number::ZeroOneBoundedFloatConversionError;
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// # fn main() -> Result<(), ZeroOneBoundedFloatConversionError> {
+    /// let p1 = ZeroOneBoundedFloat::new(0.75_f64)?;
+    /// let p2 = ZeroOneBoundedFloat::new(0.5_f64)?;
+    ///
+    /// assert_eq!(p1.checked_rem(p2), Ok(ZeroOneBoundedFloat::new(0.25_f64)?));
+    ///
+    /// assert_eq!(
+    ///     p1.checked_rem(ZeroOneBoundedFloat::ZERO),
+    ///     Err(ZeroOneBoundedFloatConversionError::Nan)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
-    fn as_ref(&self) -> &f64 {
-        &self.0
+    pub fn checked_rem(self, other: Self) -> Result<Self, ConversionError> {
+        Self::new(self.float() % other.float())
     }
-}
 
-/// Error for the conversion form a [`f64`] to a [`ZeroOneBoundedFloat`]
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[non_exhaustive]
-pub enum ConversionError {
-    /// The float is < 0
-    TooLow,
-    /// The float is [`f64::NAN`]
-    Nan,
-    /// The float is too big, > 1
-    TooBig,
-}
+    /// Create a [`ZeroOneBoundedFloat`] from the ratio `numerator / denominator` of two integers.
+    ///
+    /// The ratio is computed with integer arithmetic and the result is the exact ratio
+    /// rounded to the nearest [`f64`], so no precision is lost by converting large
+    /// integers to [`f64`] before the division.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::TooBig`] if `numerator > denominator`
+    /// - [`ConversionError::Nan`] if both `numerator` and `denominator` are zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::number::ZeroOneBoundedFloatConversionError;
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// # fn main() -> Result<(), ZeroOneBoundedFloatConversionError> {
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::from_ratio(3, 4)?,
+    ///     ZeroOneBoundedFloat::new(0.75_f64)?
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::from_ratio(u64::MAX, u64::MAX)?,
+    ///     ZeroOneBoundedFloat::ONE
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::from_ratio(4, 3),
+    ///     Err(ZeroOneBoundedFloatConversionError::TooBig)
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::from_ratio(0, 0),
+    ///     Err(ZeroOneBoundedFloatConversionError::Nan)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_ratio(numerator: u64, denominator: u64) -> Result<Self, ConversionError> {
+        if numerator > denominator {
+            return Err(ConversionError::TooBig);
+        } else if denominator == 0 {
+            // numerator is also 0
+            return Err(ConversionError::Nan);
+        } else if numerator == denominator {
+            return Ok(Self::ONE);
+        } else if numerator == 0 {
+            return Ok(Self::ZERO);
+        }
+
+        // We compute the quotient `numerator * 2^shift / denominator` where the shift is chosen
+        // such that the quotient has at least 63 significant bits. As numerator < denominator
+        // it fits in a u128.
+        let shift = numerator.leading_zeros() + 64;
+        let scaled_numerator = u128::from(numerator) << shift;
+        let denominator = u128::from(denominator);
+        let mut quotient = scaled_numerator / denominator;
+        if !scaled_numerator.is_multiple_of(denominator) {
+            // sticky bit so that the conversion below rounds correctly
+            quotient |= 1;
+        }
+        // 2^-shift, it is a normal float as shift <= 127
+        let scale = f64::from_bits(u64::from(1023 - shift) << 52);
+        #[allow(clippy::cast_precision_loss)] // reason = "the only rounding is the intended one"
+        Ok(Self(quotient as f64 * scale))
+    }
+
+    /// Wrap the result of an operation that always gives a value in `[0, 1]` for a non NaN
+    /// input. The validity is only checked with a debug assertion, see [`Self::new_unchecked`].
+    /// A [`f64::NAN`] (coming from a NaN input) is mapped to [`Self::ZERO`], like
+    /// [`Self::new_or_bounded`].
+    pub(crate) const fn from_bounded_operation(float: f64) -> Self {
+        if float.is_nan() {
+            return Self::ZERO;
+        }
+        // SAFETY: the operation gives a value in [0, 1] as it is not NaN
+        unsafe { Self::new_unchecked(float) }
+    }
+
+    /// The logistic function `1 / (1 + e^-x)`, mapping the real line to `[0, 1]`.
+    /// It is the inverse of [`Self::logit`].
+    ///
+    /// The result is always valid: [`f64::NEG_INFINITY`] gives 0, [`f64::INFINITY`] gives 1
+    /// and large values saturate to the bounds. [`f64::NAN`] gives [`Self::ZERO`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// assert_eq!(ZeroOneBoundedFloat::sigmoid(0_f64).float(), 0.5_f64);
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::sigmoid(f64::INFINITY),
+    ///     ZeroOneBoundedFloat::ONE
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::sigmoid(f64::NEG_INFINITY),
+    ///     ZeroOneBoundedFloat::ZERO
+    /// );
+    /// assert!(
+    ///     (ZeroOneBoundedFloat::sigmoid(2_f64).float() - 0.880_797_077_977_882_4_f64).abs()
+    ///         < 1E-15_f64
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn sigmoid(x: f64) -> Self {
+        // the two forms avoid computing e^x for large positive x which loses precision near 0
+        let float = if x >= 0_f64 {
+            1_f64 / (1_f64 + (-x).exp())
+        } else {
+            let exp = x.exp();
+            exp / (1_f64 + exp)
+        };
+        Self::from_bounded_operation(float)
+    }
+
+    /// The logit function `ln(p / (1 - p))`, the inverse of [`Self::sigmoid`].
+    ///
+    /// The result is [`f64::NEG_INFINITY`] for 0 and [`f64::INFINITY`] for 1. It is never
+    /// [`f64::NAN`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    /// # use utils_lib::number::zero_one_bounded_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// assert_eq!(ZeroOneBoundedFloat::new(0.5_f64)?.logit(), 0_f64);
+    /// assert_eq!(ZeroOneBoundedFloat::ZERO.logit(), f64::NEG_INFINITY);
+    /// assert_eq!(ZeroOneBoundedFloat::ONE.logit(), f64::INFINITY);
+    ///
+    /// let p = ZeroOneBoundedFloat::new(0.3_f64)?;
+    /// assert!((ZeroOneBoundedFloat::sigmoid(p.logit()).float() - 0.3_f64).abs() < 1E-15_f64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn logit(self) -> f64 {
+        self.float().ln() - (-self.float()).ln_1p()
+    }
+
+    /// The smoothstep function `3t^2 - 2t^3` with `t` clamped to `[0, 1]`. It is an
+    /// S-shaped interpolation from 0 to 1 with a zero derivative at both ends.
+    ///
+    /// [`f64::NAN`] gives [`Self::ZERO`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::smoothstep(-1_f64),
+    ///     ZeroOneBoundedFloat::ZERO
+    /// );
+    /// assert_eq!(ZeroOneBoundedFloat::smoothstep(0.5_f64).float(), 0.5_f64);
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::smoothstep(0.25_f64).float(),
+    ///     0.156_25_f64
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::smoothstep(2_f64),
+    ///     ZeroOneBoundedFloat::ONE
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn smoothstep(t: f64) -> Self {
+        let t = t.clamp(0_f64, 1_f64);
+        Self::from_bounded_operation(t * t * mul_add(-2_f64, t, 3_f64))
+    }
+
+    /// Map a real to `[0, 1]` with `(sin(x) + 1) / 2`.
+    ///
+    /// Infinite values and [`f64::NAN`] give [`Self::ZERO`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// use utils_lib::ZeroOneBoundedFloat;
+    ///
+    /// assert_eq!(ZeroOneBoundedFloat::from_unit_sin(0_f64).float(), 0.5_f64);
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::from_unit_sin(FRAC_PI_2),
+    ///     ZeroOneBoundedFloat::ONE
+    /// );
+    /// assert_eq!(
+    ///     ZeroOneBoundedFloat::from_unit_sin(-FRAC_PI_2),
+    ///     ZeroOneBoundedFloat::ZERO
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn from_unit_sin(x: f64) -> Self {
+        Self::from_bounded_operation(x.sin().midpoint(1_f64))
+    }
+
+    /// Get a value implementing [`Display`] rendering the float as a percentage, like `"42.0%"`.
+    /// The precision flag is applied on the percentage and it defaults to one decimal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use utils_lib::ZeroOneBoundedFloat;
+    /// # use utils_lib::number::zero_one_bounded_float::ConversionError;
+    ///
+    /// # fn main() -> Result<(), ConversionError> {
+    /// let float = ZeroOneBoundedFloat::new(0.42_f64)?;
+    /// assert_eq!(float.display_percent().to_string(), "42.0%");
+    /// assert_eq!(format!("{:.2}", float.display_percent()), "42.00%");
+    /// assert_eq!(
+    ///     format!("{:.0}", ZeroOneBoundedFloat::ONE.display_percent()),
+    ///     "100%"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_percent(self) -> impl Display {
+        PercentDisplay::new(self)
+    }
+}
+
+impl AsRef<f64> for ZeroOneBoundedFloat {
+    #[inline]
+    fn as_ref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+/// Error for the conversion form a [`f64`] to a [`ZeroOneBoundedFloat`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// The float is < 0
+    TooLow,
+    /// The float is [`f64::NAN`]
+    Nan,
+    /// The float is too big, > 1
+    TooBig,
+    /// The lower bound of a range is above its upper bound, see [`ZeroOneBoundedFloat::checked_clamp`]
+    InvertedBounds,
+}
 
 impl Display for ConversionError {
     #[inline]
@@ -508,6 +1149,7 @@ impl Display for ConversionError {
             Self::TooBig => write!(f, "the float is above one"),
             Self::Nan => write!(f, "the float is not a number"),
             Self::TooLow => write!(f, "the float is below zero"),
+            Self::InvertedBounds => write!(f, "the minimum is above the maximum"),
         }
     }
 }
@@ -516,7 +1158,7 @@ impl Error for ConversionError {
     #[inline]
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::TooBig | Self::Nan | Self::TooLow => None,
+            Self::TooBig | Self::Nan | Self::TooLow | Self::InvertedBounds => None,
         }
     }
 }
@@ -563,10 +1205,7 @@ impl<'a> From<&'a mut ZeroOneBoundedFloat> for ValidationGuard<'a, ZeroOneBounde
 impl Validation for ZeroOneBoundedFloat {
     #[inline]
     fn validate_data(t: f64) -> bool {
-        matches!(
-            t.classify(),
-            FpCategory::Normal | FpCategory::Subnormal | FpCategory::Zero
-        ) && (0_f64..=1_f64).contains(&t)
+        Self::is_valid(t)
     }
 
     #[inline]
@@ -577,13 +1216,80 @@ impl Validation for ZeroOneBoundedFloat {
             BoundRange::LowerBound | BoundRange::Nan => 0_f64,
         };
     }
+
+    /// Same as [`Self::set_float`], the values below 0 and [`f64::NAN`] are set to 0 and the values above 1 to 1.
+    #[inline]
+    fn set_float_clamped(&mut self, float: f64) {
+        self.set_float(float);
+    }
+
+    /// The invalid values are set to 0, the default value.
+    #[inline]
+    fn set_float_default(&mut self, float: f64) {
+        self.0 = if Self::validate_data(float) {
+            float
+        } else {
+            0_f64
+        };
+    }
 }
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "std")]
+    use std::f64::consts::FRAC_PI_2;
+
     use super::{super::Validation, ConversionError, ZeroOneBoundedFloat};
     use crate::ValidationGuard;
 
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "exact values"
+    fn const_constructors() {
+        const BOUNDED: [ZeroOneBoundedFloat; 4] = [
+            ZeroOneBoundedFloat::new_or_bounded(-1_f64),
+            ZeroOneBoundedFloat::new_or_bounded(0.25_f64),
+            ZeroOneBoundedFloat::new_or_bounded(2_f64),
+            ZeroOneBoundedFloat::new_or_bounded(f64::NAN),
+        ];
+        const DEFAULT: ZeroOneBoundedFloat = ZeroOneBoundedFloat::new_or_default(2_f64);
+        const SATURATING: ZeroOneBoundedFloat = ZeroOneBoundedFloat::new_saturating(2_f64);
+        const OK: Option<ZeroOneBoundedFloat> = ZeroOneBoundedFloat::new_ok(0.75_f64);
+        const NONE: Option<ZeroOneBoundedFloat> = ZeroOneBoundedFloat::new_ok(1.5_f64);
+        const NEW: Result<ZeroOneBoundedFloat, ConversionError> =
+            ZeroOneBoundedFloat::new(-0.5_f64);
+        const FROM: Option<ZeroOneBoundedFloat> = ZeroOneBoundedFloat::from_f64(1_f64);
+
+        assert_eq!(
+            BOUNDED.map(ZeroOneBoundedFloat::float),
+            [0_f64, 0.25_f64, 1_f64, 0_f64]
+        );
+        assert_eq!(DEFAULT, ZeroOneBoundedFloat::ZERO);
+        assert_eq!(SATURATING, ZeroOneBoundedFloat::ONE);
+        assert_eq!(OK.map(ZeroOneBoundedFloat::float), Some(0.75_f64));
+        assert_eq!(NONE, None);
+        assert_eq!(NEW, Err(ConversionError::TooLow));
+        assert_eq!(FROM, Some(ZeroOneBoundedFloat::ONE));
+        for float in [
+            -1_f64,
+            -0_f64,
+            0_f64,
+            0.5_f64,
+            1_f64,
+            1.5_f64,
+            f64::NAN,
+            f64::INFINITY,
+        ] {
+            assert_eq!(
+                ZeroOneBoundedFloat::new_ok(float),
+                ZeroOneBoundedFloat::new(float).ok()
+            );
+            assert_eq!(
+                ZeroOneBoundedFloat::new_or_default(float),
+                ZeroOneBoundedFloat::new(float).unwrap_or_default()
+            );
+        }
+    }
+
     #[test]
     fn zero_one_bounded_float_const() -> Result<(), ConversionError> {
         assert_eq!(
@@ -699,6 +1405,235 @@ mod test {
         Ok(())
     }
 
+    #[allow(clippy::float_cmp)] // reason = "the ratio is correctly rounded so the comparison is exact"
+    #[test]
+    fn from_ratio() -> Result<(), ConversionError> {
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(1, 3)?.float(),
+            1_f64 / 3_f64
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(2, 3)?.float(),
+            2_f64 / 3_f64
+        );
+        assert_eq!(ZeroOneBoundedFloat::from_ratio(1, 2)?.float(), 0.5_f64);
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(0, 1)?,
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(0, u64::MAX)?,
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(u64::MAX, u64::MAX)?,
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(u64::MAX - 1, u64::MAX)?,
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(1, u64::MAX)?.float(),
+            5.421_010_862_427_522e-20_f64
+        );
+        // converting to f64 before the division gives 0.4999999999999999
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio((1 << 53_u32) + 1, (1 << 54_u32) + 3)?.float(),
+            0.5_f64
+        );
+
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(2, 1),
+            Err(ConversionError::TooBig)
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(1, 0),
+            Err(ConversionError::TooBig)
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(u64::MAX, u64::MAX - 1),
+            Err(ConversionError::TooBig)
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_ratio(0, 0),
+            Err(ConversionError::Nan)
+        );
+
+        Ok(())
+    }
+
+    #[allow(clippy::float_cmp)] // reason = "the values are exact"
+    #[test]
+    fn new_unchecked() {
+        for float in [0_f64, -0_f64, 0.5_f64, 1_f64, f64::MIN_POSITIVE] {
+            // SAFETY: the values are valid
+            let unchecked = unsafe { ZeroOneBoundedFloat::new_unchecked(float) };
+            assert_eq!(unchecked.float(), float);
+            assert_eq!(ZeroOneBoundedFloat::new_saturating(float), unchecked);
+        }
+        assert_eq!(
+            ZeroOneBoundedFloat::new_saturating(-1_f64),
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new_saturating(1.5_f64),
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new_saturating(f64::NAN),
+            ZeroOneBoundedFloat::ZERO
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the float is not valid")]
+    fn new_unchecked_invalid() {
+        // SAFETY: it is not safe, but the debug assertion panics before the value is used
+        let _float = unsafe { ZeroOneBoundedFloat::new_unchecked(1.5_f64) };
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::float_cmp)] // reason = "the bounds are exact"
+    #[test]
+    fn sigmoid_logit() -> Result<(), ConversionError> {
+        for i in 1_u32..100_u32 {
+            let p = f64::from(i) / 100_f64;
+            let round_trip = ZeroOneBoundedFloat::sigmoid(ZeroOneBoundedFloat::new(p)?.logit());
+            assert!(
+                (round_trip.float() - p).abs() < 1E-14_f64,
+                "sigmoid(logit({p})) = {round_trip}"
+            );
+        }
+        // the precision is lost near 1 as 1 - p is computed with an absolute error of epsilon
+        for i in -40_i32..=40_i32 {
+            let x = f64::from(i) / 4_f64;
+            let round_trip = ZeroOneBoundedFloat::sigmoid(x).logit();
+            assert!(
+                (round_trip - x).abs() < 1E-10_f64,
+                "logit(sigmoid({x})) = {round_trip}"
+            );
+        }
+
+        // boundaries
+        assert_eq!(ZeroOneBoundedFloat::sigmoid(0_f64).float(), 0.5_f64);
+        assert_eq!(
+            ZeroOneBoundedFloat::sigmoid(f64::INFINITY),
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::sigmoid(1000_f64),
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::sigmoid(f64::NEG_INFINITY),
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::sigmoid(-1000_f64),
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::sigmoid(f64::NAN),
+            ZeroOneBoundedFloat::ZERO
+        );
+        // precision near zero is kept
+        assert!(ZeroOneBoundedFloat::sigmoid(-40_f64).float() > 0_f64);
+
+        assert_eq!(ZeroOneBoundedFloat::ZERO.logit(), f64::NEG_INFINITY);
+        assert_eq!(ZeroOneBoundedFloat::ONE.logit(), f64::INFINITY);
+        assert_eq!(ZeroOneBoundedFloat::new(0.5_f64)?.logit(), 0_f64);
+        assert!(ZeroOneBoundedFloat::new(f64::MIN_POSITIVE)?.logit() < -700_f64);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::float_cmp)] // reason = "the values are exact"
+    #[test]
+    fn smoothstep_sin() {
+        for t in [-1_f64, 0_f64, -0_f64, f64::NEG_INFINITY, f64::NAN] {
+            assert_eq!(
+                ZeroOneBoundedFloat::smoothstep(t),
+                ZeroOneBoundedFloat::ZERO
+            );
+        }
+        for t in [1_f64, 1.5_f64, f64::INFINITY] {
+            assert_eq!(ZeroOneBoundedFloat::smoothstep(t), ZeroOneBoundedFloat::ONE);
+        }
+        assert_eq!(ZeroOneBoundedFloat::smoothstep(0.5_f64).float(), 0.5_f64);
+        let mut previous = ZeroOneBoundedFloat::ZERO;
+        for i in 0_u32..=64_u32 {
+            let value = ZeroOneBoundedFloat::smoothstep(f64::from(i) / 64_f64);
+            assert!(previous <= value, "smoothstep is not increasing");
+            previous = value;
+        }
+
+        assert_eq!(ZeroOneBoundedFloat::from_unit_sin(0_f64).float(), 0.5_f64);
+        assert_eq!(
+            ZeroOneBoundedFloat::from_unit_sin(FRAC_PI_2),
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::from_unit_sin(-FRAC_PI_2),
+            ZeroOneBoundedFloat::ZERO
+        );
+        for x in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            assert_eq!(
+                ZeroOneBoundedFloat::from_unit_sin(x),
+                ZeroOneBoundedFloat::ZERO
+            );
+        }
+        for i in -100_i32..100_i32 {
+            // no panic on the debug assertion
+            let _value = ZeroOneBoundedFloat::from_unit_sin(f64::from(i) * 0.37_f64);
+        }
+    }
+
+    #[test]
+    fn display_percent() -> Result<(), ConversionError> {
+        let third = ZeroOneBoundedFloat::from_ratio(1, 3)?;
+        assert_eq!(third.display_percent().to_string(), "33.3%");
+        assert_eq!(format!("{:.3}", third.display_percent()), "33.333%");
+        assert_eq!(format!("{:.0}", third.display_percent()), "33%");
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO.display_percent().to_string(),
+            "0.0%"
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE.display_percent().to_string(),
+            "100.0%"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn debug() -> Result<(), ConversionError> {
+        assert_eq!(
+            format!("{:?}", ZeroOneBoundedFloat::new(0.5_f64)?),
+            "ZeroOneBoundedFloat(0.5)"
+        );
+        assert_eq!(
+            format!("{:?}", ZeroOneBoundedFloat::ONE),
+            "ZeroOneBoundedFloat(1.0)"
+        );
+        // invalid values can only be built in this module, without the debug assertion
+        assert_eq!(
+            format!("{:?}", ZeroOneBoundedFloat(1.5_f64)),
+            "ZeroOneBoundedFloat(1.5 [INVALID])"
+        );
+        assert_eq!(
+            format!("{:?}", ZeroOneBoundedFloat(-0.5_f64)),
+            "ZeroOneBoundedFloat(-0.5 [INVALID])"
+        );
+        assert_eq!(
+            format!("{:?}", ZeroOneBoundedFloat(f64::NAN)),
+            "ZeroOneBoundedFloat(NaN [INVALID])"
+        );
+        Ok(())
+    }
+
     #[test]
     fn fmt() -> Result<(), ConversionError> {
         assert_eq!(
@@ -733,6 +1668,118 @@ mod test {
             format!("{:.1e}", ZeroOneBoundedFloat::new(1.234_56e-10_f64)?),
             "1.2e-10"
         );
+        assert_eq!(
+            format!("{:>10.3}", ZeroOneBoundedFloat::new(0.234_56_f64)?),
+            "     0.235"
+        );
+        assert_eq!(
+            format!("{:*<9.2}", ZeroOneBoundedFloat::new(0.234_56_f64)?),
+            "0.23*****"
+        );
+        assert_eq!(
+            format!("{:^+9.1}", ZeroOneBoundedFloat::new(0.234_56_f64)?),
+            "  +0.2   "
+        );
+        assert_eq!(
+            format!("{:010.2E}", ZeroOneBoundedFloat::new(1.234_56E-10_f64)?),
+            "001.23E-10"
+        );
+
+        // the flags are applied exactly like on a plain f64
+        for float in [0_f64, 0.234_56_f64, 1_f64, 1.234_56E-10_f64] {
+            let wrapped = ZeroOneBoundedFloat::new(float)?;
+            assert_eq!(format!("{wrapped:>12.4}"), format!("{float:>12.4}"));
+            assert_eq!(format!("{wrapped:#<7}"), format!("{float:#<7}"));
+            assert_eq!(format!("{wrapped:^14.3e}"), format!("{float:^14.3e}"));
+            assert_eq!(format!("{wrapped:+012.1E}"), format!("{float:+012.1E}"));
+        }
         Ok(())
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // reason = "exact values"
+    fn closed_powers() {
+        const CUBE: ZeroOneBoundedFloat = ZeroOneBoundedFloat::new_or_bounded(0.5_f64).cubed();
+        assert_eq!(CUBE.float(), 0.125_f64);
+
+        for float in [
+            0_f64, 1E-3_f64, 0.1_f64, 0.3_f64, 0.5_f64, 0.9_f64, 0.999_f64, 1_f64,
+        ] {
+            let wrapped = ZeroOneBoundedFloat::new_or_bounded(float);
+            assert_eq!(wrapped.squared().float(), float * float);
+            assert_eq!(wrapped.cubed().float(), float * float * float);
+            assert_eq!(wrapped.powi_fast(0), ZeroOneBoundedFloat::ONE);
+            assert_eq!(wrapped.powi_fast(1), wrapped);
+            assert_eq!(wrapped.powi_fast(2), wrapped.squared());
+            for n in [3_u32, 5_u32, 10_u32, 17_u32, 30_u32, 100_u32] {
+                let expected = ZeroOneBoundedFloat::new_or_bounded(float.powf(f64::from(n)));
+                assert!(
+                    wrapped.powi_fast(n).max_ulp_diff(expected) <= u64::from(n),
+                    "{float}^{n}: {} != {expected}",
+                    wrapped.powi_fast(n)
+                );
+            }
+        }
+
+        // boundaries
+        assert_eq!(ZeroOneBoundedFloat::ONE.squared(), ZeroOneBoundedFloat::ONE);
+        assert_eq!(ZeroOneBoundedFloat::ONE.cubed(), ZeroOneBoundedFloat::ONE);
+        assert_eq!(
+            ZeroOneBoundedFloat::ONE.powi_fast(u32::MAX),
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO.squared(),
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(ZeroOneBoundedFloat::ZERO.cubed(), ZeroOneBoundedFloat::ZERO);
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO.powi_fast(0),
+            ZeroOneBoundedFloat::ONE
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::ZERO.powi_fast(u32::MAX),
+            ZeroOneBoundedFloat::ZERO
+        );
+        assert_eq!(
+            ZeroOneBoundedFloat::new_or_bounded(0.5_f64).powi_fast(2000),
+            ZeroOneBoundedFloat::ZERO
+        );
+    }
+
+    #[test]
+    fn clamp() -> Result<(), ConversionError> {
+        let min = ZeroOneBoundedFloat::new(0.25_f64)?;
+        let max = ZeroOneBoundedFloat::new(0.75_f64)?;
+        let inside = ZeroOneBoundedFloat::new(0.5_f64)?;
+
+        assert_eq!(ZeroOneBoundedFloat::ZERO.clamp(min, max), min);
+        assert_eq!(inside.clamp(min, max), inside);
+        assert_eq!(ZeroOneBoundedFloat::ONE.clamp(min, max), max);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(ZeroOneBoundedFloat::ZERO.clamp(inside, inside), inside);
+        assert_eq!(ZeroOneBoundedFloat::ONE.clamp(inside, inside), inside);
+        assert_eq!(
+            inside.clamp(ZeroOneBoundedFloat::ZERO, ZeroOneBoundedFloat::ONE),
+            inside
+        );
+
+        assert_eq!(ZeroOneBoundedFloat::ZERO.checked_clamp(min, max), Ok(min));
+        assert_eq!(inside.checked_clamp(min, max), Ok(inside));
+        assert_eq!(ZeroOneBoundedFloat::ONE.checked_clamp(min, max), Ok(max));
+        assert_eq!(inside.checked_clamp(inside, inside), Ok(inside));
+        assert_eq!(
+            inside.checked_clamp(max, min),
+            Err(ConversionError::InvertedBounds)
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "min <= max")]
+    fn clamp_inverted_bounds() {
+        let _float =
+            ZeroOneBoundedFloat::ONE.clamp(ZeroOneBoundedFloat::ONE, ZeroOneBoundedFloat::ZERO);
+    }
 }
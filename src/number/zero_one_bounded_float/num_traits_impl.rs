@@ -1,9 +1,14 @@
 //! mod to separate the implementation of [`num_traits`] traits for [`ZeroOneBoundedFloat`]
 
+#[cfg(feature = "std")]
+use num_traits::Pow;
 use num_traits::{
-    AsPrimitive, Bounded, CheckedMul, Inv, NumCast, One, Pow, SaturatingMul, ToBytes, ToPrimitive,
+    AsPrimitive, Bounded, CheckedMul, FromPrimitive, Inv, NumCast, One, SaturatingMul, ToBytes,
+    ToPrimitive,
 };
 
+#[cfg(feature = "std")]
+use super::super::pow_unsigned;
 use super::ZeroOneBoundedFloat;
 use crate::PositiveFloat;
 
@@ -76,12 +81,48 @@ impl NumCast for ZeroOneBoundedFloat {
     }
 }
 
+/// The conversions are validating and return [`None`] for values out of the range of the
+/// type. The reverse of [`AsPrimitive`], `AsPrimitive<ZeroOneBoundedFloat>` for the primitive types, is
+/// not implemented as [`AsPrimitive::as_`] cannot fail.
+impl FromPrimitive for ZeroOneBoundedFloat {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::new(n.into()).ok()
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::new(n).ok()
+    }
+}
+
 // impl Unsigned for PositiveFloat {}
 
 // impl Num for PositiveFloat {}
 
 // impl NumOps for PositiveFloat {}
 
+#[cfg(feature = "std")]
 impl Pow<Self> for ZeroOneBoundedFloat {
     // only positive power => it stay between 0 and 1.
     type Output = Self;
@@ -92,6 +133,7 @@ impl Pow<Self> for ZeroOneBoundedFloat {
     }
 }
 
+#[cfg(feature = "std")]
 impl Pow<PositiveFloat> for ZeroOneBoundedFloat {
     // only positive power => it stay between 0 and 1.
     type Output = Self;
@@ -109,6 +151,7 @@ impl Pow<PositiveFloat> for ZeroOneBoundedFloat {
     }
 }
 
+#[cfg(feature = "std")]
 impl Pow<f64> for ZeroOneBoundedFloat {
     // this has the potential to be negative power and therefore be > 1.
     type Output = PositiveFloat;
@@ -119,6 +162,27 @@ impl Pow<f64> for ZeroOneBoundedFloat {
     }
 }
 
+/// Implement [`Pow`] with an unsigned integer exponent for [`ZeroOneBoundedFloat`].
+/// A positive power of a number between 0 and 1 stays between 0 and 1 so the result
+/// is always valid.
+macro_rules! impl_pow_unsigned {
+    ($ty:ty) => {
+        #[cfg(feature = "std")]
+        impl Pow<$ty> for ZeroOneBoundedFloat {
+            type Output = Self;
+
+            #[inline]
+            fn pow(self, rhs: $ty) -> Self::Output {
+                Self(pow_unsigned(self.float(), rhs))
+            }
+        }
+    };
+}
+
+impl_pow_unsigned!(u8);
+impl_pow_unsigned!(u16);
+impl_pow_unsigned!(u32);
+
 impl ToBytes for ZeroOneBoundedFloat {
     type Bytes = <f64 as ToBytes>::Bytes;
 
@@ -193,11 +257,46 @@ impl Inv for ZeroOneBoundedFloat {
 
 #[cfg(test)]
 mod test {
-    use num_traits::{Bounded, CheckedMul, One, SaturatingMul};
+    use num_traits::{Bounded, CheckedMul, FromPrimitive, One, SaturatingMul, ToPrimitive};
 
     use super::ZeroOneBoundedFloat;
     use crate::number::ZeroOneBoundedFloatConversionError;
 
+    /// Convert a value to `T` and back to a [`f64`] through the [`FromPrimitive`] and
+    /// [`ToPrimitive`] traits.
+    fn round_trip<T: FromPrimitive + ToPrimitive>(value: f64) -> Option<f64> {
+        T::from_f64(value)?.to_f64()
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn from_primitive() {
+        assert_eq!(round_trip::<ZeroOneBoundedFloat>(0.25_f64), Some(0.25_f64));
+        assert_eq!(round_trip::<ZeroOneBoundedFloat>(1_f64), Some(1_f64));
+        assert_eq!(round_trip::<ZeroOneBoundedFloat>(1.5_f64), None);
+        assert_eq!(round_trip::<ZeroOneBoundedFloat>(-0.5_f64), None);
+        assert_eq!(round_trip::<ZeroOneBoundedFloat>(f64::NAN), None);
+
+        assert_eq!(
+            ZeroOneBoundedFloat::from_u8(1_u8),
+            Some(ZeroOneBoundedFloat::ONE)
+        );
+        assert_eq!(ZeroOneBoundedFloat::from_u8(2_u8), None);
+        assert_eq!(
+            ZeroOneBoundedFloat::from_i64(0_i64),
+            Some(ZeroOneBoundedFloat::ZERO)
+        );
+        assert_eq!(ZeroOneBoundedFloat::from_i128(-1_i128), None);
+        assert_eq!(
+            ZeroOneBoundedFloat::from_f32(0.75_f32).map(ZeroOneBoundedFloat::float),
+            Some(0.75_f64)
+        );
+        assert_eq!(
+            <ZeroOneBoundedFloat as FromPrimitive>::from_f64(0.5_f64),
+            ZeroOneBoundedFloat::from_f64(0.5_f64)
+        );
+    }
+
     #[allow(clippy::float_cmp)]
     #[test]
     fn zero() {